@@ -0,0 +1,28 @@
+use colored::Colorize;
+use std::fmt::Display;
+use std::io::Write;
+
+use crate::output::is_verbose;
+
+/// Environment variable naming the file that receives the action log
+/// (set by `--log-file`)
+pub const LOG_FILE_ENV: &str = "SKILLSHUB_LOG_FILE";
+
+/// Append a timestamped line to the action log, if one is configured.
+///
+/// Records network requests, retries, git operations, and file changes for
+/// bug reports. Write failures are ignored so logging can't break a command.
+/// With `--verbose` the same line is echoed to stderr.
+pub fn log_action(msg: impl Display) {
+    if is_verbose() {
+        eprintln!("  {} {}", "·".dimmed(), msg);
+    }
+    let Some(path) = std::env::var_os(LOG_FILE_ENV).filter(|p| !p.is_empty()) else {
+        return;
+    };
+    let Ok(mut file) = std::fs::OpenOptions::new().create(true).append(true).open(path) else {
+        return;
+    };
+    let now = chrono::Local::now().format("%Y-%m-%dT%H:%M:%S%.3f");
+    let _ = writeln!(file, "{} {}", now, msg);
+}
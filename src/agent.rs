@@ -1,9 +1,12 @@
+use anyhow::Result;
 use std::path::PathBuf;
 use tabled::Tabled;
 
 use crate::paths::get_home_dir;
+use crate::registry::db::load_db;
 
-/// Agent configuration: (agent_dir, skills_subdir)
+/// Agent configuration: (agent_dir, skills_subdir).
+/// `skillshub agents subdir` overrides the subdirectory per agent.
 pub const KNOWN_AGENTS: &[(&str, &str)] = &[
     (".claude", "skills"),
     (".codex", "skills"),
@@ -25,9 +28,27 @@ pub const KNOWN_AGENTS: &[(&str, &str)] = &[
 ];
 
 /// Discovered agent info
+#[derive(Debug, Clone)]
 pub struct AgentInfo {
     pub path: PathBuf,
-    pub skills_subdir: &'static str,
+    /// Subdirectory skills are linked into, after any `agents subdir` override
+    pub skills_subdir: String,
+}
+
+impl AgentInfo {
+    /// The `skill` or `skills` directory next to the one in use, when both exist.
+    ///
+    /// Agents have switched between the two names, so having both usually means links
+    /// are going to the directory the agent no longer reads.
+    pub fn conflicting_skills_dir(&self) -> Option<PathBuf> {
+        let other = match self.skills_subdir.as_str() {
+            "skills" => "skill",
+            "skill" => "skills",
+            _ => return None,
+        };
+        let other = self.path.join(other);
+        (self.path.join(&self.skills_subdir).is_dir() && other.is_dir()).then_some(other)
+    }
 }
 
 /// Table row for displaying agents
@@ -39,12 +60,15 @@ pub struct AgentRow {
     pub status: &'static str,
     #[tabled(rename = "Skills")]
     pub skills: String,
+    #[tabled(rename = "Subdir")]
+    pub subdir: String,
     #[tabled(rename = "Path")]
     pub path: String,
 }
 
-/// Discover coding agents on the system
+/// Discover coding agents on the system, applying the subdirectory overrides in db.json
 pub fn discover_agents() -> Vec<AgentInfo> {
+    let overrides = load_db().map(|db| db.agent_subdirs).unwrap_or_default();
     let mut agents = Vec::new();
 
     if let Some(home) = get_home_dir() {
@@ -53,7 +77,10 @@ pub fn discover_agents() -> Vec<AgentInfo> {
             if agent_path.exists() && agent_path.is_dir() {
                 agents.push(AgentInfo {
                     path: agent_path,
-                    skills_subdir,
+                    skills_subdir: overrides
+                        .get(*agent_dir)
+                        .cloned()
+                        .unwrap_or_else(|| skills_subdir.to_string()),
                 });
             }
         }
@@ -62,6 +89,48 @@ pub fn discover_agents() -> Vec<AgentInfo> {
     agents
 }
 
+/// The built-in skills subdirectory of a known agent
+pub fn default_subdir(agent_dir: &str) -> Option<&'static str> {
+    KNOWN_AGENTS
+        .iter()
+        .find(|(dir, _)| *dir == agent_dir)
+        .map(|(_, subdir)| *subdir)
+}
+
+/// Normalize an agent name given on the command line (`claude` or `.claude`) to its
+/// directory name, failing for agents skillshub does not know
+pub fn known_agent_dir(name: &str) -> Result<String> {
+    let dir = format!(".{}", name.trim_start_matches('.'));
+    if default_subdir(&dir).is_none() {
+        anyhow::bail!("Unknown agent '{}'. Known agents: {}", name, known_agent_names());
+    }
+    Ok(dir)
+}
+
+/// Discover agents, keeping only the named ones when `names` is non-empty (`--agent`).
+/// Names may omit the leading dot (`claude` or `.claude`). Unknown or missing agents are errors.
+pub fn select_agents(names: &[String]) -> Result<Vec<AgentInfo>> {
+    let discovered = discover_agents();
+    if names.is_empty() {
+        return Ok(discovered);
+    }
+
+    let mut selected: Vec<AgentInfo> = Vec::new();
+    for name in names {
+        let dir = known_agent_dir(name)?;
+        if selected.iter().any(|agent| agent.path.ends_with(&dir)) {
+            continue;
+        }
+        let agent = discovered
+            .iter()
+            .find(|agent| agent.path.ends_with(&dir))
+            .ok_or_else(|| anyhow::anyhow!("Agent '{}' was not found in your home directory", dir))?;
+        selected.push(agent.clone());
+    }
+
+    Ok(selected)
+}
+
 /// Get a comma-separated list of known agent names
 pub fn known_agent_names() -> String {
     KNOWN_AGENTS
@@ -120,4 +189,47 @@ mod tests {
             assert!(agent.path.exists());
         }
     }
+
+    /// Having both `skill` and `skills` under an agent is reported as a conflict
+    #[test]
+    fn test_conflicting_skills_dir() {
+        assert_eq!(default_subdir(".kiro"), Some("steering"));
+        assert_eq!(default_subdir(".notepad"), None);
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let agent = AgentInfo {
+            path: temp.path().to_path_buf(),
+            skills_subdir: "skills".to_string(),
+        };
+        std::fs::create_dir_all(temp.path().join("skills")).unwrap();
+        assert_eq!(agent.conflicting_skills_dir(), None);
+        std::fs::create_dir_all(temp.path().join("skill")).unwrap();
+        assert_eq!(agent.conflicting_skills_dir(), Some(temp.path().join("skill")));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_select_agents() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::fs::create_dir_all(temp.path().join(".claude")).unwrap();
+        std::fs::create_dir_all(temp.path().join(".codex")).unwrap();
+
+        let prev = std::env::var("SKILLSHUB_TEST_HOME").ok();
+        std::env::set_var("SKILLSHUB_TEST_HOME", temp.path());
+        let all = select_agents(&[]).map(|a| a.len());
+        let one = select_agents(&["claude".to_string(), ".claude".to_string()]);
+        let missing = select_agents(&[".cursor".to_string()]);
+        let unknown = select_agents(&["notepad".to_string()]);
+        match prev {
+            Some(v) => std::env::set_var("SKILLSHUB_TEST_HOME", v),
+            None => std::env::remove_var("SKILLSHUB_TEST_HOME"),
+        }
+
+        assert_eq!(all.unwrap(), 2);
+        let one = one.unwrap();
+        assert_eq!(one.len(), 1);
+        assert!(one[0].path.ends_with(".claude"));
+        assert!(missing.unwrap_err().to_string().contains("not found"));
+        assert!(unknown.unwrap_err().to_string().contains("Unknown agent 'notepad'"));
+    }
 }
@@ -1,4 +1,9 @@
 use clap::{Parser, Subcommand, ValueEnum};
+use std::path::PathBuf;
+
+use crate::output::ColorChoice;
+use crate::registry::models::{InstallLayout, SkillSource};
+use crate::registry::tap::TAP_PREVIEW_LIMIT;
 
 /// Skillshub - A package manager for AI coding agent skills
 #[derive(Parser)]
@@ -6,6 +11,48 @@ use clap::{Parser, Subcommand, ValueEnum};
 #[command(author, version, about, long_about = None)]
 #[command(propagate_version = true)]
 pub struct Cli {
+    /// Preview install/uninstall/update/link/tap add without changing files or the database
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+
+    /// Never access the network; use cached registries and local clones only
+    /// (same as SKILLSHUB_OFFLINE=1)
+    #[arg(long, global = true)]
+    pub offline: bool,
+
+    /// Ignore HTTPS_PROXY/HTTP_PROXY/ALL_PROXY for GitHub API requests
+    /// (same as SKILLSHUB_NO_PROXY=1)
+    #[arg(long, global = true)]
+    pub no_proxy: bool,
+
+    /// Refresh tap registries cached longer than this many seconds
+    /// (same as SKILLSHUB_CACHE_TTL_SECS)
+    #[arg(long, global = true, value_name = "SECS")]
+    pub registry_cache_ttl: Option<u64>,
+
+    /// Suppress status output on success; errors and warnings still go to stderr
+    /// (same as SKILLSHUB_QUIET=1)
+    #[arg(long, short, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+
+    /// Also print network requests, git and file operations to stderr
+    /// (same as SKILLSHUB_VERBOSE=1)
+    #[arg(long, short, global = true)]
+    pub verbose: bool,
+
+    /// Append a log of network requests, retries, git and file operations to PATH
+    /// (same as SKILLSHUB_LOG_FILE)
+    #[arg(long, global = true, value_name = "PATH")]
+    pub log_file: Option<PathBuf>,
+
+    /// When to color output (NO_COLOR also turns color off under auto)
+    #[arg(long, global = true, value_enum, value_name = "WHEN", default_value_t = ColorChoice::Auto)]
+    pub color: ColorChoice,
+
+    /// Print errors to stderr as a JSON object instead of text (implied by a command's --json)
+    #[arg(long, global = true)]
+    pub json_errors: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
@@ -13,52 +60,272 @@ pub struct Cli {
 #[derive(Subcommand)]
 pub enum Commands {
     /// Install all skills from all added taps
-    InstallAll,
+    InstallAll {
+        /// Only install skills not installed yet and summarize what was skipped
+        #[arg(long)]
+        only_new: bool,
 
-    /// Install a skill (format: owner/repo/skill[@commit])
+        /// Exit with status 2 when no skill was newly installed
+        #[arg(long)]
+        fail_on_empty: bool,
+    },
+
+    /// Install one or more skills (format: owner/repo/skill[@commit])
     Install {
-        /// Full skill name (e.g., EYH0602/skillshub/using-skillshub)
-        name: String,
+        /// Full skill names (e.g., EYH0602/skillshub/using-skillshub)
+        #[arg(required_unless_present = "from_file", value_name = "NAME")]
+        names: Vec<String>,
+
+        /// Install the skills listed in a file, one per line (blank lines and # comments are skipped)
+        #[arg(long, value_name = "PATH", conflicts_with = "names")]
+        from_file: Option<PathBuf>,
+
+        /// Run the skill's post-install hook (scripts/postinstall.sh or the frontmatter `postinstall` script)
+        #[arg(long)]
+        run_hooks: bool,
+
+        /// Install from this branch instead of the tap's; `update` keeps following it
+        #[arg(long)]
+        branch: Option<String>,
     },
 
-    /// Add a skill directly from a GitHub URL
+    /// Add a skill directly from a GitHub URL or a tarball on disk
     Add {
         /// GitHub folder URL (e.g., https://github.com/user/repo/tree/commit/path/to/skill)
-        url: String,
+        #[arg(required_unless_present = "archive")]
+        url: Option<String>,
+
+        /// Install the skill in a .tar.gz, .tgz, or .tar file as a local skill
+        #[arg(long, value_name = "FILE", conflicts_with = "url")]
+        archive: Option<PathBuf>,
+
+        /// Use this branch instead of the one in the URL
+        #[arg(long, conflicts_with = "archive")]
+        branch: Option<String>,
     },
 
     /// Uninstall a skill (format: owner/repo/skill)
     Uninstall {
         /// Full skill name (e.g., EYH0602/skillshub/using-skillshub)
         name: String,
+
+        /// Keep the tap's directory under ~/.skillshub/skills even if it becomes empty
+        #[arg(long)]
+        keep_empty_taps: bool,
+
+        /// Also forget an external skill of the same name and its synced links; succeeds even
+        /// if some of these are already gone
+        #[arg(long)]
+        purge: bool,
+    },
+
+    /// Reinstall a skill from its recorded source, replacing its directory (format: owner/repo/skill)
+    Reinstall {
+        /// Full skill name (e.g., EYH0602/skillshub/using-skillshub)
+        name: String,
+    },
+
+    /// Link an installed skill into agents under a different name
+    Alias {
+        /// Full skill name (e.g., EYH0602/skillshub/using-skillshub)
+        name: String,
+
+        /// Name to use for the skill's agent links
+        #[arg(required_unless_present = "clear")]
+        alias: Option<String>,
+
+        /// Remove the alias and link the skill under its own name again
+        #[arg(long, conflicts_with = "alias")]
+        clear: bool,
     },
 
     /// Update installed skill(s) to latest version
     Update {
         /// Full skill name to update, or omit to update all
         name: Option<String>,
+
+        /// Only refresh files in ~/.skillshub/skills; skip re-linking agents
+        #[arg(long, conflicts_with = "link")]
+        skill_only: bool,
+
+        /// Re-link agents even if no skill changed
+        #[arg(long)]
+        link: bool,
+
+        /// Only report available updates without downloading anything; exits 1 if any are found
+        #[arg(long, conflicts_with_all = ["skill_only", "link"])]
+        check: bool,
     },
 
     /// List all available skills
-    List,
+    List {
+        /// Only show skills carrying this tag
+        #[arg(long)]
+        tag: Option<String>,
+
+        /// Only show skills from this source
+        #[arg(long, value_enum)]
+        source: Option<SourceFilter>,
+
+        /// Refresh cached tap registries first (same as --registry-cache-ttl 0)
+        #[arg(long)]
+        refresh: bool,
+
+        /// Show at most N rows
+        #[arg(long, value_name = "N")]
+        limit: Option<usize>,
+
+        /// Show full descriptions, wrapped to the terminal width, instead of truncating them
+        #[arg(long, visible_alias = "full")]
+        wrap: bool,
+    },
 
     /// Search for skills across all taps
     Search {
         /// Search query
         query: String,
+
+        /// Typo-tolerant matching on names and descriptions (default)
+        #[arg(long, conflicts_with = "exact")]
+        fuzzy: bool,
+
+        /// Only match exact substrings
+        #[arg(long)]
+        exact: bool,
+
+        /// Only show skills carrying this tag
+        #[arg(long)]
+        tag: Option<String>,
+
+        /// Only show skills that are installed
+        #[arg(long, conflicts_with = "not_installed")]
+        installed: bool,
+
+        /// Only show skills that are not installed yet
+        #[arg(long)]
+        not_installed: bool,
+
+        /// Refresh cached tap registries first (same as --registry-cache-ttl 0)
+        #[arg(long)]
+        refresh: bool,
+
+        /// Show at most N rows
+        #[arg(long, value_name = "N")]
+        limit: Option<usize>,
+
+        /// Show full descriptions, wrapped to the terminal width, instead of truncating them
+        #[arg(long, visible_alias = "full")]
+        wrap: bool,
     },
 
     /// Show detailed information about a skill
     Info {
+        /// Full skill name (e.g., EYH0602/skillshub/using-skillshub) or GitHub URL to a skill directory
+        name: String,
+
+        /// Print the skill info as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// Print a GitHub link to the exact commit the skill was installed from
+        #[arg(long)]
+        show_commit_url: bool,
+
+        /// Show the full commit SHA instead of the 7-character short form
+        #[arg(long)]
+        full_sha: bool,
+
+        /// Show timestamps in RFC 3339 (UTC) instead of `YYYY-MM-DD HH:MM`
+        #[arg(long)]
+        rfc3339: bool,
+    },
+
+    /// Open a skill's homepage or source repository in the browser
+    Open {
         /// Full skill name (e.g., EYH0602/skillshub/using-skillshub)
         name: String,
+
+        /// Print the URL instead of opening it
+        #[arg(long)]
+        print: bool,
+    },
+
+    /// Print the directory a skill is installed in
+    Which {
+        /// Skill name, full (e.g., EYH0602/skillshub/using-skillshub) or bare (e.g., using-skillshub)
+        name: String,
+
+        /// Also print each agent link that points to the skill
+        #[arg(long)]
+        link: bool,
+    },
+
+    /// Show the file structure of an installed skill
+    Tree {
+        /// Skill name, full (e.g., EYH0602/skillshub/using-skillshub) or bare (e.g., using-skillshub)
+        name: String,
+
+        /// Only descend this many levels
+        #[arg(long, value_name = "N")]
+        depth: Option<usize>,
+    },
+
+    /// Show what updating an installed skill would change
+    Diff {
+        /// Full skill name (e.g., owner/repo/skill)
+        name: String,
+
+        /// Only list changed files with their line counts
+        #[arg(long)]
+        stat: bool,
     },
 
     /// Link installed skills to discovered coding agents
-    Link,
+    Link {
+        /// Add the managed links to each agent's .gitignore (or .git/info/exclude)
+        #[arg(long)]
+        git_exclude: bool,
+
+        /// Place the links in this subfolder of each agent's skills directory
+        /// (e.g. community -> ~/.claude/skills/community/<skill>)
+        #[arg(long, visible_alias = "prefix", value_name = "DIR")]
+        into: Option<PathBuf>,
+
+        /// Only link to this agent (repeatable, e.g. --agent .claude)
+        #[arg(long = "agent", value_name = "NAME")]
+        agents: Vec<String>,
+
+        /// Print what was linked per agent as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// Only link skillshub-managed skills; external skills are still tracked but not
+        /// linked into other agents
+        #[arg(long)]
+        no_sync_external: bool,
+
+        /// Re-check every agent, even those unchanged since the last link
+        #[arg(long)]
+        force: bool,
+    },
 
     /// Show which coding agents are detected on this system
-    Agents,
+    #[command(args_conflicts_with_subcommands = true)]
+    Agents {
+        #[command(subcommand)]
+        command: Option<AgentCommands>,
+
+        /// Print each agent's linkage state as a JSON array
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Summarize taps, installed skills, linked agents, and available updates
+    Status {
+        /// Print the summary as a JSON object
+        #[arg(long)]
+        json: bool,
+    },
 
     /// Manage skill taps (repositories)
     #[command(subcommand)]
@@ -72,6 +339,13 @@ pub enum Commands {
     #[command(subcommand)]
     Clean(CleanCommands),
 
+    /// Remove empty directories and leftover temp directories from ~/.skillshub
+    Gc,
+
+    /// Repair agent links in one pass: prune orphaned entries, remove stale links,
+    /// and link every installed skill
+    Relink,
+
     /// Add all taps from a GitHub star list
     StarList {
         /// GitHub star list URL (e.g., https://github.com/stars/user/lists/list-name)
@@ -82,17 +356,72 @@ pub enum Commands {
         install: bool,
     },
 
+    /// Export taps and installed skills to a portable manifest
+    Export {
+        /// File to write the manifest to (prints to stdout if omitted)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Only record installed skills (default)
+        #[arg(long, conflicts_with = "include_available")]
+        installed_only: bool,
+
+        /// Also record the skills offered by configured taps as recommended
+        #[arg(long)]
+        include_available: bool,
+    },
+
+    /// Re-add taps and install skills from a manifest written by `export`
+    Import {
+        /// Manifest file to import
+        file: PathBuf,
+
+        /// Also install the recommended skills recorded with `export --include-available`
+        #[arg(long)]
+        with_recommended: bool,
+    },
+
     /// Run diagnostic checks on your skillshub installation
-    Doctor,
+    Doctor {
+        /// Print the checks as JSON and exit non-zero if any hard check fails
+        #[arg(long)]
+        json: bool,
+    },
 
     /// Migrate old-style installations to the new registry format
-    Migrate,
+    Migrate {
+        /// Move installed skills to this layout: `nested` (skills/<tap>/<skill>) or `flat` (skills/<skill>)
+        #[arg(long, value_enum)]
+        layout: Option<InstallLayout>,
+    },
 
     /// Generate shell completion scripts
     Completions {
         /// Shell to generate completions for
         shell: Shell,
     },
+
+    /// Show the installed version and check GitHub for a newer release
+    Version,
+
+    /// Show the remaining GitHub API quota and when it resets
+    #[command(name = "ratelimit")]
+    RateLimit,
+}
+
+impl Commands {
+    /// Whether the command prints `--json` output, in which case errors are JSON too
+    pub fn wants_json(&self) -> bool {
+        matches!(
+            self,
+            Commands::Info { json: true, .. }
+                | Commands::Link { json: true, .. }
+                | Commands::Agents { json: true, .. }
+                | Commands::Status { json: true }
+                | Commands::Doctor { json: true }
+                | Commands::External(ExternalCommands::List { json: true, .. })
+        )
+    }
 }
 
 /// Supported shells for completion generation
@@ -103,6 +432,27 @@ pub enum Shell {
     Fish,
 }
 
+/// Values of `list --source`
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum SourceFilter {
+    /// Bundled or otherwise copied from the local machine
+    Local,
+    /// Installed from an added tap
+    Remote,
+    /// Added directly from a GitHub or gist URL
+    Url,
+}
+
+impl From<SourceFilter> for SkillSource {
+    fn from(filter: SourceFilter) -> Self {
+        match filter {
+            SourceFilter::Local => SkillSource::Local,
+            SourceFilter::Remote => SkillSource::Remote,
+            SourceFilter::Url => SkillSource::Url,
+        }
+    }
+}
+
 #[derive(Subcommand)]
 pub enum TapCommands {
     /// Add a new tap from a GitHub repository
@@ -117,6 +467,24 @@ pub enum TapCommands {
         /// Clone a specific branch instead of the default
         #[arg(short, long)]
         branch: Option<String>,
+
+        /// Environment variable holding the GitHub token for this tap
+        /// (defaults to GH_TOKEN / GITHUB_TOKEN)
+        #[arg(long, value_name = "VAR")]
+        token_env: Option<String>,
+
+        /// Only discover skills under this folder of the repository
+        #[arg(long, value_name = "DIR")]
+        skills_path: Option<String>,
+
+        /// Read the tap's skills from this JSON registry file in the repository
+        /// instead of discovering SKILL.md files
+        #[arg(long, value_name = "FILE", conflicts_with = "skills_path")]
+        registry_file: Option<String>,
+
+        /// Number of the tap's skills to preview after adding it
+        #[arg(long, value_name = "N", default_value_t = TAP_PREVIEW_LIMIT)]
+        limit: usize,
     },
 
     /// Remove a tap (uninstalls its skills by default)
@@ -132,23 +500,113 @@ pub enum TapCommands {
     /// List configured taps
     List,
 
+    /// Check that every skill in a tap's registry has a readable SKILL.md in the repository
+    Verify {
+        /// Tap name (e.g., owner/repo)
+        name: String,
+    },
+
+    /// Show details for a tap and the skills it offers
+    Info {
+        /// Name of the tap (e.g., EYH0602/skillshub)
+        name: String,
+    },
+
+    /// Re-add the bundled default tap if it was removed or edited, or add a well-known tap
+    /// by its shortname (e.g., anthropics)
+    AddDefault {
+        /// Shortname of a well-known tap; omit to restore the bundled tap
+        shortname: Option<String>,
+    },
+
+    /// Make a tap the default: `install-all` installs its skills first
+    SetDefault {
+        /// Name of the tap (e.g., owner/repo)
+        name: String,
+    },
+
+    /// Rename a tap and move its installed skills (e.g., after a GitHub repo rename)
+    Rename {
+        /// Current name of the tap
+        old: String,
+
+        /// New name of the tap (e.g., owner/new-repo)
+        new: String,
+    },
+
     /// Update tap registry (fetch latest from remote)
     Update {
         /// Name of the tap to update, or omit to update all
         name: Option<String>,
     },
 
+    /// Install one skill from a specific tap
+    Install {
+        /// Name of the tap (e.g., EYH0602/skillshub)
+        tap: String,
+
+        /// Skill name within the tap
+        skill: String,
+
+        /// Run the skill's post-install hook (scripts/postinstall.sh or the frontmatter `postinstall` script)
+        #[arg(long)]
+        run_hooks: bool,
+    },
+
+    /// Uninstall all skills installed from a specific tap
+    UninstallAll {
+        /// Name of the tap to uninstall from (e.g., owner/repo)
+        name: String,
+
+        /// Allow uninstalling the default tap's skills
+        #[arg(long)]
+        include_default: bool,
+    },
+
     /// Install all skills from a specific tap
     InstallAll {
         /// Name of the tap to install from (e.g., EYH0602/skillshub)
         name: String,
+
+        /// Exit with status 2 when no skill was newly installed
+        #[arg(long)]
+        fail_on_empty: bool,
+    },
+}
+
+#[derive(Subcommand)]
+pub enum AgentCommands {
+    /// Change the subdirectory skills are linked into for one agent
+    Subdir {
+        /// Agent directory name (e.g., .opencode)
+        agent: String,
+
+        /// Subdirectory under the agent directory (e.g., skill)
+        #[arg(required_unless_present = "reset")]
+        subdir: Option<String>,
+
+        /// Go back to the built-in subdirectory
+        #[arg(long, conflicts_with = "subdir")]
+        reset: bool,
     },
 }
 
 #[derive(Subcommand)]
 pub enum ExternalCommands {
     /// List all discovered external skills
-    List,
+    List {
+        /// Only show skills found in this agent (e.g., .claude)
+        #[arg(long)]
+        agent: Option<String>,
+
+        /// Only show skills discovered on or after this date (YYYY-MM-DD or RFC 3339)
+        #[arg(long, value_name = "DATE")]
+        since: Option<String>,
+
+        /// Print the skills as a JSON array
+        #[arg(long)]
+        json: bool,
+    },
 
     /// Scan agent directories for external skills
     Scan,
@@ -158,6 +616,12 @@ pub enum ExternalCommands {
         /// Name of the external skill to forget
         name: String,
     },
+
+    /// Copy an external skill into skillshub and manage it as local/<name>
+    Adopt {
+        /// Name of the external skill to adopt
+        name: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -168,10 +632,17 @@ pub enum CleanCommands {
     /// Remove all skillshub-managed symlinks from agent directories
     Links {
         /// Also remove all installed skills from ~/.skillshub/skills
-        #[arg(long)]
+        #[arg(long, conflicts_with = "agents")]
         remove_skills: bool,
+
+        /// Only remove links from this agent (repeatable, e.g. --agent .claude)
+        #[arg(long = "agent", value_name = "NAME")]
+        agents: Vec<String>,
     },
 
+    /// Remove database entries for skills whose directories no longer exist
+    Orphans,
+
     /// Completely remove all skillshub-managed state (full uninstall/purge)
     All {
         /// Skip interactive confirmation prompt (useful for scripts/CI)
@@ -1,43 +1,42 @@
 use anyhow::Result;
 use colored::Colorize;
+use serde::Serialize;
 use std::fs;
+use std::path::PathBuf;
 use tabled::{
     settings::{Padding, Style},
     Table,
 };
 
-use crate::agent::{discover_agents, known_agent_names, AgentRow};
+use crate::agent::{default_subdir, discover_agents, known_agent_dir, known_agent_names, AgentRow};
+use crate::commands::link::is_category_dir;
 use crate::paths::display_path_with_tilde;
-use crate::registry::db::load_db;
+use crate::registry::db::{init_db, load_db, save_db};
 
 /// Count skills in an agent's skills directory
 /// Returns (total, managed_by_skillshub, external)
+///
+/// Category and `link --into` prefix folders are descended into rather than counted.
 fn count_skills_in_dir(skills_path: &std::path::Path, db: &crate::registry::models::Database) -> (usize, usize, usize) {
     if !skills_path.exists() || !skills_path.is_dir() {
         return (0, 0, 0);
     }
 
-    let entries: Vec<_> = match fs::read_dir(skills_path) {
-        Ok(entries) => entries
-            .filter_map(|e| e.ok())
-            .filter(|e| {
-                let path = e.path();
-                // Count directories and symlinks (skills are either real dirs or symlinks)
-                path.is_dir() || path.is_symlink()
-            })
-            .collect(),
-        Err(_) => return (0, 0, 0),
-    };
+    let mut skill_names = Vec::new();
+    collect_skill_entries(skills_path, &mut skill_names);
 
-    let total = entries.len();
+    let total = skill_names.len();
     let mut managed = 0;
     let mut external = 0;
 
-    for entry in entries {
-        let skill_name = entry.file_name().to_string_lossy().to_string();
-
-        // Check if this skill is managed by skillshub (exists in db.installed)
-        let is_managed = db.installed.values().any(|s| s.skill == skill_name);
+    for skill_name in skill_names {
+        // Check if this skill is managed by skillshub (exists in db.installed, possibly
+        // under an alias or a disambiguated link name)
+        let is_managed = db
+            .installed
+            .values()
+            .any(|s| s.skill == skill_name || s.alias.as_deref() == Some(skill_name.as_str()))
+            || db.link_names.values().any(|name| *name == skill_name);
 
         // Check if this skill is tracked as external
         let is_external = db.external.contains_key(&skill_name);
@@ -55,52 +54,111 @@ fn count_skills_in_dir(skills_path: &std::path::Path, db: &crate::registry::mode
     (total, managed, external)
 }
 
-/// Show discovered coding agents
-pub fn show_agents() -> Result<()> {
-    let agents = discover_agents();
+/// Collect the names of skill entries (directories or symlinks) below `dir`,
+/// descending into skillshub-created category and prefix folders
+fn collect_skill_entries(dir: &std::path::Path, names: &mut Vec<String>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
 
-    if agents.is_empty() {
-        println!("No coding agents found.");
-        println!();
-        println!("Looked for: {}", known_agent_names());
-        return Ok(());
+    for entry in entries.flatten() {
+        let path = entry.path();
+        // Count directories and symlinks (skills are either real dirs or symlinks)
+        if !(path.is_dir() || path.is_symlink()) {
+            continue;
+        }
+        if is_category_dir(&path) {
+            collect_skill_entries(&path, names);
+        } else {
+            names.push(entry.file_name().to_string_lossy().to_string());
+        }
     }
+}
+
+/// Linkage state of one detected agent, as printed by `agents --json`
+#[derive(Debug, Serialize)]
+pub struct AgentStatus {
+    /// Agent directory name, e.g. ".claude"
+    pub name: String,
+    /// Whether `skillshub link` has set up this agent
+    pub linked: bool,
+    pub skills_total: usize,
+    /// Skills linked from ~/.skillshub/skills
+    pub skills_managed: usize,
+    /// Everything else in the agent's skills directory
+    pub skills_external: usize,
+    /// Subdirectory skills are linked into (built in, or set with `agents subdir`)
+    pub skills_subdir: String,
+    /// The agent's skills directory
+    pub path: PathBuf,
+    /// A sibling `skill`/`skills` directory that also exists, making the layout ambiguous
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conflicting_dir: Option<PathBuf>,
+}
 
+/// Linkage state of every detected agent
+pub(crate) fn collect_agent_statuses() -> Vec<AgentStatus> {
     // Load database to check which skills are managed
     let db = load_db().unwrap_or_default();
 
-    let rows: Vec<AgentRow> = agents
+    discover_agents()
         .iter()
         .map(|agent| {
-            let agent_name = agent.path.file_name().unwrap().to_string_lossy().to_string();
-            let skills_path = agent.path.join(agent.skills_subdir);
+            let name = agent.path.file_name().unwrap().to_string_lossy().to_string();
+            let path = agent.path.join(&agent.skills_subdir);
+            let (total, managed, external) = count_skills_in_dir(&path, &db);
 
-            // Count skills in the directory
-            let (total, managed, external) = count_skills_in_dir(&skills_path, &db);
+            AgentStatus {
+                linked: db.linked_agents.contains(&name),
+                name,
+                skills_total: total,
+                skills_managed: managed,
+                skills_external: external,
+                skills_subdir: agent.skills_subdir.clone(),
+                path,
+                conflicting_dir: agent.conflicting_skills_dir(),
+            }
+        })
+        .collect()
+}
 
-            // Status is "linked" if the agent is recorded in the database
-            let status = if db.linked_agents.contains(&agent_name) {
-                "✓ linked"
-            } else {
-                "○ not linked"
-            };
+/// Show discovered coding agents as a table, or as a JSON array with `json`
+pub fn show_agents(json: bool) -> Result<()> {
+    let statuses = collect_agent_statuses();
 
+    if json {
+        println!("{}", serde_json::to_string_pretty(&statuses)?);
+        return Ok(());
+    }
+
+    if statuses.is_empty() {
+        println!("No coding agents found.");
+        println!();
+        println!("Looked for: {}", known_agent_names());
+        return Ok(());
+    }
+
+    let rows: Vec<AgentRow> = statuses
+        .iter()
+        .map(|status| {
             // Format skills column: show count or "-" if not linked
-            let skills = if db.linked_agents.contains(&agent_name) {
-                if total > 0 {
-                    format!("{} ({} managed, {} other)", total, managed, external)
-                } else {
-                    "0".to_string()
-                }
-            } else {
+            let skills = if !status.linked {
                 "-".to_string()
+            } else if status.skills_total > 0 {
+                format!(
+                    "{} ({} managed, {} other)",
+                    status.skills_total, status.skills_managed, status.skills_external
+                )
+            } else {
+                "0".to_string()
             };
 
             AgentRow {
-                name: agent_name,
-                status,
+                name: status.name.clone(),
+                status: if status.linked { "✓ linked" } else { "○ not linked" },
                 skills,
-                path: display_path_with_tilde(&skills_path),
+                subdir: status.skills_subdir.clone(),
+                path: display_path_with_tilde(&status.path),
             }
         })
         .collect();
@@ -111,6 +169,19 @@ pub fn show_agents() -> Result<()> {
         .to_string();
 
     println!("{}", table);
+    for status in &statuses {
+        if let Some(other) = &status.conflicting_dir {
+            println!(
+                "{} {} has both {} and {}; skills are linked into {} (change with {})",
+                "!".yellow(),
+                status.name,
+                display_path_with_tilde(&status.path),
+                display_path_with_tilde(other),
+                status.skills_subdir,
+                format!("skillshub agents subdir {} <dir>", status.name).bold()
+            );
+        }
+    }
     println!();
     println!(
         "{} Run {} to link skills to agents",
@@ -120,3 +191,170 @@ pub fn show_agents() -> Result<()> {
 
     Ok(())
 }
+
+/// Set (or with `None`, reset) the subdirectory skills are linked into for `agent`.
+///
+/// Existing links in the previous directory are left alone; `skillshub link` creates
+/// links in the new one.
+pub fn set_agent_subdir(agent: &str, subdir: Option<&str>) -> Result<()> {
+    let agent = known_agent_dir(agent)?;
+    let default = default_subdir(&agent).unwrap_or("skills");
+    let subdir = subdir.map(|s| s.trim().trim_end_matches('/')).filter(|s| *s != default);
+    if let Some(subdir) = subdir {
+        let mut components = std::path::Path::new(subdir).components();
+        let single_dir = matches!(
+            (components.next(), components.next()),
+            (Some(std::path::Component::Normal(_)), None)
+        );
+        if !single_dir {
+            anyhow::bail!(
+                "Invalid subdirectory '{}': use a single directory name such as 'skills'",
+                subdir
+            );
+        }
+    }
+
+    let mut db = init_db()?;
+    match subdir {
+        Some(subdir) => {
+            db.agent_subdirs.insert(agent.clone(), subdir.to_string());
+        }
+        None => {
+            db.agent_subdirs.remove(&agent);
+        }
+    }
+    save_db(&db)?;
+
+    let effective = subdir.unwrap_or(default);
+    println!(
+        "{} {} now links skills into {}/{}",
+        "✓".green(),
+        agent,
+        agent,
+        effective
+    );
+    println!(
+        "{} Run {} to link skills there; links in the old directory are left as they are",
+        "Tip:".cyan(),
+        "skillshub link".bold()
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::models::{Database, InstalledSkill};
+    use tempfile::TempDir;
+
+    #[test]
+    #[cfg(unix)]
+    fn test_count_skills_in_dir_descends_into_prefix_folders() {
+        let temp = TempDir::new().unwrap();
+        let target = temp.path().join("store/reviewer");
+        fs::create_dir_all(&target).unwrap();
+
+        let skills_path = temp.path().join("agent/skills");
+        fs::create_dir_all(skills_path.join("community/review")).unwrap();
+        std::os::unix::fs::symlink(&target, skills_path.join("community/review/reviewer")).unwrap();
+        fs::create_dir_all(skills_path.join("my-own")).unwrap();
+        fs::write(skills_path.join("my-own/SKILL.md"), "---\nname: my-own\n---\n").unwrap();
+
+        let mut db = Database::default();
+        db.installed.insert(
+            "tap/reviewer".to_string(),
+            InstalledSkill {
+                tap: "tap".to_string(),
+                skill: "reviewer".to_string(),
+                commit: None,
+                installed_at: chrono::Utc::now(),
+                source_url: None,
+                source_path: None,
+                gist_updated_at: None,
+                content_sha256: None,
+                size_bytes: None,
+                alias: None,
+                branch: None,
+            },
+        );
+
+        assert_eq!(count_skills_in_dir(&skills_path, &db), (2, 1, 1));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_collect_agent_statuses_json_shape() {
+        let temp = TempDir::new().unwrap();
+        let home = temp.path();
+        fs::create_dir_all(home.join(".claude/skills/my-own")).unwrap();
+        fs::create_dir_all(home.join(".codex")).unwrap();
+        fs::create_dir_all(home.join(".skillshub")).unwrap();
+        fs::write(
+            home.join(".skillshub/db.json"),
+            r#"{"taps":{},"installed":{},"linked_agents":[".claude"],"external":{}}"#,
+        )
+        .unwrap();
+
+        let prev = std::env::var("SKILLSHUB_TEST_HOME").ok();
+        std::env::set_var("SKILLSHUB_TEST_HOME", home);
+        let statuses = collect_agent_statuses();
+        match prev {
+            Some(v) => std::env::set_var("SKILLSHUB_TEST_HOME", v),
+            None => std::env::remove_var("SKILLSHUB_TEST_HOME"),
+        }
+
+        let json = serde_json::to_value(&statuses).unwrap();
+        let claude = json
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|a| a["name"] == ".claude")
+            .unwrap();
+        assert_eq!(claude["linked"], true);
+        assert_eq!(claude["skills_total"], 1);
+        assert_eq!(claude["skills_managed"], 0);
+        assert_eq!(claude["skills_external"], 1);
+        assert_eq!(claude["path"], home.join(".claude/skills").display().to_string());
+
+        let codex = json.as_array().unwrap().iter().find(|a| a["name"] == ".codex").unwrap();
+        assert_eq!(codex["linked"], false);
+        assert_eq!(codex["skills_total"], 0);
+        assert_eq!(codex["skills_subdir"], "skills");
+    }
+
+    /// An override moves the agent's skills directory until it is reset, and a
+    /// nested path is rejected
+    #[test]
+    #[serial_test::serial]
+    fn test_set_agent_subdir_overrides_and_resets() {
+        let temp = TempDir::new().unwrap();
+        let home = temp.path();
+        fs::create_dir_all(home.join(".claude/skills")).unwrap();
+        fs::create_dir_all(home.join(".claude/skill")).unwrap();
+
+        let prev = std::env::var("SKILLSHUB_TEST_HOME").ok();
+        std::env::set_var("SKILLSHUB_TEST_HOME", home);
+        let before = collect_agent_statuses();
+        let set = set_agent_subdir("claude", Some("skill"));
+        let overridden = collect_agent_statuses();
+        let nested = set_agent_subdir(".claude", Some("a/b"));
+        let reset = set_agent_subdir(".claude", None);
+        let after = collect_agent_statuses();
+        let db = load_db().unwrap();
+        match prev {
+            Some(v) => std::env::set_var("SKILLSHUB_TEST_HOME", v),
+            None => std::env::remove_var("SKILLSHUB_TEST_HOME"),
+        }
+
+        assert_eq!(before[0].path, home.join(".claude/skills"));
+        assert_eq!(before[0].conflicting_dir, Some(home.join(".claude/skill")));
+        set.unwrap();
+        assert_eq!(overridden[0].skills_subdir, "skill");
+        assert_eq!(overridden[0].path, home.join(".claude/skill"));
+        assert!(nested.unwrap_err().to_string().contains("Invalid subdirectory"));
+        reset.unwrap();
+        assert_eq!(after[0].skills_subdir, "skills");
+        assert!(db.agent_subdirs.is_empty());
+    }
+}
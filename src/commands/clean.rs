@@ -1,12 +1,14 @@
-use anyhow::{Context, Result};
+use anyhow::Result;
 use colored::Colorize;
 use std::fs;
 use std::io::{self, BufRead, Write};
 use std::path::Path;
 
-use crate::agent::{discover_agents, AgentInfo};
-use crate::paths::{display_path_with_tilde, get_home_dir, get_skills_install_dir, get_skillshub_home};
+use crate::agent::{discover_agents, select_agents, AgentInfo};
+use crate::commands::link::COPY_MARKER;
+use crate::paths::{display_path_with_tilde, get_skills_install_dir, get_skillshub_home};
 use crate::registry::db::{get_db_path, init_db, save_db};
+use crate::registry::models::Database;
 
 /// Clear cached registry data from all taps
 pub fn clean_cache() -> Result<()> {
@@ -35,7 +37,171 @@ pub fn clean_cache() -> Result<()> {
     Ok(())
 }
 
+/// Remove skillshub-managed entries inside `dir`.
+/// Category and `link --into` prefix folders (real directories without a SKILL.md) are
+/// scanned recursively and removed once emptied. Returns the number of entries removed.
+fn remove_managed_entries(dir: &Path, skills_dir_canonical: &Path) -> usize {
+    let mut removed_count = 0;
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return 0;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+
+        // Fallback copies made when symlinks and junctions were unavailable
+        if !path.is_symlink() && path.join(COPY_MARKER).is_file() {
+            if is_skillshub_managed_copy(&path, skills_dir_canonical) {
+                if let Err(e) = fs::remove_dir_all(&path) {
+                    eprintln!("  {} Failed to remove {}: {}", "!".red(), path.display(), e);
+                } else {
+                    removed_count += 1;
+                }
+            }
+            continue;
+        }
+
+        // Category and prefix folders (real directories without a SKILL.md) hold nested links
+        if path.is_dir() && !path.is_symlink() && !path.join("SKILL.md").exists() {
+            let nested = remove_managed_entries(&path, skills_dir_canonical);
+            if nested > 0 && fs::read_dir(&path).map(|mut e| e.next().is_none()).unwrap_or(false) {
+                let _ = fs::remove_dir(&path);
+            }
+            removed_count += nested;
+            continue;
+        }
+
+        // Only process symlinks (junctions also report as symlinks on Windows)
+        if !path.is_symlink() {
+            continue;
+        }
+
+        // Check if symlink points to skillshub-managed directory
+        if is_skillshub_managed_link(&path, skills_dir_canonical) {
+            if let Err(e) = remove_link(&path) {
+                eprintln!("  {} Failed to remove {}: {}", "!".red(), path.display(), e);
+            } else {
+                removed_count += 1;
+            }
+        }
+    }
+
+    removed_count
+}
+
+/// Remove broken skillshub-managed links inside `dir`: links into the skills directory
+/// whose skill is gone, links to an external skill source in `external_sources` that no
+/// longer exists, and fallback copies whose source was removed. Category and prefix
+/// folders are scanned recursively and removed once emptied. Returns the paths removed.
+pub(crate) fn remove_stale_links(
+    dir: &Path,
+    skills_dir_canonical: &Path,
+    external_sources: &[std::path::PathBuf],
+) -> Vec<std::path::PathBuf> {
+    let mut removed = Vec::new();
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return removed;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+
+        if path.is_symlink() {
+            if path.exists() {
+                continue;
+            }
+            let to_external = fs::read_link(&path)
+                .map(|target| external_sources.contains(&normalize_link_target(target)))
+                .unwrap_or(false);
+            if to_external || is_skillshub_managed_link(&path, skills_dir_canonical) {
+                match remove_link(&path) {
+                    Ok(()) => removed.push(path),
+                    Err(e) => eprintln!("  {} Failed to remove {}: {}", "!".red(), path.display(), e),
+                }
+            }
+            continue;
+        }
+
+        let marker = path.join(COPY_MARKER);
+        if marker.is_file() {
+            let source_gone = fs::read_to_string(&marker)
+                .map(|source| !Path::new(source.trim()).exists())
+                .unwrap_or(false);
+            if source_gone && is_skillshub_managed_copy(&path, skills_dir_canonical) {
+                match fs::remove_dir_all(&path) {
+                    Ok(()) => removed.push(path),
+                    Err(e) => eprintln!("  {} Failed to remove {}: {}", "!".red(), path.display(), e),
+                }
+            }
+            continue;
+        }
+
+        // Category and prefix folders (real directories without a SKILL.md) hold nested links
+        if path.is_dir() && !path.join("SKILL.md").exists() {
+            let nested = remove_stale_links(&path, skills_dir_canonical, external_sources);
+            if !nested.is_empty() && fs::read_dir(&path).map(|mut e| e.next().is_none()).unwrap_or(false) {
+                let _ = fs::remove_dir(&path);
+            }
+            removed.extend(nested);
+        }
+    }
+
+    removed
+}
+
+/// Find agent links and fallback copies that point at `skill_dir` (canonical) in every
+/// detected agent, including inside category and prefix folders
+pub(crate) fn find_agent_links_to(skill_dir: &Path) -> Vec<std::path::PathBuf> {
+    let mut found = Vec::new();
+    for agent in discover_agents() {
+        collect_links_to(&agent.path.join(&agent.skills_subdir), skill_dir, &mut found);
+    }
+    found
+}
+
+fn collect_links_to(dir: &Path, skill_dir: &Path, found: &mut Vec<std::path::PathBuf>) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_symlink() {
+            if is_skillshub_managed_link(&path, skill_dir) {
+                found.push(path);
+            }
+        } else if path.join(COPY_MARKER).is_file() {
+            if is_skillshub_managed_copy(&path, skill_dir) {
+                found.push(path);
+            }
+        } else if path.is_dir() && !path.join("SKILL.md").exists() {
+            collect_links_to(&path, skill_dir, found);
+        }
+    }
+}
+
+/// Remove agent links and fallback copies, warning on failures. Returns how many were removed.
+pub(crate) fn remove_agent_links(paths: &[std::path::PathBuf]) -> usize {
+    let mut removed = 0;
+    for path in paths {
+        let result = if path.is_symlink() {
+            remove_link(path)
+        } else {
+            fs::remove_dir_all(path)
+        };
+        match result {
+            Ok(()) => removed += 1,
+            Err(e) => eprintln!("  {} Failed to remove {}: {}", "!".yellow(), path.display(), e),
+        }
+    }
+    removed
+}
+
 /// Remove all skillshub-managed symlinks from all detected agent directories.
+/// Windows junctions, marked fallback copies, and links nested in category or prefix
+/// folders are removed as well.
 /// Returns the total number of symlinks removed.
 fn remove_managed_symlinks(agents: &[AgentInfo], skills_dir_canonical: &Path) -> usize {
     let mut total_removed = 0;
@@ -46,34 +212,13 @@ fn remove_managed_symlinks(agents: &[AgentInfo], skills_dir_canonical: &Path) ->
             .file_name()
             .map(|n| n.to_string_lossy().into_owned())
             .unwrap_or_else(|| agent.path.display().to_string());
-        let skills_path = agent.path.join(agent.skills_subdir);
+        let skills_path = agent.path.join(&agent.skills_subdir);
 
         if !skills_path.exists() {
             continue;
         }
 
-        let mut removed_count = 0;
-
-        // Scan entries in the agent's skills directory
-        if let Ok(entries) = fs::read_dir(&skills_path) {
-            for entry in entries.flatten() {
-                let path = entry.path();
-
-                // Only process symlinks
-                if !path.is_symlink() {
-                    continue;
-                }
-
-                // Check if symlink points to skillshub-managed directory
-                if is_skillshub_managed_link(&path, skills_dir_canonical) {
-                    if let Err(e) = fs::remove_file(&path) {
-                        eprintln!("  {} Failed to remove {}: {}", "!".red(), path.display(), e);
-                    } else {
-                        removed_count += 1;
-                    }
-                }
-            }
-        }
+        let removed_count = remove_managed_entries(&skills_path, skills_dir_canonical);
 
         if removed_count > 0 {
             println!("  {} {} (removed {} link(s))", "✓".green(), agent_name, removed_count);
@@ -85,13 +230,14 @@ fn remove_managed_symlinks(agents: &[AgentInfo], skills_dir_canonical: &Path) ->
 }
 
 /// Remove all skillshub-managed symlinks from agent directories
-/// If remove_skills is true, also delete all installed skills
-pub fn clean_links(remove_skills: bool) -> Result<()> {
+/// If remove_skills is true, also delete all installed skills.
+/// A non-empty `agent_names` (`--agent`) only unlinks those agents.
+pub fn clean_links(remove_skills: bool, agent_names: &[String]) -> Result<()> {
     let mut db = init_db()?;
     let skills_dir = get_skills_install_dir()?;
     let skills_dir_canonical = skills_dir.canonicalize().unwrap_or_else(|_| skills_dir.clone());
 
-    let agents = discover_agents();
+    let agents = select_agents(agent_names)?;
 
     if agents.is_empty() {
         println!("{} No coding agents found", "Info:".cyan());
@@ -106,8 +252,20 @@ pub fn clean_links(remove_skills: bool) -> Result<()> {
 
     let total_removed = remove_managed_symlinks(&agents, &skills_dir_canonical);
 
-    // Clear linked_agents from database
-    db.linked_agents.clear();
+    // Clear linked_agents and disambiguated link names from database. Link names are
+    // shared by all agents, so they are kept while other agents remain linked.
+    if agent_names.is_empty() {
+        db.linked_agents.clear();
+        db.link_states.clear();
+        db.link_names.clear();
+    } else {
+        for agent in &agents {
+            if let Some(name) = agent.path.file_name() {
+                db.linked_agents.remove(name.to_string_lossy().as_ref());
+                db.link_states.remove(name.to_string_lossy().as_ref());
+            }
+        }
+    }
 
     if remove_skills {
         // Also remove all installed skills
@@ -156,6 +314,79 @@ pub fn clean_links(remove_skills: bool) -> Result<()> {
     Ok(())
 }
 
+/// Remove `db.json` entries whose directories no longer exist: installed skills missing
+/// from the install dir, and external skills whose source path is gone
+pub fn clean_orphans() -> Result<()> {
+    let mut db = init_db()?;
+    let skills_dir = get_skills_install_dir()?;
+
+    println!(
+        "{} Checking {} installed and {} external skill(s) for missing directories",
+        "=>".green().bold(),
+        db.installed.len(),
+        db.external.len()
+    );
+
+    let removed = prune_orphans(&mut db, &skills_dir);
+
+    if removed.is_empty() {
+        println!("{} No orphaned entries found", "Info:".cyan());
+        return Ok(());
+    }
+
+    save_db(&db)?;
+    println!(
+        "\n{} Removed {} orphaned entr{}",
+        "Done!".green().bold(),
+        removed.len(),
+        if removed.len() == 1 { "y" } else { "ies" }
+    );
+
+    Ok(())
+}
+
+/// Drop orphaned installed and external entries from `db`, printing each one.
+/// Returns the names of the removed entries.
+pub(crate) fn prune_orphans(db: &mut Database, skills_dir: &Path) -> Vec<String> {
+    let mut removed = Vec::new();
+
+    let mut missing: Vec<(String, std::path::PathBuf)> = db
+        .installed
+        .iter()
+        .map(|(name, skill)| (name.clone(), db.skill_dir(skills_dir, &skill.tap, &skill.skill)))
+        .filter(|(_, dir)| !dir.exists())
+        .collect();
+    missing.sort();
+
+    for (name, dir) in missing {
+        db.installed.remove(&name);
+        db.link_names.remove(&name);
+        println!("  {} {} (missing {})", "✓".green(), name, display_path_with_tilde(&dir));
+        removed.push(name);
+    }
+
+    let mut missing_external: Vec<(String, std::path::PathBuf)> = db
+        .external
+        .iter()
+        .filter(|(_, ext)| !ext.source_path.exists())
+        .map(|(name, ext)| (name.clone(), ext.source_path.clone()))
+        .collect();
+    missing_external.sort();
+
+    for (name, path) in missing_external {
+        db.external.remove(&name);
+        println!(
+            "  {} {} (external, missing {})",
+            "✓".green(),
+            name,
+            display_path_with_tilde(&path)
+        );
+        removed.push(name);
+    }
+
+    removed
+}
+
 /// Completely remove all skillshub-managed state (full uninstall/purge).
 /// Removes all managed symlinks from agent directories, then deletes ~/.skillshub/ entirely.
 /// If confirm is false, prints a summary and prompts the user to type 'yes' before proceeding.
@@ -190,7 +421,7 @@ fn clean_all_with_input(confirm: bool, input: &mut impl BufRead) -> Result<()> {
                 .file_name()
                 .map(|n| n.to_string_lossy().into_owned())
                 .unwrap_or_else(|| agent.path.display().to_string());
-            let skills_path = agent.path.join(agent.skills_subdir);
+            let skills_path = agent.path.join(&agent.skills_subdir);
             println!("      {} ({})", agent_name, display_path_with_tilde(&skills_path));
         }
         println!("  - Installed skills: {}", display_path_with_tilde(&skills_dir));
@@ -222,12 +453,11 @@ fn clean_all_with_input(confirm: bool, input: &mut impl BufRead) -> Result<()> {
     println!("{} Starting full uninstall...", "=>".green().bold());
 
     // --- Remove symlinks ---
-    // Derive canonical skills path from the home directory (which should exist)
-    // rather than canonicalizing the skills dir itself, which may not exist in a
+    // Derive canonical skills path from the skillshub home rather than
+    // canonicalizing the skills dir itself, which may not exist in a
     // partially-cleaned state.
-    let home = get_home_dir().context("Could not determine home directory")?;
-    let home_canonical = home.canonicalize().unwrap_or_else(|_| home.clone());
-    let skills_dir_canonical = home_canonical.join(".skillshub").join("skills");
+    let skillshub_home_canonical = skillshub_home.canonicalize().unwrap_or_else(|_| skillshub_home.clone());
+    let skills_dir_canonical = skillshub_home_canonical.join("skills");
 
     println!("  {} Removing skillshub-managed symlinks...", "=>".green().bold());
     let total_removed = remove_managed_symlinks(&agents, &skills_dir_canonical);
@@ -238,6 +468,7 @@ fn clean_all_with_input(confirm: bool, input: &mut impl BufRead) -> Result<()> {
     // partway (e.g. permission error).
     if let Ok(mut db) = init_db() {
         db.linked_agents.clear();
+        db.link_states.clear();
         db.installed.clear();
         let _ = save_db(&db);
     }
@@ -269,9 +500,36 @@ fn clean_all_with_input(confirm: bool, input: &mut impl BufRead) -> Result<()> {
     Ok(())
 }
 
-/// Check if a symlink points to a skillshub-managed directory
+/// Remove a symlink or junction without touching its target.
+/// Directory links on Windows must be removed with `remove_dir`.
+fn remove_link(path: &Path) -> io::Result<()> {
+    fs::remove_file(path).or_else(|_| fs::remove_dir(path))
+}
+
+/// Normalize a Windows junction target (`\??\C:\...`) to a verbatim path (`\\?\C:\...`)
+fn normalize_link_target(target: std::path::PathBuf) -> std::path::PathBuf {
+    match target.to_str().and_then(|s| s.strip_prefix(r"\??\")) {
+        Some(rest) => std::path::PathBuf::from(format!(r"\\?\{}", rest)),
+        None => target,
+    }
+}
+
+/// Check if a fallback copy was made from a skillshub-managed directory.
+/// The marker file records the source path the copy was taken from.
+fn is_skillshub_managed_copy(copy_path: &Path, skillshub_skills_dir: &Path) -> bool {
+    let Ok(source) = fs::read_to_string(copy_path.join(COPY_MARKER)) else {
+        return false;
+    };
+    let source = std::path::PathBuf::from(source.trim());
+    let source = source.canonicalize().unwrap_or(source);
+    source.starts_with(skillshub_skills_dir)
+}
+
+/// Check if a symlink (or Windows junction) points to a skillshub-managed directory
 fn is_skillshub_managed_link(link_path: &Path, skillshub_skills_dir: &Path) -> bool {
     if let Ok(target) = fs::read_link(link_path) {
+        let target = normalize_link_target(target);
+
         // Resolve the target path (handle relative symlinks)
         let resolved = if target.is_absolute() {
             target
@@ -328,6 +586,63 @@ mod tests {
         }
     }
 
+    // ---------------------------------------------------------------------------
+    // clean_orphans tests
+    // ---------------------------------------------------------------------------
+
+    /// Entries whose directories are gone are pruned; everything else is kept.
+    #[test]
+    #[serial]
+    fn test_clean_orphans_prunes_missing_entries() {
+        let temp = TempDir::new().unwrap();
+        let home = temp.path().join("home");
+        let skillshub_home = home.join(".skillshub");
+        fs::create_dir_all(skillshub_home.join("skills/owner/repo/present")).unwrap();
+        let external_dir = home.join(".claude/skills/ext-present");
+        fs::create_dir_all(&external_dir).unwrap();
+
+        let installed = |skill: &str| {
+            serde_json::json!({
+                "tap": "owner/repo",
+                "skill": skill,
+                "commit": "abc1234",
+                "installed_at": "2026-01-01T00:00:00Z"
+            })
+        };
+        let external = |name: &str, path: &std::path::Path| {
+            serde_json::json!({
+                "name": name,
+                "source_agent": ".claude",
+                "source_path": path,
+                "discovered_at": "2026-01-01T00:00:00Z"
+            })
+        };
+        let db_json = serde_json::json!({
+            "taps": {},
+            "installed": {
+                "owner/repo/present": installed("present"),
+                "owner/repo/deleted": installed("deleted")
+            },
+            "linked_agents": [],
+            "external": {
+                "ext-present": external("ext-present", &external_dir),
+                "ext-gone": external("ext-gone", &home.join(".claude/skills/ext-gone"))
+            },
+            "link_names": { "owner/repo/deleted": "deleted@owner" }
+        });
+        fs::write(skillshub_home.join("db.json"), db_json.to_string()).unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        clean_orphans().unwrap();
+
+        let db = init_db().unwrap();
+        assert!(db.installed.contains_key("owner/repo/present"));
+        assert!(!db.installed.contains_key("owner/repo/deleted"));
+        assert!(db.external.contains_key("ext-present"));
+        assert!(!db.external.contains_key("ext-gone"));
+        assert!(db.link_names.is_empty());
+    }
+
     // ---------------------------------------------------------------------------
     // clean_all tests
     // ---------------------------------------------------------------------------
@@ -590,4 +905,89 @@ mod tests {
         // Regular directory, not a symlink
         assert!(!is_skillshub_managed_link(&regular_dir, &canonical));
     }
+
+    #[test]
+    fn test_normalize_link_target_junction_prefix() {
+        assert_eq!(
+            normalize_link_target(std::path::PathBuf::from(r"\??\C:\Users\me\.skillshub\skills\tap\skill")),
+            std::path::PathBuf::from(r"\\?\C:\Users\me\.skillshub\skills\tap\skill")
+        );
+        let plain = std::path::PathBuf::from("/home/me/.skillshub/skills/tap/skill");
+        assert_eq!(normalize_link_target(plain.clone()), plain);
+    }
+
+    #[test]
+    fn test_remove_managed_symlinks_removes_marked_copies() {
+        let temp = TempDir::new().unwrap();
+        let skillshub_dir = temp.path().join(".skillshub/skills");
+        let skill_dir = skillshub_dir.join("tap/skill");
+        fs::create_dir_all(&skill_dir).unwrap();
+
+        let agent_path = temp.path().join(".claude");
+        let agent_skills = agent_path.join("skills");
+        let managed_copy = agent_skills.join("skill");
+        fs::create_dir_all(&managed_copy).unwrap();
+        fs::write(managed_copy.join(COPY_MARKER), skill_dir.to_string_lossy().as_bytes()).unwrap();
+
+        // A marked copy of something outside skillshub must be left alone
+        let foreign_copy = agent_skills.join("foreign");
+        fs::create_dir_all(&foreign_copy).unwrap();
+        fs::write(foreign_copy.join(COPY_MARKER), temp.path().to_string_lossy().as_bytes()).unwrap();
+
+        let agents = vec![AgentInfo {
+            path: agent_path,
+            skills_subdir: "skills".to_string(),
+        }];
+        let canonical = skillshub_dir.canonicalize().unwrap();
+        assert_eq!(remove_managed_symlinks(&agents, &canonical), 1);
+        assert!(!managed_copy.exists());
+        assert!(foreign_copy.exists());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_remove_managed_symlinks_descends_into_categories() {
+        let temp = TempDir::new().unwrap();
+        let skillshub_dir = temp.path().join(".skillshub/skills");
+        let skill_dir = skillshub_dir.join("tap/skill");
+        fs::create_dir_all(&skill_dir).unwrap();
+
+        let agent_path = temp.path().join(".claude");
+        let category_dir = agent_path.join("skills/review");
+        fs::create_dir_all(&category_dir).unwrap();
+        std::os::unix::fs::symlink(&skill_dir, category_dir.join("skill")).unwrap();
+
+        let agents = vec![AgentInfo {
+            path: agent_path,
+            skills_subdir: "skills".to_string(),
+        }];
+        let canonical = skillshub_dir.canonicalize().unwrap();
+        assert_eq!(remove_managed_symlinks(&agents, &canonical), 1);
+        assert!(!category_dir.exists(), "emptied category folder should be removed");
+        assert!(skill_dir.exists(), "link target must be preserved");
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn test_remove_managed_symlinks_descends_into_prefix_and_category() {
+        let temp = TempDir::new().unwrap();
+        let skillshub_dir = temp.path().join(".skillshub/skills");
+        let skill_dir = skillshub_dir.join("tap/skill");
+        fs::create_dir_all(&skill_dir).unwrap();
+
+        let agent_path = temp.path().join(".claude");
+        let prefix_dir = agent_path.join("skills/community");
+        fs::create_dir_all(prefix_dir.join("review")).unwrap();
+        std::os::unix::fs::symlink(&skill_dir, prefix_dir.join("skill")).unwrap();
+        std::os::unix::fs::symlink(&skill_dir, prefix_dir.join("review/skill")).unwrap();
+
+        let agents = vec![AgentInfo {
+            path: agent_path.clone(),
+            skills_subdir: "skills".to_string(),
+        }];
+        let canonical = skillshub_dir.canonicalize().unwrap();
+        assert_eq!(remove_managed_symlinks(&agents, &canonical), 2);
+        assert!(!prefix_dir.exists(), "emptied prefix folder should be removed");
+        assert!(agent_path.join("skills").exists());
+    }
 }
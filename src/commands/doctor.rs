@@ -1,58 +1,132 @@
 use anyhow::Result;
 use colored::Colorize;
+use serde::Serialize;
+use std::path::Path;
 
+use crate::agent::discover_agents;
 use crate::paths::{get_skills_install_dir, get_taps_clone_dir};
 use crate::registry::db;
 use crate::registry::git;
 use crate::registry::models::SkillId;
 
+/// Outcome of a single diagnostic check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum CheckStatus {
+    Ok,
+    /// Worth reporting, but skillshub still works (e.g. an orphan clone)
+    Warn,
+    /// A hard failure that breaks installs or agent links
+    Fail,
+}
+
+/// One line of `doctor` output, also the element type of `doctor --json`
+#[derive(Debug, Serialize)]
+struct DoctorCheck {
+    check: String,
+    status: CheckStatus,
+    detail: String,
+}
+
+impl DoctorCheck {
+    fn new(check: impl Into<String>, status: CheckStatus, detail: impl Into<String>) -> Self {
+        Self {
+            check: check.into(),
+            status,
+            detail: detail.into(),
+        }
+    }
+}
+
+/// `doctor --json` output: every check plus whether all hard checks passed
+#[derive(Debug, Serialize)]
+struct DoctorReport {
+    ok: bool,
+    checks: Vec<DoctorCheck>,
+}
+
+impl DoctorReport {
+    fn new(checks: Vec<DoctorCheck>) -> Self {
+        let ok = checks.iter().all(|c| c.status != CheckStatus::Fail);
+        Self { ok, checks }
+    }
+}
+
 /// Run diagnostic checks on the skillshub installation.
 /// Returns the number of issues found.
 pub fn run_doctor() -> Result<usize> {
     println!("{} Running diagnostics...\n", "=>".green().bold());
-    let mut issues = 0;
+
+    let checks = collect_checks()?;
+    for check in &checks {
+        let symbol = match check.status {
+            CheckStatus::Ok => "\u{2713}".green(),
+            CheckStatus::Warn => "!".yellow(),
+            CheckStatus::Fail => "\u{2717}".red(),
+        };
+        println!("  {} {}: {}", symbol, check.check, check.detail);
+    }
+    let issues = checks.iter().filter(|c| c.status != CheckStatus::Ok).count();
+
+    println!();
+    if issues == 0 {
+        println!("{} All checks passed!", "\u{2713}".green().bold());
+    } else {
+        println!("{} {} issue(s) found", "!".yellow().bold(), issues);
+    }
+    Ok(issues)
+}
+
+/// Run the same checks as [`run_doctor`] and print them as JSON.
+/// Returns whether every hard check passed.
+pub fn run_doctor_json() -> Result<bool> {
+    let report = DoctorReport::new(collect_checks()?);
+    println!("{}", serde_json::to_string_pretty(&report)?);
+    Ok(report.ok)
+}
+
+fn collect_checks() -> Result<Vec<DoctorCheck>> {
+    let mut checks = Vec::new();
 
     // 1. Git health
     match git::check_git() {
-        Ok(()) => println!("  {} git is installed", "\u{2713}".green()),
-        Err(e) => {
-            println!("  {} git: {}", "\u{2717}".red(), e);
-            issues += 1;
-        }
+        Ok(()) => checks.push(DoctorCheck::new("git", CheckStatus::Ok, "installed")),
+        Err(e) => checks.push(DoctorCheck::new("git", CheckStatus::Fail, e.to_string())),
     }
 
     // 2. Clone health -- for each tap, verify clone dir
     let db = db::load_db()?;
-    for (name, tap) in &db.taps {
+    let mut tap_names: Vec<&String> = db.taps.keys().collect();
+    tap_names.sort();
+    for name in tap_names {
+        let tap = &db.taps[name];
         if tap.url.contains("gist.github.com") || tap.is_default {
             continue;
         }
+        let label = format!("tap '{}'", name);
         let clone_dir = crate::paths::get_tap_clone_dir(name)?;
         if !clone_dir.exists() {
-            println!("  {} tap '{}': clone directory missing", "\u{2717}".red(), name);
-            issues += 1;
+            checks.push(DoctorCheck::new(label, CheckStatus::Fail, "clone directory missing"));
         } else if !clone_dir.join(".git").exists() {
-            println!(
-                "  {} tap '{}': .git directory missing (corrupted clone)",
-                "\u{2717}".red(),
-                name
-            );
-            issues += 1;
+            checks.push(DoctorCheck::new(
+                label,
+                CheckStatus::Fail,
+                ".git directory missing (corrupted clone)",
+            ));
         } else {
             // Quick rev-parse check
             match git::git_head_sha(&clone_dir) {
-                Ok(_) => println!("  {} tap '{}': clone healthy", "\u{2713}".green(), name),
-                Err(_) => {
-                    println!("  {} tap '{}': git rev-parse failed", "\u{2717}".red(), name);
-                    issues += 1;
-                }
+                Ok(_) => checks.push(DoctorCheck::new(label, CheckStatus::Ok, "clone healthy")),
+                Err(_) => checks.push(DoctorCheck::new(label, CheckStatus::Fail, "git rev-parse failed")),
             }
         }
     }
 
     // 3. Skill health -- for each installed skill, check files exist
     let install_dir = get_skills_install_dir()?;
-    for (full_name, installed) in &db.installed {
+    let mut installed: Vec<_> = db.installed.iter().collect();
+    installed.sort_by(|a, b| a.0.cmp(b.0));
+    for (full_name, installed) in installed {
         // Use SkillId::parse or fall back to the InstalledSkill fields directly
         let (tap, skill) = if let Some(id) = SkillId::parse(full_name) {
             (id.tap, id.skill)
@@ -60,12 +134,12 @@ pub fn run_doctor() -> Result<usize> {
             (installed.tap.clone(), installed.skill.clone())
         };
 
-        let skill_dir = install_dir.join(&tap).join(&skill);
+        let label = format!("skill '{}'", full_name);
+        let skill_dir = db.skill_dir(&install_dir, &tap, &skill);
         if !skill_dir.join("SKILL.md").exists() {
-            println!("  {} skill '{}': SKILL.md missing", "\u{2717}".red(), full_name);
-            issues += 1;
+            checks.push(DoctorCheck::new(label, CheckStatus::Fail, "SKILL.md missing"));
         } else {
-            println!("  {} skill '{}': files present", "\u{2713}".green(), full_name);
+            checks.push(DoctorCheck::new(label, CheckStatus::Ok, "files present"));
         }
     }
 
@@ -84,21 +158,66 @@ pub fn run_doctor() -> Result<usize> {
                         repo_entry.file_name().to_string_lossy()
                     );
                     if !db.taps.contains_key(&tap_name) {
-                        println!("  {} orphan clone: {} (no matching tap in db)", "!".yellow(), tap_name);
-                        issues += 1;
+                        checks.push(DoctorCheck::new(
+                            format!("orphan clone '{}'", tap_name),
+                            CheckStatus::Warn,
+                            "no matching tap in db",
+                        ));
                     }
                 }
             }
         }
     }
 
-    println!();
-    if issues == 0 {
-        println!("{} All checks passed!", "\u{2713}".green().bold());
-    } else {
-        println!("{} {} issue(s) found", "!".yellow().bold(), issues);
+    // 5. Agent links -- symlinks in agent skills folders whose target is gone. Only links
+    // into the skillshub install dir are ours; broken links the user made are just noted.
+    for agent in discover_agents() {
+        let mut broken = Vec::new();
+        find_broken_links(&agent.path.join(&agent.skills_subdir), &mut broken);
+        for link in broken {
+            let target = std::fs::read_link(&link).unwrap_or_default();
+            let resolved = link
+                .parent()
+                .map_or_else(|| target.clone(), |parent| parent.join(&target));
+            let (status, detail) = if resolved.starts_with(&install_dir) {
+                (
+                    CheckStatus::Fail,
+                    format!("broken (target {} is missing)", target.display()),
+                )
+            } else {
+                (
+                    CheckStatus::Warn,
+                    format!(
+                        "broken (target {} is missing, not managed by skillshub)",
+                        target.display()
+                    ),
+                )
+            };
+            checks.push(DoctorCheck::new(format!("link '{}'", link.display()), status, detail));
+        }
+    }
+
+    Ok(checks)
+}
+
+/// Collect symlinks below `dir` whose targets no longer exist, descending into
+/// category and prefix folders (real directories without a SKILL.md)
+fn find_broken_links(dir: &Path, broken: &mut Vec<std::path::PathBuf>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    let mut paths: Vec<_> = entries.flatten().map(|e| e.path()).collect();
+    paths.sort();
+
+    for path in paths {
+        if path.is_symlink() {
+            if !path.exists() {
+                broken.push(path);
+            }
+        } else if path.is_dir() && !path.join("SKILL.md").exists() {
+            find_broken_links(&path, broken);
+        }
     }
-    Ok(issues)
 }
 
 #[cfg(test)]
@@ -208,11 +327,13 @@ mod tests {
             "owner/repo".to_string(),
             TapInfo {
                 url: "https://github.com/owner/repo".to_string(),
-                skills_path: "skills".to_string(),
+                custom_skills_path: None,
                 updated_at: None,
                 is_default: false,
                 cached_registry: None,
                 branch: None,
+                token_env: None,
+                registry_file: None,
             },
         );
         write_db_json(&skillshub_home, &db);
@@ -240,11 +361,13 @@ mod tests {
             "owner/repo".to_string(),
             TapInfo {
                 url: "https://github.com/owner/repo".to_string(),
-                skills_path: "skills".to_string(),
+                custom_skills_path: None,
                 updated_at: None,
                 is_default: false,
                 cached_registry: None,
                 branch: None,
+                token_env: None,
+                registry_file: None,
             },
         );
         write_db_json(&skillshub_home, &db);
@@ -275,6 +398,10 @@ mod tests {
                 source_url: None,
                 source_path: None,
                 gist_updated_at: None,
+                content_sha256: None,
+                size_bytes: None,
+                alias: None,
+                branch: None,
             },
         );
         write_db_json(&skillshub_home, &db);
@@ -308,4 +435,65 @@ mod tests {
         let issues = run_doctor().unwrap();
         assert!(issues >= 1, "orphan clone should report at least 1 issue");
     }
+
+    #[test]
+    #[serial]
+    #[cfg(unix)]
+    fn test_doctor_json_reports_broken_link() {
+        let temp = TempDir::new().unwrap();
+        let home = temp.path().join("home");
+        let skillshub_home = home.join(".skillshub");
+        fs::create_dir_all(&skillshub_home).unwrap();
+        write_db_json(&skillshub_home, &crate::registry::models::Database::default());
+
+        let agent_skills = home.join(".claude/skills");
+        fs::create_dir_all(&agent_skills).unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        let healthy = DoctorReport::new(collect_checks().unwrap());
+        assert!(healthy.ok);
+
+        std::os::unix::fs::symlink(skillshub_home.join("skills/gone/skill"), agent_skills.join("skill")).unwrap();
+        let report = DoctorReport::new(collect_checks().unwrap());
+        let json = serde_json::to_value(&report).unwrap();
+
+        assert_eq!(json["ok"], false);
+        let checks = json["checks"].as_array().unwrap();
+        assert!(checks
+            .iter()
+            .all(|c| c["check"].is_string() && c["status"].is_string() && c["detail"].is_string()));
+        assert!(checks.iter().any(|c| c["check"] == "git"));
+        let link = checks
+            .iter()
+            .find(|c| c["check"].as_str().unwrap().starts_with("link "))
+            .expect("broken link should be reported");
+        assert_eq!(link["status"], "fail");
+    }
+
+    /// Broken links pointing outside the skillshub install dir are not ours to fail on
+    #[test]
+    #[serial]
+    #[cfg(unix)]
+    fn test_doctor_warns_on_unmanaged_broken_link() {
+        let temp = TempDir::new().unwrap();
+        let home = temp.path().join("home");
+        let skillshub_home = home.join(".skillshub");
+        fs::create_dir_all(&skillshub_home).unwrap();
+        write_db_json(&skillshub_home, &crate::registry::models::Database::default());
+
+        let agent_skills = home.join(".claude/skills");
+        fs::create_dir_all(&agent_skills).unwrap();
+        std::os::unix::fs::symlink(temp.path().join("my-skills/gone"), agent_skills.join("mine")).unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        let report = DoctorReport::new(collect_checks().unwrap());
+
+        assert!(report.ok);
+        let link = report
+            .checks
+            .iter()
+            .find(|c| c.check.starts_with("link "))
+            .expect("unmanaged broken link should still be noted");
+        assert_eq!(link.status, CheckStatus::Warn);
+    }
 }
@@ -1,5 +1,5 @@
-use anyhow::Result;
-use chrono::Utc;
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use colored::Colorize;
 use std::collections::HashSet;
 use std::fs;
@@ -9,12 +9,19 @@ use tabled::{
     Table, Tabled,
 };
 
+use super::clean::{find_agent_links_to, remove_agent_links};
+use super::link::{is_category_dir, link_to_agents};
 use crate::agent::{discover_agents, AgentInfo};
-use crate::paths::get_skills_install_dir;
+use crate::context::RunContext;
+use crate::paths::{display_path_with_tilde, get_skills_install_dir};
 use crate::registry::db::{
-    add_external_skill, get_all_external_skills, init_db, is_external_skill, remove_external_skill, save_db,
+    add_external_skill, add_installed_skill, get_all_external_skills, init_db, is_external_skill,
+    remove_external_skill, save_db, LOCAL_TAP_NAME,
 };
-use crate::registry::models::{Database, ExternalSkill};
+use crate::registry::models::{Database, ExternalSkill, InstalledSkill};
+use crate::registry::skill::ensure_flat_name_free;
+use crate::skill::validate_skill_name;
+use crate::util::{copy_dir_contents, dir_size};
 
 #[derive(Tabled)]
 struct ExternalSkillRow {
@@ -28,12 +35,53 @@ struct ExternalSkillRow {
     discovered: String,
 }
 
-/// List all discovered external skills
-pub fn external_list() -> Result<()> {
+/// Parse a `--since` value: a date (`YYYY-MM-DD`, midnight UTC) or an RFC 3339 timestamp
+fn parse_since(value: &str) -> Result<DateTime<Utc>> {
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        return Ok(date.and_time(NaiveTime::MIN).and_utc());
+    }
+    DateTime::parse_from_rfc3339(value)
+        .map(|at| at.with_timezone(&Utc))
+        .with_context(|| format!("Invalid --since '{}'. Use YYYY-MM-DD or an RFC 3339 timestamp", value))
+}
+
+/// External skills from `agent` (with or without the leading dot) discovered at or after
+/// `since`, sorted by name
+fn filter_external_skills<'a>(
+    skills: Vec<(&'a String, &'a ExternalSkill)>,
+    agent: Option<&str>,
+    since: Option<DateTime<Utc>>,
+) -> Vec<&'a ExternalSkill> {
+    let agent = agent.map(|a| a.trim_start_matches('.'));
+    let mut matched: Vec<&ExternalSkill> = skills
+        .into_iter()
+        .map(|(_, skill)| skill)
+        .filter(|skill| agent.map_or(true, |a| skill.source_agent.trim_start_matches('.') == a))
+        .filter(|skill| since.map_or(true, |at| skill.discovered_at >= at))
+        .collect();
+    matched.sort_by(|a, b| a.name.cmp(&b.name));
+    matched
+}
+
+/// List discovered external skills, optionally only those from `agent` or discovered
+/// since a date, as a table or (with `json`) as a JSON array
+pub fn external_list(agent: Option<&str>, since: Option<&str>, json: bool) -> Result<()> {
+    let since = since.map(parse_since).transpose()?;
     let db = init_db()?;
     let external_skills = get_all_external_skills(&db);
+    let filtering = agent.is_some() || since.is_some();
+    let matched = filter_external_skills(external_skills, agent, since);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&matched)?);
+        return Ok(());
+    }
 
-    if external_skills.is_empty() {
+    if matched.is_empty() {
+        if filtering {
+            println!("{} No external skills match the given filters.", "Info:".cyan());
+            return Ok(());
+        }
         println!("{} No external skills discovered yet.", "Info:".cyan());
         println!("Run 'skillshub link' or 'skillshub external scan' to discover external skills.");
         return Ok(());
@@ -44,9 +92,9 @@ pub fn external_list() -> Result<()> {
         "=>".green().bold()
     );
 
-    let mut rows: Vec<ExternalSkillRow> = external_skills
+    let rows: Vec<ExternalSkillRow> = matched
         .iter()
-        .map(|(_, skill)| ExternalSkillRow {
+        .map(|skill| ExternalSkillRow {
             name: skill.name.clone(),
             source_agent: skill.source_agent.clone(),
             source_path: skill.source_path.display().to_string(),
@@ -54,8 +102,6 @@ pub fn external_list() -> Result<()> {
         })
         .collect();
 
-    rows.sort_by(|a, b| a.name.cmp(&b.name));
-
     let table = Table::new(rows)
         .with(Style::rounded())
         .with(Padding::new(1, 1, 0, 1))
@@ -74,11 +120,11 @@ pub fn external_scan() -> Result<()> {
     let agents = discover_agents();
 
     if agents.is_empty() {
-        println!("{} No coding agents found.", "Info:".cyan());
+        say!("{} No coding agents found.", "Info:".cyan());
         return Ok(());
     }
 
-    println!(
+    say!(
         "{} Scanning {} agent(s) for external skills...",
         "=>".green().bold(),
         agents.len()
@@ -87,24 +133,24 @@ pub fn external_scan() -> Result<()> {
     let (new_external, all_external) = discover_external_skills_internal(&agents, &mut db, &skills_dir_canonical)?;
 
     if new_external.is_empty() {
-        println!(
+        say!(
             "{} No new external skills discovered. Total tracked: {}",
             "Info:".cyan(),
             all_external.len()
         );
     } else {
-        println!(
+        say!(
             "{} Discovered {} new external skill(s):",
             "=>".green().bold(),
             new_external.len()
         );
         for name in &new_external {
             if let Some(ext) = db.external.get(name) {
-                println!("  {} {} (from {})", "+".green(), name, ext.source_agent);
+                say!("  {} {} (from {})", "+".green(), name, ext.source_agent);
             }
         }
         save_db(&db)?;
-        println!(
+        say!(
             "\n{} Total external skills tracked: {}",
             "Done!".green().bold(),
             all_external.len()
@@ -126,13 +172,13 @@ pub fn external_forget(name: &str) -> Result<()> {
     save_db(&db)?;
 
     if let Some(skill) = removed {
-        println!(
+        say!(
             "{} Stopped tracking external skill '{}' (was from {})",
             "Done!".green().bold(),
             name,
             skill.source_agent
         );
-        println!(
+        say!(
             "{} The skill itself was not deleted. Symlinks in other agents will remain until removed.",
             "Note:".cyan()
         );
@@ -141,6 +187,85 @@ pub fn external_forget(name: &str) -> Result<()> {
     Ok(())
 }
 
+/// Turn a tracked external skill into a skillshub-managed one (`external adopt`).
+///
+/// The skill's source directory is copied to `~/.skillshub/skills/local/<name>` and
+/// recorded as an installed skill from the `local` tap, and the external entry is dropped.
+/// Links that synced the old source into other agents are replaced by managed links. The
+/// original directory is left untouched, so its own agent keeps it and is not double-linked.
+pub fn external_adopt(name: &str) -> Result<()> {
+    let mut db = init_db()?;
+    let external = db
+        .external
+        .get(name)
+        .cloned()
+        .with_context(|| format!("External skill '{}' not found. Run 'skillshub external list'", name))?;
+    validate_skill_name(name)?;
+
+    let full_name = format!("{}/{}", LOCAL_TAP_NAME, name);
+    if db.installed.contains_key(&full_name) {
+        anyhow::bail!("Skill '{}' is already installed", full_name);
+    }
+    ensure_flat_name_free(&db, LOCAL_TAP_NAME, name)?;
+
+    let source = &external.source_path;
+    if !source.join("SKILL.md").is_file() {
+        anyhow::bail!("External skill '{}' has no SKILL.md at {}", name, source.display());
+    }
+
+    let dest = db.skill_dir(&get_skills_install_dir()?, LOCAL_TAP_NAME, name);
+    if dest.exists() {
+        anyhow::bail!("{} already exists", dest.display());
+    }
+
+    say!("{} Adopting external skill '{}'", "=>".green().bold(), name);
+    fs::create_dir_all(&dest)?;
+    if let Err(e) = copy_dir_contents(source, &dest) {
+        let _ = fs::remove_dir_all(&dest);
+        return Err(e);
+    }
+    say!(
+        "  {} Copied {} -> {}",
+        "✓".green(),
+        display_path_with_tilde(source),
+        display_path_with_tilde(&dest)
+    );
+
+    // Links synced from the old source would keep pointing at the agent's copy
+    let synced = find_agent_links_to(source);
+    remove_agent_links(&synced);
+
+    add_installed_skill(
+        &mut db,
+        &full_name,
+        InstalledSkill {
+            tap: LOCAL_TAP_NAME.to_string(),
+            skill: name.to_string(),
+            commit: None,
+            installed_at: Utc::now(),
+            source_url: None,
+            source_path: None,
+            gist_updated_at: None,
+            content_sha256: None,
+            size_bytes: Some(dir_size(&dest)),
+            alias: None,
+            branch: None,
+        },
+    );
+    remove_external_skill(&mut db, name);
+    save_db(&db)?;
+
+    say!("{} Adopted '{}' as {}", "✓".green(), name, full_name.bold());
+    say!(
+        "{} The original in {} was left in place, so that agent keeps its own copy. \
+         Remove it and run 'skillshub link' to link the adopted copy there too.",
+        "Note:".yellow().bold(),
+        display_path_with_tilde(source)
+    );
+
+    link_to_agents(&RunContext::default())
+}
+
 /// Internal function to discover external skills (shared with link.rs logic)
 ///
 /// External skills are real directories (not symlinks) in agent skill directories
@@ -164,7 +289,7 @@ fn discover_external_skills_internal(
             .file_name()
             .map(|n| n.to_string_lossy().to_string())
             .unwrap_or_default();
-        let skills_path = agent.path.join(agent.skills_subdir);
+        let skills_path = agent.path.join(&agent.skills_subdir);
 
         if !skills_path.exists() || !skills_path.is_dir() {
             continue;
@@ -187,6 +312,11 @@ fn discover_external_skills_internal(
                 continue;
             }
 
+            // Skip category folders that only hold skillshub-managed links
+            if is_category_dir(&path) {
+                continue;
+            }
+
             // Skip if not a directory
             if !path.is_dir() {
                 continue;
@@ -252,6 +382,103 @@ mod tests {
         assert_eq!(row.source_agent, ".claude");
     }
 
+    /// `--agent` and `--since` each narrow the seeded rows, and together keep only
+    /// skills matching both
+    #[test]
+    fn test_filter_external_skills_by_agent_and_date() {
+        let mut db = Database::default();
+        for (name, agent, discovered) in [
+            ("old-claude", ".claude", "2024-01-10T09:00:00Z"),
+            ("new-claude", ".claude", "2024-03-02T12:00:00Z"),
+            ("new-codex", ".codex", "2024-03-05T08:30:00Z"),
+        ] {
+            add_external_skill(
+                &mut db,
+                name,
+                ExternalSkill {
+                    name: name.to_string(),
+                    source_agent: agent.to_string(),
+                    source_path: PathBuf::from(format!("/home/user/{}/skills/{}", agent, name)),
+                    discovered_at: parse_since(discovered).unwrap(),
+                },
+            );
+        }
+        let names = |agent: Option<&str>, since: Option<&str>| -> Vec<String> {
+            let since = since.map(|s| parse_since(s).unwrap());
+            filter_external_skills(get_all_external_skills(&db), agent, since)
+                .into_iter()
+                .map(|skill| skill.name.clone())
+                .collect()
+        };
+
+        assert_eq!(names(None, None), ["new-claude", "new-codex", "old-claude"]);
+        assert_eq!(names(Some(".claude"), None), ["new-claude", "old-claude"]);
+        assert_eq!(names(Some("codex"), None), ["new-codex"]);
+        assert_eq!(names(None, Some("2024-03-01")), ["new-claude", "new-codex"]);
+        assert_eq!(names(None, Some("2024-03-05T08:30:00Z")), ["new-codex"]);
+        assert_eq!(names(Some(".claude"), Some("2024-03-01")), ["new-claude"]);
+        assert!(names(Some(".cursor"), None).is_empty());
+    }
+
+    #[test]
+    fn test_parse_since_rejects_other_formats() {
+        assert_eq!(
+            parse_since("2024-03-01").unwrap().to_rfc3339(),
+            "2024-03-01T00:00:00+00:00"
+        );
+        assert!(parse_since("03/01/2024").is_err());
+    }
+
+    /// Adopting copies the skill into ~/.skillshub, swaps the other agents' synced links
+    /// for managed ones, and leaves the source agent's own directory alone
+    #[test]
+    #[cfg(unix)]
+    #[serial_test::serial]
+    fn test_external_adopt_moves_skill_under_skillshub() {
+        let temp = TempDir::new().unwrap();
+        let home = temp.path();
+        let source = home.join(".claude/skills/notes");
+        create_skill_dir(&source);
+        fs::create_dir_all(home.join(".codex/skills")).unwrap();
+        let source = source.canonicalize().unwrap();
+        std::os::unix::fs::symlink(&source, home.join(".codex/skills/notes")).unwrap();
+
+        let mut db = Database::default();
+        add_external_skill(
+            &mut db,
+            "notes",
+            ExternalSkill {
+                name: "notes".to_string(),
+                source_agent: ".claude".to_string(),
+                source_path: source.clone(),
+                discovered_at: Utc::now(),
+            },
+        );
+
+        let prev = std::env::var("SKILLSHUB_TEST_HOME").ok();
+        std::env::set_var("SKILLSHUB_TEST_HOME", home);
+        let result = save_db(&db).and_then(|_| external_adopt("notes"));
+        let db = crate::registry::db::load_db();
+        match prev {
+            Some(v) => std::env::set_var("SKILLSHUB_TEST_HOME", v),
+            None => std::env::remove_var("SKILLSHUB_TEST_HOME"),
+        }
+        result.unwrap();
+        let db = db.unwrap();
+
+        let adopted = home.join(".skillshub/skills/local/notes");
+        assert!(adopted.join("SKILL.md").is_file());
+        let installed = &db.installed["local/notes"];
+        assert_eq!(installed.commit, None);
+        assert!(!db.external.contains_key("notes"));
+
+        let codex_link = home.join(".codex/skills/notes");
+        assert!(codex_link.is_symlink());
+        assert_eq!(fs::read_link(&codex_link).unwrap(), adopted);
+        let original = home.join(".claude/skills/notes");
+        assert!(!original.is_symlink() && original.join("SKILL.md").is_file());
+    }
+
     #[test]
     fn test_discover_external_skills_empty() {
         let temp = TempDir::new().unwrap();
@@ -281,7 +508,7 @@ mod tests {
 
         let agents = vec![AgentInfo {
             path: agent_path,
-            skills_subdir: "skills",
+            skills_subdir: "skills".to_string(),
         }];
 
         let mut db = Database::default();
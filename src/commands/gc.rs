@@ -0,0 +1,171 @@
+use anyhow::Result;
+use colored::Colorize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::paths::{display_path_with_tilde, get_skills_install_dir, get_skillshub_home, get_taps_clone_dir};
+use crate::util::{dir_size, format_size};
+
+/// Prefix used by `tempfile` for directories it creates
+const TEMP_DIR_PREFIX: &str = ".tmp";
+
+/// What a garbage collection pass removed
+#[derive(Debug, Default)]
+struct GcReport {
+    removed: Vec<PathBuf>,
+    bytes_freed: u64,
+}
+
+/// Remove empty directories and leftover temp directories from ~/.skillshub
+pub fn gc() -> Result<()> {
+    let skillshub_home = get_skillshub_home()?;
+
+    if !skillshub_home.exists() {
+        println!("{} Nothing to collect", "Info:".cyan());
+        return Ok(());
+    }
+
+    let roots = [get_skills_install_dir()?, get_taps_clone_dir()?];
+    let report = collect_garbage(&skillshub_home, &roots);
+
+    if report.removed.is_empty() {
+        println!("{} Nothing to collect", "Info:".cyan());
+        return Ok(());
+    }
+
+    for path in &report.removed {
+        println!("  {} Removed {}", "✓".green(), display_path_with_tilde(path));
+    }
+
+    println!(
+        "\n{} Removed {} director{}, freed {}",
+        "Done!".green().bold(),
+        report.removed.len(),
+        if report.removed.len() == 1 { "y" } else { "ies" },
+        format_size(report.bytes_freed)
+    );
+
+    Ok(())
+}
+
+/// Remove temp directories directly inside `home` or any of `roots`, then prune
+/// empty directories under `roots`
+fn collect_garbage(home: &Path, roots: &[PathBuf]) -> GcReport {
+    let mut report = GcReport::default();
+
+    for dir in std::iter::once(home).chain(roots.iter().map(PathBuf::as_path)) {
+        remove_temp_dirs(dir, &mut report);
+    }
+    for root in roots {
+        remove_empty_dirs(root, &mut report);
+    }
+
+    report
+}
+
+fn remove_temp_dirs(dir: &Path, report: &mut GcReport) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let is_temp = entry.file_name().to_string_lossy().starts_with(TEMP_DIR_PREFIX);
+        if !is_temp || !entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+            continue;
+        }
+
+        let size = dir_size(&path);
+        match fs::remove_dir_all(&path) {
+            Ok(()) => {
+                report.bytes_freed += size;
+                report.removed.push(path);
+            }
+            Err(e) => eprintln!("  {} Failed to remove {}: {}", "!".red(), path.display(), e),
+        }
+    }
+}
+
+/// Remove empty directories below `root`, deepest first.
+///
+/// Skill directories and git clones are not descended into, so empty folders that
+/// belong to a skill's contents or a clone's `.git` are left alone.
+fn remove_empty_dirs(root: &Path, report: &mut GcReport) {
+    let Ok(entries) = fs::read_dir(root) else {
+        return;
+    };
+
+    for entry in entries.flatten() {
+        // `file_type` does not follow symlinks, so linked directories are skipped
+        if !entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+            continue;
+        }
+
+        let path = entry.path();
+        if path.join("SKILL.md").exists() || path.join(".git").exists() {
+            continue;
+        }
+
+        remove_empty_dirs(&path, report);
+
+        let is_empty = fs::read_dir(&path).map(|mut e| e.next().is_none()).unwrap_or(false);
+        if is_empty && fs::remove_dir(&path).is_ok() {
+            report.removed.push(path);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn setup() -> (TempDir, PathBuf, [PathBuf; 2]) {
+        let temp = TempDir::new().unwrap();
+        let home = temp.path().join(".skillshub");
+        let roots = [home.join("skills"), home.join("taps")];
+        for root in &roots {
+            fs::create_dir_all(root).unwrap();
+        }
+        (temp, home, roots)
+    }
+
+    #[test]
+    fn test_gc_removes_empty_tap_dir() {
+        let (_temp, home, roots) = setup();
+        let empty_tap = roots[0].join("owner/repo");
+        fs::create_dir_all(&empty_tap).unwrap();
+
+        let skill = roots[0].join("other/repo/skill");
+        fs::create_dir_all(skill.join("assets")).unwrap();
+        fs::write(skill.join("SKILL.md"), "---\nname: skill\n---\n").unwrap();
+
+        let report = collect_garbage(&home, &roots);
+
+        assert!(!empty_tap.exists());
+        assert!(!roots[0].join("owner").exists(), "emptied parent should go too");
+        assert!(skill.join("assets").exists(), "skill contents are left alone");
+        assert_eq!(report.removed.len(), 2);
+        assert_eq!(report.bytes_freed, 0);
+        assert!(roots[0].exists(), "roots themselves are kept");
+    }
+
+    #[test]
+    fn test_gc_removes_temp_dirs_and_counts_bytes() {
+        let (_temp, home, roots) = setup();
+        let stray = home.join(".tmpAbC123");
+        fs::create_dir_all(stray.join("partial")).unwrap();
+        fs::write(stray.join("partial/SKILL.md"), "12345").unwrap();
+
+        // Empty directories inside a clone's .git are part of the clone
+        let clone = roots[1].join("owner/repo");
+        fs::create_dir_all(clone.join(".git/refs/tags")).unwrap();
+
+        let report = collect_garbage(&home, &roots);
+
+        assert!(!stray.exists());
+        assert!(clone.join(".git/refs/tags").exists());
+        assert_eq!(report.removed, vec![stray]);
+        assert_eq!(report.bytes_freed, 5);
+    }
+}
@@ -1,30 +1,177 @@
 use anyhow::Result;
 use chrono::Utc;
 use colored::Colorize;
-use std::collections::HashSet;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use crate::agent::{discover_agents, known_agent_names, AgentInfo};
+use crate::agent::{known_agent_names, select_agents, AgentInfo};
+use crate::context::RunContext;
 use crate::paths::get_skills_install_dir;
-use crate::registry::db::{add_external_skill, init_db, is_external_skill, save_db};
+use crate::registry::db::{add_external_skill, init_db_with, is_external_skill, save_db};
+use crate::registry::github::is_safe_skill_name;
 use crate::registry::models::{Database, ExternalSkill};
 use crate::skill::{has_references_dir, has_scripts_dir, Skill};
 
+/// Marker file written into skill directories that were copied (not linked) into an agent,
+/// so `clean links` can still recognize them as skillshub-managed.
+pub(crate) const COPY_MARKER: &str = ".skillshub-copy";
+
+/// Environment variable choosing how clashing link names from different taps are resolved
+pub const DUPLICATE_LINKS_ENV: &str = "SKILLSHUB_LINK_DUPLICATES";
+
+/// How to link a skill whose directory name is already taken by a skill from another tap
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DuplicateLinks {
+    /// Link as `<skill>@<tap-owner>` (default)
+    Owner,
+    /// Link as `<skill>@<tap-owner>-<tap-repo>`
+    Tap,
+    /// Link only the first skill and warn about the rest
+    Skip,
+}
+
+impl DuplicateLinks {
+    fn from_env() -> Self {
+        Self::parse(std::env::var(DUPLICATE_LINKS_ENV).ok().as_deref())
+    }
+
+    fn parse(value: Option<&str>) -> Self {
+        match value.map(|v| v.trim().to_ascii_lowercase()).as_deref() {
+            Some("tap") => Self::Tap,
+            Some("skip") => Self::Skip,
+            _ => Self::Owner,
+        }
+    }
+}
+
+/// An installed skill and the directory name it gets inside agent skills folders
+struct SkillLink {
+    skill: Skill,
+    /// Path of the skill under the install dir (e.g., "owner/repo/skill")
+    full_name: String,
+    link_name: String,
+}
+
+/// How a skill directory was exposed inside an agent's skills folder
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(unix, allow(dead_code))]
+enum LinkMethod {
+    Symlink,
+    Junction,
+    Copy,
+}
+
+impl fmt::Display for LinkMethod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinkMethod::Symlink => write!(f, "symlink"),
+            LinkMethod::Junction => write!(f, "junction"),
+            LinkMethod::Copy => write!(f, "copy"),
+        }
+    }
+}
+
 /// Link installed skills to all discovered coding agents
-pub fn link_to_agents() -> Result<()> {
+///
+/// With `ctx.dry_run`, prints the directories, symlinks, and `db.json` entries that
+/// would be created without touching the filesystem.
+pub fn link_to_agents(ctx: &RunContext) -> Result<()> {
+    link_to_agents_with(ctx, &LinkOptions::default())
+}
+
+/// Options for `skillshub link`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct LinkOptions<'a> {
+    /// Keep the managed links out of version control (`--git-exclude`)
+    pub git_exclude: bool,
+    /// Place links under `<agent>/<skills_subdir>/<prefix>/` (`--into`)
+    pub prefix: Option<&'a Path>,
+    /// Only link these agents (`--agent`); empty means all discovered agents
+    pub agents: &'a [String],
+    /// Print a JSON report instead of the per-agent summary (`--json`)
+    pub json: bool,
+    /// Still discover external skills but don't link them into other agents
+    /// (`--no-sync-external`)
+    pub no_sync_external: bool,
+    /// Re-check agents whose skills and settings are unchanged since the last link (`--force`)
+    pub force: bool,
+}
+
+/// What `link` did for one agent, printed as text or serialized with `--json`
+#[derive(Debug, Default, Serialize)]
+pub struct AgentLinkReport {
+    pub agent: String,
+    /// Skillshub-managed skills linked (`<category>/<name>` for categorized skills)
+    pub linked: Vec<String>,
+    /// External skills synced from other agents
+    pub synced_external: Vec<String>,
+    /// Entries left alone because an unmanaged file or directory has the same name
+    pub skipped: Vec<String>,
+    /// Nothing changed since the last link, so the agent was not re-checked
+    pub unchanged: bool,
+    #[serde(skip)]
+    git_excluded: usize,
+}
+
+/// Print progress to stdout, or to stderr when stdout carries the `--json` report.
+/// Silent under `--quiet`.
+macro_rules! status {
+    ($json:expr, $($arg:tt)*) => {
+        if $json {
+            if !$crate::output::is_quiet() {
+                eprintln!($($arg)*)
+            }
+        } else {
+            say!($($arg)*)
+        }
+    };
+}
+
+/// Link installed skills to the discovered coding agents as configured by `options`
+pub fn link_to_agents_with(ctx: &RunContext, options: &LinkOptions) -> Result<()> {
+    let reports = link_agents(ctx, options)?;
+    if options.json {
+        println!("{}", serde_json::to_string_pretty(&reports)?);
+    }
+    Ok(())
+}
+
+/// Link skills to each selected agent and return what was done for every agent
+fn link_agents(ctx: &RunContext, options: &LinkOptions) -> Result<Vec<AgentLinkReport>> {
+    let LinkOptions {
+        git_exclude,
+        prefix,
+        agents: agent_names,
+        json,
+        no_sync_external,
+        force,
+    } = *options;
+
+    if let Some(prefix) = prefix {
+        validate_link_prefix(prefix)?;
+    }
+    if json && ctx.dry_run {
+        anyhow::bail!("--json cannot be combined with --dry-run");
+    }
+
     let skills_dir = get_skills_install_dir()?;
-    let mut db = init_db()?;
+    let mut db = init_db_with(ctx)?;
 
-    let agents = discover_agents();
+    let agents = select_agents(agent_names)?;
 
     if agents.is_empty() {
-        println!(
-            "{} No coding agents found. Looked for: {}",
-            "Info:".cyan(),
-            known_agent_names()
-        );
-        return Ok(());
+        if !json {
+            say!(
+                "{} No coding agents found. Looked for: {}",
+                "Info:".cyan(),
+                known_agent_names()
+            );
+        }
+        return Ok(Vec::new());
     }
 
     // Step 1: Discover external skills from agent directories
@@ -32,36 +179,92 @@ pub fn link_to_agents() -> Result<()> {
     let (new_external, all_external) = discover_external_skills(&agents, &mut db, &skills_dir_canonical)?;
 
     if !new_external.is_empty() {
-        println!(
-            "{} Discovered {} new external skill(s)",
+        status!(
+            json,
+            "{}{} Discovered {} new external skill(s)",
+            ctx.prefix(),
             "=>".green().bold(),
             new_external.len()
         );
         for name in &new_external {
             if let Some(ext) = db.external.get(name) {
-                println!("  {} {} (from {})", "+".green(), name, ext.source_agent);
+                status!(json, "  {} {} (from {})", "+".green(), name, ext.source_agent);
+            }
+        }
+        if ctx.dry_run {
+            for name in &new_external {
+                ctx.would(format!("add db.json entry external[\"{}\"]", name));
             }
+        } else {
+            save_db(&db)?;
         }
-        save_db(&db)?;
     }
 
-    // Step 2: Collect skillshub-managed skills
+    // Step 2: Collect skillshub-managed skills and give clashing names from other taps
+    // a distinct link name
     let skills = if skills_dir.exists() {
         collect_installed_skills(&skills_dir)?
     } else {
         Vec::new()
     };
+    let skills = assign_link_names(skills, &skills_dir, &db, DuplicateLinks::from_env());
 
-    println!(
-        "{} Linking skills to {} discovered agent(s)",
+    let link_names: HashMap<String, String> = skills
+        .iter()
+        .filter(|link| link.link_name != skill_link_name(&link.skill, installed_alias(&db, &link.full_name)))
+        .map(|link| (link.full_name.clone(), link.link_name.clone()))
+        .collect();
+    for (full_name, link_name) in &link_names {
+        if db.link_names.get(full_name) != Some(link_name) {
+            status!(
+                json,
+                "  {} {} linked as '{}' (name already used by another tap)",
+                "!".yellow(),
+                full_name,
+                link_name
+            );
+            if ctx.dry_run {
+                ctx.would(format!("add db.json entry link_names[\"{}\"]", full_name));
+            }
+        }
+    }
+    db.link_names = link_names;
+
+    status!(
+        json,
+        "{}{} Linking skills to {} discovered agent(s)",
+        ctx.prefix(),
         "=>".green().bold(),
         agents.len()
     );
 
     // Step 3: Link skills to each agent
+    let mut reports = Vec::new();
     for agent in &agents {
         let agent_name = agent.path.file_name().unwrap().to_string_lossy();
-        let link_path = agent.path.join(agent.skills_subdir);
+        let link_path = agent.path.join(&agent.skills_subdir);
+        let link_root = match prefix {
+            Some(prefix) => link_path.join(prefix),
+            None => link_path.clone(),
+        };
+        let to_sync: &[ExternalSkill] = if no_sync_external { &[] } else { &all_external };
+
+        // Skip agents already linked with exactly these skills and settings, as long as
+        // every expected link is still in place
+        let state = agent_link_state(agent, &skills, to_sync, options);
+        let unchanged = db.link_states.get(agent_name.as_ref()) == Some(&state);
+        if !force && unchanged && links_intact(&link_root, &agent_name, &skills, to_sync) {
+            let report = AgentLinkReport {
+                agent: agent_name.to_string(),
+                unchanged: true,
+                ..Default::default()
+            };
+            if !json {
+                say!("  {}{} {} (unchanged)", ctx.prefix(), "○".yellow(), report.agent);
+            }
+            reports.push(report);
+            continue;
+        }
 
         // Ensure skills directory exists and is a directory (not a symlink to skillshub)
         if link_path.exists() {
@@ -71,10 +274,15 @@ pub fn link_to_agents() -> Result<()> {
 
                 if link_target == skills_dir_canonical {
                     // Old-style symlink to skillshub skills dir, convert to directory
-                    fs::remove_file(&link_path)?;
-                    fs::create_dir_all(&link_path)?;
+                    if ctx.dry_run {
+                        ctx.would(format!("replace symlink {} with a directory", link_path.display()));
+                    } else {
+                        fs::remove_file(&link_path)?;
+                        fs::create_dir_all(&link_path)?;
+                    }
                 } else {
-                    println!(
+                    status!(
+                        json,
                         "  {} {} ({} exists but is not managed by skillshub)",
                         "!".yellow(),
                         agent_name,
@@ -83,7 +291,8 @@ pub fn link_to_agents() -> Result<()> {
                     continue;
                 }
             } else if !link_path.is_dir() {
-                println!(
+                status!(
+                    json,
                     "  {} {} ({} exists but is not a directory)",
                     "!".yellow(),
                     agent_name,
@@ -91,40 +300,66 @@ pub fn link_to_agents() -> Result<()> {
                 );
                 continue;
             }
+        } else if ctx.dry_run {
+            ctx.would(format!("create directory {}", link_path.display()));
         } else {
             fs::create_dir_all(&link_path)?;
         }
 
-        let mut linked_count = 0;
-        let mut skipped_count = 0;
-        let mut external_synced = 0;
+        let mut report = AgentLinkReport {
+            agent: agent_name.to_string(),
+            ..Default::default()
+        };
+        // Entries under the link root that skillshub manages
+        let mut managed_entries: Vec<String> = Vec::new();
 
-        // Link skillshub-managed skills
-        for skill in &skills {
-            let link_name = skill_link_name(skill);
-            let skill_link_path = link_path.join(&link_name);
+        // Link skillshub-managed skills (categorized skills go one level deeper)
+        for SkillLink { skill, link_name, .. } in &skills {
+            let link_parent = match &skill.category {
+                Some(category) => link_root.join(category),
+                None => link_root.clone(),
+            };
+            let skill_link_path = link_parent.join(link_name);
+            let top_level_entry = skill.category.clone().unwrap_or_else(|| link_name.clone());
+            let entry_name = match &skill.category {
+                Some(category) => format!("{}/{}", category, link_name),
+                None => link_name.clone(),
+            };
 
             if skill_link_path.exists() {
-                if skill_link_path.is_symlink() {
-                    linked_count += 1;
+                if is_managed_entry(&skill_link_path) {
+                    report.linked.push(entry_name);
+                    managed_entries.push(top_level_entry);
                 } else {
-                    skipped_count += 1;
+                    report.skipped.push(entry_name);
                 }
                 continue;
             }
 
-            #[cfg(unix)]
-            std::os::unix::fs::symlink(&skill.path, &skill_link_path)?;
-
-            #[cfg(windows)]
-            std::os::windows::fs::symlink_dir(&skill.path, &skill_link_path)?;
+            if ctx.dry_run {
+                if !link_parent.exists() && link_parent != link_path {
+                    ctx.would(format!("create directory {}", link_parent.display()));
+                }
+                ctx.would(format!(
+                    "symlink {} -> {}",
+                    skill_link_path.display(),
+                    skill.path.display()
+                ));
+                report.linked.push(entry_name);
+                managed_entries.push(top_level_entry);
+                continue;
+            }
 
-            linked_count += 1;
+            fs::create_dir_all(&link_parent)?;
+            let method = link_skill_dir(&skill.path, &skill_link_path)?;
+            report_fallback(method, link_name, json);
+            report.linked.push(entry_name);
+            managed_entries.push(top_level_entry);
         }
 
         // Sync external skills to this agent (from their source agents)
-        for ext_skill in &all_external {
-            let skill_link_path = link_path.join(&ext_skill.name);
+        for ext_skill in to_sync {
+            let skill_link_path = link_root.join(&ext_skill.name);
 
             // Skip if this is the source agent (skill already exists there)
             let current_agent_name = format!(".{}", agent_name);
@@ -134,44 +369,186 @@ pub fn link_to_agents() -> Result<()> {
 
             // Skip if skill already exists (either as file/dir or symlink)
             if skill_link_path.exists() {
-                if skill_link_path.is_symlink() {
-                    external_synced += 1;
+                if is_managed_entry(&skill_link_path) {
+                    report.synced_external.push(ext_skill.name.clone());
+                    managed_entries.push(ext_skill.name.clone());
                 } else {
-                    skipped_count += 1;
+                    report.skipped.push(ext_skill.name.clone());
                 }
                 continue;
             }
 
-            // Create symlink to the external skill's source
-            #[cfg(unix)]
-            std::os::unix::fs::symlink(&ext_skill.source_path, &skill_link_path)?;
-
-            #[cfg(windows)]
-            std::os::windows::fs::symlink_dir(&ext_skill.source_path, &skill_link_path)?;
+            if ctx.dry_run {
+                if !link_root.exists() && link_root != link_path {
+                    ctx.would(format!("create directory {}", link_root.display()));
+                }
+                ctx.would(format!(
+                    "symlink {} -> {}",
+                    skill_link_path.display(),
+                    ext_skill.source_path.display()
+                ));
+                report.synced_external.push(ext_skill.name.clone());
+                managed_entries.push(ext_skill.name.clone());
+                continue;
+            }
 
-            external_synced += 1;
+            // Create symlink to the external skill's source
+            fs::create_dir_all(&link_root)?;
+            let method = link_skill_dir(&ext_skill.source_path, &skill_link_path)?;
+            report_fallback(method, &ext_skill.name, json);
+            report.synced_external.push(ext_skill.name.clone());
+            managed_entries.push(ext_skill.name.clone());
         }
 
-        // Mark agent as linked in the database
-        db.linked_agents.insert(agent_name.to_string());
+        if git_exclude && !managed_entries.is_empty() {
+            let exclude_file = git_exclude_file(&agent.path);
+            let root_pattern = match prefix {
+                Some(prefix) => format!("/{}/{}", agent.skills_subdir, prefix_pattern(prefix)),
+                None => format!("/{}", agent.skills_subdir),
+            };
+            let patterns: Vec<String> = managed_entries
+                .iter()
+                .map(|entry| format!("{}/{}", root_pattern, entry))
+                .collect();
+            if ctx.dry_run {
+                ctx.would(format!(
+                    "add missing entries for {} link(s) to {}",
+                    patterns.len(),
+                    exclude_file.display()
+                ));
+            } else {
+                report.git_excluded = append_git_excludes(&exclude_file, &patterns)?;
+            }
+        }
 
-        // Print status
-        let mut parts = vec![format!("linked {}", linked_count)];
-        if external_synced > 0 {
-            parts.push(format!("synced {} external", external_synced));
+        // Mark agent as linked in the database. Skipped entries may be linkable once the
+        // conflicting file is gone, so the agent is only marked unchanged without them.
+        if db.linked_agents.insert(agent_name.to_string()) && ctx.dry_run {
+            ctx.would(format!("add '{}' to db.json linked_agents", agent_name));
         }
-        if skipped_count > 0 {
-            parts.push(format!("skipped {}", skipped_count));
+        if report.skipped.is_empty() {
+            db.link_states.insert(agent_name.to_string(), state);
+        } else {
+            db.link_states.remove(agent_name.as_ref());
+        }
+
+        if !json {
+            print_agent_report(ctx, &report);
         }
-        println!("  {} {} ({})", "✓".green(), agent_name, parts.join(", "));
+        reports.push(report);
+    }
+
+    if ctx.dry_run {
+        status!(
+            json,
+            "\n{}{} Skills linked successfully!",
+            ctx.prefix(),
+            "Done!".green().bold()
+        );
+        return Ok(reports);
     }
 
     // Save the database with linked agents
     save_db(&db)?;
 
-    println!("\n{} Skills linked successfully!", "Done!".green().bold());
+    if !json {
+        say!("\n{} Skills linked successfully!", "Done!".green().bold());
+    }
 
-    Ok(())
+    Ok(reports)
+}
+
+/// Digest of everything `link` would put into `agent`: its skills folder, the options that
+/// shape the links, and each managed and external skill with its link name and source
+fn agent_link_state(
+    agent: &AgentInfo,
+    skills: &[SkillLink],
+    external: &[ExternalSkill],
+    options: &LinkOptions,
+) -> String {
+    let mut lines = vec![
+        format!("subdir\0{}", agent.skills_subdir),
+        format!(
+            "prefix\0{}",
+            options.prefix.map(|p| p.display().to_string()).unwrap_or_default()
+        ),
+        format!("git-exclude\0{}", options.git_exclude),
+    ];
+    for link in skills {
+        lines.push(format!(
+            "skill\0{}\0{}\0{}\0{}",
+            link.full_name,
+            link.link_name,
+            link.skill.category.as_deref().unwrap_or_default(),
+            link.skill.path.display()
+        ));
+    }
+    for ext in external {
+        lines.push(format!(
+            "external\0{}\0{}\0{}",
+            ext.name,
+            ext.source_agent,
+            ext.source_path.display()
+        ));
+    }
+    lines.sort();
+
+    let mut hasher = Sha256::new();
+    for line in &lines {
+        hasher.update(line.as_bytes());
+        hasher.update(b"\n");
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+/// Whether every link `link` would create under `link_root` exists and points at its
+/// skill, so an agent with an unchanged digest can be skipped. Only reads the links.
+fn links_intact(link_root: &Path, agent_name: &str, skills: &[SkillLink], external: &[ExternalSkill]) -> bool {
+    let managed = skills.iter().map(|SkillLink { skill, link_name, .. }| {
+        let link_parent = match &skill.category {
+            Some(category) => link_root.join(category),
+            None => link_root.to_path_buf(),
+        };
+        (link_parent.join(link_name), skill.path.as_path())
+    });
+    let synced = external
+        .iter()
+        .filter(|ext| ext.source_agent != format!(".{}", agent_name) && ext.source_agent != agent_name)
+        .map(|ext| (link_root.join(&ext.name), ext.source_path.as_path()));
+
+    link_root.is_dir()
+        && managed
+            .chain(synced)
+            .all(|(link, target)| link_points_to(&link, target))
+}
+
+/// Whether `link` is a link to `target`, or a fallback copy made where links are unavailable
+fn link_points_to(link: &Path, target: &Path) -> bool {
+    match fs::read_link(link) {
+        Ok(dest) => dest == target || matches!((dest.canonicalize(), target.canonicalize()), (Ok(a), Ok(b)) if a == b),
+        Err(_) => link.join(COPY_MARKER).is_file(),
+    }
+}
+
+/// Print the one-line text summary for an agent, e.g. `✓ .claude (linked 3, skipped 1)`
+fn print_agent_report(ctx: &RunContext, report: &AgentLinkReport) {
+    let mut parts = vec![format!("linked {}", report.linked.len())];
+    if !report.synced_external.is_empty() {
+        parts.push(format!("synced {} external", report.synced_external.len()));
+    }
+    if !report.skipped.is_empty() {
+        parts.push(format!("skipped {}", report.skipped.len()));
+    }
+    if report.git_excluded > 0 {
+        parts.push(format!("git-excluded {}", report.git_excluded));
+    }
+    say!(
+        "  {}{} {} ({})",
+        ctx.prefix(),
+        "✓".green(),
+        report.agent,
+        parts.join(", ")
+    );
 }
 
 /// Discover external skills from agent directories
@@ -198,7 +575,7 @@ fn discover_external_skills(
             .file_name()
             .map(|n| n.to_string_lossy().to_string())
             .unwrap_or_default();
-        let skills_path = agent.path.join(agent.skills_subdir);
+        let skills_path = agent.path.join(&agent.skills_subdir);
 
         if !skills_path.exists() || !skills_path.is_dir() {
             continue;
@@ -215,9 +592,9 @@ fn discover_external_skills(
                 continue;
             }
 
-            // Skip symlinks - we only track real directories as sources
-            // Symlinks are either skillshub-managed or created by us for syncing
-            if path.is_symlink() {
+            // Skip symlinks and fallback copies - we only track real directories as sources
+            // These are either skillshub-managed or created by us for syncing
+            if is_managed_entry(&path) || is_category_dir(&path) {
                 continue;
             }
 
@@ -258,7 +635,172 @@ fn discover_external_skills(
     Ok((new_external, all_external))
 }
 
-fn skill_link_name(skill: &Skill) -> String {
+/// Create a directory link at `link` pointing to `target`.
+///
+/// On Windows without Developer Mode, symlink creation is denied. In that case fall back
+/// to a directory junction (no privilege required), and if that fails too, to a copy.
+fn link_skill_dir(target: &Path, link: &Path) -> Result<LinkMethod> {
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(target, link)?;
+        Ok(LinkMethod::Symlink)
+    }
+
+    #[cfg(windows)]
+    {
+        match std::os::windows::fs::symlink_dir(target, link) {
+            Ok(()) => return Ok(LinkMethod::Symlink),
+            Err(e) if is_symlink_privilege_error(&e) => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        if create_junction(target, link).is_ok() {
+            return Ok(LinkMethod::Junction);
+        }
+
+        copy_skill_dir(target, link)?;
+        Ok(LinkMethod::Copy)
+    }
+}
+
+/// Windows reports a missing symlink privilege as ERROR_PRIVILEGE_NOT_HELD (1314)
+#[cfg(any(windows, test))]
+fn is_symlink_privilege_error(err: &std::io::Error) -> bool {
+    err.kind() == std::io::ErrorKind::PermissionDenied || err.raw_os_error() == Some(1314)
+}
+
+#[cfg(windows)]
+fn create_junction(target: &Path, link: &Path) -> Result<()> {
+    let output = std::process::Command::new("cmd")
+        .args(["/C", "mklink", "/J"])
+        .arg(link)
+        .arg(target)
+        .output()?;
+
+    if !output.status.success() || !link.exists() {
+        anyhow::bail!("mklink /J failed: {}", String::from_utf8_lossy(&output.stderr).trim());
+    }
+
+    Ok(())
+}
+
+/// Last-resort fallback: copy the skill and drop a marker so it is still treated as managed
+#[cfg(any(windows, test))]
+fn copy_skill_dir(target: &Path, link: &Path) -> Result<()> {
+    fs::create_dir_all(link)?;
+    if let Err(e) = crate::util::copy_dir_contents(target, link) {
+        let _ = fs::remove_dir_all(link);
+        return Err(e);
+    }
+    fs::write(link.join(COPY_MARKER), target.to_string_lossy().as_bytes())?;
+    Ok(())
+}
+
+/// Where to record ignore patterns for an agent directory: `.git/info/exclude` when the
+/// agent directory is itself a git checkout (keeps the tracked `.gitignore` untouched),
+/// otherwise its `.gitignore`
+fn git_exclude_file(agent_dir: &Path) -> PathBuf {
+    let git_dir = agent_dir.join(".git");
+    if git_dir.is_dir() {
+        git_dir.join("info").join("exclude")
+    } else {
+        agent_dir.join(".gitignore")
+    }
+}
+
+/// Append `patterns` that are not already present in `file`, creating it if needed.
+/// Returns how many patterns were added.
+fn append_git_excludes(file: &Path, patterns: &[String]) -> Result<usize> {
+    let existing = fs::read_to_string(file).unwrap_or_default();
+    let mut present: HashSet<&str> = existing.lines().map(str::trim).collect();
+
+    let mut additions = String::new();
+    for pattern in patterns {
+        if present.insert(pattern.as_str()) {
+            additions.push_str(pattern);
+            additions.push('\n');
+        }
+    }
+
+    if additions.is_empty() {
+        return Ok(0);
+    }
+
+    if let Some(parent) = file.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut content = existing.clone();
+    if !content.is_empty() && !content.ends_with('\n') {
+        content.push('\n');
+    }
+    content.push_str(&additions);
+    fs::write(file, content)?;
+
+    Ok(additions.lines().count())
+}
+
+/// Print which mechanism was used when a skill could not be symlinked
+fn report_fallback(method: LinkMethod, name: &str, json: bool) {
+    if method != LinkMethod::Symlink {
+        status!(
+            json,
+            "    {} {} (symlink not permitted, used {})",
+            "!".yellow(),
+            name,
+            method
+        );
+    }
+}
+
+/// Whether an entry in an agent's skills directory was created by skillshub
+/// (a symlink or junction, or a fallback copy carrying the marker file)
+fn is_managed_entry(path: &Path) -> bool {
+    path.is_symlink() || path.join(COPY_MARKER).is_file()
+}
+
+/// Whether `path` is a category or `--into` prefix folder created by skillshub: a real
+/// directory without a SKILL.md whose entries are all skillshub-managed links or such folders
+pub(crate) fn is_category_dir(path: &Path) -> bool {
+    if path.is_symlink() || !path.is_dir() || path.join("SKILL.md").exists() {
+        return false;
+    }
+
+    let Ok(entries) = fs::read_dir(path) else {
+        return false;
+    };
+    let mut entries = entries.flatten().peekable();
+    entries.peek().is_some() && entries.all(|e| is_managed_entry(&e.path()) || is_category_dir(&e.path()))
+}
+
+/// Reject `--into` prefixes that would place links outside the agent's skills directory
+fn validate_link_prefix(prefix: &Path) -> Result<()> {
+    let is_plain = prefix.components().next().is_some()
+        && prefix
+            .components()
+            .all(|c| matches!(c, std::path::Component::Normal(part) if is_safe_skill_name(&part.to_string_lossy())));
+    if !is_plain {
+        anyhow::bail!(
+            "Invalid --into prefix '{}': use a relative folder such as 'community' or 'vendor/community'",
+            prefix.display()
+        );
+    }
+    Ok(())
+}
+
+/// `/`-separated form of a link prefix for `.gitignore` patterns
+fn prefix_pattern(prefix: &Path) -> String {
+    prefix
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Directory name for a skill's agent link: its alias if one is set, else its own directory name
+fn skill_link_name(skill: &Skill, alias: Option<&str>) -> String {
+    if let Some(alias) = alias {
+        return alias.to_string();
+    }
     skill
         .path
         .file_name()
@@ -266,6 +808,11 @@ fn skill_link_name(skill: &Skill) -> String {
         .unwrap_or_else(|| skill.name.clone())
 }
 
+/// The alias recorded in `db.json` for an installed skill, if any
+fn installed_alias<'a>(db: &'a Database, full_name: &str) -> Option<&'a str> {
+    db.installed.get(full_name).and_then(|i| i.alias.as_deref())
+}
+
 fn collect_installed_skills(skills_dir: &Path) -> Result<Vec<Skill>> {
     let mut skills = Vec::new();
 
@@ -295,12 +842,29 @@ fn collect_installed_skills(skills_dir: &Path) -> Result<Vec<Skill>> {
                         let has_scripts = has_scripts_dir(&path);
                         let has_references = has_references_dir(&path);
 
+                        // Categories become a directory name, so reject traversal
+                        let category = match metadata.category {
+                            Some(c) if !is_safe_skill_name(&c) => {
+                                eprintln!(
+                                    "{} Ignoring unsafe category '{}' for skill at {}",
+                                    "Warning:".yellow(),
+                                    c,
+                                    path.display()
+                                );
+                                None
+                            }
+                            other => other,
+                        };
+
                         skills.push(Skill {
                             name: metadata.name,
                             description: metadata.description.unwrap_or_else(|| "No description".to_string()),
                             path,
                             has_scripts,
                             has_references,
+                            category,
+                            tags: metadata.tags,
+                            aliases: metadata.aliases,
                         });
                     }
                     Err(e) => {
@@ -322,27 +886,78 @@ fn collect_installed_skills(skills_dir: &Path) -> Result<Vec<Skill>> {
     }
 
     find_skills_recursive(skills_dir, &mut skills)?;
+    skills.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(skills)
+}
+
+/// Give every skill a link name that is unique within its agent folder (or category folder).
+///
+/// Skills are taken in install order, so the first one keeps its plain directory name (or its
+/// alias) and later skills from other taps are linked as `<skill>@<tap-owner>` (or per `policy`).
+fn assign_link_names(skills: Vec<Skill>, skills_dir: &Path, db: &Database, policy: DuplicateLinks) -> Vec<SkillLink> {
+    let mut skills: Vec<(String, Skill)> = skills
+        .into_iter()
+        .map(|skill| {
+            let full_name = skill
+                .path
+                .strip_prefix(skills_dir)
+                .map(|p| p.to_string_lossy().replace('\\', "/"))
+                .unwrap_or_else(|_| skill.name.clone());
+            (full_name, skill)
+        })
+        .collect();
 
-    let mut seen = HashSet::new();
-    let mut unique = Vec::new();
+    // Untracked (legacy) skills have no install time and sort first
+    skills.sort_by_key(|(full_name, _)| (db.installed.get(full_name).map(|i| i.installed_at), full_name.clone()));
 
-    for skill in skills {
-        let link_name = skill_link_name(&skill);
-        if !seen.insert(link_name.clone()) {
-            println!(
+    let mut taken: HashSet<(Option<String>, String)> = HashSet::new();
+    let mut links = Vec::new();
+
+    for (full_name, skill) in skills {
+        let base = skill_link_name(&skill, installed_alias(db, &full_name));
+        let chosen = link_name_candidates(&base, &full_name, policy)
+            .into_iter()
+            .find(|name| !taken.contains(&(skill.category.clone(), name.clone())));
+
+        match chosen {
+            Some(link_name) => {
+                taken.insert((skill.category.clone(), link_name.clone()));
+                links.push(SkillLink {
+                    skill,
+                    full_name,
+                    link_name,
+                });
+            }
+            None => println!(
                 "{} Duplicate skill name '{}' at {}",
                 "Warning:".yellow(),
-                link_name,
+                base,
                 skill.path.display()
-            );
-            continue;
+            ),
         }
-        unique.push(skill);
     }
 
-    unique.sort_by_key(skill_link_name);
+    links.sort_by(|a, b| a.link_name.cmp(&b.link_name));
+    links
+}
+
+/// Link names to try for a skill, in order of preference
+fn link_name_candidates(base: &str, full_name: &str, policy: DuplicateLinks) -> Vec<String> {
+    let mut candidates = vec![base.to_string()];
+    if policy == DuplicateLinks::Skip {
+        return candidates;
+    }
 
-    Ok(unique)
+    // Only tap skills ("owner/repo/skill") carry an owner to disambiguate with
+    let parts: Vec<&str> = full_name.split('/').collect();
+    if let [owner, repo, _, ..] = parts.as_slice() {
+        if policy == DuplicateLinks::Owner {
+            candidates.push(format!("{}@{}", base, owner));
+        }
+        candidates.push(format!("{}@{}-{}", base, owner, repo));
+    }
+    candidates
 }
 
 #[cfg(test)]
@@ -369,10 +984,517 @@ mod tests {
         write_skill(&skills_dir.join("tap-a").join("nested-skill"), "nested-skill");
 
         let skills = collect_installed_skills(skills_dir).unwrap();
-        let names: Vec<String> = skills.iter().map(skill_link_name).collect();
+        let names: Vec<String> = skills.iter().map(|skill| skill_link_name(skill, None)).collect();
 
         assert_eq!(names.len(), 2);
         assert!(names.contains(&"legacy-skill".to_string()));
         assert!(names.contains(&"nested-skill".to_string()));
     }
+
+    #[test]
+    fn test_flat_and_nested_layouts_link_identically() {
+        use crate::registry::models::InstallLayout;
+
+        let link_names = |layout: InstallLayout| {
+            let temp = TempDir::new().unwrap();
+            for (tap, skill) in [("owner/repo", "code-review"), ("owner/other", "deploy")] {
+                write_skill(&layout.skill_dir(temp.path(), tap, skill), skill);
+            }
+            let mut names: Vec<String> = collect_installed_skills(temp.path())
+                .unwrap()
+                .iter()
+                .map(|skill| skill_link_name(skill, None))
+                .collect();
+            names.sort();
+            names
+        };
+
+        assert_eq!(link_names(InstallLayout::Nested), vec!["code-review", "deploy"]);
+        assert_eq!(link_names(InstallLayout::Flat), link_names(InstallLayout::Nested));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_link_to_agents_dry_run_creates_nothing() {
+        let temp = TempDir::new().unwrap();
+        let home = temp.path();
+        fs::create_dir_all(home.join(".claude")).unwrap();
+        write_skill(&home.join(".skillshub/skills/tap-a/my-skill"), "my-skill");
+
+        let prev = std::env::var("SKILLSHUB_TEST_HOME").ok();
+        std::env::set_var("SKILLSHUB_TEST_HOME", home);
+        let result = link_to_agents(&RunContext::new(true));
+        match prev {
+            Some(v) => std::env::set_var("SKILLSHUB_TEST_HOME", v),
+            None => std::env::remove_var("SKILLSHUB_TEST_HOME"),
+        }
+
+        assert!(result.is_ok(), "dry-run link failed: {:?}", result);
+        assert!(
+            !home.join(".claude/skills").exists(),
+            "dry-run must not create agent dirs"
+        );
+        assert!(
+            !home.join(".skillshub/db.json").exists(),
+            "dry-run must not write db.json"
+        );
+    }
+
+    #[test]
+    fn test_is_symlink_privilege_error() {
+        let denied = std::io::Error::from_raw_os_error(1314);
+        assert!(is_symlink_privilege_error(&denied));
+        let perm = std::io::Error::new(std::io::ErrorKind::PermissionDenied, "denied");
+        assert!(is_symlink_privilege_error(&perm));
+        let other = std::io::Error::new(std::io::ErrorKind::NotFound, "missing");
+        assert!(!is_symlink_privilege_error(&other));
+    }
+
+    #[test]
+    fn test_copy_skill_dir_marks_copy_as_managed() {
+        let temp = TempDir::new().unwrap();
+        let source = temp.path().join("source");
+        write_skill(&source, "copied");
+        let dest = temp.path().join("agent-skills").join("copied");
+
+        copy_skill_dir(&source, &dest).unwrap();
+
+        assert!(dest.join("SKILL.md").exists());
+        assert!(is_managed_entry(&dest));
+        assert!(!is_managed_entry(&source));
+    }
+
+    #[test]
+    #[cfg(unix)]
+    #[serial_test::serial]
+    fn test_link_to_agents_places_categorized_skill_in_subfolder() {
+        let temp = TempDir::new().unwrap();
+        let home = temp.path();
+        fs::create_dir_all(home.join(".claude")).unwrap();
+        let skill_dir = home.join(".skillshub/skills/tap-a/reviewer");
+        fs::create_dir_all(&skill_dir).unwrap();
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: reviewer\ndescription: Reviews code\ncategory: review\n---\n",
+        )
+        .unwrap();
+
+        let prev = std::env::var("SKILLSHUB_TEST_HOME").ok();
+        std::env::set_var("SKILLSHUB_TEST_HOME", home);
+        let first = link_to_agents(&RunContext::default());
+        // Linking again must not mistake the category folder for an external skill
+        let second = link_to_agents(&RunContext::default());
+        let db = crate::registry::db::load_db();
+        match prev {
+            Some(v) => std::env::set_var("SKILLSHUB_TEST_HOME", v),
+            None => std::env::remove_var("SKILLSHUB_TEST_HOME"),
+        }
+
+        assert!(first.is_ok() && second.is_ok());
+        let link = home.join(".claude/skills/review/reviewer");
+        assert!(
+            link.is_symlink(),
+            "categorized skill should be linked under skills/review/"
+        );
+        assert_eq!(fs::read_link(&link).unwrap(), skill_dir);
+        assert!(db.unwrap().external.is_empty());
+    }
+
+    /// A second `link` with nothing changed leaves the agent's links untouched, while a link
+    /// removed by hand, `--force`, or a newly installed skill re-checks it
+    #[test]
+    #[cfg(unix)]
+    #[serial_test::serial]
+    fn test_link_skips_unchanged_agents() {
+        use std::os::unix::fs::MetadataExt;
+
+        let temp = TempDir::new().unwrap();
+        let home = temp.path();
+        fs::create_dir_all(home.join(".claude")).unwrap();
+        let install_dir = home.join(".skillshub/skills/tap-a");
+        write_skill(&install_dir.join("linter"), "linter");
+
+        let prev = std::env::var("SKILLSHUB_TEST_HOME").ok();
+        std::env::set_var("SKILLSHUB_TEST_HOME", home);
+        let linter = home.join(".claude/skills/linter");
+        let inode = |path: &Path| fs::symlink_metadata(path).map(|m| m.ino()).ok();
+        let first = link_to_agents(&RunContext::default());
+        let linked_first = linter.is_symlink();
+        let first_inode = inode(&linter);
+        let mtime = |path: &Path| fs::metadata(path).and_then(|m| m.modified()).ok();
+        let first_mtime = mtime(&home.join(".claude/skills"));
+
+        let unchanged = link_to_agents(&RunContext::default());
+        let unchanged_inode = inode(&linter);
+        let unchanged_mtime = mtime(&home.join(".claude/skills"));
+
+        fs::remove_file(&linter).unwrap();
+        let repaired = link_to_agents(&RunContext::default());
+        let relinked_missing = linter.is_symlink();
+
+        let forced = link_to_agents_with(
+            &RunContext::default(),
+            &LinkOptions {
+                force: true,
+                ..Default::default()
+            },
+        );
+
+        write_skill(&install_dir.join("formatter"), "formatter");
+        let changed = link_to_agents(&RunContext::default());
+        let db = crate::registry::db::load_db();
+        match prev {
+            Some(v) => std::env::set_var("SKILLSHUB_TEST_HOME", v),
+            None => std::env::remove_var("SKILLSHUB_TEST_HOME"),
+        }
+
+        assert!(first.is_ok() && unchanged.is_ok() && repaired.is_ok() && forced.is_ok() && changed.is_ok());
+        assert!(linked_first);
+        assert_eq!(unchanged_inode, first_inode, "unchanged agent should not be relinked");
+        assert_eq!(
+            unchanged_mtime, first_mtime,
+            "unchanged agent's skills dir should not be written"
+        );
+        assert!(relinked_missing, "a link removed by hand should be recreated");
+        assert!(linter.is_symlink());
+        assert!(home.join(".claude/skills/formatter").is_symlink());
+        let db = db.unwrap();
+        assert!(db.linked_agents.contains(".claude"));
+        assert!(db.link_states.contains_key(".claude"));
+    }
+
+    /// With `--agent`, the `--json` report covers only that agent, listing managed skills
+    /// blocked by an unmanaged file of the same name as skipped
+    #[test]
+    #[cfg(unix)]
+    #[serial_test::serial]
+    fn test_link_json_report_for_single_agent() {
+        let temp = TempDir::new().unwrap();
+        let home = temp.path();
+        fs::create_dir_all(home.join(".claude")).unwrap();
+        fs::create_dir_all(home.join(".codex/skills")).unwrap();
+        fs::write(home.join(".codex/skills/linter"), "not a skill").unwrap();
+        write_skill(&home.join(".skillshub/skills/tap-a/linter"), "linter");
+        write_skill(&home.join(".skillshub/skills/tap-a/formatter"), "formatter");
+
+        let prev = std::env::var("SKILLSHUB_TEST_HOME").ok();
+        std::env::set_var("SKILLSHUB_TEST_HOME", home);
+        let only_codex = vec![".codex".to_string()];
+        let reports = link_agents(
+            &RunContext::default(),
+            &LinkOptions {
+                agents: &only_codex,
+                json: true,
+                ..Default::default()
+            },
+        );
+        match prev {
+            Some(v) => std::env::set_var("SKILLSHUB_TEST_HOME", v),
+            None => std::env::remove_var("SKILLSHUB_TEST_HOME"),
+        }
+
+        let json = serde_json::to_value(reports.unwrap()).unwrap();
+        let reports = json.as_array().unwrap();
+        assert_eq!(reports.len(), 1, "only the named agent is reported");
+        let codex = &reports[0];
+        assert_eq!(codex["agent"], ".codex");
+        assert_eq!(codex["linked"], serde_json::json!(["formatter"]));
+        assert_eq!(codex["synced_external"], serde_json::json!([]));
+        assert_eq!(codex["skipped"], serde_json::json!(["linter"]));
+        assert_eq!(codex["unchanged"], false);
+        assert!(home.join(".codex/skills/formatter").is_symlink());
+        assert!(!home.join(".claude/skills").exists());
+    }
+
+    /// `--agent` links only the named agent, and `clean links --agent` unlinks only it
+    #[test]
+    #[cfg(unix)]
+    #[serial_test::serial]
+    fn test_link_and_clean_single_agent() {
+        let temp = TempDir::new().unwrap();
+        let home = temp.path();
+        fs::create_dir_all(home.join(".claude")).unwrap();
+        fs::create_dir_all(home.join(".codex")).unwrap();
+        let skill = home.join(".skillshub/skills/tap-a/linter");
+        fs::create_dir_all(&skill).unwrap();
+        fs::write(skill.join("SKILL.md"), "---\nname: linter\ndescription: Lints\n---\n").unwrap();
+
+        let prev = std::env::var("SKILLSHUB_TEST_HOME").ok();
+        std::env::set_var("SKILLSHUB_TEST_HOME", home);
+        let only_claude = vec![".claude".to_string()];
+        let linked = link_to_agents_with(
+            &RunContext::default(),
+            &LinkOptions {
+                agents: &only_claude,
+                ..Default::default()
+            },
+        );
+        let claude_linked = home.join(".claude/skills/linter").is_symlink();
+        let codex_linked = home.join(".codex/skills/linter").exists();
+
+        let both = link_to_agents_with(&RunContext::default(), &LinkOptions::default());
+        let cleaned = crate::commands::clean_links(false, &only_claude);
+        match prev {
+            Some(v) => std::env::set_var("SKILLSHUB_TEST_HOME", v),
+            None => std::env::remove_var("SKILLSHUB_TEST_HOME"),
+        }
+
+        assert!(linked.is_ok() && both.is_ok() && cleaned.is_ok());
+        assert!(claude_linked, "named agent should be linked");
+        assert!(!codex_linked, "other agents should be left alone");
+        assert!(!home.join(".claude/skills/linter").exists());
+        assert!(home.join(".codex/skills/linter").is_symlink());
+    }
+
+    /// With `--no-sync-external`, an external skill is still recorded in db.json but is
+    /// not linked into the other agents
+    #[test]
+    #[cfg(unix)]
+    #[serial_test::serial]
+    fn test_link_no_sync_external_skips_cross_agent_links() {
+        let temp = TempDir::new().unwrap();
+        let home = temp.path();
+        write_skill(&home.join(".claude/skills/handmade"), "handmade");
+        fs::create_dir_all(home.join(".codex")).unwrap();
+        write_skill(&home.join(".skillshub/skills/tap-a/linter"), "linter");
+
+        let prev = std::env::var("SKILLSHUB_TEST_HOME").ok();
+        std::env::set_var("SKILLSHUB_TEST_HOME", home);
+        let linked = link_to_agents_with(
+            &RunContext::default(),
+            &LinkOptions {
+                no_sync_external: true,
+                ..Default::default()
+            },
+        );
+        let db = crate::registry::db::load_db();
+        match prev {
+            Some(v) => std::env::set_var("SKILLSHUB_TEST_HOME", v),
+            None => std::env::remove_var("SKILLSHUB_TEST_HOME"),
+        }
+
+        linked.unwrap();
+        assert!(
+            db.unwrap().external.contains_key("handmade"),
+            "external skill still tracked"
+        );
+        assert!(
+            home.join(".codex/skills/linter").is_symlink(),
+            "managed skills still linked"
+        );
+        assert!(
+            !home.join(".codex/skills/handmade").exists(),
+            "external skill not synced"
+        );
+        assert!(!home.join(".claude/skills/handmade").is_symlink());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    #[serial_test::serial]
+    fn test_link_to_agents_into_prefix() {
+        let temp = TempDir::new().unwrap();
+        let home = temp.path();
+        fs::create_dir_all(home.join(".claude")).unwrap();
+        let plain = home.join(".skillshub/skills/tap-a/linter");
+        fs::create_dir_all(&plain).unwrap();
+        fs::write(plain.join("SKILL.md"), "---\nname: linter\ndescription: Lints\n---\n").unwrap();
+        let categorized = home.join(".skillshub/skills/tap-a/reviewer");
+        fs::create_dir_all(&categorized).unwrap();
+        fs::write(
+            categorized.join("SKILL.md"),
+            "---\nname: reviewer\ndescription: Reviews code\ncategory: review\n---\n",
+        )
+        .unwrap();
+
+        let prev = std::env::var("SKILLSHUB_TEST_HOME").ok();
+        std::env::set_var("SKILLSHUB_TEST_HOME", home);
+        let prefix = Path::new("community");
+        let first = link_to_agents_with(
+            &RunContext::default(),
+            &LinkOptions {
+                prefix: Some(prefix),
+                ..Default::default()
+            },
+        );
+        // The prefix folder must not be mistaken for an external skill on the next run
+        let second = link_to_agents_with(
+            &RunContext::default(),
+            &LinkOptions {
+                prefix: Some(prefix),
+                ..Default::default()
+            },
+        );
+        let escaping = link_to_agents_with(
+            &RunContext::default(),
+            &LinkOptions {
+                prefix: Some(Path::new("../outside")),
+                ..Default::default()
+            },
+        );
+        let db = crate::registry::db::load_db();
+        match prev {
+            Some(v) => std::env::set_var("SKILLSHUB_TEST_HOME", v),
+            None => std::env::remove_var("SKILLSHUB_TEST_HOME"),
+        }
+
+        assert!(first.is_ok() && second.is_ok());
+        assert!(escaping.is_err(), "prefixes must stay inside the skills directory");
+        assert_eq!(
+            fs::read_link(home.join(".claude/skills/community/linter")).unwrap(),
+            plain
+        );
+        assert_eq!(
+            fs::read_link(home.join(".claude/skills/community/review/reviewer")).unwrap(),
+            categorized
+        );
+        assert!(!home.join(".claude/skills/linter").exists());
+        assert!(db.unwrap().external.is_empty());
+    }
+
+    #[test]
+    fn test_link_name_candidates_by_policy() {
+        assert_eq!(
+            link_name_candidates("debugging", "bob/kit/debugging", DuplicateLinks::Owner),
+            vec!["debugging", "debugging@bob", "debugging@bob-kit"]
+        );
+        assert_eq!(
+            link_name_candidates("debugging", "bob/kit/debugging", DuplicateLinks::Tap),
+            vec!["debugging", "debugging@bob-kit"]
+        );
+        assert_eq!(
+            link_name_candidates("debugging", "bob/kit/debugging", DuplicateLinks::Skip),
+            vec!["debugging"]
+        );
+        // Legacy flat skills have no tap to disambiguate with
+        assert_eq!(
+            link_name_candidates("debugging", "debugging", DuplicateLinks::Owner),
+            vec!["debugging"]
+        );
+        assert_eq!(DuplicateLinks::parse(Some("TAP")), DuplicateLinks::Tap);
+        assert_eq!(DuplicateLinks::parse(None), DuplicateLinks::Owner);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    #[serial_test::serial]
+    fn test_link_to_agents_disambiguates_duplicate_names_across_taps() {
+        let temp = TempDir::new().unwrap();
+        let home = temp.path();
+        fs::create_dir_all(home.join(".claude")).unwrap();
+        let alice = home.join(".skillshub/skills/alice/tools/debugging");
+        let bob = home.join(".skillshub/skills/bob/kit/debugging");
+        write_skill(&alice, "debugging");
+        write_skill(&bob, "debugging");
+
+        // alice's copy was installed first, so it keeps the plain name
+        let installed = |tap: &str, at: &str| {
+            serde_json::json!({
+                "tap": tap, "skill": "debugging", "commit": null, "installed_at": at,
+                "source_url": null, "source_path": null, "gist_updated_at": null
+            })
+        };
+        let db_json = serde_json::json!({
+            "taps": {},
+            "installed": {
+                "bob/kit/debugging": installed("bob/kit", "2026-02-01T00:00:00Z"),
+                "alice/tools/debugging": installed("alice/tools", "2026-01-01T00:00:00Z")
+            },
+            "linked_agents": [],
+            "external": {}
+        });
+        fs::write(home.join(".skillshub/db.json"), db_json.to_string()).unwrap();
+
+        let prev = std::env::var("SKILLSHUB_TEST_HOME").ok();
+        std::env::set_var("SKILLSHUB_TEST_HOME", home);
+        let result = link_to_agents(&RunContext::default());
+        let db = crate::registry::db::load_db().unwrap();
+        let agent_skills = home.join(".claude/skills");
+        let plain = fs::read_link(agent_skills.join("debugging")).ok();
+        let disambiguated = fs::read_link(agent_skills.join("debugging@bob")).ok();
+        let cleaned = crate::commands::clean_links(false, &[]);
+        let db_after_clean = crate::registry::db::load_db().unwrap();
+        match prev {
+            Some(v) => std::env::set_var("SKILLSHUB_TEST_HOME", v),
+            None => std::env::remove_var("SKILLSHUB_TEST_HOME"),
+        }
+
+        assert!(result.is_ok(), "link failed: {:?}", result);
+        assert_eq!(plain, Some(alice));
+        assert_eq!(disambiguated, Some(bob));
+        assert_eq!(
+            db.link_names.get("bob/kit/debugging").map(String::as_str),
+            Some("debugging@bob")
+        );
+
+        assert!(cleaned.is_ok());
+        assert!(!agent_skills.join("debugging").exists());
+        assert!(!agent_skills.join("debugging@bob").is_symlink());
+        assert!(db_after_clean.link_names.is_empty());
+    }
+
+    #[test]
+    fn test_append_git_excludes_adds_each_pattern_once() {
+        let temp = TempDir::new().unwrap();
+        let gitignore = temp.path().join(".gitignore");
+        fs::write(&gitignore, "settings.local.json").unwrap();
+
+        let patterns = vec![
+            "/skills/a".to_string(),
+            "/skills/b".to_string(),
+            "/skills/a".to_string(),
+        ];
+        assert_eq!(append_git_excludes(&gitignore, &patterns).unwrap(), 2);
+        assert_eq!(append_git_excludes(&gitignore, &patterns).unwrap(), 0);
+
+        let content = fs::read_to_string(&gitignore).unwrap();
+        assert_eq!(content, "settings.local.json\n/skills/a\n/skills/b\n");
+    }
+
+    #[test]
+    fn test_git_exclude_file_prefers_info_exclude_in_checkouts() {
+        let temp = TempDir::new().unwrap();
+        assert_eq!(git_exclude_file(temp.path()), temp.path().join(".gitignore"));
+
+        fs::create_dir_all(temp.path().join(".git")).unwrap();
+        assert_eq!(
+            git_exclude_file(temp.path()),
+            temp.path().join(".git").join("info").join("exclude")
+        );
+    }
+
+    #[test]
+    #[cfg(unix)]
+    #[serial_test::serial]
+    fn test_link_git_exclude_writes_link_names_once() {
+        let temp = TempDir::new().unwrap();
+        let home = temp.path();
+        fs::create_dir_all(home.join(".claude")).unwrap();
+        write_skill(&home.join(".skillshub/skills/tap-a/my-skill"), "my-skill");
+
+        let prev = std::env::var("SKILLSHUB_TEST_HOME").ok();
+        std::env::set_var("SKILLSHUB_TEST_HOME", home);
+        let first = link_to_agents_with(
+            &RunContext::default(),
+            &LinkOptions {
+                git_exclude: true,
+                ..Default::default()
+            },
+        );
+        let second = link_to_agents_with(
+            &RunContext::default(),
+            &LinkOptions {
+                git_exclude: true,
+                ..Default::default()
+            },
+        );
+        match prev {
+            Some(v) => std::env::set_var("SKILLSHUB_TEST_HOME", v),
+            None => std::env::remove_var("SKILLSHUB_TEST_HOME"),
+        }
+
+        assert!(first.is_ok() && second.is_ok());
+        let content = fs::read_to_string(home.join(".claude/.gitignore")).unwrap();
+        assert_eq!(content.matches("/skills/my-skill").count(), 1, "{}", content);
+    }
 }
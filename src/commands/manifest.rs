@@ -0,0 +1,508 @@
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use super::link::link_to_agents;
+use crate::context::RunContext;
+use crate::registry::add_skill_from_url;
+use crate::registry::db::{get_installed_skill, init_db, DEFAULT_TAP_NAME, LOCAL_TAP_NAME};
+use crate::registry::github::is_gist_url;
+use crate::registry::models::{is_commit_sha, Database};
+use crate::registry::skill::{install_skill_at_commit, install_skill_internal};
+use crate::registry::tap::{add_tap, get_tap_registry, save_refreshed_registries, TapAddOptions};
+
+/// Current manifest format version
+const MANIFEST_VERSION: u32 = 1;
+
+/// Portable description of a skillshub setup, written by `skillshub export`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Manifest {
+    /// Manifest format version
+    pub version: u32,
+
+    /// When the manifest was written
+    pub exported_at: DateTime<Utc>,
+
+    /// Configured taps, excluding the default tap
+    #[serde(default)]
+    pub taps: Vec<ManifestTap>,
+
+    /// Installed skills, excluding bundled skills from the default tap
+    #[serde(default)]
+    pub skills: Vec<ManifestSkill>,
+
+    /// Skills offered by configured taps but not installed (`--include-available`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recommended: Vec<String>,
+}
+
+/// A tap entry in a manifest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestTap {
+    /// Tap name (e.g., "owner/repo")
+    pub name: String,
+
+    /// Repository URL the tap was added from
+    pub url: String,
+
+    /// Branch that was cloned (None = repo default branch)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+}
+
+/// An installed skill entry in a manifest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestSkill {
+    /// Full skill name (e.g., "owner/repo/skill")
+    pub name: String,
+
+    /// Source URL for the skill (tap or gist URL)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_url: Option<String>,
+
+    /// Commit the skill was installed at
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+}
+
+/// Export taps and installed skills to a manifest file, or stdout when `output` is `None`
+pub fn export_manifest(output: Option<&Path>, include_available: bool) -> Result<()> {
+    let mut db = init_db()?;
+    let manifest = build_manifest(&mut db, include_available)?;
+    save_refreshed_registries(&db)?;
+    let json = serde_json::to_string_pretty(&manifest)?;
+
+    match output {
+        Some(path) => {
+            std::fs::write(path, format!("{}\n", json))
+                .with_context(|| format!("Failed to write manifest to {}", path.display()))?;
+            println!(
+                "{} Exported {} tap(s) and {} skill(s) to {}",
+                "✓".green(),
+                manifest.taps.len(),
+                manifest.skills.len(),
+                path.display()
+            );
+            if !manifest.recommended.is_empty() {
+                println!(
+                    "  {} recommended skill(s) from configured taps",
+                    manifest.recommended.len()
+                );
+            }
+        }
+        None => println!("{}", json),
+    }
+
+    Ok(())
+}
+
+/// Counts reported at the end of `skillshub import`
+#[derive(Debug, Default)]
+struct ImportSummary {
+    taps_added: usize,
+    taps_skipped: usize,
+    skills_installed: usize,
+    skills_skipped: usize,
+    failed: usize,
+}
+
+/// Re-add the taps and install the skills recorded in a manifest written by `skillshub export`.
+/// Already configured taps and already installed skills are skipped.
+pub fn import_manifest(file: &Path, with_recommended: bool) -> Result<()> {
+    let content =
+        std::fs::read_to_string(file).with_context(|| format!("Failed to read manifest {}", file.display()))?;
+    let manifest = parse_manifest(&content).with_context(|| format!("Invalid manifest {}", file.display()))?;
+
+    let mut summary = ImportSummary::default();
+
+    for tap in &manifest.taps {
+        if init_db()?.taps.contains_key(&tap.name) {
+            println!("  {} {} (tap already configured)", "○".yellow(), tap.name);
+            summary.taps_skipped += 1;
+            continue;
+        }
+        match add_tap(
+            &RunContext::default(),
+            &tap.url,
+            &TapAddOptions {
+                branch: tap.branch.as_deref(),
+                ..TapAddOptions::default()
+            },
+        ) {
+            Ok(()) => summary.taps_added += 1,
+            Err(e) => {
+                println!("  {} {} ({})", "✗".red(), tap.name, e);
+                summary.failed += 1;
+            }
+        }
+    }
+
+    let mut skills = manifest.skills.clone();
+    if with_recommended {
+        skills.extend(manifest.recommended.iter().map(|name| ManifestSkill {
+            name: name.clone(),
+            source_url: None,
+            commit: None,
+        }));
+    }
+
+    if !skills.is_empty() {
+        println!("{} Installing {} skill(s)", "=>".green().bold(), skills.len());
+    }
+
+    for skill in &skills {
+        if get_installed_skill(&init_db()?, &skill.name).is_some() {
+            println!("  {} {} (already installed)", "○".yellow(), skill.name);
+            summary.skills_skipped += 1;
+            continue;
+        }
+
+        match import_skill(skill) {
+            Ok(true) => summary.skills_installed += 1,
+            Ok(false) => summary.skills_skipped += 1,
+            Err(e) => {
+                println!("  {} {} ({})", "✗".red(), skill.name, e);
+                summary.failed += 1;
+            }
+        }
+    }
+
+    // Link once after all installations
+    if summary.skills_installed > 0 {
+        link_to_agents(&RunContext::default())?;
+    }
+
+    println!(
+        "\n{} Taps: {} added, {} already configured. Skills: {} installed, {} already installed.",
+        "Done!".green().bold(),
+        summary.taps_added,
+        summary.taps_skipped,
+        summary.skills_installed,
+        summary.skills_skipped
+    );
+    if summary.failed > 0 {
+        println!("  {} {} item(s) failed, see above", "!".yellow(), summary.failed);
+    }
+
+    Ok(())
+}
+
+/// Install one manifest skill. Gist skills are re-added from their URL; everything else is
+/// installed from its tap at the recorded commit, falling back to the recorded source URL
+/// for skills added by URL.
+fn import_skill(skill: &ManifestSkill) -> Result<bool> {
+    if let Some(url) = skill.source_url.as_deref().filter(|url| is_gist_url(url)) {
+        add_skill_from_url(url, None)?;
+        return Ok(true);
+    }
+
+    let ctx = RunContext::default();
+    let installed = match skill.commit.as_deref().filter(|c| is_commit_sha(c)) {
+        Some(commit) => install_skill_at_commit(&ctx, &skill.name, commit),
+        None => install_skill_internal(&ctx, &skill.name, None),
+    };
+    match installed {
+        Ok(installed) => Ok(installed),
+        Err(e) => match skill.source_url.as_deref() {
+            Some(url) if url.contains("/tree/") => {
+                add_skill_from_url(url, None)?;
+                Ok(true)
+            }
+            _ => Err(e),
+        },
+    }
+}
+
+/// Parse manifest JSON, rejecting versions newer than this build understands
+fn parse_manifest(content: &str) -> Result<Manifest> {
+    let manifest: Manifest = serde_json::from_str(content)?;
+    if manifest.version > MANIFEST_VERSION {
+        anyhow::bail!(
+            "manifest version {} is newer than supported version {}; upgrade skillshub",
+            manifest.version,
+            MANIFEST_VERSION
+        );
+    }
+    Ok(manifest)
+}
+
+/// Build a manifest from the database. Bundled skills from the default tap are left out
+/// since every installation already ships them.
+fn build_manifest(db: &mut Database, include_available: bool) -> Result<Manifest> {
+    let mut taps: Vec<ManifestTap> = db
+        .taps
+        .iter()
+        .filter(|(name, tap)| !is_default_tap(name, tap.is_default) && !is_gist_url(&tap.url))
+        .map(|(name, tap)| ManifestTap {
+            name: name.clone(),
+            url: tap.url.clone(),
+            branch: tap.branch.clone(),
+        })
+        .collect();
+    taps.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut skills: Vec<ManifestSkill> = db
+        .installed
+        .iter()
+        .filter(|(_, installed)| {
+            let tap_is_default = db.taps.get(&installed.tap).is_some_and(|t| t.is_default);
+            // Adopted skills exist only on this machine, so there is nothing to re-install
+            !is_default_tap(&installed.tap, tap_is_default) && installed.tap != LOCAL_TAP_NAME
+        })
+        .map(|(full_name, installed)| ManifestSkill {
+            name: full_name.clone(),
+            source_url: installed.source_url.clone(),
+            commit: installed.commit.clone(),
+        })
+        .collect();
+    skills.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut recommended = Vec::new();
+    if include_available {
+        let tap_names: Vec<String> = db.taps.keys().cloned().collect();
+        for tap_name in &tap_names {
+            let registry = match get_tap_registry(db, tap_name) {
+                Ok(Some(r)) => r,
+                Ok(None) | Err(_) => continue,
+            };
+            for skill_name in registry.skills.keys() {
+                let full_name = format!("{}/{}", tap_name, skill_name);
+                if !db.installed.contains_key(&full_name) {
+                    recommended.push(full_name);
+                }
+            }
+        }
+        recommended.sort();
+    }
+
+    Ok(Manifest {
+        version: MANIFEST_VERSION,
+        exported_at: Utc::now(),
+        taps,
+        skills,
+        recommended,
+    })
+}
+
+fn is_default_tap(name: &str, is_default: bool) -> bool {
+    is_default || name == DEFAULT_TAP_NAME
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::models::{InstalledSkill, SkillEntry, TapInfo, TapRegistry};
+    use std::collections::HashMap;
+
+    fn entry(path: &str) -> SkillEntry {
+        SkillEntry {
+            path: path.to_string(),
+            description: None,
+            homepage: None,
+            commit: None,
+            tags: Vec::new(),
+            aliases: Vec::new(),
+        }
+    }
+
+    fn installed(tap: &str, skill: &str, commit: Option<&str>) -> InstalledSkill {
+        InstalledSkill {
+            tap: tap.to_string(),
+            skill: skill.to_string(),
+            commit: commit.map(str::to_string),
+            installed_at: Utc::now(),
+            source_url: Some(format!("https://github.com/{}", tap)),
+            source_path: Some(format!("skills/{}", skill)),
+            gist_updated_at: None,
+            content_sha256: None,
+            size_bytes: None,
+            alias: None,
+            branch: None,
+        }
+    }
+
+    /// A db with tap `owner/repo` offering `alpha` (installed) and `beta` (not installed),
+    /// plus a bundled skill installed from the default tap.
+    fn sample_db() -> Database {
+        let mut db = Database::default();
+        db.taps.insert(
+            "owner/repo".to_string(),
+            TapInfo {
+                url: "https://github.com/owner/repo".to_string(),
+                custom_skills_path: None,
+                updated_at: None,
+                is_default: false,
+                cached_registry: Some(TapRegistry {
+                    name: "owner/repo".to_string(),
+                    description: None,
+                    schema_version: None,
+                    skills: HashMap::from([
+                        ("alpha".to_string(), entry("skills/alpha")),
+                        ("beta".to_string(), entry("skills/beta")),
+                    ]),
+                }),
+                branch: Some("dev".to_string()),
+                token_env: None,
+                registry_file: None,
+            },
+        );
+        db.installed.insert(
+            "owner/repo/alpha".to_string(),
+            installed("owner/repo", "alpha", Some("abc1234")),
+        );
+        db.installed.insert(
+            format!("{}/bundled", DEFAULT_TAP_NAME),
+            installed(DEFAULT_TAP_NAME, "bundled", None),
+        );
+        db
+    }
+
+    #[test]
+    fn test_build_manifest_installed_only() {
+        let manifest = build_manifest(&mut sample_db(), false).unwrap();
+
+        assert_eq!(manifest.taps.len(), 1);
+        assert_eq!(manifest.taps[0].name, "owner/repo");
+        assert_eq!(manifest.taps[0].branch.as_deref(), Some("dev"));
+
+        // Bundled default-tap skills are not exported
+        assert_eq!(manifest.skills.len(), 1);
+        assert_eq!(manifest.skills[0].name, "owner/repo/alpha");
+        assert_eq!(manifest.skills[0].commit.as_deref(), Some("abc1234"));
+
+        assert!(manifest.recommended.is_empty());
+        let json = serde_json::to_string(&manifest).unwrap();
+        assert!(!json.contains("recommended"), "lean manifest should omit the section");
+    }
+
+    #[test]
+    fn test_build_manifest_include_available_adds_recommended() {
+        let manifest = build_manifest(&mut sample_db(), true).unwrap();
+
+        assert_eq!(manifest.recommended, vec!["owner/repo/beta".to_string()]);
+        assert!(
+            manifest.skills.iter().all(|s| s.name != "owner/repo/beta"),
+            "available skills must not be listed as installed"
+        );
+    }
+
+    #[test]
+    fn test_parse_manifest_roundtrip_and_version_check() {
+        let manifest = build_manifest(&mut sample_db(), true).unwrap();
+        let json = serde_json::to_string(&manifest).unwrap();
+        let parsed = parse_manifest(&json).unwrap();
+        assert_eq!(parsed.skills.len(), 1);
+        assert_eq!(parsed.recommended, vec!["owner/repo/beta".to_string()]);
+
+        let newer = json.replace("\"version\":1", "\"version\":99");
+        let err = parse_manifest(&newer).unwrap_err();
+        assert!(err.to_string().contains("newer than supported"), "{}", err);
+    }
+
+    /// RAII guard that restores `SKILLSHUB_TEST_HOME` on drop
+    struct TestHomeGuard(Option<String>);
+
+    impl TestHomeGuard {
+        fn set(home: &Path) -> Self {
+            let prev = std::env::var("SKILLSHUB_TEST_HOME").ok();
+            std::env::set_var("SKILLSHUB_TEST_HOME", home);
+            Self(prev)
+        }
+    }
+
+    impl Drop for TestHomeGuard {
+        fn drop(&mut self) {
+            match self.0.take() {
+                Some(v) => std::env::set_var("SKILLSHUB_TEST_HOME", v),
+                None => std::env::remove_var("SKILLSHUB_TEST_HOME"),
+            }
+        }
+    }
+
+    /// Importing a manifest onto a machine that already has everything changes nothing
+    #[test]
+    #[serial_test::serial]
+    fn test_import_skips_existing_taps_and_skills() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let home = temp.path().join("home");
+        std::fs::create_dir_all(home.join(".skillshub")).unwrap();
+
+        let mut db = sample_db();
+        std::fs::write(home.join(".skillshub/db.json"), serde_json::to_string(&db).unwrap()).unwrap();
+        let manifest_path = temp.path().join("skills.json");
+        let manifest = build_manifest(&mut db, false).unwrap();
+        std::fs::write(&manifest_path, serde_json::to_string(&manifest).unwrap()).unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        import_manifest(&manifest_path, false).unwrap();
+
+        let after = init_db().unwrap();
+        assert_eq!(after.installed.len(), db.installed.len());
+        assert!(after.installed.contains_key("owner/repo/alpha"));
+        assert_eq!(after.taps["owner/repo"].url, "https://github.com/owner/repo");
+    }
+
+    fn git(dir: &Path, args: &[&str]) -> String {
+        let output = std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    /// Skills are installed at the commit recorded in the manifest, not the tap's HEAD
+    #[test]
+    #[serial_test::serial]
+    fn test_import_installs_recorded_commit() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let origin = temp.path().join("origin");
+        std::fs::create_dir_all(origin.join("skills/alpha")).unwrap();
+        git(&origin, &["init"]);
+        git(&origin, &["config", "user.email", "test@test.com"]);
+        git(&origin, &["config", "user.name", "Test"]);
+        std::fs::write(origin.join("skills/alpha/SKILL.md"), "---\nname: alpha\n---\nv1\n").unwrap();
+        git(&origin, &["add", "."]);
+        git(&origin, &["commit", "-m", "v1"]);
+        let recorded = git(&origin, &["rev-parse", "HEAD"]);
+        std::fs::write(origin.join("skills/alpha/SKILL.md"), "---\nname: alpha\n---\nv2\n").unwrap();
+        git(&origin, &["commit", "-am", "v2"]);
+
+        let home = temp.path().join("home");
+        std::fs::create_dir_all(home.join(".skillshub")).unwrap();
+        let mut db = sample_db();
+        db.installed.remove("owner/repo/alpha");
+        let tap = db.taps.get_mut("owner/repo").unwrap();
+        tap.url = format!("file://{}", origin.display());
+        tap.branch = None;
+        tap.updated_at = Some(Utc::now());
+        std::fs::write(home.join(".skillshub/db.json"), serde_json::to_string(&db).unwrap()).unwrap();
+
+        let manifest_path = temp.path().join("skills.json");
+        let manifest = Manifest {
+            version: MANIFEST_VERSION,
+            exported_at: Utc::now(),
+            taps: Vec::new(),
+            skills: vec![ManifestSkill {
+                name: "owner/repo/alpha".to_string(),
+                source_url: None,
+                commit: Some(recorded.clone()),
+            }],
+            recommended: Vec::new(),
+        };
+        std::fs::write(&manifest_path, serde_json::to_string(&manifest).unwrap()).unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        import_manifest(&manifest_path, false).unwrap();
+
+        let after = init_db().unwrap();
+        assert_eq!(
+            after.installed["owner/repo/alpha"].commit.as_deref(),
+            Some(recorded.as_str())
+        );
+        let installed_md = std::fs::read_to_string(home.join(".skillshub/skills/owner/repo/alpha/SKILL.md")).unwrap();
+        assert!(installed_md.contains("v1"), "{}", installed_md);
+    }
+}
@@ -2,9 +2,22 @@ mod agents;
 mod clean;
 pub mod doctor;
 mod external;
+mod gc;
 mod link;
+mod manifest;
+mod ratelimit;
+mod relink;
+mod status;
+mod version;
 
-pub use agents::show_agents;
-pub use clean::{clean_all, clean_cache, clean_links};
-pub use external::{external_forget, external_list, external_scan};
-pub use link::link_to_agents;
+pub use agents::{set_agent_subdir, show_agents};
+pub use clean::{clean_all, clean_cache, clean_links, clean_orphans};
+pub(crate) use clean::{find_agent_links_to, remove_agent_links};
+pub use external::{external_adopt, external_forget, external_list, external_scan};
+pub use gc::gc;
+pub use link::{link_to_agents, link_to_agents_with, LinkOptions};
+pub use manifest::{export_manifest, import_manifest};
+pub use ratelimit::show_rate_limit;
+pub use relink::relink;
+pub use status::show_status;
+pub use version::check_version;
@@ -0,0 +1,69 @@
+use anyhow::Result;
+use chrono::{DateTime, Local, Utc};
+use colored::Colorize;
+
+use crate::registry::github::fetch_rate_limit;
+
+/// Print the remaining GitHub API quota, when it resets, and whether a token is in use
+pub fn show_rate_limit() -> Result<()> {
+    let status = fetch_rate_limit()?;
+
+    println!("{}", "GitHub API rate limit".bold());
+    println!();
+
+    let remaining = match (status.remaining, status.limit) {
+        (Some(0), Some(limit)) => format!("{}/{}", "0".red().bold(), limit),
+        (Some(remaining), Some(limit)) => format!("{}/{}", remaining, limit),
+        (Some(remaining), None) => remaining.to_string(),
+        (None, _) => "unknown".dimmed().to_string(),
+    };
+    println!("  {}: {}", "Remaining".cyan(), remaining);
+
+    let now = Utc::now();
+    match status.reset.and_then(|ts| DateTime::<Utc>::from_timestamp(ts, 0)) {
+        Some(reset) => println!(
+            "  {}: in {} min (at {})",
+            "Resets".cyan(),
+            minutes_until(reset, now),
+            reset.with_timezone(&Local).format("%H:%M")
+        ),
+        None => println!("  {}: {}", "Resets".cyan(), "unknown".dimmed()),
+    }
+
+    let token = if status.authenticated {
+        "configured".green().to_string()
+    } else {
+        "none".yellow().to_string()
+    };
+    println!("  {}: {}", "Token".cyan(), token);
+
+    if !status.authenticated {
+        println!();
+        println!(
+            "{} Set GH_TOKEN or GITHUB_TOKEN for a higher limit before large installs",
+            "Tip:".cyan()
+        );
+    }
+
+    Ok(())
+}
+
+/// Whole minutes from `now` until `reset`, rounded up; 0 once the reset has passed
+fn minutes_until(reset: DateTime<Utc>, now: DateTime<Utc>) -> i64 {
+    let secs = reset.signed_duration_since(now).num_seconds().max(0);
+    (secs + 59) / 60
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minutes_until() {
+        let now = Utc::now();
+        assert_eq!(minutes_until(now + chrono::Duration::seconds(90), now), 2);
+        assert_eq!(minutes_until(now + chrono::Duration::minutes(30), now), 30);
+        assert_eq!(minutes_until(now, now), 0);
+        assert_eq!(minutes_until(now - chrono::Duration::minutes(5), now), 0);
+    }
+}
@@ -0,0 +1,138 @@
+use anyhow::Result;
+use colored::Colorize;
+use std::path::PathBuf;
+
+use crate::agent::discover_agents;
+use crate::commands::clean::{prune_orphans, remove_stale_links};
+use crate::commands::link::link_to_agents;
+use crate::context::RunContext;
+use crate::paths::{display_path_with_tilde, get_skills_install_dir};
+use crate::registry::db::{init_db, save_db};
+
+/// Reconcile agent links with the installed skills in one pass: prune orphaned
+/// `db.json` entries, remove stale managed links, then link every installed skill
+/// and re-sync tracked external skills. Running it twice is a no-op.
+pub fn relink() -> Result<()> {
+    let mut db = init_db()?;
+    let skills_dir = get_skills_install_dir()?;
+    let skills_dir_canonical = skills_dir.canonicalize().unwrap_or_else(|_| skills_dir.clone());
+
+    // Remember external sources before pruning so links to removed ones count as stale
+    let external_sources: Vec<PathBuf> = db.external.values().map(|ext| ext.source_path.clone()).collect();
+
+    say!("{} Pruning orphaned db.json entries", "=>".green().bold());
+    let pruned = prune_orphans(&mut db, &skills_dir);
+    if pruned.is_empty() {
+        say!("  {} No orphaned entries", "○".yellow());
+    } else {
+        save_db(&db)?;
+    }
+
+    say!("{} Removing stale links", "=>".green().bold());
+    let mut stale = 0;
+    for agent in discover_agents() {
+        for path in remove_stale_links(
+            &agent.path.join(&agent.skills_subdir),
+            &skills_dir_canonical,
+            &external_sources,
+        ) {
+            say!("  {} Removed {}", "✓".green(), display_path_with_tilde(&path));
+            stale += 1;
+        }
+    }
+    if stale == 0 {
+        say!("  {} No stale links", "○".yellow());
+    }
+
+    link_to_agents(&RunContext::default())?;
+
+    say!(
+        "{} Pruned {} orphaned entr{}, removed {} stale link(s)",
+        "Info:".cyan(),
+        pruned.len(),
+        if pruned.len() == 1 { "y" } else { "ies" },
+        stale
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serial_test::serial;
+    use std::fs;
+    use tempfile::TempDir;
+
+    /// RAII guard that restores `SKILLSHUB_TEST_HOME` on drop, even if a test panics.
+    struct TestHomeGuard(Option<String>);
+
+    impl TestHomeGuard {
+        fn set(home: &std::path::Path) -> Self {
+            let prev = std::env::var("SKILLSHUB_TEST_HOME").ok();
+            std::env::set_var("SKILLSHUB_TEST_HOME", home);
+            Self(prev)
+        }
+    }
+
+    impl Drop for TestHomeGuard {
+        fn drop(&mut self) {
+            match self.0.take() {
+                Some(v) => std::env::set_var("SKILLSHUB_TEST_HOME", v),
+                None => std::env::remove_var("SKILLSHUB_TEST_HOME"),
+            }
+        }
+    }
+
+    /// A stale link, a missing link, and an orphan entry are all fixed in one run,
+    /// and a second run changes nothing.
+    #[test]
+    #[serial]
+    #[cfg(unix)]
+    fn test_relink_repairs_drift() {
+        let temp = TempDir::new().unwrap();
+        let home = temp.path().join("home");
+        let skillshub_home = home.join(".skillshub");
+        let skills_dir = skillshub_home.join("skills");
+
+        // Installed skill with no agent link yet
+        let present = skills_dir.join("tap").join("present");
+        fs::create_dir_all(&present).unwrap();
+        fs::write(present.join("SKILL.md"), "---\nname: present\ndescription: Here\n---\n").unwrap();
+
+        // db.json also tracks a skill whose directory is gone
+        fs::write(
+            skillshub_home.join("db.json"),
+            r#"{"taps":{},"installed":{
+                "tap/present":{"tap":"tap","skill":"present","commit":null,"installed_at":"2024-01-01T00:00:00Z"},
+                "tap/gone":{"tap":"tap","skill":"gone","commit":null,"installed_at":"2024-01-01T00:00:00Z"}
+            },"linked_agents":[],"external":{}}"#,
+        )
+        .unwrap();
+
+        // Agent dir holds a broken link to the removed skill
+        let claude_skills = home.join(".claude").join("skills");
+        fs::create_dir_all(&claude_skills).unwrap();
+        let stale_link = claude_skills.join("gone");
+        std::os::unix::fs::symlink(skills_dir.join("tap").join("gone"), &stale_link).unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        relink().unwrap();
+
+        let link = claude_skills.join("present");
+        assert!(link.is_symlink(), "missing link should be created");
+        assert_eq!(fs::read_link(&link).unwrap(), present);
+        assert!(!stale_link.is_symlink(), "stale link should be removed");
+        let db = crate::registry::db::load_db().unwrap();
+        assert!(db.installed.contains_key("tap/present"));
+        assert!(!db.installed.contains_key("tap/gone"), "orphan entry should be pruned");
+        assert!(db.external.is_empty());
+
+        // Idempotent: a second pass leaves the same state
+        relink().unwrap();
+        assert!(link.is_symlink());
+        let entries: Vec<_> = fs::read_dir(&claude_skills).unwrap().flatten().collect();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(crate::registry::db::load_db().unwrap().installed.len(), 1);
+    }
+}
@@ -0,0 +1,297 @@
+use anyhow::Result;
+use colored::Colorize;
+use serde::Serialize;
+
+use crate::commands::agents::{collect_agent_statuses, AgentStatus};
+use crate::registry::db::{init_db, DEFAULT_TAP_NAME};
+use crate::registry::github::is_gist_url;
+use crate::registry::models::{is_commit_sha, same_commit, Database, SkillSource};
+
+/// Tap counts by kind
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+pub struct TapCounts {
+    pub total: usize,
+    /// The bundled default tap
+    pub default: usize,
+    /// Taps hosted on GitHub (repositories and gists)
+    pub remote: usize,
+    /// Taps cloned from a local path or `file://` URL
+    pub local: usize,
+}
+
+/// Installed skill counts by [`SkillSource`]
+#[derive(Debug, Default, PartialEq, Eq, Serialize)]
+pub struct SkillCounts {
+    pub installed: usize,
+    pub local: usize,
+    pub remote: usize,
+    pub url: usize,
+}
+
+/// Everything `status` reports, printed as text or serialized by `status --json`
+#[derive(Debug, Serialize)]
+pub struct Status {
+    pub taps: TapCounts,
+    pub skills: SkillCounts,
+    /// Number of coding agents found on this system
+    pub agents_detected: usize,
+    /// Agents set up by `skillshub link`
+    pub agents_linked: Vec<String>,
+    /// Skills tracked in `db.json` that skillshub did not install
+    pub external_skills: usize,
+    /// Installed skills whose tap registry lists a newer commit
+    pub updates_available: Vec<String>,
+}
+
+/// Installed skills whose cached tap registry records a different commit.
+///
+/// Only the registries saved by the last `tap update` are consulted, so nothing is
+/// fetched. Bundled, gist, and tag-pinned installs are not compared.
+fn pending_updates(db: &Database) -> Vec<String> {
+    let mut pending: Vec<String> = db
+        .installed
+        .iter()
+        .filter(|(_, installed)| installed.gist_updated_at.is_none())
+        .filter_map(|(full_name, installed)| {
+            let commit = installed.commit.as_deref().filter(|c| is_commit_sha(c))?;
+            let registry = db.taps.get(&installed.tap)?.cached_registry.as_ref()?;
+            let latest = registry.skills.get(&installed.skill)?.commit.as_deref()?;
+            (!same_commit(latest, commit)).then(|| full_name.clone())
+        })
+        .collect();
+    pending.sort();
+    pending
+}
+
+/// Aggregate taps, installed skills, and pending updates from `db` with the detected `agents`
+fn collect_status(db: &Database, agents: Vec<AgentStatus>) -> Status {
+    let mut taps = TapCounts::default();
+    for (name, tap) in &db.taps {
+        taps.total += 1;
+        if tap.is_default || name == DEFAULT_TAP_NAME {
+            taps.default += 1;
+        } else if tap.url.starts_with("https://") || tap.url.starts_with("http://") || is_gist_url(&tap.url) {
+            taps.remote += 1;
+        } else {
+            taps.local += 1;
+        }
+    }
+
+    let mut skills = SkillCounts::default();
+    for installed in db.installed.values() {
+        skills.installed += 1;
+        let tap_url = db.taps.get(&installed.tap).map(|t| t.url.as_str());
+        match SkillSource::of_installed(installed, tap_url) {
+            SkillSource::Local => skills.local += 1,
+            SkillSource::Remote => skills.remote += 1,
+            SkillSource::Url => skills.url += 1,
+        }
+    }
+
+    Status {
+        taps,
+        skills,
+        agents_detected: agents.len(),
+        agents_linked: agents.into_iter().filter(|a| a.linked).map(|a| a.name).collect(),
+        external_skills: db.external.len(),
+        updates_available: pending_updates(db),
+    }
+}
+
+/// Summarize taps, installed skills, agents, external skills, and available updates,
+/// as text or (with `json`) as one JSON object
+pub fn show_status(json: bool) -> Result<()> {
+    let db = init_db()?;
+    let status = collect_status(&db, collect_agent_statuses());
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&status)?);
+        return Ok(());
+    }
+
+    println!("{}", "skillshub status".bold());
+    println!();
+    println!(
+        "  {}: {} ({} default, {} remote, {} local)",
+        "Taps".cyan(),
+        status.taps.total,
+        status.taps.default,
+        status.taps.remote,
+        status.taps.local
+    );
+    println!(
+        "  {}: {} installed ({} local, {} remote, {} url)",
+        "Skills".cyan(),
+        status.skills.installed,
+        status.skills.local,
+        status.skills.remote,
+        status.skills.url
+    );
+    let linked = if status.agents_linked.is_empty() {
+        String::new()
+    } else {
+        format!(": {}", status.agents_linked.join(", "))
+    };
+    println!(
+        "  {}: {} linked of {} detected{}",
+        "Agents".cyan(),
+        status.agents_linked.len(),
+        status.agents_detected,
+        linked
+    );
+    println!("  {}: {}", "External".cyan(), status.external_skills);
+    if status.updates_available.is_empty() {
+        println!("  {}: {}", "Updates".cyan(), "none".green());
+    } else {
+        println!(
+            "  {}: {} available ({})",
+            "Updates".cyan(),
+            status.updates_available.len().to_string().yellow(),
+            status.updates_available.join(", ")
+        );
+        println!();
+        println!("{} Run {} to apply them", "Tip:".cyan(), "skillshub update".bold());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::models::{InstalledSkill, SkillEntry, TapInfo, TapRegistry};
+
+    fn installed(tap: &str, skill: &str, commit: Option<&str>, source_url: Option<&str>) -> InstalledSkill {
+        InstalledSkill {
+            tap: tap.to_string(),
+            skill: skill.to_string(),
+            commit: commit.map(str::to_string),
+            installed_at: chrono::Utc::now(),
+            source_url: source_url.map(str::to_string),
+            source_path: None,
+            gist_updated_at: None,
+            content_sha256: None,
+            size_bytes: None,
+            alias: None,
+            branch: None,
+        }
+    }
+
+    fn tap(url: &str, is_default: bool, skills: &[(&str, &str)]) -> TapInfo {
+        let registry = TapRegistry {
+            name: url.to_string(),
+            description: None,
+            schema_version: None,
+            skills: skills
+                .iter()
+                .map(|(name, commit)| {
+                    (
+                        name.to_string(),
+                        SkillEntry {
+                            path: format!("skills/{}", name),
+                            description: None,
+                            homepage: None,
+                            commit: Some(commit.to_string()),
+                            tags: Vec::new(),
+                            aliases: Vec::new(),
+                        },
+                    )
+                })
+                .collect(),
+        };
+        TapInfo {
+            url: url.to_string(),
+            custom_skills_path: None,
+            updated_at: None,
+            is_default,
+            cached_registry: Some(registry),
+            branch: None,
+            token_env: None,
+            registry_file: None,
+        }
+    }
+
+    /// Taps and skills are counted by kind, and only skills whose cached registry
+    /// lists a newer commit are reported as updatable.
+    #[test]
+    fn test_collect_status_counts_and_pending_updates() {
+        let old = "1111111111111111111111111111111111111111";
+        let new = "2222222222222222222222222222222222222222";
+        let mut db = Database::default();
+        db.taps.insert(
+            DEFAULT_TAP_NAME.to_string(),
+            tap("https://github.com/EYH0602/skillshub", true, &[]),
+        );
+        db.taps.insert(
+            "acme/tools".to_string(),
+            tap(
+                "https://github.com/acme/tools",
+                false,
+                &[("stale", new), ("fresh", old)],
+            ),
+        );
+        db.taps
+            .insert("mine/local".to_string(), tap("file:///src/skills", false, &[]));
+
+        let acme = Some("https://github.com/acme/tools");
+        db.installed.insert(
+            format!("{}/bundled", DEFAULT_TAP_NAME),
+            installed(DEFAULT_TAP_NAME, "bundled", None, None),
+        );
+        db.installed.insert(
+            "acme/tools/stale".to_string(),
+            installed("acme/tools", "stale", Some(old), acme),
+        );
+        db.installed.insert(
+            "acme/tools/fresh".to_string(),
+            installed("acme/tools", "fresh", Some(old), acme),
+        );
+        db.installed.insert(
+            "acme/tools/pinned".to_string(),
+            installed("acme/tools", "pinned", Some("v1.0.0"), acme),
+        );
+        db.installed.insert(
+            "other/repo/added".to_string(),
+            installed(
+                "other/repo",
+                "added",
+                Some(old),
+                Some("https://github.com/other/repo/tree/main/added"),
+            ),
+        );
+
+        let agent = |name: &str, linked: bool| AgentStatus {
+            name: name.to_string(),
+            linked,
+            skills_total: 0,
+            skills_managed: 0,
+            skills_external: 0,
+            skills_subdir: "skills".to_string(),
+            path: std::path::PathBuf::from(name),
+            conflicting_dir: None,
+        };
+        let status = collect_status(&db, vec![agent(".claude", true), agent(".codex", false)]);
+        assert_eq!(
+            status.taps,
+            TapCounts {
+                total: 3,
+                default: 1,
+                remote: 1,
+                local: 1
+            }
+        );
+        assert_eq!(
+            status.skills,
+            SkillCounts {
+                installed: 5,
+                local: 1,
+                remote: 3,
+                url: 1
+            }
+        );
+        assert_eq!(status.updates_available, vec!["acme/tools/stale".to_string()]);
+        assert_eq!(status.agents_detected, 2);
+        assert_eq!(status.agents_linked, vec![".claude".to_string()]);
+        assert_eq!(status.external_skills, 0);
+    }
+}
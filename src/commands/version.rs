@@ -0,0 +1,76 @@
+use anyhow::Result;
+use colored::Colorize;
+
+use crate::registry::github::{fetch_latest_release, is_offline};
+
+/// Repository whose GitHub releases are checked for newer versions
+const RELEASE_REPO: (&str, &str) = ("EYH0602", "skillshub");
+
+/// Print the installed version and whether a newer release is available on GitHub.
+///
+/// Read-only: nothing is downloaded or replaced.
+pub fn check_version() -> Result<()> {
+    let current = env!("CARGO_PKG_VERSION");
+    println!("skillshub {}", current);
+
+    if is_offline() {
+        println!("{} Offline mode; skipping the release check", "Note:".yellow().bold());
+        return Ok(());
+    }
+
+    let (owner, repo) = RELEASE_REPO;
+    let release = fetch_latest_release(owner, repo)?;
+    let latest = release.tag_name.trim_start_matches('v');
+
+    if is_newer(latest, current) {
+        println!(
+            "{} Update available: {} -> {}",
+            "!".yellow(),
+            current,
+            latest.green().bold()
+        );
+        println!("  Download: {}", release.html_url);
+        println!("  Or upgrade with: {}", "cargo install skillshub --force".bold());
+    } else {
+        println!("{} Up to date (latest release: {})", "✓".green(), latest);
+    }
+
+    Ok(())
+}
+
+/// Whether version `latest` is newer than `current`, comparing dotted numeric parts.
+///
+/// Pre-release and build suffixes (`-rc.1`, `+abc`) are ignored, and missing parts count as 0.
+fn is_newer(latest: &str, current: &str) -> bool {
+    fn parts(version: &str) -> Vec<u64> {
+        version
+            .split(['-', '+'])
+            .next()
+            .unwrap_or("")
+            .split('.')
+            .map(|part| part.parse().unwrap_or(0))
+            .collect()
+    }
+
+    let (latest, current) = (parts(latest), parts(current));
+    let len = latest.len().max(current.len());
+    let padded = |v: &[u64]| (0..len).map(|i| v.get(i).copied().unwrap_or(0)).collect::<Vec<_>>();
+    padded(&latest) > padded(&current)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_newer() {
+        assert!(is_newer("0.3.0", "0.2.9"));
+        assert!(is_newer("1.0.0", "0.10.0"));
+        assert!(is_newer("0.2.10", "0.2.9"));
+        assert!(is_newer("0.2.1", "0.2"));
+        assert!(!is_newer("0.2.0", "0.2"));
+        assert!(!is_newer("0.2.9", "0.2.9"));
+        assert!(!is_newer("0.2.8", "0.2.9"));
+        assert!(!is_newer("0.3.0-rc.1", "0.3.0"));
+    }
+}
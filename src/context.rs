@@ -0,0 +1,50 @@
+use colored::Colorize;
+use std::fmt::Display;
+
+/// Global options threaded into commands that modify skills, links, or the database.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunContext {
+    /// Preview changes without touching the filesystem or `db.json`
+    pub dry_run: bool,
+    /// Run skills' post-install hooks (`install --run-hooks`)
+    pub run_hooks: bool,
+}
+
+impl RunContext {
+    pub fn new(dry_run: bool) -> Self {
+        Self {
+            dry_run,
+            run_hooks: false,
+        }
+    }
+
+    /// The same context with post-install hooks enabled or disabled
+    pub fn with_hooks(self, run_hooks: bool) -> Self {
+        Self { run_hooks, ..self }
+    }
+
+    /// Prefix for status lines: `"[dry-run] "` in dry-run mode, empty otherwise.
+    pub fn prefix(&self) -> String {
+        if self.dry_run {
+            format!("{} ", "[dry-run]".yellow())
+        } else {
+            String::new()
+        }
+    }
+
+    /// Print a planned change (only meaningful in dry-run mode).
+    pub fn would(&self, action: impl Display) {
+        println!("  {}would {}", self.prefix(), action);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prefix_only_in_dry_run() {
+        assert_eq!(RunContext::default().prefix(), "");
+        assert!(RunContext::new(true).prefix().contains("[dry-run]"));
+    }
+}
@@ -1,6 +1,12 @@
+// Declared first so its macros are visible in every module below
+#[macro_use]
+mod output;
+
+mod action_log;
 mod agent;
 mod cli;
 mod commands;
+mod context;
 mod paths;
 mod registry;
 mod skill;
@@ -10,57 +16,299 @@ use anyhow::Result;
 use clap::{CommandFactory, Parser};
 use clap_complete::{generate, Shell as ClapShell};
 
-use cli::{CleanCommands, Cli, Commands, ExternalCommands, Shell, TapCommands};
+use cli::{AgentCommands, CleanCommands, Cli, Commands, ExternalCommands, Shell, TapCommands};
 use commands::{
-    clean_all, clean_cache, clean_links, external_forget, external_list, external_scan, link_to_agents, show_agents,
+    check_version, clean_all, clean_cache, clean_links, clean_orphans, export_manifest, external_adopt,
+    external_forget, external_list, external_scan, gc, import_manifest, link_to_agents_with, relink, set_agent_subdir,
+    show_agents, show_rate_limit, show_status, LinkOptions,
 };
+use context::RunContext;
 use registry::{
-    add_skill_from_url, add_tap, import_star_list, install_all, install_all_from_tap, install_skill, list_skills,
-    list_taps, migrate_old_installations, needs_migration, remove_tap, search_skills, show_skill_info, uninstall_skill,
-    update_skill, update_tap,
+    add_default_tap, add_skill_from_archive, add_skill_from_url, add_tap, add_well_known_tap, alias_skill, diff_skill,
+    import_star_list, install_all, install_all_from_tap, install_skill_from_tap, install_skills,
+    install_skills_from_file, list_skills, list_taps, migrate_layout, migrate_old_installations, needs_migration,
+    open_skill_page, reinstall_skill, remove_tap, rename_tap, search_skills, set_default_tap, show_skill_info,
+    show_skill_tree, show_tap_info, uninstall_all_from_tap, uninstall_skill, update_skill, update_tap, verify_tap,
+    which_skill, TapAddOptions, UninstallOptions, UpdateLinkMode,
 };
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
+    let json_errors = cli.json_errors || cli.command.wants_json();
+    let Err(e) = run(cli) else {
+        return Ok(());
+    };
+    let code = output::exit_code(&e);
+    if json_errors {
+        eprintln!("{}", output::json_error(&e));
+    } else if code == 1 {
+        return Err(e);
+    } else {
+        eprintln!("Error: {:?}", e);
+    }
+    std::process::exit(code);
+}
+
+fn run(cli: Cli) -> Result<()> {
+    let ctx = RunContext::new(cli.dry_run);
+    output::init_color(cli.color);
+    // Read by the say! and status! output macros and action_log::log_action
+    output::init_output(output::OutputContext::new(cli.quiet, cli.verbose));
+
+    if let Some(path) = &cli.log_file {
+        // Read by action_log::log_action; absolute so commands that change directory keep logging
+        let path = std::env::current_dir()
+            .map(|cwd| cwd.join(path))
+            .unwrap_or_else(|_| path.clone());
+        std::env::set_var(action_log::LOG_FILE_ENV, &path);
+        let args: Vec<String> = std::env::args().skip(1).collect();
+        action_log::log_action(format!("skillshub {} {}", env!("CARGO_PKG_VERSION"), args.join(" ")));
+    }
+
+    if cli.offline {
+        // Read by the network helpers in registry::github and registry::git
+        std::env::set_var(registry::github::OFFLINE_ENV, "1");
+    }
+
+    if cli.no_proxy {
+        // Read by registry::github::build_client
+        std::env::set_var(registry::github::NO_PROXY_ENV, "1");
+    }
+
+    if let Some(ttl) = cli.registry_cache_ttl {
+        // Read by registry::tap::get_tap_registry
+        std::env::set_var(registry::tap::CACHE_TTL_ENV, ttl.to_string());
+    }
+    if matches!(
+        cli.command,
+        Commands::List { refresh: true, .. } | Commands::Search { refresh: true, .. }
+    ) {
+        // A zero TTL makes get_tap_registry treat every cached registry as stale
+        std::env::set_var(registry::tap::CACHE_TTL_ENV, "0");
+    }
+    if ctx.dry_run {
+        // An automatic refresh would pull clones and rewrite db.json
+        std::env::remove_var(registry::tap::CACHE_TTL_ENV);
+    }
+
+    if ctx.dry_run
+        && !matches!(
+            cli.command,
+            Commands::Install { .. }
+                | Commands::Uninstall { .. }
+                | Commands::Update { .. }
+                | Commands::Link { .. }
+                | Commands::Tap(TapCommands::Add { .. })
+                | Commands::Tap(TapCommands::Install { .. })
+        )
+    {
+        anyhow::bail!("--dry-run is only supported by install, tap add, tap install, uninstall, update, and link");
+    }
+
+    // Serialize db.json updates with other skillshub processes for the whole command.
+    // `clean all` deletes the skillshub home (lock file included); completions, version, and
+    // ratelimit never touch it.
+    let _db_lock = if ctx.dry_run
+        || matches!(
+            cli.command,
+            Commands::Completions { .. }
+                | Commands::Version
+                | Commands::RateLimit
+                | Commands::Clean(CleanCommands::All { .. })
+        ) {
+        None
+    } else {
+        Some(registry::db::lock_db()?)
+    };
 
     // Auto-migrate old installations on first run (except for migrate command itself)
-    if !matches!(cli.command, Commands::Migrate) && needs_migration()? {
+    if !matches!(cli.command, Commands::Migrate { .. }) && !ctx.dry_run && needs_migration()? {
         migrate_old_installations()?;
     }
 
     match cli.command {
-        Commands::InstallAll => install_all()?,
-        Commands::Install { name } => install_skill(&name)?,
-        Commands::Add { url } => add_skill_from_url(&url)?,
-        Commands::Uninstall { name } => uninstall_skill(&name)?,
-        Commands::Update { name } => update_skill(name.as_deref())?,
-        Commands::List => list_skills()?,
-        Commands::Search { query } => search_skills(&query)?,
-        Commands::Info { name } => show_skill_info(&name)?,
-        Commands::Link => link_to_agents()?,
-        Commands::Agents => show_agents()?,
+        Commands::InstallAll {
+            only_new,
+            fail_on_empty,
+        } => {
+            if install_all(only_new)? == 0 && fail_on_empty {
+                return Err(output::NothingInstalled.into());
+            }
+        }
+        Commands::Install {
+            names,
+            from_file,
+            run_hooks,
+            branch,
+        } => match from_file {
+            Some(path) => install_skills_from_file(&ctx.with_hooks(run_hooks), &path, branch.as_deref())?,
+            None => install_skills(&ctx.with_hooks(run_hooks), &names, branch.as_deref())?,
+        },
+        Commands::Add { url, archive, branch } => match (url, archive) {
+            (_, Some(archive)) => add_skill_from_archive(&archive)?,
+            (Some(url), None) => add_skill_from_url(&url, branch.as_deref())?,
+            (None, None) => unreachable!("clap requires a URL or --archive"),
+        },
+        Commands::Uninstall {
+            name,
+            keep_empty_taps,
+            purge,
+        } => uninstall_skill(&ctx, &name, &UninstallOptions { keep_empty_taps, purge })?,
+        Commands::Reinstall { name } => reinstall_skill(&name)?,
+        Commands::Alias { name, alias, .. } => alias_skill(&name, alias.as_deref())?,
+        Commands::Update {
+            name,
+            skill_only,
+            link,
+            check,
+        } => {
+            let link_mode = if skill_only {
+                UpdateLinkMode::SkillOnly
+            } else if link {
+                UpdateLinkMode::Always
+            } else {
+                UpdateLinkMode::Auto
+            };
+            let updates = update_skill(&ctx, name.as_deref(), link_mode, check)?;
+            if check && updates > 0 {
+                return Err(output::UpdatesAvailable(updates).into());
+            }
+        }
+        Commands::List {
+            tag,
+            source,
+            limit,
+            wrap,
+            ..
+        } => list_skills(tag.as_deref(), source.map(Into::into), limit, wrap)?,
+        Commands::Search {
+            query,
+            exact,
+            tag,
+            installed,
+            not_installed,
+            limit,
+            wrap,
+            ..
+        } => {
+            let installed_filter = if installed {
+                Some(true)
+            } else if not_installed {
+                Some(false)
+            } else {
+                None
+            };
+            search_skills(&query, !exact, tag.as_deref(), installed_filter, limit, wrap)?
+        }
+        Commands::Info {
+            name,
+            json,
+            show_commit_url,
+            full_sha,
+            rfc3339,
+        } => show_skill_info(&name, json, show_commit_url, full_sha, rfc3339)?,
+        Commands::Open { name, print } => open_skill_page(&name, print)?,
+        Commands::Which { name, link } => which_skill(&name, link)?,
+        Commands::Tree { name, depth } => show_skill_tree(&name, depth)?,
+        Commands::Diff { name, stat } => diff_skill(&name, stat)?,
+        Commands::Link {
+            git_exclude,
+            into,
+            agents,
+            json,
+            no_sync_external,
+            force,
+        } => link_to_agents_with(
+            &ctx,
+            &LinkOptions {
+                git_exclude,
+                prefix: into.as_deref(),
+                agents: &agents,
+                json,
+                no_sync_external,
+                force,
+            },
+        )?,
+        Commands::Agents { command, json } => match command {
+            Some(AgentCommands::Subdir { agent, subdir, .. }) => set_agent_subdir(&agent, subdir.as_deref())?,
+            None => show_agents(json)?,
+        },
+        Commands::Status { json } => show_status(json)?,
         Commands::Tap(tap_cmd) => match tap_cmd {
-            TapCommands::Add { url, install, branch } => add_tap(&url, branch.as_deref(), install)?,
+            TapCommands::Add {
+                url,
+                install,
+                branch,
+                token_env,
+                skills_path,
+                registry_file,
+                limit,
+            } => add_tap(
+                &ctx,
+                &url,
+                &TapAddOptions {
+                    branch: branch.as_deref(),
+                    token_env: token_env.as_deref(),
+                    skills_path: skills_path.as_deref(),
+                    registry_file: registry_file.as_deref(),
+                    install,
+                    preview_limit: limit,
+                },
+            )?,
             TapCommands::Remove { name, keep_skills } => remove_tap(&name, keep_skills)?,
             TapCommands::List => list_taps()?,
+            TapCommands::Info { name } => show_tap_info(&name)?,
+            TapCommands::Verify { name } => verify_tap(&name)?,
+            TapCommands::AddDefault { shortname: None } => add_default_tap()?,
+            TapCommands::AddDefault {
+                shortname: Some(shortname),
+            } => add_well_known_tap(&ctx, &shortname)?,
+            TapCommands::SetDefault { name } => set_default_tap(&name)?,
+            TapCommands::Rename { old, new } => rename_tap(&old, &new)?,
             TapCommands::Update { name } => update_tap(name.as_deref())?,
-            TapCommands::InstallAll { name } => install_all_from_tap(&name)?,
+            TapCommands::Install { tap, skill, run_hooks } => {
+                install_skill_from_tap(&ctx.with_hooks(run_hooks), &tap, &skill)?
+            }
+            TapCommands::InstallAll { name, fail_on_empty } => {
+                if install_all_from_tap(&name)? == 0 && fail_on_empty {
+                    return Err(output::NothingInstalled.into());
+                }
+            }
+            TapCommands::UninstallAll { name, include_default } => uninstall_all_from_tap(&name, include_default)?,
         },
         Commands::External(ext_cmd) => match ext_cmd {
-            ExternalCommands::List => external_list()?,
+            ExternalCommands::List { agent, since, json } => external_list(agent.as_deref(), since.as_deref(), json)?,
             ExternalCommands::Scan => external_scan()?,
             ExternalCommands::Forget { name } => external_forget(&name)?,
+            ExternalCommands::Adopt { name } => external_adopt(&name)?,
         },
         Commands::Clean(clean_cmd) => match clean_cmd {
             CleanCommands::Cache => clean_cache()?,
-            CleanCommands::Links { remove_skills } => clean_links(remove_skills)?,
+            CleanCommands::Links { remove_skills, agents } => clean_links(remove_skills, &agents)?,
+            CleanCommands::Orphans => clean_orphans()?,
             CleanCommands::All { confirm } => clean_all(confirm)?,
         },
+        Commands::Gc => gc()?,
+        Commands::Relink => relink()?,
         Commands::StarList { url, install } => import_star_list(&url, install)?,
-        Commands::Doctor => {
+        Commands::Export {
+            output,
+            include_available,
+            ..
+        } => export_manifest(output.as_deref(), include_available)?,
+        Commands::Import { file, with_recommended } => import_manifest(&file, with_recommended)?,
+        Commands::Doctor { json: false } => {
             commands::doctor::run_doctor()?;
         }
-        Commands::Migrate => migrate_old_installations()?,
+        Commands::Doctor { json: true } => {
+            if !commands::doctor::run_doctor_json()? {
+                return Err(output::ChecksFailed.into());
+            }
+        }
+        Commands::Migrate { layout: None } => migrate_old_installations()?,
+        Commands::Migrate { layout: Some(layout) } => migrate_layout(layout)?,
+        Commands::Version => check_version()?,
+        Commands::RateLimit => show_rate_limit()?,
         Commands::Completions { shell } => {
             let clap_shell = match shell {
                 Shell::Bash => ClapShell::Bash,
@@ -0,0 +1,283 @@
+//! Console verbosity set by `--quiet` and `--verbose`, coloring set by `--color`, and the
+//! JSON error format used by `--json-errors`
+
+use std::io::IsTerminal;
+use std::sync::OnceLock;
+
+/// Environment variable equivalent to `--quiet`: suppress status output on success
+pub const QUIET_ENV: &str = "SKILLSHUB_QUIET";
+
+/// Environment variable equivalent to `--verbose`: echo resolved URLs and paths to stderr
+pub const VERBOSE_ENV: &str = "SKILLSHUB_VERBOSE";
+
+/// Console verbosity for the whole run
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OutputContext {
+    /// Suppress status lines (`--quiet`)
+    pub quiet: bool,
+    /// Echo action log entries to stderr (`--verbose`)
+    pub verbose: bool,
+}
+
+impl OutputContext {
+    /// Verbosity from the command-line flags, or from `SKILLSHUB_QUIET`/`SKILLSHUB_VERBOSE`
+    pub fn new(quiet: bool, verbose: bool) -> Self {
+        Self::from_flags(
+            quiet,
+            verbose,
+            std::env::var(QUIET_ENV).ok().as_deref(),
+            std::env::var(VERBOSE_ENV).ok().as_deref(),
+        )
+    }
+
+    fn from_flags(quiet: bool, verbose: bool, quiet_env: Option<&str>, verbose_env: Option<&str>) -> Self {
+        Self {
+            quiet: quiet || flag_enabled(quiet_env),
+            verbose: verbose || flag_enabled(verbose_env),
+        }
+    }
+}
+
+static OUTPUT: OnceLock<OutputContext> = OnceLock::new();
+
+/// Apply `output` for the rest of the run; call before any output. Later calls are ignored.
+pub fn init_output(output: OutputContext) {
+    let _ = OUTPUT.set(output);
+}
+
+/// Whether status lines should be suppressed
+pub fn is_quiet() -> bool {
+    OUTPUT.get().is_some_and(|output| output.quiet)
+}
+
+/// Whether action log entries should also be echoed to stderr
+pub fn is_verbose() -> bool {
+    OUTPUT.get().is_some_and(|output| output.verbose)
+}
+
+fn flag_enabled(value: Option<&str>) -> bool {
+    matches!(value.map(str::trim), Some(v) if v == "1" || v.eq_ignore_ascii_case("true"))
+}
+
+/// When to color output (`--color`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ColorChoice {
+    /// Color when stdout is a terminal, unless `NO_COLOR` is set
+    #[default]
+    Auto,
+    /// Color even when piped
+    Always,
+    /// Never color
+    Never,
+}
+
+/// The color override for `choice`, or `None` to leave it to the environment
+/// (`NO_COLOR`, `CLICOLOR`, `CLICOLOR_FORCE`). `auto` turns color off when stdout is
+/// not a terminal, unless `CLICOLOR_FORCE` asks for it.
+fn color_override(choice: ColorChoice, stdout_is_tty: bool, force: bool) -> Option<bool> {
+    match choice {
+        ColorChoice::Always => Some(true),
+        ColorChoice::Never => Some(false),
+        ColorChoice::Auto if !stdout_is_tty && !force => Some(false),
+        ColorChoice::Auto => None,
+    }
+}
+
+/// Apply `--color` to every colored string and progress bar; call before any output
+pub fn init_color(choice: ColorChoice) {
+    let force = std::env::var("CLICOLOR_FORCE").is_ok_and(|v| v != "0");
+    if let Some(enabled) = color_override(choice, std::io::stdout().is_terminal(), force) {
+        colored::control::set_override(enabled);
+    }
+    // Progress bars check their own terminal (stderr) and NO_COLOR unless told otherwise
+    if choice != ColorChoice::Auto {
+        console::set_colors_enabled(choice == ColorChoice::Always);
+        console::set_colors_enabled_stderr(choice == ColorChoice::Always);
+    }
+}
+
+/// Exit code of a command that ran fine but found nothing to do under `--fail-on-empty`
+pub const EMPTY_EXIT_CODE: i32 = 2;
+
+/// Error returned under `--fail-on-empty` when nothing new was installed; `main` exits
+/// with [`EMPTY_EXIT_CODE`] instead of 1 so scripts can tell it apart from a failure
+#[derive(Debug)]
+pub struct NothingInstalled;
+
+impl std::fmt::Display for NothingInstalled {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("No new skills were installed (--fail-on-empty)")
+    }
+}
+
+impl std::error::Error for NothingInstalled {}
+
+/// Error returned by `update --check` when updates are available, so `main` exits 1
+/// after the report has been printed
+#[derive(Debug)]
+pub struct UpdatesAvailable(pub usize);
+
+impl std::fmt::Display for UpdatesAvailable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} update(s) available", self.0)
+    }
+}
+
+impl std::error::Error for UpdatesAvailable {}
+
+/// Error returned by `doctor --json` when a hard check failed, so `main` exits 1 after
+/// the report has been printed
+#[derive(Debug)]
+pub struct ChecksFailed;
+
+impl std::fmt::Display for ChecksFailed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("One or more doctor checks failed")
+    }
+}
+
+impl std::error::Error for ChecksFailed {}
+
+/// Process exit code for a failed command: [`EMPTY_EXIT_CODE`] for [`NothingInstalled`],
+/// 1 for everything else, including [`UpdatesAvailable`] and [`ChecksFailed`]
+pub fn exit_code(err: &anyhow::Error) -> i32 {
+    if err.is::<NothingInstalled>() {
+        EMPTY_EXIT_CODE
+    } else {
+        1
+    }
+}
+
+/// Broad class of a failed command's error, from the first recognized cause in its chain
+fn error_kind(err: &anyhow::Error) -> &'static str {
+    for cause in err.chain() {
+        if cause.is::<NothingInstalled>() {
+            return "Empty";
+        }
+        if cause.is::<UpdatesAvailable>() {
+            return "UpdatesAvailable";
+        }
+        if cause.is::<ChecksFailed>() {
+            return "ChecksFailed";
+        }
+        if cause.is::<std::io::Error>() {
+            return "Io";
+        }
+        if cause.is::<reqwest::Error>() {
+            return "Network";
+        }
+        if cause.is::<serde_json::Error>() || cause.is::<serde_yaml::Error>() {
+            return "Parse";
+        }
+    }
+    "Other"
+}
+
+/// `{"error": {"kind": ..., "message": ...}}` for a failed command; `message` is the full
+/// context chain, as in the text output
+pub fn json_error(err: &anyhow::Error) -> serde_json::Value {
+    serde_json::json!({
+        "error": {
+            "kind": error_kind(err),
+            "message": format!("{:#}", err),
+        }
+    })
+}
+
+/// `println!` for status lines; silent under `--quiet`.
+/// Errors and warnings keep using `eprintln!` so they are never hidden.
+macro_rules! say {
+    ($($arg:tt)*) => {
+        if !$crate::output::is_quiet() {
+            println!($($arg)*)
+        }
+    };
+}
+
+/// `print!` counterpart of `say!`, for progress lines finished later
+macro_rules! say_inline {
+    ($($arg:tt)*) => {
+        if !$crate::output::is_quiet() {
+            print!($($arg)*)
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flag_enabled() {
+        assert!(flag_enabled(Some("1")));
+        assert!(flag_enabled(Some(" TRUE ")));
+        assert!(!flag_enabled(Some("0")));
+        assert!(!flag_enabled(Some("")));
+        assert!(!flag_enabled(None));
+    }
+
+    #[test]
+    fn test_output_context_from_flags_or_env() {
+        let quiet = OutputContext {
+            quiet: true,
+            verbose: false,
+        };
+        assert_eq!(OutputContext::from_flags(true, false, None, None), quiet);
+        assert_eq!(OutputContext::from_flags(false, false, Some("1"), Some("0")), quiet);
+        assert_eq!(
+            OutputContext::from_flags(false, false, None, None),
+            OutputContext::default()
+        );
+        assert!(OutputContext::from_flags(false, true, None, None).verbose);
+        assert!(OutputContext::from_flags(false, false, None, Some("true")).verbose);
+    }
+
+    #[test]
+    fn test_json_error_kind_and_message() {
+        let io = std::io::Error::new(std::io::ErrorKind::NotFound, "no such file");
+        let err = anyhow::Error::new(io).context("Failed to read db.json");
+        let value = json_error(&err);
+        assert_eq!(value["error"]["kind"], "Io");
+        assert_eq!(value["error"]["message"], "Failed to read db.json: no such file");
+
+        let parse = serde_json::from_str::<serde_json::Value>("{").unwrap_err();
+        assert_eq!(json_error(&anyhow::Error::new(parse))["error"]["kind"], "Parse");
+        assert_eq!(
+            json_error(&anyhow::anyhow!("Tap 'x' not found"))["error"]["kind"],
+            "Other"
+        );
+    }
+
+    #[test]
+    fn test_nothing_installed_exit_code_and_kind() {
+        let err = anyhow::Error::new(NothingInstalled);
+        assert_eq!(exit_code(&err), EMPTY_EXIT_CODE);
+        assert_eq!(json_error(&err)["error"]["kind"], "Empty");
+        assert_eq!(exit_code(&anyhow::anyhow!("Tap 'x' not found")), 1);
+    }
+
+    #[test]
+    fn test_updates_available_exit_code_and_kind() {
+        let err = anyhow::Error::new(UpdatesAvailable(3));
+        assert_eq!(exit_code(&err), 1);
+        let json = json_error(&err);
+        assert_eq!(json["error"]["kind"], "UpdatesAvailable");
+        assert_eq!(json["error"]["message"], "3 update(s) available");
+    }
+
+    #[test]
+    fn test_checks_failed_exit_code_and_kind() {
+        let err = anyhow::Error::new(ChecksFailed);
+        assert_eq!(exit_code(&err), 1);
+        assert_eq!(json_error(&err)["error"]["kind"], "ChecksFailed");
+    }
+
+    #[test]
+    fn test_color_override() {
+        assert_eq!(color_override(ColorChoice::Always, false, false), Some(true));
+        assert_eq!(color_override(ColorChoice::Never, true, true), Some(false));
+        assert_eq!(color_override(ColorChoice::Auto, false, false), Some(false));
+        assert_eq!(color_override(ColorChoice::Auto, false, true), None);
+        assert_eq!(color_override(ColorChoice::Auto, true, false), None);
+    }
+}
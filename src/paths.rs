@@ -9,8 +9,20 @@ pub fn get_home_dir() -> Option<PathBuf> {
         .or_else(dirs::home_dir)
 }
 
+/// Environment variable that relocates the whole skillshub directory
+pub const SKILLSHUB_HOME_ENV: &str = "SKILLSHUB_HOME";
+
 /// Get the skillshub home directory (~/.skillshub)
+///
+/// Resolution order:
+/// 1. `SKILLSHUB_HOME`, used as the skillshub directory itself
+/// 2. `SKILLSHUB_TEST_HOME` joined with `.skillshub`
+/// 3. The user's home directory joined with `.skillshub`
 pub fn get_skillshub_home() -> Result<PathBuf> {
+    if let Some(dir) = std::env::var_os(SKILLSHUB_HOME_ENV).filter(|dir| !dir.is_empty()) {
+        return Ok(PathBuf::from(dir));
+    }
+
     let home = get_home_dir().context("Could not determine home directory")?;
     Ok(home.join(".skillshub"))
 }
@@ -116,6 +128,39 @@ mod tests {
         }
     }
 
+    #[test]
+    #[serial]
+    fn test_skillshub_home_env_takes_precedence() {
+        let original_home = std::env::var("SKILLSHUB_HOME").ok();
+        let original_test_home = std::env::var("SKILLSHUB_TEST_HOME").ok();
+
+        std::env::set_var("SKILLSHUB_TEST_HOME", "/test/home");
+        std::env::set_var("SKILLSHUB_HOME", "/data/skillshub");
+        assert_eq!(get_skillshub_home().unwrap(), PathBuf::from("/data/skillshub"));
+        assert_eq!(
+            get_skills_install_dir().unwrap(),
+            PathBuf::from("/data/skillshub/skills")
+        );
+        assert_eq!(
+            crate::registry::db::get_db_path().unwrap(),
+            PathBuf::from("/data/skillshub/db.json")
+        );
+
+        // An empty value is ignored
+        std::env::set_var("SKILLSHUB_HOME", "");
+        assert_eq!(get_skillshub_home().unwrap(), PathBuf::from("/test/home/.skillshub"));
+
+        for (var, value) in [
+            ("SKILLSHUB_HOME", original_home),
+            ("SKILLSHUB_TEST_HOME", original_test_home),
+        ] {
+            match value {
+                Some(val) => std::env::set_var(var, val),
+                None => std::env::remove_var(var),
+            }
+        }
+    }
+
     #[test]
     #[serial]
     fn test_get_skillshub_home() {
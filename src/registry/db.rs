@@ -1,13 +1,21 @@
 use anyhow::{Context, Result};
+use fs2::FileExt;
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
 use super::models::{Database, ExternalSkill, InstalledSkill, TapInfo};
+use crate::action_log::log_action;
+use crate::context::RunContext;
 use crate::paths::get_skillshub_home;
 
 /// Default tap name for bundled skills (owner/repo format)
 pub const DEFAULT_TAP_NAME: &str = "EYH0602/skillshub";
 
+/// Tap name recorded for external skills adopted with `external adopt`; it has no
+/// repository and is never added to `taps`
+pub const LOCAL_TAP_NAME: &str = "local";
+
 /// Default tap URL (this repository)
 pub const DEFAULT_TAP_URL: &str = "https://github.com/EYH0602/skillshub";
 
@@ -16,6 +24,38 @@ pub fn get_db_path() -> Result<PathBuf> {
     Ok(get_skillshub_home()?.join("db.json"))
 }
 
+/// Advisory lock on `db.lock` next to `db.json`, released when dropped
+pub struct DbLock {
+    _file: fs::File,
+}
+
+/// Take an exclusive lock so concurrent `skillshub` processes don't interleave their
+/// load-modify-save cycles. Blocks (with a notice) while another process holds it.
+pub fn lock_db() -> Result<DbLock> {
+    lock_file(&get_skillshub_home()?.join("db.lock"))
+}
+
+fn lock_file(lock_path: &Path) -> Result<DbLock> {
+    if let Some(parent) = lock_path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    let file = fs::OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(lock_path)
+        .with_context(|| format!("Failed to open lock file {}", lock_path.display()))?;
+
+    if file.try_lock_exclusive().is_err() {
+        eprintln!("Waiting for another skillshub process to finish...");
+        file.lock_exclusive()
+            .with_context(|| format!("Failed to lock {}", lock_path.display()))?;
+    }
+
+    Ok(DbLock { _file: file })
+}
+
 /// Load the database from disk, or return a default if it doesn't exist
 pub fn load_db() -> Result<Database> {
     let db_path = get_db_path()?;
@@ -101,8 +141,22 @@ pub fn save_db(db: &Database) -> Result<()> {
     }
 
     let content = serde_json::to_string_pretty(db)?;
-    fs::write(&db_path, content).with_context(|| format!("Failed to write database to {}", db_path.display()))?;
+    log_action(format!("write {}", db_path.display()));
+    write_atomic(&db_path, |file| file.write_all(content.as_bytes()))
+        .with_context(|| format!("Failed to write database to {}", db_path.display()))?;
+
+    Ok(())
+}
 
+/// Replace `path` atomically: `write` fills a temporary file in the same directory,
+/// which is then renamed over `path`. If writing fails or the process dies first,
+/// the previous file is left intact.
+fn write_atomic(path: &Path, write: impl FnOnce(&mut fs::File) -> std::io::Result<()>) -> Result<()> {
+    let dir = path.parent().context("Database path has no parent directory")?;
+    let mut tmp = tempfile::NamedTempFile::new_in(dir)?;
+    write(tmp.as_file_mut())?;
+    tmp.as_file().sync_all()?;
+    tmp.persist(path)?;
     Ok(())
 }
 
@@ -111,11 +165,13 @@ fn default_taps() -> Vec<(&'static str, TapInfo)> {
         DEFAULT_TAP_NAME,
         TapInfo {
             url: DEFAULT_TAP_URL.to_string(),
-            skills_path: "skills".to_string(),
+            custom_skills_path: None,
             updated_at: None,
             is_default: true,
             cached_registry: None,
             branch: None,
+            token_env: None,
+            registry_file: None,
         },
     )]
 }
@@ -133,6 +189,30 @@ fn ensure_default_taps(db: &mut Database) -> bool {
     changed
 }
 
+/// Re-insert the bundled default taps, also repairing entries whose URL or default
+/// flag were edited by hand. Cached registry data is kept. Returns the restored tap names.
+pub fn restore_default_taps(db: &mut Database) -> Vec<&'static str> {
+    let mut restored = Vec::new();
+
+    for (name, tap) in default_taps() {
+        match db.taps.get_mut(name) {
+            Some(existing) if existing.is_default && existing.url == tap.url => {}
+            Some(existing) => {
+                existing.url = tap.url;
+                existing.is_default = true;
+                existing.branch = tap.branch;
+                restored.push(name);
+            }
+            None => {
+                db.taps.insert(name.to_string(), tap);
+                restored.push(name);
+            }
+        }
+    }
+
+    restored
+}
+
 /// Initialize the database with the default tap if it doesn't exist
 pub fn init_db() -> Result<Database> {
     let mut db = load_db()?;
@@ -144,6 +224,17 @@ pub fn init_db() -> Result<Database> {
     Ok(db)
 }
 
+/// Like `init_db`, but in dry-run mode the default tap is only added in memory
+pub fn init_db_with(ctx: &RunContext) -> Result<Database> {
+    if !ctx.dry_run {
+        return init_db();
+    }
+
+    let mut db = load_db()?;
+    ensure_default_taps(&mut db);
+    Ok(db)
+}
+
 /// Check if a skill is installed
 pub fn is_skill_installed(db: &Database, full_name: &str) -> bool {
     db.installed.contains_key(full_name)
@@ -174,11 +265,30 @@ pub fn add_tap(db: &mut Database, name: &str, tap: TapInfo) {
     db.taps.insert(name.to_string(), tap);
 }
 
-/// Remove a tap from the database
+/// Remove a tap from the database. If it was chosen with `tap set-default`, the bundled
+/// default tap takes over again.
 pub fn remove_tap(db: &mut Database, name: &str) -> Option<TapInfo> {
+    if db.default_tap.as_deref() == Some(name) {
+        db.default_tap = None;
+    }
     db.taps.remove(name)
 }
 
+/// Name of the default tap: the one chosen with `tap set-default` if it is still added,
+/// otherwise the bundled tap
+pub fn default_tap_name(db: &Database) -> Option<&str> {
+    db.default_tap
+        .as_deref()
+        .filter(|name| db.taps.contains_key(*name))
+        .or_else(|| {
+            db.taps
+                .iter()
+                .filter(|(_, tap)| tap.is_default)
+                .map(|(name, _)| name.as_str())
+                .min()
+        })
+}
+
 /// Get all skills installed from a specific tap
 pub fn get_skills_from_tap<'a>(db: &'a Database, tap_name: &str) -> Vec<(&'a String, &'a InstalledSkill)> {
     db.installed.iter().filter(|(_, skill)| skill.tap == tap_name).collect()
@@ -230,6 +340,22 @@ mod tests {
         assert!(!ensure_default_taps(&mut db));
     }
 
+    #[test]
+    fn test_restore_default_taps_repairs_edited_entry() {
+        let mut db = Database::default();
+        assert_eq!(restore_default_taps(&mut db), vec![DEFAULT_TAP_NAME]);
+        assert!(restore_default_taps(&mut db).is_empty());
+
+        let tap = db.taps.get_mut(DEFAULT_TAP_NAME).unwrap();
+        tap.is_default = false;
+        tap.url = "https://github.com/someone/fork".to_string();
+
+        assert_eq!(restore_default_taps(&mut db), vec![DEFAULT_TAP_NAME]);
+        let tap = db.taps.get(DEFAULT_TAP_NAME).unwrap();
+        assert!(tap.is_default);
+        assert_eq!(tap.url, DEFAULT_TAP_URL);
+    }
+
     #[test]
     fn test_is_skill_installed() {
         let mut db = Database::default();
@@ -245,6 +371,10 @@ mod tests {
                 source_url: None,
                 source_path: None,
                 gist_updated_at: None,
+                content_sha256: None,
+                size_bytes: None,
+                alias: None,
+                branch: None,
             },
         );
 
@@ -263,6 +393,10 @@ mod tests {
             source_url: None,
             source_path: None,
             gist_updated_at: None,
+            content_sha256: None,
+            size_bytes: None,
+            alias: None,
+            branch: None,
         };
 
         add_installed_skill(&mut db, "tap/skill", skill);
@@ -279,11 +413,13 @@ mod tests {
 
         let tap = TapInfo {
             url: "https://github.com/user/repo".to_string(),
-            skills_path: "skills".to_string(),
+            custom_skills_path: None,
             updated_at: None,
             is_default: false,
             cached_registry: None,
             branch: None,
+            token_env: None,
+            registry_file: None,
         };
 
         add_tap(&mut db, "my-tap", tap);
@@ -306,6 +442,10 @@ mod tests {
             source_url: None,
             source_path: None,
             gist_updated_at: None,
+            content_sha256: None,
+            size_bytes: None,
+            alias: None,
+            branch: None,
         };
         let skill2 = InstalledSkill {
             tap: "tap1".to_string(),
@@ -315,6 +455,10 @@ mod tests {
             source_url: None,
             source_path: None,
             gist_updated_at: None,
+            content_sha256: None,
+            size_bytes: None,
+            alias: None,
+            branch: None,
         };
         let skill3 = InstalledSkill {
             tap: "tap2".to_string(),
@@ -324,6 +468,10 @@ mod tests {
             source_url: None,
             source_path: None,
             gist_updated_at: None,
+            content_sha256: None,
+            size_bytes: None,
+            alias: None,
+            branch: None,
         };
 
         add_installed_skill(&mut db, "tap1/skill1", skill1);
@@ -367,14 +515,31 @@ mod tests {
     fn make_tap(is_default: bool) -> TapInfo {
         TapInfo {
             url: "https://github.com/user/repo".to_string(),
-            skills_path: "skills".to_string(),
+            custom_skills_path: None,
             updated_at: None,
             is_default,
             cached_registry: None,
             branch: None,
+            token_env: None,
+            registry_file: None,
         }
     }
 
+    #[test]
+    fn test_default_tap_name_prefers_chosen_tap_until_removed() {
+        let mut db = Database::default();
+        db.taps.insert(DEFAULT_TAP_NAME.to_string(), make_tap(true));
+        db.taps.insert("other/tap".to_string(), make_tap(false));
+        assert_eq!(default_tap_name(&db), Some(DEFAULT_TAP_NAME));
+
+        db.default_tap = Some("other/tap".to_string());
+        assert_eq!(default_tap_name(&db), Some("other/tap"));
+
+        remove_tap(&mut db, "other/tap");
+        assert_eq!(db.default_tap, None);
+        assert_eq!(default_tap_name(&db), Some(DEFAULT_TAP_NAME));
+    }
+
     #[test]
     fn test_normalize_default_taps_single_default() {
         let mut db = Database::default();
@@ -435,4 +600,38 @@ mod tests {
         // Nothing changes when there are zero defaults
         assert!(!db.taps["user/tap"].is_default);
     }
+
+    #[test]
+    fn test_write_atomic_failure_keeps_old_db() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let db_path = temp.path().join("db.json");
+        fs::write(&db_path, r#"{"taps":{}}"#).unwrap();
+
+        // Simulate a crash halfway through serializing the new database
+        let result = write_atomic(&db_path, |file| {
+            file.write_all(br#"{"taps":{"half"#)?;
+            Err(std::io::Error::other("killed mid-write"))
+        });
+
+        assert!(result.is_err());
+        assert_eq!(fs::read_to_string(&db_path).unwrap(), r#"{"taps":{}}"#);
+        let leftovers: Vec<_> = fs::read_dir(temp.path()).unwrap().flatten().collect();
+        assert_eq!(leftovers.len(), 1, "temporary file should be cleaned up");
+
+        write_atomic(&db_path, |file| file.write_all(b"{}")).unwrap();
+        assert_eq!(fs::read_to_string(&db_path).unwrap(), "{}");
+    }
+
+    #[test]
+    fn test_lock_file_is_exclusive_until_dropped() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let lock_path = temp.path().join("db.lock");
+
+        let guard = lock_file(&lock_path).unwrap();
+        let other = fs::File::open(&lock_path).unwrap();
+        assert!(other.try_lock_exclusive().is_err(), "second lock should be refused");
+
+        drop(guard);
+        assert!(other.try_lock_exclusive().is_ok());
+    }
 }
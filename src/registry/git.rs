@@ -2,6 +2,9 @@ use anyhow::{Context, Result};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use super::github::ensure_online;
+use crate::action_log::log_action;
+
 /// Pre-flight check that git is available.
 pub fn check_git() -> Result<()> {
     let output = Command::new("git")
@@ -27,6 +30,7 @@ pub fn tap_clone_path(taps_dir: &Path, tap_name: &str) -> PathBuf {
 /// If `branch` is provided, clones that specific branch.
 /// Uses `.status()` so git's progress output streams to the terminal.
 pub fn git_clone(url: &str, dest: &Path, branch: Option<&str>) -> Result<()> {
+    ensure_online(&format!("git clone {}", url))?;
     check_git()?;
     let mut cmd = Command::new("git");
     cmd.args(["clone", "--depth", "1"]);
@@ -37,6 +41,7 @@ pub fn git_clone(url: &str, dest: &Path, branch: Option<&str>) -> Result<()> {
 
     cmd.arg(url).arg(dest);
 
+    log_action(format!("git clone {} -> {}", url, dest.display()));
     let status = cmd.status().context("Failed to run git clone (is git installed?)")?;
 
     if !status.success() {
@@ -46,9 +51,82 @@ pub fn git_clone(url: &str, dest: &Path, branch: Option<&str>) -> Result<()> {
     Ok(())
 }
 
+/// Shallow, blobless clone that checks out only `path` (a directory in the repo).
+/// Saves bandwidth on large monorepos when a single skill is needed.
+pub fn git_sparse_clone(url: &str, dest: &Path, branch: Option<&str>, path: &str) -> Result<()> {
+    ensure_online(&format!("git clone {}", url))?;
+    check_git()?;
+    let mut cmd = Command::new("git");
+    cmd.args(["clone", "--depth", "1", "--filter=blob:none", "--sparse"]);
+
+    if let Some(b) = branch {
+        cmd.args(["-b", b]);
+    }
+
+    cmd.arg(url).arg(dest);
+
+    log_action(format!("git clone --sparse {} -> {} ({})", url, dest.display(), path));
+    let status = cmd.status().context("Failed to run git clone (is git installed?)")?;
+    if !status.success() {
+        anyhow::bail!("git clone --sparse failed");
+    }
+
+    let output = Command::new("git")
+        .args(["sparse-checkout", "set", "--", path])
+        .current_dir(dest)
+        .output()
+        .context("Failed to run git sparse-checkout")?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("git sparse-checkout failed: {}", stderr.trim());
+    }
+
+    Ok(())
+}
+
+/// Check out `commit` of `url` into `dest` (an empty or missing directory).
+///
+/// A full SHA is fetched on its own at depth 1; abbreviated SHAs, and servers that
+/// refuse to serve unadvertised commits, fall back to a full clone.
+pub fn git_checkout_commit(url: &str, dest: &Path, commit: &str) -> Result<()> {
+    ensure_online(&format!("git fetch {}", url))?;
+    check_git()?;
+    log_action(format!("git checkout {} of {} -> {}", commit, url, dest.display()));
+
+    let run = |args: &[&str]| -> Result<()> {
+        let output = Command::new("git")
+            .args(args)
+            .current_dir(dest)
+            .output()
+            .context("Failed to run git")?;
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            anyhow::bail!("git {} failed: {}", args[0], stderr.trim());
+        }
+        Ok(())
+    };
+
+    std::fs::create_dir_all(dest)?;
+    if commit.len() == 40 {
+        let fetched = run(&["init", "-q"])
+            .and_then(|()| run(&["fetch", "-q", "--depth", "1", url, commit]))
+            .and_then(|()| run(&["checkout", "-q", "--detach", "FETCH_HEAD"]));
+        if fetched.is_ok() {
+            return Ok(());
+        }
+        std::fs::remove_dir_all(dest)?;
+        std::fs::create_dir_all(dest)?;
+    }
+
+    run(&["clone", "-q", "--no-checkout", url, "."])?;
+    run(&["checkout", "-q", "--detach", commit]).with_context(|| format!("Commit {} not found in {}", commit, url))
+}
+
 /// Pull latest changes in an existing clone (fast-forward only).
 /// Uses `.status()` so git's progress output streams to the terminal.
 pub fn git_pull(repo_path: &Path) -> Result<()> {
+    ensure_online("git pull")?;
+    log_action(format!("git pull {}", repo_path.display()));
     let status = Command::new("git")
         .args(["pull", "--ff-only"])
         .current_dir(repo_path)
@@ -62,10 +140,10 @@ pub fn git_pull(repo_path: &Path) -> Result<()> {
     Ok(())
 }
 
-/// Get the HEAD commit SHA (short, 7 chars) of a local repository.
+/// Get the full HEAD commit SHA of a local repository.
 pub fn git_head_sha(repo_path: &Path) -> Result<String> {
     let output = Command::new("git")
-        .args(["rev-parse", "--short=7", "HEAD"])
+        .args(["rev-parse", "HEAD"])
         .current_dir(repo_path)
         .output()
         .context("Failed to run git rev-parse")?;
@@ -78,6 +156,117 @@ pub fn git_head_sha(repo_path: &Path) -> Result<String> {
     Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
 }
 
+/// Get the checked-out branch name of a local repository.
+pub fn git_current_branch(repo_path: &Path) -> Result<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--abbrev-ref", "HEAD"])
+        .current_dir(repo_path)
+        .output()
+        .context("Failed to run git rev-parse")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("git rev-parse failed: {}", stderr.trim());
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Resolve the full remote HEAD (or `branch`) commit SHA without touching any clone.
+/// Uses `git ls-remote`, so it is safe for read-only previews such as `--dry-run`.
+pub fn git_remote_head_sha(url: &str, branch: Option<&str>) -> Result<String> {
+    ensure_online(&format!("git ls-remote {}", url))?;
+    check_git()?;
+    log_action(format!("git ls-remote {} {}", url, branch.unwrap_or("HEAD")));
+    let output = Command::new("git")
+        .args(["ls-remote", url, branch.unwrap_or("HEAD")])
+        .output()
+        .context("Failed to run git ls-remote")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("git ls-remote failed: {}", stderr.trim());
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let sha = stdout
+        .lines()
+        .next()
+        .and_then(|line| line.split_whitespace().next())
+        .with_context(|| format!("Ref '{}' not found on remote", branch.unwrap_or("HEAD")))?;
+
+    Ok(sha.to_string())
+}
+
+/// Check whether `tag` exists on the remote as `refs/tags/<tag>`.
+pub fn git_remote_has_tag(url: &str, tag: &str) -> Result<bool> {
+    ensure_online(&format!("git ls-remote {}", url))?;
+    check_git()?;
+    log_action(format!("git ls-remote --tags {} {}", url, tag));
+    let output = Command::new("git")
+        .args(["ls-remote", "--tags", url, &format!("refs/tags/{}", tag)])
+        .output()
+        .context("Failed to run git ls-remote")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("git ls-remote failed: {}", stderr.trim());
+    }
+
+    Ok(!String::from_utf8_lossy(&output.stdout).trim().is_empty())
+}
+
+/// Check whether `name` is a branch or tag on the remote (`refs/heads/<name>` or
+/// `refs/tags/<name>`), so a hex-looking ref is not mistaken for a commit SHA.
+pub fn git_remote_has_ref(url: &str, name: &str) -> Result<bool> {
+    ensure_online(&format!("git ls-remote {}", url))?;
+    check_git()?;
+    log_action(format!("git ls-remote {} {}", url, name));
+    let output = Command::new("git")
+        .args([
+            "ls-remote",
+            url,
+            &format!("refs/heads/{}", name),
+            &format!("refs/tags/{}", name),
+        ])
+        .output()
+        .context("Failed to run git ls-remote")?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        anyhow::bail!("git ls-remote failed: {}", stderr.trim());
+    }
+
+    Ok(!String::from_utf8_lossy(&output.stdout).trim().is_empty())
+}
+
+/// Check whether the checkout at `repo_path` is on `expected`, either as the current
+/// branch or as a tag pointing at a detached HEAD.
+fn head_matches_ref(repo_path: &Path, expected: &str) -> bool {
+    let current = Command::new("git")
+        .args(["rev-parse", "--abbrev-ref", "HEAD"])
+        .current_dir(repo_path)
+        .output();
+    let current = match current {
+        Ok(output) if output.status.success() => String::from_utf8_lossy(&output.stdout).trim().to_string(),
+        _ => return false,
+    };
+    if current == expected {
+        return true;
+    }
+    if current != "HEAD" {
+        return false;
+    }
+
+    // Cloning with `-b <tag>` leaves HEAD detached at the tag
+    let tags = Command::new("git")
+        .args(["tag", "--points-at", "HEAD"])
+        .current_dir(repo_path)
+        .output();
+    matches!(tags, Ok(ref output) if output.status.success()
+        && String::from_utf8_lossy(&output.stdout).lines().any(|tag| tag.trim() == expected))
+}
+
 /// Ensure a tap clone exists and is healthy. Clone if missing or corrupted.
 pub fn ensure_clone(clone_dir: &Path, url: &str, branch: Option<&str>) -> Result<PathBuf> {
     if clone_dir.join(".git").exists() {
@@ -104,14 +293,7 @@ pub fn ensure_clone(clone_dir: &Path, url: &str, branch: Option<&str>) -> Result
         // Verify checked-out branch matches requested branch (if specified)
         let branch_ok = if rev_ok && remote_ok {
             match branch {
-                Some(expected) => {
-                    let current = Command::new("git")
-                        .args(["rev-parse", "--abbrev-ref", "HEAD"])
-                        .current_dir(clone_dir)
-                        .output();
-                    matches!(current, Ok(ref output) if output.status.success()
-                        && String::from_utf8_lossy(&output.stdout).trim() == expected)
-                }
+                Some(expected) => head_matches_ref(clone_dir, expected),
                 None => true, // No specific branch requested, any branch is fine
             }
         } else {
@@ -141,10 +323,14 @@ pub fn ensure_clone(clone_dir: &Path, url: &str, branch: Option<&str>) -> Result
 /// If re-clone also fails (e.g. transient network error), the backup is restored
 /// so the user doesn't lose their local clone.
 pub fn pull_or_reclone(clone_dir: &Path, url: &str, branch: Option<&str>) -> Result<()> {
+    // Bail before the backup/re-clone dance, which cannot succeed offline
+    ensure_online("git pull")?;
+
     match git_pull(clone_dir) {
         Ok(()) => Ok(()),
         Err(_) => {
             eprintln!("  Pull failed, re-cloning...");
+            log_action(format!("git pull failed, re-cloning {}", url));
             let backup = clone_dir.with_extension("bak");
             // Move existing clone to backup
             if backup.exists() {
@@ -281,6 +467,25 @@ mod tests {
         assert!(check_git().is_ok());
     }
 
+    #[test]
+    fn test_git_sparse_clone_checks_out_only_path() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let origin = create_local_repo(temp.path());
+        for dir in ["skills/wanted", "skills/other"] {
+            std::fs::create_dir_all(origin.join(dir)).unwrap();
+            std::fs::write(origin.join(dir).join("SKILL.md"), "---\nname: x\n---\n").unwrap();
+        }
+        for args in [&["add", "."][..], &["commit", "-m", "skills"][..]] {
+            StdCommand::new("git").args(args).current_dir(&origin).output().unwrap();
+        }
+
+        let dest = temp.path().join("sparse");
+        git_sparse_clone(&file_url(&origin), &dest, None, "skills/wanted").unwrap();
+
+        assert!(dest.join("skills/wanted/SKILL.md").exists());
+        assert!(!dest.join("skills/other").exists());
+    }
+
     #[test]
     fn test_ensure_clone_creates_missing() {
         let temp = tempfile::TempDir::new().unwrap();
@@ -296,6 +501,30 @@ mod tests {
         assert!(clone_dir.join("README.md").exists());
     }
 
+    #[test]
+    fn test_ensure_clone_keeps_tag_checkout() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let origin = create_local_repo(temp.path());
+        StdCommand::new("git")
+            .args(["tag", "v1.2.0"])
+            .current_dir(&origin)
+            .output()
+            .unwrap();
+        let url = file_url(&origin);
+
+        assert!(git_remote_has_tag(&url, "v1.2.0").unwrap());
+        assert!(!git_remote_has_tag(&url, "v9.9.9").unwrap());
+
+        let clone_dir = temp.path().join("clone");
+        ensure_clone(&clone_dir, &url, Some("v1.2.0")).unwrap();
+
+        // A detached HEAD at the requested tag is healthy and must not be re-cloned
+        let marker = clone_dir.join("untracked.txt");
+        std::fs::write(&marker, "keep").unwrap();
+        ensure_clone(&clone_dir, &url, Some("v1.2.0")).unwrap();
+        assert!(marker.exists(), "tag checkout should not be re-cloned");
+    }
+
     #[test]
     fn test_ensure_clone_repairs_corrupted() {
         let temp = tempfile::TempDir::new().unwrap();
@@ -621,6 +850,20 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_git_remote_head_sha_matches_clone() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let origin = create_local_repo_with_branch(temp.path(), "feature-branch");
+        let url = file_url(&origin);
+
+        let clone_dir = temp.path().join("clone");
+        git_clone(&url, &clone_dir, Some("feature-branch")).unwrap();
+
+        let remote = git_remote_head_sha(&url, Some("feature-branch")).unwrap();
+        assert_eq!(remote, git_head_sha(&clone_dir).unwrap());
+        assert!(git_remote_head_sha(&url, Some("no-such-branch")).is_err());
+    }
+
     #[test]
     fn test_git_pull_non_repo() {
         let temp = tempfile::TempDir::new().unwrap();
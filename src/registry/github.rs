@@ -1,11 +1,13 @@
 use anyhow::{anyhow, Context, Result};
+use colored::Colorize;
 use reqwest::blocking::{Client, RequestBuilder, Response};
 use serde::Deserialize;
 use std::collections::HashMap;
 use std::time::{Duration, SystemTime};
 
 use super::models::{GitHubUrl, SkillEntry, TapRegistry};
-use crate::skill::SkillMetadata;
+use crate::action_log::log_action;
+use crate::skill::{validate_skill_name, SkillMetadata};
 
 /// GraphQL API URL (overridden in tests via SKILLSHUB_GITHUB_GRAPHQL_URL)
 fn graphql_url() -> String {
@@ -15,6 +17,27 @@ fn graphql_url() -> String {
 /// User agent for API requests
 const USER_AGENT: &str = "skillshub";
 
+/// Environment variable that disables all network access (also set by `--offline`)
+pub const OFFLINE_ENV: &str = "SKILLSHUB_OFFLINE";
+
+/// Whether offline mode is enabled via `--offline` or `SKILLSHUB_OFFLINE=1`
+pub fn is_offline() -> bool {
+    offline_enabled(std::env::var(OFFLINE_ENV).ok().as_deref())
+}
+
+fn offline_enabled(value: Option<&str>) -> bool {
+    matches!(value.map(str::trim), Some(v) if v == "1" || v.eq_ignore_ascii_case("true"))
+}
+
+/// Fail fast with a clear error when offline mode is enabled.
+/// `action` describes what needed the network (e.g. "git clone <url>").
+pub fn ensure_online(action: &str) -> Result<()> {
+    if is_offline() {
+        anyhow::bail!("offline mode: network disabled ({} requires network access)", action);
+    }
+    Ok(())
+}
+
 /// Maximum number of retries for transient errors
 const MAX_RETRIES: u32 = 5;
 
@@ -30,8 +53,45 @@ const MAX_BACKOFF_MS: u64 = 60_000;
 /// Maximum time to wait for a rate limit reset (seconds)
 const MAX_RATE_LIMIT_WAIT_SECS: u64 = 300;
 
+/// Environment variable overriding `MAX_RETRIES` (clamped to 1-20)
+pub const MAX_RETRIES_ENV: &str = "SKILLSHUB_MAX_RETRIES";
+
+/// Environment variable overriding `INITIAL_BACKOFF_MS` (clamped to `MAX_BACKOFF_MS`)
+pub const BACKOFF_MS_ENV: &str = "SKILLSHUB_BACKOFF_MS";
+
+/// Environment variable overriding `MAX_RATE_LIMIT_WAIT_SECS` (clamped to one hour)
+pub const MAX_RATE_LIMIT_WAIT_ENV: &str = "SKILLSHUB_MAX_RATELIMIT_WAIT";
+
+/// Retry limits for one `send_with_retry` call: the defaults above, unless overridden
+/// by environment variables
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct RetryConfig {
+    max_retries: u32,
+    initial_backoff_ms: u64,
+    max_rate_limit_wait_secs: u64,
+}
+
+impl RetryConfig {
+    fn from_env() -> Self {
+        let var = |name: &str| std::env::var(name).ok();
+        Self {
+            max_retries: parse_clamped(var(MAX_RETRIES_ENV).as_deref(), 1, 20).map_or(MAX_RETRIES, |n| n as u32),
+            initial_backoff_ms: parse_clamped(var(BACKOFF_MS_ENV).as_deref(), 0, MAX_BACKOFF_MS)
+                .unwrap_or(INITIAL_BACKOFF_MS),
+            max_rate_limit_wait_secs: parse_clamped(var(MAX_RATE_LIMIT_WAIT_ENV).as_deref(), 0, 3600)
+                .unwrap_or(MAX_RATE_LIMIT_WAIT_SECS),
+        }
+    }
+}
+
+/// Parse a non-negative integer and clamp it to `min..=max`; `None` when unset or invalid
+fn parse_clamped(value: Option<&str>, min: u64, max: u64) -> Option<u64> {
+    value?.trim().parse::<u64>().ok().map(|n| n.clamp(min, max))
+}
+
 /// Parsed rate limit information from GitHub response headers
 struct RateLimitInfo {
+    limit: Option<u64>,
     remaining: Option<u64>,
     reset: Option<i64>,
 }
@@ -39,6 +99,12 @@ struct RateLimitInfo {
 impl RateLimitInfo {
     /// Parse rate limit headers from a response
     fn from_response(resp: &Response) -> Self {
+        let limit = resp
+            .headers()
+            .get("X-RateLimit-Limit")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok());
+
         let remaining = resp
             .headers()
             .get("X-RateLimit-Remaining")
@@ -51,7 +117,11 @@ impl RateLimitInfo {
             .and_then(|v| v.to_str().ok())
             .and_then(|v| v.parse::<i64>().ok());
 
-        Self { remaining, reset }
+        Self {
+            limit,
+            remaining,
+            reset,
+        }
     }
 
     /// Compute the duration to wait until the rate limit resets
@@ -70,8 +140,8 @@ impl RateLimitInfo {
 }
 
 /// Compute exponential backoff duration for a given attempt (1-based)
-fn backoff_duration(attempt: u32) -> Duration {
-    let base_ms = INITIAL_BACKOFF_MS.saturating_mul(1u64 << (attempt.saturating_sub(1)));
+fn backoff_duration(attempt: u32, initial_ms: u64) -> Duration {
+    let base_ms = initial_ms.saturating_mul(1u64 << (attempt.saturating_sub(1)));
     let jitter = simple_jitter_ms();
     let total_ms = base_ms.saturating_add(jitter).min(MAX_BACKOFF_MS);
     Duration::from_millis(total_ms)
@@ -86,7 +156,7 @@ fn simple_jitter_ms() -> u64 {
 }
 
 /// Determine how long to wait before retrying based on response headers or backoff
-fn retry_after_from_response(resp: &Response, attempt: u32) -> Duration {
+fn retry_after_from_response(resp: &Response, attempt: u32, initial_backoff_ms: u64) -> Duration {
     // Check Retry-After header first
     if let Some(retry_after) = resp
         .headers()
@@ -104,16 +174,16 @@ fn retry_after_from_response(resp: &Response, attempt: u32) -> Duration {
     }
 
     // Fall back to exponential backoff
-    backoff_duration(attempt)
+    backoff_duration(attempt, initial_backoff_ms)
 }
 
 /// Print a rate limit wait message to stderr
-fn print_rate_limit_wait(reason: &str, wait_secs: u64, attempt: u32) {
+fn print_rate_limit_wait(reason: &str, wait_secs: u64, attempt: u32, max_retries: u32) {
     eprint!(
         "  {} Waiting {}s before retrying (attempt {}/{})...",
-        reason, wait_secs, attempt, MAX_RETRIES
+        reason, wait_secs, attempt, max_retries
     );
-    if github_token().is_none() {
+    if !matches!(github_token(), Ok(Some(_))) {
         eprint!("\n  Tip: Set GH_TOKEN or GITHUB_TOKEN for higher rate limits (5000/hour vs 60/hour).");
     }
     eprintln!();
@@ -122,30 +192,41 @@ fn print_rate_limit_wait(reason: &str, wait_secs: u64, attempt: u32) {
 /// Send an HTTP request with retry logic for rate limits, server errors, and network errors.
 ///
 /// The `build_request` closure is called on each attempt since `RequestBuilder` is consumed
-/// on `.send()`.
+/// on `.send()`. Retry limits are read once per call from `SKILLSHUB_MAX_RETRIES`,
+/// `SKILLSHUB_BACKOFF_MS`, and `SKILLSHUB_MAX_RATELIMIT_WAIT`.
 fn send_with_retry<F>(build_request: F, url: &str) -> Result<Response>
 where
     F: Fn() -> RequestBuilder,
 {
+    ensure_online(&format!("request to {}", url))?;
+    let config = RetryConfig::from_env();
+
     let mut attempt = 0u32;
 
     loop {
         attempt += 1;
 
+        log_action(format!("GET {} (attempt {}/{})", url, attempt, config.max_retries));
         let result = build_request().send();
 
         match result {
             Ok(resp) => {
                 let status = resp.status();
+                log_action(format!("HTTP {} {}", status.as_u16(), url));
 
                 // 429 Too Many Requests
                 if status == reqwest::StatusCode::TOO_MANY_REQUESTS {
-                    if attempt >= MAX_RETRIES {
-                        anyhow::bail!("Rate limited (HTTP 429) after {} retries for {}", MAX_RETRIES, url);
+                    if attempt >= config.max_retries {
+                        anyhow::bail!(
+                            "Rate limited (HTTP 429) after {} retries for {}",
+                            config.max_retries,
+                            url
+                        );
                     }
-                    let wait = retry_after_from_response(&resp, attempt);
+                    let wait = retry_after_from_response(&resp, attempt, config.initial_backoff_ms);
                     let wait_secs = wait.as_secs();
-                    print_rate_limit_wait("Rate limited (429).", wait_secs, attempt);
+                    log_action(format!("rate limited (429), retrying in {}s: {}", wait_secs, url));
+                    print_rate_limit_wait("Rate limited (429).", wait_secs, attempt, config.max_retries);
                     std::thread::sleep(wait);
                     continue;
                 }
@@ -154,22 +235,32 @@ where
                 if status == reqwest::StatusCode::FORBIDDEN {
                     let rate_info = RateLimitInfo::from_response(&resp);
                     if rate_info.remaining == Some(0) {
-                        if attempt >= MAX_RETRIES {
+                        if attempt >= config.max_retries {
                             anyhow::bail!(
                                 "Rate limit exceeded (HTTP 403) after {} retries for {}",
-                                MAX_RETRIES,
+                                config.max_retries,
                                 url
                             );
                         }
                         if let Some(wait) = rate_info.wait_duration() {
-                            if wait.as_secs() > MAX_RATE_LIMIT_WAIT_SECS {
+                            if wait.as_secs() > config.max_rate_limit_wait_secs {
                                 anyhow::bail!(
                                     "Rate limit reset is {}s away (>{} max). Set GH_TOKEN or GITHUB_TOKEN for higher limits.",
                                     wait.as_secs(),
-                                    MAX_RATE_LIMIT_WAIT_SECS
+                                    config.max_rate_limit_wait_secs
                                 );
                             }
-                            print_rate_limit_wait("Rate limit exceeded (403).", wait.as_secs(), attempt);
+                            log_action(format!(
+                                "rate limit exceeded (403), retrying in {}s: {}",
+                                wait.as_secs(),
+                                url
+                            ));
+                            print_rate_limit_wait(
+                                "Rate limit exceeded (403).",
+                                wait.as_secs(),
+                                attempt,
+                                config.max_retries,
+                            );
                             std::thread::sleep(wait);
                             continue;
                         }
@@ -181,21 +272,22 @@ where
 
                 // 5xx server errors
                 if status.is_server_error() {
-                    if attempt >= MAX_RETRIES {
+                    if attempt >= config.max_retries {
                         anyhow::bail!(
                             "Server error (HTTP {}) after {} retries for {}",
                             status.as_u16(),
-                            MAX_RETRIES,
+                            config.max_retries,
                             url
                         );
                     }
-                    let wait = backoff_duration(attempt);
+                    let wait = backoff_duration(attempt, config.initial_backoff_ms);
+                    log_action(format!("server error, retrying in {}s: {}", wait.as_secs(), url));
                     eprintln!(
                         "  Server error (HTTP {}). Retrying in {}s... (attempt {}/{})",
                         status.as_u16(),
                         wait.as_secs(),
                         attempt,
-                        MAX_RETRIES
+                        config.max_retries
                     );
                     std::thread::sleep(wait);
                     continue;
@@ -219,16 +311,17 @@ where
             }
             Err(e) => {
                 // Network errors
-                if attempt >= MAX_RETRIES {
-                    anyhow::bail!("Network error after {} retries for {}: {}", MAX_RETRIES, url, e);
+                log_action(format!("network error for {}: {}", url, e));
+                if attempt >= config.max_retries {
+                    anyhow::bail!("Network error after {} retries for {}: {}", config.max_retries, url, e);
                 }
-                let wait = backoff_duration(attempt);
+                let wait = backoff_duration(attempt, config.initial_backoff_ms);
                 eprintln!(
                     "  Network error: {}. Retrying in {}s... (attempt {}/{})",
                     e,
                     wait.as_secs(),
                     attempt,
-                    MAX_RETRIES
+                    config.max_retries
                 );
                 std::thread::sleep(wait);
             }
@@ -236,8 +329,39 @@ where
     }
 }
 
+/// Environment variable that makes the HTTP client ignore proxy settings (also set by `--no-proxy`)
+pub const NO_PROXY_ENV: &str = "SKILLSHUB_NO_PROXY";
+
+/// Proxies named by `HTTPS_PROXY`, `HTTP_PROXY`, and `ALL_PROXY` (or their lowercase forms),
+/// each skipping the hosts listed in `NO_PROXY`. A malformed proxy URL is an error.
+fn env_proxies() -> Result<Vec<reqwest::Proxy>> {
+    let var = |name: &str| {
+        std::env::var(name)
+            .or_else(|_| std::env::var(name.to_lowercase()))
+            .ok()
+            .filter(|v| !v.trim().is_empty())
+    };
+    let mut proxies = Vec::new();
+    for name in ["HTTPS_PROXY", "HTTP_PROXY", "ALL_PROXY"] {
+        if let Some(url) = var(name) {
+            let proxy = match name {
+                "HTTPS_PROXY" => reqwest::Proxy::https(&url),
+                "HTTP_PROXY" => reqwest::Proxy::http(&url),
+                _ => reqwest::Proxy::all(&url),
+            }
+            .with_context(|| format!("Invalid proxy URL in {}: '{}'", name, url))?;
+            log_action(format!("proxy {} from {}", url, name));
+            proxies.push(proxy.no_proxy(reqwest::NoProxy::from_env()));
+        }
+    }
+    Ok(proxies)
+}
+
 /// Build an HTTP client with GitHub token if available
 ///
+/// Proxies come only from the environment (see [`env_proxies`]), so the platform's
+/// system proxy probing is skipped; `--no-proxy` drops them as well.
+///
 /// Uses `catch_unwind` to intercept panics from the underlying `system-configuration`
 /// crate (macOS proxy detection) or reqwest's blocking runtime, converting them into
 /// proper `Result::Err` values.
@@ -247,12 +371,23 @@ where
 /// the process will still abort. Additionally, `catch_unwind` is a no-op when compiled
 /// with `panic = "abort"`.
 fn build_client() -> Result<Client> {
-    std::panic::catch_unwind(|| {
-        Client::builder()
-            .user_agent(USER_AGENT)
+    ensure_online("GitHub API access")?;
+
+    let proxies = if offline_enabled(std::env::var(NO_PROXY_ENV).ok().as_deref()) {
+        Vec::new()
+    } else {
+        env_proxies()?
+    };
+
+    // The proxies are only moved into the builder, so a panic cannot leave them half-updated
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        let builder = Client::builder().user_agent(USER_AGENT).no_proxy();
+        proxies
+            .into_iter()
+            .fold(builder, |builder, proxy| builder.proxy(proxy))
             .build()
             .context("Failed to build HTTP client")
-    })
+    }))
     .unwrap_or_else(|panic_payload| {
         let msg = panic_payload
             .downcast_ref::<String>()
@@ -267,24 +402,56 @@ fn build_client() -> Result<Client> {
     })
 }
 
+/// Environment variable naming a file that holds the GitHub token, for CI systems
+/// that mount secrets as files
+pub const GITHUB_TOKEN_FILE_ENV: &str = "GITHUB_TOKEN_FILE";
+
 /// Read the GitHub auth token from the environment.
 ///
 /// Checks `GH_TOKEN` first (matching the `gh` CLI convention), then falls
-/// back to `GITHUB_TOKEN`. Empty values are treated as unset.
-fn github_token() -> Option<String> {
+/// back to `GITHUB_TOKEN`, then to the file named by `GITHUB_TOKEN_FILE`
+/// (trimmed). Empty values are treated as unset. Fails if `GITHUB_TOKEN_FILE`
+/// is consulted but cannot be read.
+fn github_token() -> Result<Option<String>> {
     for var in ["GH_TOKEN", "GITHUB_TOKEN"] {
         if let Ok(token) = std::env::var(var) {
             if !token.is_empty() {
-                return Some(token);
+                return Ok(Some(token));
             }
         }
     }
-    None
+
+    let Some(path) = std::env::var_os(GITHUB_TOKEN_FILE_ENV).filter(|p| !p.is_empty()) else {
+        return Ok(None);
+    };
+    let token = std::fs::read_to_string(&path).with_context(|| {
+        format!(
+            "Failed to read the GitHub token from {} ({})",
+            GITHUB_TOKEN_FILE_ENV,
+            std::path::Path::new(&path).display()
+        )
+    })?;
+    let token = token.trim();
+    Ok((!token.is_empty()).then(|| token.to_string()))
 }
 
-/// Add GitHub token authentication to a request if a token env var is set.
-fn with_auth(request: RequestBuilder) -> RequestBuilder {
-    if let Some(token) = github_token() {
+/// Read the GitHub auth token for a tap.
+///
+/// Uses the variable named by the tap's `token_env` when it is set and non-empty,
+/// falling back to [`github_token`] so taps without their own token keep working.
+pub fn tap_token(token_env: Option<&str>) -> Result<Option<String>> {
+    match token_env
+        .and_then(|var| std::env::var(var).ok())
+        .filter(|token| !token.is_empty())
+    {
+        Some(token) => Ok(Some(token)),
+        None => github_token(),
+    }
+}
+
+/// Add GitHub token authentication to a request if a token is available.
+fn with_auth(request: RequestBuilder, token: Option<&str>) -> RequestBuilder {
+    if let Some(token) = token {
         request.bearer_auth(token)
     } else {
         request
@@ -337,13 +504,13 @@ pub struct GistFile {
     pub content: Option<String>,
 }
 
-/// Get the default branch for a repository from GitHub API
-pub fn get_default_branch(owner: &str, repo: &str) -> Result<String> {
+/// Get the default branch for a repository from GitHub API, authenticating with `token` if given
+pub fn get_default_branch(owner: &str, repo: &str, token: Option<&str>) -> Result<String> {
     let client = build_client()?;
     let api_base = std::env::var("SKILLSHUB_GITHUB_API_BASE").unwrap_or_else(|_| "https://api.github.com".to_string());
     let url = format!("{}/repos/{}/{}", api_base, owner, repo);
 
-    let response = send_with_retry(|| with_auth(client.get(&url)), &url)?;
+    let response = send_with_retry(|| with_auth(client.get(&url), token), &url)?;
 
     let status = response.status();
     if !status.is_success() {
@@ -366,6 +533,72 @@ pub fn get_default_branch(owner: &str, repo: &str) -> Result<String> {
     Ok(info.default_branch)
 }
 
+/// GitHub Releases API response (partial)
+#[derive(Debug, Deserialize)]
+pub struct LatestRelease {
+    pub tag_name: String,
+    pub html_url: String,
+}
+
+/// Fetch the latest published release of a repository from the GitHub API
+pub fn fetch_latest_release(owner: &str, repo: &str) -> Result<LatestRelease> {
+    let client = build_client()?;
+    let api_base = std::env::var("SKILLSHUB_GITHUB_API_BASE").unwrap_or_else(|_| "https://api.github.com".to_string());
+    let url = format!("{}/repos/{}/{}/releases/latest", api_base, owner, repo);
+
+    let token = github_token()?;
+    let response = send_with_retry(|| with_auth(client.get(&url), token.as_deref()), &url)?;
+
+    let status = response.status();
+    if !status.is_success() {
+        if status == reqwest::StatusCode::NOT_FOUND {
+            anyhow::bail!("No releases found for {}/{}", owner, repo);
+        }
+        anyhow::bail!("Failed to fetch latest release: HTTP {}", status);
+    }
+
+    response.json().with_context(|| "Failed to parse release API response")
+}
+
+/// The caller's GitHub API quota, as reported by the `/rate_limit` endpoint
+#[derive(Debug)]
+pub struct RateLimitStatus {
+    /// Requests allowed per window
+    pub limit: Option<u64>,
+    /// Requests left in the current window
+    pub remaining: Option<u64>,
+    /// Unix timestamp when the window resets
+    pub reset: Option<i64>,
+    /// Whether the request was sent with a GitHub token
+    pub authenticated: bool,
+}
+
+/// Fetch the current GitHub API quota. Querying `/rate_limit` does not use up a request.
+pub fn fetch_rate_limit() -> Result<RateLimitStatus> {
+    let client = build_client()?;
+    let api_base = std::env::var("SKILLSHUB_GITHUB_API_BASE").unwrap_or_else(|_| "https://api.github.com".to_string());
+    let url = format!("{}/rate_limit", api_base);
+
+    let token = github_token()?;
+    let response = send_with_retry(|| with_auth(client.get(&url), token.as_deref()), &url)?;
+
+    let status = response.status();
+    if !status.is_success() {
+        if status == reqwest::StatusCode::UNAUTHORIZED {
+            anyhow::bail!("GitHub rejected the token (HTTP 401). Check GH_TOKEN or GITHUB_TOKEN.");
+        }
+        anyhow::bail!("Failed to fetch rate limit: HTTP {}", status);
+    }
+
+    let info = RateLimitInfo::from_response(&response);
+    Ok(RateLimitStatus {
+        limit: info.limit,
+        remaining: info.remaining,
+        reset: info.reset,
+        authenticated: token.is_some(),
+    })
+}
+
 /// Parse a GitHub URL or repository identifier into components
 ///
 /// Supports formats:
@@ -373,9 +606,13 @@ pub fn get_default_branch(owner: &str, repo: &str) -> Result<String> {
 /// - https://github.com/owner/repo (uses repo's default branch)
 /// - https://github.com/owner/repo/tree/branch
 /// - https://github.com/owner/repo/tree/branch/path/to/folder
+/// - https://github.com/owner/repo/blob/branch/path/to/folder/SKILL.md (file name dropped)
+/// - https://github.com/owner/repo/path/to/folder (uses repo's default branch)
 ///
 /// When no branch is specified in the URL, `branch` will be `None`,
-/// indicating that the repository's default branch should be used.
+/// indicating that the repository's default branch should be used. Links to other
+/// GitHub pages of a repository (issues, pull requests, commits, ...) are rejected
+/// rather than read as a folder path.
 pub fn parse_github_url(url: &str) -> Result<GitHubUrl> {
     let url = url.trim_end_matches('/');
 
@@ -413,15 +650,28 @@ pub fn parse_github_url(url: &str) -> Result<GitHubUrl> {
     let owner = parts[0].to_string();
     let repo = parts[1].to_string();
 
-    // Check for /tree/branch/path format
-    let (branch, subpath) = if parts.len() > 3 && parts[2] == "tree" {
+    // Check for /tree/branch/path or /blob/branch/path/SKILL.md format
+    let (branch, subpath) = if parts.len() > 3 && (parts[2] == "tree" || parts[2] == "blob") {
         let branch = Some(parts[3].to_string());
-        let subpath = if parts.len() > 4 {
-            Some(parts[4..].join("/"))
-        } else {
-            None
-        };
+        let mut rest = &parts[4..];
+        // A blob URL points at SKILL.md itself; the skill is its directory
+        if parts[2] == "blob" && rest.last() == Some(&"SKILL.md") {
+            rest = &rest[..rest.len() - 1];
+        }
+        let subpath = (!rest.is_empty()).then(|| rest.join("/"));
         (branch, subpath)
+    } else if parts.len() > 2 && GITHUB_REPO_ROUTES.contains(&parts[2]) {
+        anyhow::bail!(
+            "'{}' links to a repository's {} page, not a skill folder. \
+             Use a folder URL such as https://github.com/{}/{}/tree/<ref>/<path>",
+            url,
+            parts[2],
+            owner,
+            repo
+        );
+    } else if parts.len() > 2 && parts[2] != "tree" && parts[2] != "blob" {
+        // Plain directory path copied without /tree/<ref>/ - use the default branch
+        (None, Some(parts[2..].join("/")))
     } else {
         // No branch specified - use None to indicate "use default branch"
         (None, None)
@@ -435,6 +685,35 @@ pub fn parse_github_url(url: &str) -> Result<GitHubUrl> {
     })
 }
 
+/// Path segments GitHub uses for repository pages other than files, which therefore
+/// never start a plain folder path (`tree` and `blob` are handled separately)
+const GITHUB_REPO_ROUTES: &[&str] = &[
+    "issues",
+    "pull",
+    "pulls",
+    "commit",
+    "commits",
+    "compare",
+    "releases",
+    "tags",
+    "branches",
+    "actions",
+    "wiki",
+    "discussions",
+    "projects",
+    "security",
+    "pulse",
+    "graphs",
+    "network",
+    "settings",
+    "milestones",
+    "labels",
+    "blame",
+    "raw",
+    "archive",
+    "search",
+];
+
 /// Check if a string looks like a valid owner/repo identifier
 /// Valid: "owner/repo", "my-org/my-repo", "user123/repo_name"
 /// Invalid: "https://...", "gitlab.com/...", "just-one-part"
@@ -472,20 +751,21 @@ fn is_valid_repo_id(s: &str) -> bool {
 ///
 /// Uses the GitHub Tree API to recursively find all SKILL.md files in the repo,
 /// then fetches each one to extract metadata.
-/// Set `GH_TOKEN` or `GITHUB_TOKEN` environment variable to avoid rate limiting.
-pub fn discover_skills_from_repo(github_url: &GitHubUrl, tap_name: &str) -> Result<TapRegistry> {
+/// Requests authenticate with `token` (see [`tap_token`]); set `GH_TOKEN` or `GITHUB_TOKEN`
+/// to avoid rate limiting.
+pub fn discover_skills_from_repo(github_url: &GitHubUrl, tap_name: &str, token: Option<&str>) -> Result<TapRegistry> {
     let client = build_client()?;
 
     // Resolve branch: use specified branch or fetch the repository's default branch
     let branch = match &github_url.branch {
         Some(b) => b.clone(),
-        None => get_default_branch(&github_url.owner, &github_url.repo)?,
+        None => get_default_branch(&github_url.owner, &github_url.repo, token)?,
     };
 
     // Fetch the full repo tree with recursive=1
     let tree_url = format!("{}/git/trees/{}?recursive=1", github_url.api_url(), branch);
 
-    let response = send_with_retry(|| with_auth(client.get(&tree_url)), &tree_url)?;
+    let response = send_with_retry(|| with_auth(client.get(&tree_url), token), &tree_url)?;
 
     if !response.status().is_success() {
         let status = response.status();
@@ -522,22 +802,30 @@ pub fn discover_skills_from_repo(github_url: &GitHubUrl, tap_name: &str) -> Resu
         };
 
         // Note: raw.githubusercontent.com doesn't need auth, but we add it anyway
-        match send_with_retry(|| with_auth(client.get(&skill_md_url)), &skill_md_url) {
-            Ok(resp) if resp.status().is_success() => {
-                if let Ok(content) = resp.text() {
-                    if let Some((name, description)) = parse_skill_md_content(&content) {
-                        skills.insert(
-                            name.clone(),
-                            SkillEntry {
-                                path: skill_path.clone(),
-                                description,
-                                homepage: None,
-                            },
-                        );
-                    }
-                }
-            }
-            _ => {
+        let metadata = match send_with_retry(|| with_auth(client.get(&skill_md_url), token), &skill_md_url) {
+            Ok(resp) if resp.status().is_success() => match resp.text() {
+                Ok(content) => match parse_skill_md_frontmatter(&content) {
+                    Some(metadata) => Some(metadata),
+                    None => continue,
+                },
+                Err(_) => continue,
+            },
+            _ => None,
+        };
+
+        let (skill_name, entry) = match metadata {
+            Some(metadata) => (
+                metadata.name,
+                SkillEntry {
+                    path: skill_path.clone(),
+                    description: metadata.description,
+                    homepage: None,
+                    commit: None,
+                    tags: metadata.tags,
+                    aliases: metadata.aliases,
+                },
+            ),
+            None => {
                 // If we can't fetch metadata, use directory name as skill name
                 // For root-level skills, use the repo name
                 let skill_name = if skill_path.is_empty() {
@@ -545,16 +833,21 @@ pub fn discover_skills_from_repo(github_url: &GitHubUrl, tap_name: &str) -> Resu
                 } else {
                     skill_path.rsplit('/').next().unwrap_or(skill_path)
                 };
-                skills.insert(
+                (
                     skill_name.to_string(),
                     SkillEntry {
                         path: skill_path.clone(),
                         description: None,
                         homepage: None,
+                        commit: None,
+                        tags: Vec::new(),
+                        aliases: Vec::new(),
                     },
-                );
+                )
             }
-        }
+        };
+
+        insert_discovered_skill(&mut skills, skill_name, entry);
     }
 
     let description = Some(format!("Skills from {}/{}", github_url.owner, github_url.repo));
@@ -562,12 +855,46 @@ pub fn discover_skills_from_repo(github_url: &GitHubUrl, tap_name: &str) -> Resu
     Ok(TapRegistry {
         name: tap_name.to_string(),
         description,
+        schema_version: None,
         skills,
     })
 }
 
+/// Record a discovered skill unless an earlier SKILL.md already declared the same name.
+///
+/// The first occurrence is kept and a warning names both paths, as local discovery does.
+/// Returns whether `entry` was inserted.
+fn insert_discovered_skill(skills: &mut HashMap<String, SkillEntry>, name: String, entry: SkillEntry) -> bool {
+    if let Some(first) = skills.get(&name) {
+        eprintln!(
+            "  {} Duplicate skill name '{}' at {} (already used by {}), keeping first occurrence",
+            "!".yellow(),
+            name,
+            display_skill_path(&entry.path),
+            display_skill_path(&first.path)
+        );
+        return false;
+    }
+    skills.insert(name, entry);
+    true
+}
+
+/// A skill's path within its repository for messages, with `.` for the repo root
+fn display_skill_path(path: &str) -> &str {
+    if path.is_empty() {
+        "."
+    } else {
+        path
+    }
+}
+
 /// Parse SKILL.md content to extract name and description from YAML frontmatter
 pub(crate) fn parse_skill_md_content(content: &str) -> Option<(String, Option<String>)> {
+    parse_skill_md_frontmatter(content).map(|metadata| (metadata.name, metadata.description))
+}
+
+/// Parse the full YAML frontmatter of SKILL.md content
+pub(crate) fn parse_skill_md_frontmatter(content: &str) -> Option<SkillMetadata> {
     // Extract YAML frontmatter between --- markers
     let parts: Vec<&str> = content.splitn(3, "---").collect();
     if parts.len() < 3 {
@@ -575,9 +902,7 @@ pub(crate) fn parse_skill_md_content(content: &str) -> Option<(String, Option<St
     }
 
     let yaml_content = parts[1].trim();
-    let metadata: SkillMetadata = serde_yaml::from_str(yaml_content).ok()?;
-
-    Some((metadata.name, metadata.description))
+    serde_yaml::from_str(yaml_content).ok()
 }
 
 /// Extract skill directory paths from a list of tree entries.
@@ -639,7 +964,8 @@ pub fn fetch_gist(gist_id: &str) -> Result<GistResponse> {
     let api_base = std::env::var("SKILLSHUB_GITHUB_API_BASE").unwrap_or_else(|_| "https://api.github.com".to_string());
     let url = format!("{}/gists/{}", api_base, gist_id);
 
-    let response = send_with_retry(|| with_auth(client.get(&url)), &url)?;
+    let token = github_token()?;
+    let response = send_with_retry(|| with_auth(client.get(&url), token.as_deref()), &url)?;
 
     let status = response.status();
     if !status.is_success() {
@@ -661,11 +987,10 @@ pub fn fetch_gist(gist_id: &str) -> Result<GistResponse> {
 
 /// Check whether a skill name is safe to use in filesystem paths.
 ///
-/// Rejects names containing path traversal sequences (`..`, `/`, `\`) or
-/// names that are empty / consist only of dots, which could escape the
-/// intended install directory.
+/// See [`validate_skill_name`] for the rules; this is the boolean form used
+/// when discovery should skip a bad name rather than fail.
 pub(crate) fn is_safe_skill_name(name: &str) -> bool {
-    !name.is_empty() && !name.contains('/') && !name.contains('\\') && !name.contains("..") && name != "."
+    validate_skill_name(name).is_ok()
 }
 
 /// Discover skills from a fetched gist.
@@ -748,10 +1073,11 @@ pub fn parse_star_list_url(url: &str) -> Result<(String, String)> {
 ///
 /// Returns a list of "owner/repo" identifiers.
 pub fn fetch_star_list_repos(username: &str, list_name: &str) -> Result<Vec<String>> {
-    let token = github_token().context(
+    let token = github_token()?.context(
         "GH_TOKEN or GITHUB_TOKEN is required for star list operations.\n\
          The GraphQL API does not support unauthenticated requests.\n\
-         Set GH_TOKEN (preferred) or GITHUB_TOKEN with a personal access token.",
+         Set GH_TOKEN (preferred) or GITHUB_TOKEN with a personal access token,\n\
+         or point GITHUB_TOKEN_FILE at a file containing one.",
     )?;
     let client = build_client()?;
     let gql_url = graphql_url();
@@ -907,7 +1233,7 @@ mod tests {
     fn test_github_token_prefers_gh_token() {
         std::env::set_var("GH_TOKEN", "gh-value");
         std::env::set_var("GITHUB_TOKEN", "github-value");
-        let token = github_token();
+        let token = github_token().unwrap();
         std::env::remove_var("GH_TOKEN");
         std::env::remove_var("GITHUB_TOKEN");
         assert_eq!(token.as_deref(), Some("gh-value"));
@@ -918,7 +1244,7 @@ mod tests {
     fn test_github_token_falls_back_to_github_token() {
         std::env::remove_var("GH_TOKEN");
         std::env::set_var("GITHUB_TOKEN", "github-value");
-        let token = github_token();
+        let token = github_token().unwrap();
         std::env::remove_var("GITHUB_TOKEN");
         assert_eq!(token.as_deref(), Some("github-value"));
     }
@@ -928,7 +1254,7 @@ mod tests {
     fn test_github_token_none_when_unset() {
         std::env::remove_var("GH_TOKEN");
         std::env::remove_var("GITHUB_TOKEN");
-        assert!(github_token().is_none());
+        assert!(github_token().unwrap().is_none());
     }
 
     #[test]
@@ -936,7 +1262,7 @@ mod tests {
     fn test_github_token_treats_empty_as_unset() {
         std::env::set_var("GH_TOKEN", "");
         std::env::set_var("GITHUB_TOKEN", "github-value");
-        let token = github_token();
+        let token = github_token().unwrap();
         std::env::remove_var("GH_TOKEN");
         std::env::remove_var("GITHUB_TOKEN");
         assert_eq!(token.as_deref(), Some("github-value"));
@@ -947,11 +1273,207 @@ mod tests {
     fn test_github_token_treats_empty_github_token_as_unset() {
         std::env::remove_var("GH_TOKEN");
         std::env::set_var("GITHUB_TOKEN", "");
-        let token = github_token();
+        let token = github_token().unwrap();
         std::env::remove_var("GITHUB_TOKEN");
         assert!(token.is_none());
     }
 
+    #[test]
+    #[serial]
+    fn test_github_token_reads_token_file() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let token_file = temp.path().join("token");
+        std::fs::write(&token_file, "  file-value\n").unwrap();
+
+        std::env::remove_var("GH_TOKEN");
+        std::env::remove_var("GITHUB_TOKEN");
+        std::env::set_var(GITHUB_TOKEN_FILE_ENV, &token_file);
+        let from_file = github_token().unwrap();
+        std::env::set_var("GITHUB_TOKEN", "env-value");
+        let env_wins = github_token().unwrap();
+        std::env::remove_var("GITHUB_TOKEN");
+        std::env::set_var(GITHUB_TOKEN_FILE_ENV, temp.path().join("missing"));
+        let unreadable = github_token();
+        std::env::remove_var(GITHUB_TOKEN_FILE_ENV);
+
+        assert_eq!(from_file.as_deref(), Some("file-value"));
+        assert_eq!(env_wins.as_deref(), Some("env-value"));
+        let err = unreadable.unwrap_err().to_string();
+        assert!(err.contains(GITHUB_TOKEN_FILE_ENV), "{}", err);
+    }
+
+    /// The token from `GITHUB_TOKEN_FILE` is sent as a bearer token
+    #[test]
+    #[serial]
+    fn test_token_file_is_sent_as_bearer_token() {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let server = rt.block_on(wiremock::MockServer::start());
+        rt.block_on(async {
+            wiremock::Mock::given(wiremock::matchers::method("GET"))
+                .and(wiremock::matchers::path("/repos/acme/tools/releases/latest"))
+                .and(wiremock::matchers::header("authorization", "Bearer file-token"))
+                .respond_with(wiremock::ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                    "tag_name": "v1.0.0",
+                    "html_url": "https://github.com/acme/tools/releases/tag/v1.0.0"
+                })))
+                .mount(&server)
+                .await;
+        });
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let token_file = temp.path().join("token");
+        std::fs::write(&token_file, "file-token\n").unwrap();
+        std::env::remove_var("GH_TOKEN");
+        std::env::remove_var("GITHUB_TOKEN");
+        std::env::set_var(GITHUB_TOKEN_FILE_ENV, &token_file);
+        std::env::set_var("SKILLSHUB_GITHUB_API_BASE", server.uri());
+
+        let result = fetch_latest_release("acme", "tools");
+
+        std::env::remove_var(GITHUB_TOKEN_FILE_ENV);
+        std::env::remove_var("SKILLSHUB_GITHUB_API_BASE");
+
+        let release = result.expect("request with the file token should match the mock");
+        assert_eq!(release.tag_name, "v1.0.0");
+    }
+
+    #[test]
+    #[serial]
+    fn test_fetch_rate_limit_reads_headers() {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let server = rt.block_on(wiremock::MockServer::start());
+        rt.block_on(async {
+            wiremock::Mock::given(wiremock::matchers::method("GET"))
+                .and(wiremock::matchers::path("/rate_limit"))
+                .respond_with(
+                    wiremock::ResponseTemplate::new(200)
+                        .insert_header("X-RateLimit-Limit", "60")
+                        .insert_header("X-RateLimit-Remaining", "12")
+                        .insert_header("X-RateLimit-Reset", "1700000000")
+                        .set_body_json(serde_json::json!({ "resources": {} })),
+                )
+                .mount(&server)
+                .await;
+        });
+
+        std::env::remove_var("GH_TOKEN");
+        std::env::remove_var("GITHUB_TOKEN");
+        std::env::remove_var(GITHUB_TOKEN_FILE_ENV);
+        std::env::set_var("SKILLSHUB_GITHUB_API_BASE", server.uri());
+
+        let result = fetch_rate_limit();
+
+        std::env::remove_var("SKILLSHUB_GITHUB_API_BASE");
+
+        let status = result.unwrap();
+        assert_eq!(status.limit, Some(60));
+        assert_eq!(status.remaining, Some(12));
+        assert_eq!(status.reset, Some(1_700_000_000));
+        assert!(!status.authenticated);
+    }
+
+    /// Requests go through the proxy named by `HTTP_PROXY`, and a malformed proxy URL is
+    /// reported instead of panicking
+    #[test]
+    #[serial]
+    fn test_build_client_uses_proxy_from_env() {
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let proxy = rt.block_on(wiremock::MockServer::start());
+        rt.block_on(async {
+            wiremock::Mock::given(wiremock::matchers::method("GET"))
+                .and(wiremock::matchers::path("/rate_limit"))
+                .respond_with(
+                    wiremock::ResponseTemplate::new(200)
+                        .insert_header("X-RateLimit-Remaining", "7")
+                        .set_body_json(serde_json::json!({ "resources": {} })),
+                )
+                .mount(&proxy)
+                .await;
+        });
+
+        std::env::remove_var("GH_TOKEN");
+        std::env::remove_var("GITHUB_TOKEN");
+        std::env::remove_var(GITHUB_TOKEN_FILE_ENV);
+        for var in [
+            "NO_PROXY",
+            "no_proxy",
+            "HTTPS_PROXY",
+            "https_proxy",
+            "ALL_PROXY",
+            "all_proxy",
+        ] {
+            std::env::remove_var(var);
+        }
+        // An unresolvable host only answers through the proxy
+        std::env::set_var("SKILLSHUB_GITHUB_API_BASE", "http://api.github.invalid");
+        std::env::set_var("HTTP_PROXY", proxy.uri());
+
+        let result = fetch_rate_limit();
+        std::env::set_var("HTTP_PROXY", "http://[not a proxy");
+        let invalid = build_client();
+
+        std::env::remove_var("HTTP_PROXY");
+        std::env::remove_var("SKILLSHUB_GITHUB_API_BASE");
+
+        assert_eq!(result.unwrap().remaining, Some(7));
+        let err = invalid.unwrap_err();
+        assert!(err.to_string().contains("HTTP_PROXY"), "{}", err);
+    }
+
+    #[test]
+    #[serial]
+    fn test_tap_token_prefers_tap_env_and_falls_back() {
+        std::env::remove_var("GH_TOKEN");
+        std::env::set_var("GITHUB_TOKEN", "global-value");
+        std::env::set_var("SKILLSHUB_TEST_ORG_TOKEN", "org-value");
+        let own = tap_token(Some("SKILLSHUB_TEST_ORG_TOKEN")).unwrap();
+        let unset_var = tap_token(Some("SKILLSHUB_TEST_MISSING_TOKEN")).unwrap();
+        let no_var = tap_token(None).unwrap();
+        std::env::remove_var("GITHUB_TOKEN");
+        std::env::remove_var("SKILLSHUB_TEST_ORG_TOKEN");
+        assert_eq!(own.as_deref(), Some("org-value"));
+        assert_eq!(unset_var.as_deref(), Some("global-value"));
+        assert_eq!(no_var.as_deref(), Some("global-value"));
+    }
+
+    /// A second SKILL.md declaring an already-seen name is dropped instead of
+    /// replacing the first.
+    #[test]
+    fn test_insert_discovered_skill_keeps_first_duplicate() {
+        let entry = |path: &str| SkillEntry {
+            path: path.to_string(),
+            description: None,
+            homepage: None,
+            commit: None,
+            tags: Vec::new(),
+            aliases: Vec::new(),
+        };
+        let mut skills = HashMap::new();
+        assert!(insert_discovered_skill(
+            &mut skills,
+            "dup".to_string(),
+            entry("skills/a")
+        ));
+        assert!(!insert_discovered_skill(
+            &mut skills,
+            "dup".to_string(),
+            entry("skills/b")
+        ));
+        assert!(insert_discovered_skill(&mut skills, "other".to_string(), entry("")));
+        assert_eq!(skills.len(), 2);
+        assert_eq!(skills["dup"].path, "skills/a");
+        assert_eq!(display_skill_path(&skills["other"].path), ".");
+    }
+
     #[test]
     fn test_parse_skill_md_content() {
         let content = r#"---
@@ -968,6 +1490,17 @@ Some content here.
         assert_eq!(desc, Some("A test skill".to_string()));
     }
 
+    #[test]
+    fn test_parse_skill_md_frontmatter_tags() {
+        let content = "---\nname: tagged\ntags: [testing, rust]\n---\n# Tagged\n";
+        let metadata = parse_skill_md_frontmatter(content).unwrap();
+        assert_eq!(metadata.name, "tagged");
+        assert_eq!(metadata.tags, vec!["testing", "rust"]);
+
+        let untagged = parse_skill_md_frontmatter("---\nname: plain\n---\n").unwrap();
+        assert!(untagged.tags.is_empty());
+    }
+
     #[test]
     fn test_parse_skill_md_content_no_description() {
         let content = r#"---
@@ -1016,6 +1549,23 @@ name: minimal-skill
         assert_eq!(url.path, Some("path/to/folder".to_string()));
     }
 
+    #[test]
+    fn test_parse_github_url_plain_directory_path() {
+        let url = parse_github_url("https://github.com/owner/repo/skills/foo").unwrap();
+        assert_eq!(url.tap_name(), "owner/repo");
+        assert!(url.branch.is_none(), "plain paths use the default branch");
+        assert_eq!(url.path.as_deref(), Some("skills/foo"));
+        assert_eq!(url.skill_name().as_deref(), Some("foo"));
+    }
+
+    #[test]
+    fn test_parse_github_url_blob_skill_md() {
+        let url = parse_github_url("https://github.com/owner/repo/blob/main/skills/foo/SKILL.md").unwrap();
+        assert_eq!(url.branch.as_deref(), Some("main"));
+        assert_eq!(url.path.as_deref(), Some("skills/foo"));
+        assert_eq!(url.skill_name().as_deref(), Some("foo"));
+    }
+
     #[test]
     fn test_parse_github_url_with_master_branch() {
         // Explicitly specifying master branch should work
@@ -1049,6 +1599,22 @@ name: minimal-skill
         assert!(parse_github_url("not-a-url").is_err());
     }
 
+    #[test]
+    fn test_parse_github_url_rejects_repo_routes() {
+        for url in [
+            "https://github.com/owner/repo/issues/12",
+            "https://github.com/owner/repo/pulls",
+            "https://github.com/owner/repo/pull/3/files",
+            "https://github.com/owner/repo/commit/abc1234",
+        ] {
+            let err = parse_github_url(url).unwrap_err();
+            assert!(err.to_string().contains("not a skill folder"), "{}: {}", url, err);
+        }
+        // Folder URLs with the same names under tree/ are still fine
+        let url = parse_github_url("https://github.com/owner/repo/tree/main/issues").unwrap();
+        assert_eq!(url.path.as_deref(), Some("issues"));
+    }
+
     #[test]
     fn test_parse_github_url_repo_id_simple() {
         let url = parse_github_url("owner/repo").unwrap();
@@ -1175,9 +1741,9 @@ name: minimal-skill
     #[test]
     fn test_backoff_duration_exponential() {
         // With INITIAL_BACKOFF_MS=10 in test mode, backoff should grow exponentially
-        let d1 = backoff_duration(1);
-        let d2 = backoff_duration(2);
-        let d3 = backoff_duration(3);
+        let d1 = backoff_duration(1, INITIAL_BACKOFF_MS);
+        let d2 = backoff_duration(2, INITIAL_BACKOFF_MS);
+        let d3 = backoff_duration(3, INITIAL_BACKOFF_MS);
 
         // attempt 1: base=10ms, attempt 2: base=20ms, attempt 3: base=40ms
         // Plus jitter (0-499ms), so just check ordering and reasonable bounds
@@ -1201,7 +1767,7 @@ name: minimal-skill
     #[test]
     fn test_backoff_capped_at_max() {
         // Very high attempt number should still be capped at MAX_BACKOFF_MS
-        let d = backoff_duration(30);
+        let d = backoff_duration(30, INITIAL_BACKOFF_MS);
         assert!(
             d.as_millis() <= MAX_BACKOFF_MS as u128,
             "backoff should be capped at {}ms, got {}ms",
@@ -1233,6 +1799,16 @@ name: minimal-skill
         test(url)
     }
 
+    #[test]
+    fn test_offline_enabled_values() {
+        assert!(offline_enabled(Some("1")));
+        assert!(offline_enabled(Some("true")));
+        assert!(offline_enabled(Some("TRUE ")));
+        assert!(!offline_enabled(Some("0")));
+        assert!(!offline_enabled(Some("")));
+        assert!(!offline_enabled(None));
+    }
+
     #[test]
     fn test_send_with_retry_success() {
         with_mock_server(
@@ -1460,6 +2036,7 @@ name: minimal-skill
     }
 
     #[test]
+    #[serial_test::serial]
     fn test_gives_up_after_max_retries() {
         let rt = tokio::runtime::Builder::new_current_thread()
             .enable_all()
@@ -1489,6 +2066,57 @@ name: minimal-skill
         );
     }
 
+    #[test]
+    fn test_parse_clamped() {
+        assert_eq!(parse_clamped(Some("3"), 1, 20), Some(3));
+        assert_eq!(parse_clamped(Some(" 0 "), 1, 20), Some(1));
+        assert_eq!(parse_clamped(Some("999"), 1, 20), Some(20));
+        assert_eq!(parse_clamped(Some("-1"), 1, 20), None);
+        assert_eq!(parse_clamped(Some("many"), 1, 20), None);
+        assert_eq!(parse_clamped(None, 1, 20), None);
+    }
+
+    /// A low `SKILLSHUB_MAX_RETRIES` gives up after that many attempts
+    #[test]
+    #[serial_test::serial]
+    fn test_max_retries_env_gives_up_earlier() {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        let rt = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let server = rt.block_on(wiremock::MockServer::start());
+
+        rt.block_on(async {
+            wiremock::Mock::given(wiremock::matchers::method("GET"))
+                .and(wiremock::matchers::path("/test"))
+                .respond_with(wiremock::ResponseTemplate::new(500))
+                .mount(&server)
+                .await;
+        });
+
+        let url = format!("{}/test", server.uri());
+        let client = build_client().unwrap();
+        let calls = AtomicU32::new(0);
+
+        std::env::set_var(MAX_RETRIES_ENV, "2");
+        std::env::set_var(BACKOFF_MS_ENV, "0");
+        let result = send_with_retry(
+            || {
+                calls.fetch_add(1, Ordering::SeqCst);
+                client.get(&url)
+            },
+            &url,
+        );
+        std::env::remove_var(MAX_RETRIES_ENV);
+        std::env::remove_var(BACKOFF_MS_ENV);
+
+        let err_msg = result.unwrap_err().to_string();
+        assert!(err_msg.contains("after 2 retries"), "unexpected error: {}", err_msg);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
     // --- Star list URL parsing tests ---
 
     #[test]
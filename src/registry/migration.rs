@@ -1,12 +1,16 @@
 use anyhow::Result;
 use chrono::Utc;
 use colored::Colorize;
+use std::collections::BTreeMap;
 use std::fs;
+use std::path::Path;
 
 use super::db::{self, DEFAULT_TAP_NAME};
-use super::models::InstalledSkill;
+use super::models::{InstallLayout, InstalledSkill};
+use crate::commands::relink;
 use crate::paths::get_skills_install_dir;
 use crate::skill::discover_skills;
+use crate::util::dir_size;
 
 /// Migrate old-style installations to the new registry format
 ///
@@ -24,6 +28,12 @@ pub fn migrate_old_installations() -> Result<()> {
         return Ok(());
     }
 
+    // Skills directly in skills/ are expected when the user opted into the flat layout
+    if db::load_db()?.layout == InstallLayout::Flat {
+        println!("{} Skills use the flat layout; nothing to migrate", "Info:".cyan());
+        return Ok(());
+    }
+
     // Discover skills directly in the install directory (old format)
     let old_skills = discover_skills(&install_dir)?;
 
@@ -78,6 +88,10 @@ pub fn migrate_old_installations() -> Result<()> {
                 source_url: None,
                 source_path: None,
                 gist_updated_at: None,
+                content_sha256: None,
+                size_bytes: Some(dir_size(&new_path)),
+                alias: None,
+                branch: None,
             };
             db::add_installed_skill(&mut db, &full_name, installed);
         }
@@ -90,6 +104,118 @@ pub fn migrate_old_installations() -> Result<()> {
     Ok(())
 }
 
+/// Move every installed skill to `layout` and record it in the database
+///
+/// Nested: ~/.skillshub/skills/<tap-name>/<skill-name>/
+/// Flat:   ~/.skillshub/skills/<skill-name>/
+///
+/// Skills are moved through a staging directory so a flat skill may take the
+/// name of a tap owner directory it is leaving. Agent links are repaired afterwards.
+pub fn migrate_layout(layout: InstallLayout) -> Result<()> {
+    let mut db = db::init_db()?;
+    let install_dir = get_skills_install_dir()?;
+
+    if db.layout == layout {
+        println!("{} Skills already use the {} layout", "Info:".cyan(), layout);
+        return Ok(());
+    }
+
+    if layout == InstallLayout::Flat {
+        let mut taps_by_skill: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for installed in db.installed.values() {
+            taps_by_skill.entry(&installed.skill).or_default().push(&installed.tap);
+        }
+        let clashes: Vec<String> = taps_by_skill
+            .into_iter()
+            .filter(|(_, taps)| taps.len() > 1)
+            .map(|(skill, mut taps)| {
+                taps.sort_unstable();
+                format!("{} ({})", skill, taps.join(", "))
+            })
+            .collect();
+        if !clashes.is_empty() {
+            anyhow::bail!(
+                "The flat layout needs unique skill names, but these are installed from several taps: {}. \
+                 Uninstall one copy of each first.",
+                clashes.join("; ")
+            );
+        }
+    }
+
+    println!("{} Switching skills to the {} layout", "=>".green().bold(), layout);
+
+    let mut names: Vec<String> = db.installed.keys().cloned().collect();
+    names.sort();
+
+    // Stage every skill first so old and new paths never overlap
+    let staging = install_dir.join(".layout-migration");
+    let mut staged = Vec::new();
+    for name in names {
+        let installed = &db.installed[&name];
+        let from = db.skill_dir(&install_dir, &installed.tap, &installed.skill);
+        if !from.is_dir() {
+            continue;
+        }
+        let to = layout.skill_dir(&install_dir, &installed.tap, &installed.skill);
+        let stage = staging.join(&installed.tap).join(&installed.skill);
+        fs::create_dir_all(stage.parent().unwrap_or(&staging))?;
+        fs::rename(&from, &stage)?;
+        staged.push((name, stage, to));
+    }
+    prune_empty_dirs(&install_dir, &staging);
+
+    for (name, stage, to) in &staged {
+        if to.exists() {
+            anyhow::bail!(
+                "Cannot move '{}': {} already exists (the skill was left in {})",
+                name,
+                to.display(),
+                stage.display()
+            );
+        }
+        fs::create_dir_all(to.parent().unwrap_or(&install_dir))?;
+        fs::rename(stage, to)?;
+        println!(
+            "  {} {} -> {}",
+            "✓".green(),
+            name,
+            to.strip_prefix(&install_dir).unwrap_or(to).display()
+        );
+    }
+    let _ = fs::remove_dir_all(&staging);
+
+    db.layout = layout;
+    db::save_db(&db)?;
+
+    relink()?;
+
+    println!(
+        "{} Moved {} skill(s) to the {} layout",
+        "Done!".green().bold(),
+        staged.len(),
+        layout
+    );
+
+    Ok(())
+}
+
+/// Remove directories under `dir` (but not `dir` itself or `skip`) left empty by a move
+fn prune_empty_dirs(dir: &Path, skip: &Path) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path == skip || !entry.file_type().map(|t| t.is_dir()).unwrap_or(false) {
+            continue;
+        }
+        prune_empty_dirs(&path, skip);
+        if path.read_dir().map(|mut e| e.next().is_none()).unwrap_or(false) {
+            let _ = fs::remove_dir(&path);
+        }
+    }
+}
+
 /// Check if a directory is a tap directory (contains skill subdirectories)
 fn is_tap_directory(path: &std::path::Path) -> bool {
     if let Ok(entries) = fs::read_dir(path) {
@@ -110,7 +236,7 @@ fn is_tap_directory(path: &std::path::Path) -> bool {
 pub fn needs_migration() -> Result<bool> {
     let install_dir = get_skills_install_dir()?;
 
-    if !install_dir.exists() {
+    if !install_dir.exists() || db::load_db()?.layout == InstallLayout::Flat {
         return Ok(false);
     }
 
@@ -133,9 +259,108 @@ pub fn needs_migration() -> Result<bool> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use serial_test::serial;
     use std::fs;
     use tempfile::TempDir;
 
+    /// RAII guard that restores `SKILLSHUB_TEST_HOME` on drop, even if a test panics.
+    struct TestHomeGuard(Option<String>);
+
+    impl TestHomeGuard {
+        fn set(home: &std::path::Path) -> Self {
+            let prev = std::env::var("SKILLSHUB_TEST_HOME").ok();
+            std::env::set_var("SKILLSHUB_TEST_HOME", home);
+            Self(prev)
+        }
+    }
+
+    impl Drop for TestHomeGuard {
+        fn drop(&mut self) {
+            match self.0.take() {
+                Some(v) => std::env::set_var("SKILLSHUB_TEST_HOME", v),
+                None => std::env::remove_var("SKILLSHUB_TEST_HOME"),
+            }
+        }
+    }
+
+    fn installed(tap: &str, skill: &str) -> InstalledSkill {
+        InstalledSkill {
+            tap: tap.to_string(),
+            skill: skill.to_string(),
+            commit: None,
+            installed_at: Utc::now(),
+            source_url: None,
+            source_path: None,
+            gist_updated_at: None,
+            content_sha256: None,
+            size_bytes: None,
+            alias: None,
+            branch: None,
+        }
+    }
+
+    /// Switching to flat and back moves the skill directories, keeps agent links
+    /// working, and flat skills are not mistaken for old-style installs.
+    #[test]
+    #[serial]
+    #[cfg(unix)]
+    fn test_migrate_layout_round_trip() {
+        let temp = TempDir::new().unwrap();
+        let home = temp.path();
+        let skills_dir = home.join(".skillshub/skills");
+        // A skill named like its tap owner must not collide with the owner directory
+        for (tap, skill) in [("owner/repo", "owner"), ("acme/tools", "linter")] {
+            let dir = skills_dir.join(tap).join(skill);
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(
+                dir.join("SKILL.md"),
+                format!("---\nname: {}\ndescription: Test\n---\n", skill),
+            )
+            .unwrap();
+        }
+        let mut db = crate::registry::models::Database::default();
+        db::add_installed_skill(&mut db, "owner/repo/owner", installed("owner/repo", "owner"));
+        db::add_installed_skill(&mut db, "acme/tools/linter", installed("acme/tools", "linter"));
+        fs::create_dir_all(home.join(".claude")).unwrap();
+
+        let _guard = TestHomeGuard::set(home);
+        db::save_db(&db).unwrap();
+
+        migrate_layout(InstallLayout::Flat).unwrap();
+        assert!(skills_dir.join("owner/SKILL.md").is_file());
+        assert!(skills_dir.join("linter/SKILL.md").is_file());
+        assert!(
+            !skills_dir.join("acme").exists(),
+            "emptied tap directories should be removed"
+        );
+        assert_eq!(db::load_db().unwrap().layout, InstallLayout::Flat);
+        assert!(!needs_migration().unwrap(), "flat skills are not old-style installs");
+        let link = home.join(".claude/skills/linter");
+        assert_eq!(fs::read_link(&link).unwrap(), skills_dir.join("linter"));
+
+        migrate_layout(InstallLayout::Nested).unwrap();
+        assert!(skills_dir.join("owner/repo/owner/SKILL.md").is_file());
+        assert!(skills_dir.join("acme/tools/linter/SKILL.md").is_file());
+        assert_eq!(db::load_db().unwrap().layout, InstallLayout::Nested);
+        assert_eq!(fs::read_link(&link).unwrap(), skills_dir.join("acme/tools/linter"));
+    }
+
+    #[test]
+    #[serial]
+    fn test_migrate_to_flat_rejects_duplicate_names() {
+        let temp = TempDir::new().unwrap();
+        let mut db = crate::registry::models::Database::default();
+        db::add_installed_skill(&mut db, "a/one/review", installed("a/one", "review"));
+        db::add_installed_skill(&mut db, "b/two/review", installed("b/two", "review"));
+
+        let _guard = TestHomeGuard::set(temp.path());
+        db::save_db(&db).unwrap();
+
+        let err = migrate_layout(InstallLayout::Flat).unwrap_err().to_string();
+        assert!(err.contains("review (a/one, b/two)"), "{}", err);
+        assert_eq!(db::load_db().unwrap().layout, InstallLayout::Nested);
+    }
+
     #[test]
     fn test_is_tap_directory_empty() {
         let dir = TempDir::new().unwrap();
@@ -6,9 +6,14 @@ pub mod models;
 pub mod skill;
 pub mod tap;
 
-pub use migration::{migrate_old_installations, needs_migration};
+pub use migration::{migrate_layout, migrate_old_installations, needs_migration};
 pub use skill::{
-    add_skill_from_url, install_all, install_all_from_tap, install_skill, list_skills, search_skills, show_skill_info,
-    uninstall_skill, update_skill,
+    add_skill_from_archive, add_skill_from_url, alias_skill, diff_skill, install_all, install_all_from_tap,
+    install_skill_from_tap, install_skills, install_skills_from_file, list_skills, open_skill_page, reinstall_skill,
+    search_skills, show_skill_info, show_skill_tree, uninstall_all_from_tap, uninstall_skill, update_skill,
+    which_skill, UninstallOptions, UpdateLinkMode,
+};
+pub use tap::{
+    add_default_tap, add_tap, add_well_known_tap, import_star_list, list_taps, remove_tap, rename_tap, set_default_tap,
+    show_tap_info, update_tap, verify_tap, TapAddOptions,
 };
-pub use tap::{add_tap, import_star_list, list_taps, remove_tap, update_tap};
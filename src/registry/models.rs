@@ -1,7 +1,7 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// The main database stored at ~/.skillshub/db.json
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -23,6 +23,75 @@ pub struct Database {
     /// This tracks which agents skillshub has set up, regardless of skill count
     #[serde(default)]
     pub linked_agents: HashSet<String>,
+
+    /// Digest of what `link` last put into each agent (agent dir -> digest), so agents
+    /// whose skills and links are unchanged can be skipped
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub link_states: HashMap<String, String>,
+
+    /// Link names given to skills whose plain name clashed with a skill from another tap
+    /// (full name "tap/skill" -> link name, e.g. "debugging@owner")
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub link_names: HashMap<String, String>,
+
+    /// How installed skills are laid out under ~/.skillshub/skills
+    #[serde(default, skip_serializing_if = "InstallLayout::is_nested")]
+    pub layout: InstallLayout,
+
+    /// Tap chosen with `tap set-default` (None = the bundled default tap)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default_tap: Option<String>,
+
+    /// Skills subdirectories set with `agents subdir` (agent dir -> subdirectory,
+    /// e.g. ".kiro" -> "steering"), replacing the built-in ones
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub agent_subdirs: HashMap<String, String>,
+
+    /// Set when a stale registry cache was refreshed in memory, so commands that otherwise
+    /// never write `db.json` know to save it
+    #[serde(skip)]
+    pub registries_refreshed: bool,
+}
+
+impl Database {
+    /// Directory an installed skill lives in under `install_dir`, following `self.layout`
+    pub fn skill_dir(&self, install_dir: &Path, tap: &str, skill: &str) -> PathBuf {
+        self.layout.skill_dir(install_dir, tap, skill)
+    }
+}
+
+/// Directory layout of ~/.skillshub/skills
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum InstallLayout {
+    /// `skills/<tap>/<skill>`; skills from different taps may share a name
+    #[default]
+    Nested,
+    /// `skills/<skill>`; skill names must be unique across taps
+    Flat,
+}
+
+impl InstallLayout {
+    fn is_nested(&self) -> bool {
+        *self == InstallLayout::Nested
+    }
+
+    /// Directory a skill from `tap` lives in under `install_dir`
+    pub fn skill_dir(self, install_dir: &Path, tap: &str, skill: &str) -> PathBuf {
+        match self {
+            InstallLayout::Nested => install_dir.join(tap).join(skill),
+            InstallLayout::Flat => install_dir.join(skill),
+        }
+    }
+}
+
+impl std::fmt::Display for InstallLayout {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            InstallLayout::Nested => "nested",
+            InstallLayout::Flat => "flat",
+        })
+    }
 }
 
 /// Information about a configured tap
@@ -31,8 +100,11 @@ pub struct TapInfo {
     /// GitHub URL of the tap repository
     pub url: String,
 
-    /// Path to skills directory within the repo (e.g., "skills")
-    pub skills_path: String,
+    /// Folder set with `tap add --skills-path` that skill discovery is limited to
+    /// (None = scan the whole repository for SKILL.md files). Older db.json files stored
+    /// a `skills_path` that always meant the whole repository, so that key is ignored.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custom_skills_path: Option<String>,
 
     /// When the tap registry was last updated
     pub updated_at: Option<DateTime<Utc>>,
@@ -49,8 +121,19 @@ pub struct TapInfo {
     /// Which branch was cloned (None = repo default branch)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub branch: Option<String>,
+
+    /// Environment variable holding this tap's GitHub token (None = GH_TOKEN / GITHUB_TOKEN)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub token_env: Option<String>,
+
+    /// Registry file in the tap repository that lists its skills (None = discover SKILL.md files)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub registry_file: Option<String>,
 }
 
+/// Folder of the bundled skills in the default tap repository
+pub const DEFAULT_SKILLS_PATH: &str = "skills";
+
 /// Information about an installed skill
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InstalledSkill {
@@ -77,6 +160,62 @@ pub struct InstalledSkill {
     /// Gist updated_at timestamp for tracking gist skill freshness (None for non-gist skills)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub gist_updated_at: Option<String>,
+
+    /// SHA-256 of the installed skill files (None for bundled/gist skills or older installs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_sha256: Option<String>,
+
+    /// Total size of the installed skill files in bytes (None for older installs)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_bytes: Option<u64>,
+
+    /// Name to link the skill under in agent directories instead of its own (set by `alias`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+
+    /// Branch the skill was installed from with `--branch`; `update` follows it instead of
+    /// the tap's branch (None = the tap's branch)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub branch: Option<String>,
+}
+
+/// Where an installed skill came from, as shown by `list`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SkillSource {
+    /// Bundled or otherwise copied from the local machine
+    Local,
+    /// Installed from an added tap
+    Remote,
+    /// Added directly from a GitHub or gist URL
+    Url,
+}
+
+impl SkillSource {
+    /// Classify an installed skill; `tap_url` is the URL of its tap, if added
+    pub fn of_installed(installed: &InstalledSkill, tap_url: Option<&str>) -> Self {
+        if installed.gist_updated_at.is_some() {
+            return Self::Url;
+        }
+        if installed.commit.is_none() {
+            return Self::Local;
+        }
+        match installed.source_url.as_deref() {
+            // Tap installs record the tap URL; `add` records the full skill URL
+            Some(url) if Some(url) != tap_url => Self::Url,
+            _ => Self::Remote,
+        }
+    }
+}
+
+impl std::fmt::Display for SkillSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Local => "local",
+            Self::Remote => "remote",
+            Self::Url => "url",
+        };
+        f.write_str(name)
+    }
 }
 
 /// Information about an externally-managed skill (not installed via skillshub)
@@ -97,6 +236,9 @@ pub struct ExternalSkill {
     pub discovered_at: DateTime<Utc>,
 }
 
+/// Newest registry file `schema_version` this build understands
+pub const REGISTRY_SCHEMA_VERSION: u32 = 1;
+
 /// Registry format for remote taps (registry.json in tap repo)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TapRegistry {
@@ -106,6 +248,11 @@ pub struct TapRegistry {
     /// Optional description of the tap
     pub description: Option<String>,
 
+    /// Registry format version the tap was written for; absent means version 1.
+    /// See [`REGISTRY_SCHEMA_VERSION`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_version: Option<u32>,
+
     /// Skills provided by this tap (skill name -> entry)
     #[serde(default)]
     pub skills: HashMap<String, SkillEntry>,
@@ -122,6 +269,18 @@ pub struct SkillEntry {
 
     /// Optional homepage URL
     pub homepage: Option<String>,
+
+    /// Tap clone HEAD (short SHA) when this registry was last refreshed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+
+    /// Tags from the skill's SKILL.md frontmatter
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
+    /// Alternative names that resolve to this skill on install
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
 }
 
 /// Parsed GitHub URL components
@@ -153,10 +312,7 @@ impl GitHubUrl {
 
     /// Check if the branch looks like a commit SHA (40 hex chars or 7+ hex prefix)
     pub fn is_commit_sha(&self) -> bool {
-        self.branch
-            .as_ref()
-            .map(|b| b.len() >= 7 && b.chars().all(|c| c.is_ascii_hexdigit()))
-            .unwrap_or(false)
+        self.branch.as_deref().map(is_commit_sha).unwrap_or(false)
     }
 
     /// Get the skill name from the path (last component)
@@ -177,6 +333,16 @@ impl GitHubUrl {
         format!("https://github.com/{}/{}", self.owner, self.repo)
     }
 
+    /// Get the browsable URL of `path` at `git_ref` (a commit, tag, or branch)
+    pub fn tree_url(&self, git_ref: &str, path: &str) -> String {
+        let path = path.trim_matches('/');
+        if path.is_empty() || path == "." {
+            format!("{}/tree/{}", self.base_url(), git_ref)
+        } else {
+            format!("{}/tree/{}/{}", self.base_url(), git_ref, path)
+        }
+    }
+
     /// Get the API URL for the repository
     pub fn api_url(&self) -> String {
         format!("{}/repos/{}/{}", Self::github_api_base(), self.owner, self.repo)
@@ -195,6 +361,23 @@ impl GitHubUrl {
     }
 }
 
+/// Check if a git ref looks like a commit SHA (40 hex chars or 7+ hex prefix).
+/// Anything else after `@` (e.g. `v1.2.0`) is treated as a tag. Branches and tags can
+/// look like SHAs too, so refs given by the user are also checked against the remote.
+pub fn is_commit_sha(git_ref: &str) -> bool {
+    git_ref.len() >= 7 && git_ref.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// Whether two recorded commits name the same commit. Full SHAs are stored now, but older
+/// db.json entries and registries hold 7-char ones, so a short SHA matches its full form.
+pub fn same_commit(a: &str, b: &str) -> bool {
+    if !is_commit_sha(a) || !is_commit_sha(b) {
+        return a == b;
+    }
+    let len = a.len().min(b.len());
+    a[..len].eq_ignore_ascii_case(&b[..len])
+}
+
 /// Full skill identifier (tap_name/skill_name)
 #[derive(Debug, Clone)]
 pub struct SkillId {
@@ -228,7 +411,8 @@ impl SkillId {
         }
     }
 
-    /// Parse commit from skill ID (e.g., "owner/repo/skill@abc123" -> Some("abc123"))
+    /// Parse the `@ref` suffix from a skill ID: a commit SHA or a tag
+    /// (e.g., "owner/repo/skill@abc123" -> Some("abc123"), "owner/repo/skill@v1.2.0" -> Some("v1.2.0"))
     pub fn parse_commit(s: &str) -> Option<String> {
         s.split('@').nth(1).map(|s| s.to_string())
     }
@@ -281,6 +465,30 @@ mod tests {
         assert_eq!(commit, Some("abc123".to_string()));
     }
 
+    #[test]
+    fn test_skill_id_parse_tag_ref() {
+        let id = SkillId::parse("owner/repo/skill@v1.2.0").unwrap();
+        assert_eq!(id.full_name(), "owner/repo/skill");
+
+        let git_ref = SkillId::parse_commit("owner/repo/skill@v1.2.0").unwrap();
+        assert_eq!(git_ref, "v1.2.0");
+        assert!(!is_commit_sha(&git_ref));
+        assert!(!is_commit_sha("release"));
+        assert!(is_commit_sha("abc1234"));
+        assert!(is_commit_sha("0123456789abcdef0123456789abcdef01234567"));
+    }
+
+    #[test]
+    fn test_same_commit_accepts_short_shas() {
+        let full = "0123456789abcdef0123456789abcdef01234567";
+        assert!(same_commit(full, full));
+        assert!(same_commit("0123456", full));
+        assert!(same_commit(full, "0123456"));
+        assert!(!same_commit("0123457", full));
+        assert!(!same_commit("v1.0.0", "v1.0.1"));
+        assert!(same_commit("v1.0.0", "v1.0.0"));
+    }
+
     #[test]
     fn test_skill_id_parse_invalid() {
         assert!(SkillId::parse("no-slash").is_none());
@@ -343,11 +551,13 @@ mod tests {
     fn test_tap_info_serialize() {
         let tap = TapInfo {
             url: "https://github.com/user/repo".to_string(),
-            skills_path: "skills".to_string(),
+            custom_skills_path: None,
             updated_at: None,
             is_default: false,
             cached_registry: None,
             branch: None,
+            token_env: None,
+            registry_file: None,
         };
 
         let json = serde_json::to_string(&tap).unwrap();
@@ -365,22 +575,28 @@ mod tests {
                 path: "skills/my-skill".to_string(),
                 description: Some("A test skill".to_string()),
                 homepage: None,
+                commit: None,
+                tags: Vec::new(),
+                aliases: Vec::new(),
             },
         );
 
         let registry = TapRegistry {
             name: "test-tap".to_string(),
             description: Some("Test tap".to_string()),
+            schema_version: None,
             skills,
         };
 
         let tap = TapInfo {
             url: "https://github.com/user/repo".to_string(),
-            skills_path: "skills".to_string(),
+            custom_skills_path: None,
             updated_at: None,
             is_default: false,
             cached_registry: Some(registry),
             branch: None,
+            token_env: None,
+            registry_file: None,
         };
 
         let json = serde_json::to_string(&tap).unwrap();
@@ -412,6 +628,9 @@ mod tests {
                 path: "skills/skill1".to_string(),
                 description: Some("First skill".to_string()),
                 homepage: Some("https://example.com".to_string()),
+                commit: None,
+                tags: Vec::new(),
+                aliases: Vec::new(),
             },
         );
         skills.insert(
@@ -420,22 +639,28 @@ mod tests {
                 path: "other/skill2".to_string(),
                 description: None,
                 homepage: None,
+                commit: None,
+                tags: Vec::new(),
+                aliases: Vec::new(),
             },
         );
 
         let registry = TapRegistry {
             name: "my-tap".to_string(),
             description: None,
+            schema_version: None,
             skills,
         };
 
         let tap = TapInfo {
             url: "https://github.com/owner/repo".to_string(),
-            skills_path: "skills".to_string(),
+            custom_skills_path: None,
             updated_at: Some(chrono::Utc::now()),
             is_default: false,
             cached_registry: Some(registry),
             branch: None,
+            token_env: None,
+            registry_file: None,
         };
 
         // Serialize and deserialize
@@ -451,6 +676,26 @@ mod tests {
         assert!(cached.skills.contains_key("skill2"));
     }
 
+    #[test]
+    fn test_skill_entry_tags_roundtrip() {
+        let entry = SkillEntry {
+            path: "skills/tagged".to_string(),
+            description: None,
+            homepage: None,
+            commit: None,
+            tags: vec!["testing".to_string()],
+            aliases: Vec::new(),
+        };
+        let json = serde_json::to_string(&entry).unwrap();
+        let restored: SkillEntry = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.tags, vec!["testing"]);
+
+        // Older caches without tags still load, and empty tags are not written
+        let legacy: SkillEntry = serde_json::from_str(r#"{"path":"p","description":null,"homepage":null}"#).unwrap();
+        assert!(legacy.tags.is_empty());
+        assert!(!serde_json::to_string(&legacy).unwrap().contains("tags"));
+    }
+
     #[test]
     fn test_installed_skill_gist_updated_at_field() {
         let skill = InstalledSkill {
@@ -461,6 +706,10 @@ mod tests {
             source_url: Some("https://gist.github.com/garrytan/001f9074cab1a8f545ebecbc73a813df".to_string()),
             source_path: None,
             gist_updated_at: Some("2025-01-15T10:30:00Z".to_string()),
+            content_sha256: None,
+            size_bytes: None,
+            alias: None,
+            branch: None,
         };
 
         let json = serde_json::to_string(&skill).unwrap();
@@ -497,15 +746,29 @@ mod tests {
         assert_eq!(tap.url, "https://github.com/user/repo");
     }
 
+    #[test]
+    fn test_database_keeps_linked_agents_a_list() {
+        // Older binaries read linked_agents as a list, so link digests live in their own field
+        let mut db: Database = serde_json::from_str(r#"{"linked_agents": [".claude"]}"#).unwrap();
+        assert!(db.link_states.is_empty());
+        db.link_states.insert(".claude".to_string(), "abc123".to_string());
+
+        let json: serde_json::Value = serde_json::to_value(&db).unwrap();
+        assert_eq!(json["linked_agents"], serde_json::json!([".claude"]));
+        assert_eq!(json["link_states"][".claude"], "abc123");
+    }
+
     #[test]
     fn test_tap_info_serialize_roundtrip_with_branch() {
         let tap = TapInfo {
             url: "https://github.com/owner/repo".to_string(),
-            skills_path: "skills".to_string(),
+            custom_skills_path: None,
             updated_at: None,
             is_default: false,
             cached_registry: None,
             branch: Some("dev".to_string()),
+            token_env: None,
+            registry_file: None,
         };
 
         let json = serde_json::to_string(&tap).unwrap();
@@ -520,15 +783,35 @@ mod tests {
     fn test_tap_info_branch_none_not_serialized() {
         let tap = TapInfo {
             url: "https://github.com/owner/repo".to_string(),
-            skills_path: "skills".to_string(),
+            custom_skills_path: None,
             updated_at: None,
             is_default: false,
             cached_registry: None,
             branch: None,
+            token_env: None,
+            registry_file: None,
         };
 
         let json = serde_json::to_string(&tap).unwrap();
         // branch should be skipped when None (skip_serializing_if)
         assert!(!json.contains("branch"));
     }
+
+    #[test]
+    fn test_tap_info_custom_skills_path() {
+        // The legacy skills_path key always meant "scan the whole repo"
+        let tap: TapInfo = serde_json::from_str(
+            r#"{"url": "https://github.com/owner/repo", "skills_path": "skills", "updated_at": null}"#,
+        )
+        .unwrap();
+        assert_eq!(tap.custom_skills_path, None);
+
+        // A custom folder named like the default still round-trips as a custom folder
+        let tap = TapInfo {
+            custom_skills_path: Some("skills".to_string()),
+            ..tap
+        };
+        let tap: TapInfo = serde_json::from_str(&serde_json::to_string(&tap).unwrap()).unwrap();
+        assert_eq!(tap.custom_skills_path.as_deref(), Some("skills"));
+    }
 }
@@ -1,23 +1,57 @@
 use anyhow::{Context, Result};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use colored::Colorize;
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+use indicatif::ProgressBar;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
 use tabled::{
-    settings::{Padding, Style},
+    settings::{peaker::PriorityMax, Padding, Style, Width},
     Table, Tabled,
 };
 
-use super::db::{self, DEFAULT_TAP_NAME};
-use super::git::{ensure_clone, git_head_sha, tap_clone_path};
-use super::github::{discover_skills_from_gist, fetch_gist, is_gist_url, parse_gist_url, parse_github_url};
-use super::models::{InstalledSkill, SkillId};
-use super::tap::get_tap_registry;
-use crate::commands::link_to_agents;
-use crate::paths::{get_embedded_skills_dir, get_skills_install_dir, get_tap_clone_dir, get_taps_clone_dir};
-use crate::skill::{discover_skills, has_references_dir, has_scripts_dir, parse_skill_metadata};
-use crate::util::{copy_dir_contents, truncate_string};
+use super::db::{self, DEFAULT_TAP_NAME, LOCAL_TAP_NAME};
+use super::git::{
+    ensure_clone, git_checkout_commit, git_clone, git_current_branch, git_head_sha, git_remote_has_ref,
+    git_remote_has_tag, git_remote_head_sha, git_sparse_clone, tap_clone_path,
+};
+use super::github::{
+    discover_skills_from_gist, discover_skills_from_repo, ensure_online, fetch_gist, is_gist_url, parse_gist_url,
+    parse_github_url, tap_token,
+};
+use super::models::{
+    is_commit_sha, same_commit, Database, GitHubUrl, InstallLayout, InstalledSkill, SkillEntry, SkillId, SkillSource,
+    TapInfo, TapRegistry,
+};
+use super::tap::{get_tap_registry, print_stale_registry_note, registry_is_fresh, save_refreshed_registries};
+use crate::action_log::log_action;
+use crate::commands::{find_agent_links_to, link_to_agents, remove_agent_links};
+use crate::context::RunContext;
+use crate::paths::{
+    display_path_with_tilde, get_embedded_skills_dir, get_skills_install_dir, get_tap_clone_dir, get_taps_clone_dir,
+};
+use crate::skill::{
+    has_references_dir, has_scripts_dir, known_tools, list_reference_files, list_script_files, parse_skill_metadata,
+    postinstall_script, validate_skill_name, SkillMetadata, KNOWN_TOOLS_ENV,
+};
+use crate::util::{
+    copy_dir_contents, dir_size, display_commit, display_timestamp, format_size, hash_dir_contents, more_line,
+    progress_bar, take_limited, terminal_width, truncate_string,
+};
 
 const DESCRIPTION_MAX_LEN: usize = 50;
 
+/// Score added to substring matches so they always rank above fuzzy-only matches
+const SUBSTRING_MATCH_BONUS: i64 = 1_000_000;
+
+/// Minimum fuzzy score, per query character, for a fuzzy-only match to be shown
+const MIN_FUZZY_SCORE_PER_CHAR: i64 = 10;
+
+/// Close matches listed when `tap install` is given an unknown skill
+const MAX_SUGGESTIONS: usize = 3;
+
 /// Table row for displaying skills
 #[derive(Tabled)]
 pub struct SkillListRow {
@@ -27,6 +61,8 @@ pub struct SkillListRow {
     pub name: String,
     #[tabled(rename = "Tap")]
     pub tap: String,
+    #[tabled(rename = "Source")]
+    pub source: SkillSource,
     #[tabled(rename = "Description")]
     pub description: String,
     #[tabled(rename = "Extras")]
@@ -52,42 +88,454 @@ fn format_extras(has_scripts: bool, has_references: bool) -> String {
     }
 }
 
-/// Install a skill by full name (tap/skill[@commit])
-pub fn install_skill(full_name: &str) -> Result<()> {
-    let installed = install_skill_internal(full_name)?;
+/// Install a skill by full name (tap/skill[@tag]), from `branch` instead of the tap's
+/// branch when given
+pub fn install_skill(ctx: &RunContext, full_name: &str, branch: Option<&str>) -> Result<()> {
+    let installed = install_skill_internal(ctx, full_name, branch)?;
+    link_after_install(ctx, installed)
+}
+
+/// Install several skills by full name (`skillshub install a/b/c a/b/d ...`).
+///
+/// db.json is loaded and saved once. A name that fails to resolve or install is reported
+/// and the rest are still installed; the command fails at the end if any did. A single
+/// name behaves exactly like [`install_skill`].
+pub fn install_skills(ctx: &RunContext, names: &[String], branch: Option<&str>) -> Result<()> {
+    let has_pattern = names.iter().any(|name| is_skill_pattern(name));
+    if let (false, [name]) = (has_pattern, names) {
+        return install_skill(ctx, name, branch);
+    }
+
+    let mut db = db::init_db_with(ctx)?;
+    let names: Vec<(Option<usize>, String)> = names.iter().map(|name| (None, name.clone())).collect();
+    let entries = expand_skill_patterns(&mut db, &names);
+    install_batch(ctx, &mut db, &entries, branch)
+}
+
+/// Install the skills listed in a text file (`skillshub install --from-file`), one
+/// `tap/skill[@ref]` or `tap/pattern` per line. Blank lines and `#` comments are
+/// skipped, and failures are reported with their line numbers.
+pub fn install_skills_from_file(ctx: &RunContext, path: &Path, branch: Option<&str>) -> Result<()> {
+    let content = std::fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+    let names: Vec<(Option<usize>, String)> = parse_skill_list(&content)
+        .into_iter()
+        .map(|(line, name)| (Some(line), name))
+        .collect();
+    if names.is_empty() {
+        anyhow::bail!("No skill names found in {}", path.display());
+    }
+
+    let mut db = db::init_db_with(ctx)?;
+    let entries = expand_skill_patterns(&mut db, &names);
+    install_batch(ctx, &mut db, &entries, branch)
+}
+
+/// Skill names in a `--from-file` list with their 1-based line numbers
+fn parse_skill_list(content: &str) -> Vec<(usize, String)> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with('#'))
+        .map(|(line_no, line)| (line_no, line.to_string()))
+        .collect()
+}
+
+/// Install `(label, name)` entries into `db`, saving it and linking agents once at the
+/// end. Failures, including entries that failed to resolve (`Err` names), are reported
+/// by label and the rest still install; the batch fails if any entry did.
+fn install_batch(
+    ctx: &RunContext,
+    db: &mut Database,
+    entries: &[(String, Result<String>)],
+    branch: Option<&str>,
+) -> Result<()> {
+    let mut installed_count = 0;
+    let mut failed: Vec<&str> = Vec::new();
+
+    for (label, name) in entries {
+        let error = match name {
+            Ok(name) => match install_skill_into(ctx, db, name, branch) {
+                Ok(true) => {
+                    installed_count += 1;
+                    continue;
+                }
+                Ok(false) => continue,
+                Err(e) => e.to_string(),
+            },
+            Err(e) => e.to_string(),
+        };
+        say!("{} {} ({})", "✗".red(), label, error);
+        failed.push(label);
+    }
+
+    if (installed_count > 0 || db.registries_refreshed) && !ctx.dry_run {
+        db::save_db(db)?;
+    }
+
+    say!(
+        "\n{}{} Installed {} of {} skills",
+        ctx.prefix(),
+        "Done!".green().bold(),
+        installed_count,
+        entries.len()
+    );
+
+    link_after_install(ctx, installed_count > 0)?;
+
+    if !failed.is_empty() {
+        anyhow::bail!("{} skill(s) failed to install: {}", failed.len(), failed.join(", "));
+    }
+
+    Ok(())
+}
+
+/// Whether the skill part of `name` is a glob pattern (`*`, `?`, or `[...]`)
+fn is_skill_pattern(name: &str) -> bool {
+    SkillId::parse(name).is_some_and(|id| id.skill.contains(['*', '?', '[']))
+}
+
+/// Replace each `tap/pattern[@ref]` in `names` (with the `--from-file` line it came
+/// from, if any) with the matching skills from the tap's cached registry, in name order,
+/// as `(label, name)` batch entries. Plain names pass through unchanged and duplicates
+/// are dropped. A pattern that matches nothing (or cannot be resolved) becomes a failed
+/// entry instead of stopping the batch.
+fn expand_skill_patterns(db: &mut Database, names: &[(Option<usize>, String)]) -> Vec<(String, Result<String>)> {
+    let label = |line: Option<usize>, name: &str| match line {
+        Some(line) => format!("line {}: {}", line, name),
+        None => name.to_string(),
+    };
+
+    let mut expanded: Vec<(String, Result<String>)> = Vec::new();
+    for (line, name) in names {
+        let matches = if is_skill_pattern(name) {
+            match match_skill_pattern(db, name) {
+                Ok(matches) => matches,
+                Err(e) => {
+                    expanded.push((label(*line, name), Err(e)));
+                    continue;
+                }
+            }
+        } else {
+            vec![name.clone()]
+        };
+        for full_name in matches {
+            if !expanded.iter().any(|(_, n)| n.as_ref().is_ok_and(|n| *n == full_name)) {
+                expanded.push((label(*line, &full_name), Ok(full_name)));
+            }
+        }
+    }
+    expanded
+}
+
+/// The skills in the tap's cached registry matching `tap/pattern[@ref]`, as full names
+/// carrying the same `@ref`. Fails if the pattern matches nothing.
+fn match_skill_pattern(db: &mut Database, name: &str) -> Result<Vec<String>> {
+    let skill_id = SkillId::parse(name).with_context(|| format!("Invalid skill name '{}'", name))?;
+    let pattern =
+        glob::Pattern::new(&skill_id.skill).with_context(|| format!("Invalid skill pattern '{}'", skill_id.skill))?;
+    let registry = get_tap_registry(db, &skill_id.tap)?.with_context(|| {
+        format!(
+            "No cached registry for tap '{}'. Run 'skillshub tap update {}' first.",
+            skill_id.tap, skill_id.tap
+        )
+    })?;
+    let suffix = SkillId::parse_commit(name)
+        .map(|git_ref| format!("@{}", git_ref))
+        .unwrap_or_default();
+    let mut skills: Vec<&String> = registry.skills.keys().filter(|s| pattern.matches(s)).collect();
+    if skills.is_empty() {
+        anyhow::bail!(
+            "Pattern '{}' matched no skills in tap '{}'",
+            skill_id.skill,
+            skill_id.tap
+        );
+    }
+    skills.sort();
+    say!(
+        "{} '{}' matched {} skill(s)",
+        "Info:".cyan(),
+        skill_id.full_name(),
+        skills.len()
+    );
+    Ok(skills
+        .into_iter()
+        .map(|skill| format!("{}/{}{}", skill_id.tap, skill, suffix))
+        .collect())
+}
 
+/// Link newly installed skills into every agent (or describe it under `--dry-run`)
+fn link_after_install(ctx: &RunContext, installed: bool) -> Result<()> {
     if installed {
-        // Auto-link to all agents
-        link_to_agents()?;
+        if ctx.dry_run {
+            // Nothing was copied, so a link preview would not include this skill yet
+            ctx.would("link the skill into every discovered agent");
+        } else {
+            // Auto-link to all agents
+            link_to_agents(ctx)?;
+        }
     }
 
     Ok(())
 }
 
+/// Install a single skill from a named tap (`skillshub tap install <tap> <skill>`)
+pub fn install_skill_from_tap(ctx: &RunContext, tap_name: &str, skill_name: &str) -> Result<()> {
+    let mut db = db::init_db_with(ctx)?;
+
+    db::get_tap(&db, tap_name)
+        .with_context(|| format!("Tap '{}' not found. Add it with 'skillshub tap add <url>'", tap_name))?;
+
+    let registry = get_tap_registry(&mut db, tap_name)?.with_context(|| {
+        format!(
+            "No cached registry for tap '{}'. Run 'skillshub tap update {}' first.",
+            tap_name, tap_name
+        )
+    })?;
+    if !ctx.dry_run {
+        save_refreshed_registries(&db)?;
+    }
+
+    if resolve_skill_entry(&registry, skill_name).is_none() {
+        let suggestions = closest_skill_names(&registry, skill_name, MAX_SUGGESTIONS);
+        if suggestions.is_empty() {
+            anyhow::bail!(
+                "Skill '{}' not found in tap '{}'. Run 'skillshub list' to see available skills.",
+                skill_name,
+                tap_name
+            );
+        }
+        anyhow::bail!(
+            "Skill '{}' not found in tap '{}'. Did you mean: {}?",
+            skill_name,
+            tap_name,
+            suggestions.join(", ")
+        );
+    }
+
+    install_skill(ctx, &format!("{}/{}", tap_name, skill_name), None)
+}
+
+/// Skill names in `registry` that resemble `query`, best match first (at most `limit`).
+fn closest_skill_names(registry: &TapRegistry, query: &str, limit: usize) -> Vec<String> {
+    let matcher = SkimMatcherV2::default();
+    let query_lower = query.to_lowercase();
+
+    let mut matches: Vec<(i64, &String)> = registry
+        .skills
+        .iter()
+        .filter_map(|(name, entry)| {
+            let description = entry.description.as_deref().unwrap_or("");
+            score_skill_match(Some(&matcher), &query_lower, name, description).map(|score| (score, name))
+        })
+        .collect();
+
+    matches.sort_by(|(a_score, a), (b_score, b)| b_score.cmp(a_score).then_with(|| a.cmp(b)));
+    matches.into_iter().take(limit).map(|(_, name)| name.clone()).collect()
+}
+
+/// Look up a skill in `registry` by name, falling back to the aliases declared in its
+/// frontmatter. Returns the canonical name and entry.
+fn resolve_skill_entry<'a>(registry: &'a TapRegistry, name: &str) -> Option<(&'a String, &'a SkillEntry)> {
+    registry.skills.get_key_value(name).or_else(|| {
+        registry
+            .skills
+            .iter()
+            .filter(|(_, entry)| entry.aliases.iter().any(|alias| alias == name))
+            // Several skills may claim the same alias; pick one deterministically
+            .min_by(|(a, _), (b, _)| a.cmp(b))
+    })
+}
+
+/// Print a notice and return true if `full_name` is already installed
+fn report_already_installed(ctx: &RunContext, db: &Database, full_name: &str) -> bool {
+    match db::get_installed_skill(db, full_name) {
+        Some(installed) => {
+            say!(
+                "{}{} Skill '{}' is already installed (commit: {})",
+                ctx.prefix(),
+                "Info:".cyan(),
+                full_name,
+                display_commit(installed.commit.as_deref().unwrap_or("local"), false)
+            );
+            true
+        }
+        None => false,
+    }
+}
+
+/// A skill install resolved against db.json, ready to fetch its files on any thread
+#[derive(Debug, Clone)]
+struct InstallPlan {
+    skill_id: SkillId,
+    tap: TapInfo,
+    entry: SkillEntry,
+    dest: PathBuf,
+    is_default_tap: bool,
+    /// `@<commit>` or `@<tag>` given on the command line
+    requested_commit: Option<String>,
+    /// The requested ref, when it is a tag to install from instead of the tap clone
+    requested_tag: Option<String>,
+    /// `--branch` given on the command line, installed from instead of the tap clone
+    requested_branch: Option<String>,
+    /// A commit to install instead of the tap clone's HEAD (manifest import)
+    pinned_commit: Option<String>,
+}
+
+/// Whether installing `branch` from `tap` can use its clone at `clone_dir`.
+///
+/// A tap added without a branch tracks whatever its clone checked out (the remote's
+/// default branch), so the clone is asked rather than resolving the default again.
+fn tap_tracks_branch(tap: &TapInfo, clone_dir: &Path, branch: &str) -> bool {
+    match tap.branch.as_deref() {
+        Some(tracked) => tracked == branch,
+        None => git_current_branch(clone_dir).is_ok_and(|current| current == branch),
+    }
+}
+
 /// Internal skill installation without auto-linking (for batch operations)
-fn install_skill_internal(full_name: &str) -> Result<bool> {
+pub(crate) fn install_skill_internal(ctx: &RunContext, full_name: &str, branch: Option<&str>) -> Result<bool> {
+    let mut db = db::init_db_with(ctx)?;
+    let installed = install_skill_into(ctx, &mut db, full_name, branch)?;
+    if installed && !ctx.dry_run {
+        db::save_db(&db)?;
+    }
+    Ok(installed)
+}
+
+/// Install `full_name` at `commit` of its tap without auto-linking, leaving the tap
+/// clone alone. Bundled default tap skills have no commit and install as usual.
+pub(crate) fn install_skill_at_commit(ctx: &RunContext, full_name: &str, commit: &str) -> Result<bool> {
+    let mut db = db::init_db_with(ctx)?;
+    let Some(mut plan) = plan_install(ctx, &mut db, full_name, None)? else {
+        return Ok(false);
+    };
+    if !plan.is_default_tap {
+        plan.pinned_commit = Some(commit.to_string());
+    }
+    install_planned(ctx, &mut db, &plan)?;
+    if !ctx.dry_run {
+        db::save_db(&db)?;
+    }
+    Ok(true)
+}
+
+/// Install `full_name` and record it in `db` without saving. Returns false if it was
+/// already installed.
+fn install_skill_into(ctx: &RunContext, db: &mut Database, full_name: &str, branch: Option<&str>) -> Result<bool> {
+    let Some(plan) = plan_install(ctx, db, full_name, branch)? else {
+        return Ok(false);
+    };
+    install_planned(ctx, db, &plan)?;
+    Ok(true)
+}
+
+/// Fetch a planned skill, run its hook, and record it in `db` without saving
+fn install_planned(ctx: &RunContext, db: &mut Database, plan: &InstallPlan) -> Result<()> {
+    let dest = &plan.dest;
+
+    if ctx.dry_run {
+        let source = if plan.is_default_tap {
+            get_embedded_skills_dir()?.join(&plan.skill_id.skill)
+        } else if let Some(tag) = &plan.requested_tag {
+            ctx.would(format!(
+                "clone {} at tag {} into a temporary directory",
+                plan.tap.url, tag
+            ));
+            PathBuf::from(&plan.entry.path)
+        } else if let Some(branch) = &plan.requested_branch {
+            ctx.would(format!(
+                "clone {} at branch {} into a temporary directory",
+                plan.tap.url, branch
+            ));
+            PathBuf::from(&plan.entry.path)
+        } else if let Some(commit) = &plan.pinned_commit {
+            ctx.would(format!(
+                "check out {} at commit {} into a temporary directory",
+                plan.tap.url,
+                display_commit(commit, false)
+            ));
+            PathBuf::from(&plan.entry.path)
+        } else {
+            let clone_dir = get_tap_clone_dir(&plan.skill_id.tap)?;
+            if !clone_dir.join(".git").exists() {
+                ctx.would(format!("clone {} into {}", plan.tap.url, clone_dir.display()));
+            }
+            clone_dir.join(&plan.entry.path)
+        };
+        ctx.would(format!("copy {} -> {}", source.display(), dest.display()));
+        if ctx.run_hooks {
+            ctx.would("run the skill's post-install hook, if it has one");
+        }
+        ctx.would(format!(
+            "add db.json entry installed[\"{}\"]",
+            plan.skill_id.full_name()
+        ));
+        say!(
+            "{}{} Installed '{}' to {}",
+            ctx.prefix(),
+            "✓".green(),
+            plan.skill_id.full_name(),
+            dest.display()
+        );
+        return Ok(());
+    }
+
+    let mut notes = Vec::new();
+    let fetched = fetch_planned(plan, &mut notes);
+    for note in &notes {
+        say!("{}", note);
+    }
+    let mut installed = fetched?;
+
+    // Hooks may generate files, so they run after the content hash is taken
+    if ctx.run_hooks {
+        if let Err(e) = run_postinstall_hook(dest) {
+            let _ = std::fs::remove_dir_all(dest);
+            return Err(e);
+        }
+        installed.size_bytes = Some(dir_size(dest));
+    } else if let Some(note) = skipped_hook_note(dest) {
+        say!("{}", note);
+    }
+    if let Some(note) = unknown_tools_note(dest) {
+        say!("{}", note);
+    }
+
+    db::add_installed_skill(db, &plan.skill_id.full_name(), installed);
+
+    say!(
+        "{} Installed '{}' to {}",
+        "✓".green(),
+        plan.skill_id.full_name(),
+        dest.display()
+    );
+
+    Ok(())
+}
+
+/// Resolve `full_name` (tap/skill[@ref], or an alias) to an install plan.
+///
+/// Returns `None` after printing a notice when the skill is already installed.
+fn plan_install(
+    ctx: &RunContext,
+    db: &mut Database,
+    full_name: &str,
+    branch: Option<&str>,
+) -> Result<Option<InstallPlan>> {
     let skill_id = SkillId::parse(full_name)
         .with_context(|| format!("Invalid skill name '{}'. Use format: tap/skill", full_name))?;
+    validate_skill_name(&skill_id.skill)?;
 
     let requested_commit = SkillId::parse_commit(full_name);
 
-    let mut db = db::init_db()?;
-    let install_dir = get_skills_install_dir()?;
-
     // Check if already installed
-    if db::is_skill_installed(&db, &skill_id.full_name()) {
-        let installed = db::get_installed_skill(&db, &skill_id.full_name()).unwrap();
-        println!(
-            "{} Skill '{}' is already installed (commit: {})",
-            "Info:".cyan(),
-            skill_id.full_name(),
-            installed.commit.as_deref().unwrap_or("local")
-        );
-        return Ok(false);
+    if report_already_installed(ctx, db, &skill_id.full_name()) {
+        return Ok(None);
     }
 
     // Get tap info
-    let tap = db::get_tap(&db, &skill_id.tap)
+    let tap = db::get_tap(db, &skill_id.tap)
         .with_context(|| {
             format!(
                 "Tap '{}' not found. Add it with 'skillshub tap add <url>'",
@@ -97,79 +545,282 @@ fn install_skill_internal(full_name: &str) -> Result<bool> {
         .clone();
 
     // Get registry to verify skill exists
-    let registry = get_tap_registry(&db, &skill_id.tap)?.with_context(|| {
+    let registry = get_tap_registry(db, &skill_id.tap)?.with_context(|| {
         format!(
             "No cached registry for tap '{}'. Run 'skillshub tap update {}' first.",
             skill_id.tap, skill_id.tap
         )
     })?;
-    let skill_entry = registry.skills.get(&skill_id.skill).with_context(|| {
+    let (canonical_name, skill_entry) = resolve_skill_entry(&registry, &skill_id.skill).with_context(|| {
         format!(
             "Skill '{}' not found in tap '{}'. Run 'skillshub search {}' to find it.",
             skill_id.skill, skill_id.tap, skill_id.skill
         )
     })?;
 
-    println!("{} Installing '{}'", "=>".green().bold(), skill_id.full_name());
+    // Installing by alias records and links the skill under its canonical name
+    let skill_id = if *canonical_name != skill_id.skill {
+        say!(
+            "{}{} '{}' is an alias for '{}'",
+            ctx.prefix(),
+            "Info:".cyan(),
+            skill_id.skill,
+            canonical_name
+        );
+        let canonical = SkillId {
+            tap: skill_id.tap,
+            skill: canonical_name.clone(),
+        };
+        if report_already_installed(ctx, db, &canonical.full_name()) {
+            return Ok(None);
+        }
+        canonical
+    } else {
+        skill_id
+    };
 
-    let dest = install_dir.join(&skill_id.tap).join(&skill_id.skill);
-    std::fs::create_dir_all(&dest)?;
+    say!(
+        "{}{} Installing '{}'",
+        ctx.prefix(),
+        "=>".green().bold(),
+        skill_id.full_name()
+    );
+
+    plan_from_entry(db, skill_id, tap, skill_entry.clone(), requested_commit, branch).map(Some)
+}
+
+/// Build the install plan for a skill already looked up in its tap's registry
+fn plan_from_entry(
+    db: &Database,
+    skill_id: SkillId,
+    tap: TapInfo,
+    entry: SkillEntry,
+    requested_commit: Option<String>,
+    branch: Option<&str>,
+) -> Result<InstallPlan> {
+    ensure_flat_name_free(db, &skill_id.tap, &skill_id.skill)?;
+    let dest = db.skill_dir(&get_skills_install_dir()?, &skill_id.tap, &skill_id.skill);
+    let is_default_tap = tap.is_default || skill_id.tap == DEFAULT_TAP_NAME;
+
+    // `@<tag>` installs from a one-off clone of the tag; pinned SHAs can't be cloned
+    let requested_tag = match requested_commit.as_deref() {
+        Some(git_ref) if !is_default_tap && !is_gist_url(&tap.url) => {
+            // A tag may look like a SHA (e.g. `1234567`), so ask the remote before refusing
+            if is_commit_sha(git_ref) && !git_remote_has_tag(&tap.url, git_ref)? {
+                anyhow::bail!("Pinned commits are not supported for git-based taps. Use a tag (@vX.Y.Z) instead.");
+            }
+            Some(git_ref.to_string())
+        }
+        _ => None,
+    };
+
+    // `--branch` installs from a one-off clone of that branch, leaving the tap clone alone
+    let requested_branch = match branch {
+        Some(_) if requested_commit.is_some() => {
+            anyhow::bail!("--branch cannot be combined with an @ref on '{}'", skill_id.full_name())
+        }
+        Some(_) if is_default_tap => {
+            anyhow::bail!("--branch is not supported for bundled default tap skills")
+        }
+        Some(_) if is_gist_url(&tap.url) => anyhow::bail!("--branch is not supported for gist skills"),
+        // The tap clone already tracks this branch
+        Some(branch) if tap_tracks_branch(&tap, &get_tap_clone_dir(&skill_id.tap)?, branch) => None,
+        Some(branch) => Some(branch.to_string()),
+        None => None,
+    };
+
+    Ok(InstallPlan {
+        skill_id,
+        tap,
+        entry,
+        dest,
+        is_default_tap,
+        requested_commit,
+        requested_tag,
+        requested_branch,
+        pinned_commit: None,
+    })
+}
+
+/// Copy a planned skill into place and build its db.json record, without touching db.json.
+///
+/// Status lines are pushed onto `notes` rather than printed, so parallel installs can
+/// show them in a stable order.
+fn fetch_planned(plan: &InstallPlan, notes: &mut Vec<String>) -> Result<InstalledSkill> {
+    let dest = &plan.dest;
+    std::fs::create_dir_all(dest)?;
 
     // For the default (bundled) tap, install from local bundled skills directory.
-    let commit = if tap.is_default || skill_id.tap == DEFAULT_TAP_NAME {
-        if requested_commit.is_some() {
-            println!(
+    let commit = if plan.is_default_tap {
+        if plan.requested_commit.is_some() {
+            notes.push(format!(
                 "  {} @commit specifier is ignored for bundled default tap skills (using local copy)",
                 "!".yellow()
-            );
+            ));
         }
-        install_from_local(&skill_id.skill, &dest)?;
-        println!("  {} Installed from bundled skills (no network required)", "✓".green());
+        install_from_local(&plan.skill_id.skill, dest)?;
+        notes.push(format!(
+            "  {} Installed from bundled skills (no network required)",
+            "✓".green()
+        ));
         None // local install has no remote commit SHA
-    } else if requested_commit.is_some() && !is_gist_url(&tap.url) {
-        // Pinned @commit is not supported for git-based taps
-        anyhow::bail!("Pinned commits are not supported for git-based taps.");
+    } else if let Some(tag) = &plan.requested_tag {
+        if let Err(e) = install_from_tag(&plan.tap.url, tag, &plan.entry.path, dest) {
+            let _ = std::fs::remove_dir_all(dest);
+            return Err(e);
+        }
+        notes.push(format!("  {} Installed from tag {}", "✓".green(), tag));
+        // The tag is recorded verbatim so `update` leaves the pinned install alone
+        Some(tag.clone())
+    } else if let Some(branch) = &plan.requested_branch {
+        match install_from_branch(&plan.tap.url, branch, &plan.entry.path, dest) {
+            Ok(commit) => {
+                notes.push(format!("  {} Installed from branch {}", "✓".green(), branch));
+                Some(commit)
+            }
+            Err(e) => {
+                let _ = std::fs::remove_dir_all(dest);
+                return Err(e);
+            }
+        }
+    } else if let Some(commit) = &plan.pinned_commit {
+        match install_from_commit(&plan.tap.url, commit, &plan.entry.path, dest) {
+            Ok(commit) => {
+                notes.push(format!(
+                    "  {} Installed at commit {}",
+                    "✓".green(),
+                    display_commit(&commit, false)
+                ));
+                Some(commit)
+            }
+            Err(e) => {
+                let _ = std::fs::remove_dir_all(dest);
+                return Err(e);
+            }
+        }
     } else {
         // Install from local tap clone (no API fallback)
-        let commit = install_from_clone(&skill_id.tap, &tap.url, &skill_entry.path, &dest, tap.branch.as_deref())?;
-        println!("  {} Installed from local tap clone", "✓".green());
+        let commit = install_from_clone(
+            &plan.skill_id.tap,
+            &plan.tap.url,
+            &plan.entry.path,
+            dest,
+            plan.tap.branch.as_deref(),
+        )?;
+        notes.push(format!("  {} Installed from local tap clone", "✓".green()));
         commit
     };
 
-    // Record in database
-    let installed = InstalledSkill {
-        tap: skill_id.tap.clone(),
-        skill: skill_id.skill.clone(),
+    // Record a content hash for clone-based installs so `update` can detect
+    // content changes that happen without a new commit
+    let content_sha256 = if commit.is_some() {
+        hash_dir_contents(dest).ok()
+    } else {
+        None
+    };
+
+    Ok(InstalledSkill {
+        tap: plan.skill_id.tap.clone(),
+        skill: plan.skill_id.skill.clone(),
         commit,
         installed_at: Utc::now(),
-        source_url: Some(tap.url.clone()),
-        source_path: Some(skill_entry.path.clone()),
+        source_url: Some(plan.tap.url.clone()),
+        source_path: Some(plan.entry.path.clone()),
         gist_updated_at: None,
-    };
+        content_sha256,
+        size_bytes: Some(dir_size(dest)),
+        alias: None,
+        branch: plan.requested_branch.clone(),
+    })
+}
 
-    db::add_installed_skill(&mut db, &skill_id.full_name(), installed);
-    db::save_db(&db)?;
+/// The notice shown when a skill has a post-install hook that was not asked for
+fn skipped_hook_note(skill_dir: &Path) -> Option<String> {
+    let script = postinstall_script(skill_dir).ok().flatten()?;
+    Some(format!(
+        "  {} Not running post-install hook {} (pass --run-hooks to run it)",
+        "!".yellow(),
+        script.strip_prefix(skill_dir).unwrap_or(&script).display()
+    ))
+}
 
-    println!(
-        "{} Installed '{}' to {}",
-        "✓".green(),
-        skill_id.full_name(),
-        dest.display()
-    );
+/// The warning shown when a skill's `allowed-tools` names tools outside `SKILLSHUB_KNOWN_TOOLS`
+fn unknown_tools_note(skill_dir: &Path) -> Option<String> {
+    let known = known_tools()?;
+    let metadata = parse_skill_metadata(&skill_dir.join("SKILL.md")).ok()?;
+    let unknown = metadata.allowed_tools.unknown(&known);
+    (!unknown.is_empty()).then(|| {
+        format!(
+            "  {} Requests tools not in {}: {}",
+            "!".yellow(),
+            KNOWN_TOOLS_ENV,
+            unknown.join(", ")
+        )
+    })
+}
 
-    Ok(true)
+/// Run the post-install hook of the skill in `skill_dir` (`install --run-hooks`)
+///
+/// The script runs with the skill directory as its working directory and its output is
+/// shown indented below the install. A non-zero exit fails the install.
+fn run_postinstall_hook(skill_dir: &Path) -> Result<()> {
+    let Some(script) = postinstall_script(skill_dir)? else {
+        return Ok(());
+    };
+    let rel = script.strip_prefix(skill_dir).unwrap_or(&script).display().to_string();
+
+    say!("  {} Running post-install hook {}", "=>".green().bold(), rel);
+    log_action(format!("run post-install hook {}", script.display()));
+    let mut command = if script.extension().is_some_and(|ext| ext == "sh") {
+        let mut command = std::process::Command::new("sh");
+        command.arg(&script);
+        command
+    } else {
+        std::process::Command::new(&script)
+    };
+    let output = command
+        .current_dir(skill_dir)
+        .output()
+        .with_context(|| format!("Failed to run post-install hook {}", rel))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let lines = stdout.lines().chain(stderr.lines());
+    if !output.status.success() {
+        // Failure output is shown even with --quiet
+        for line in lines {
+            eprintln!("    {}", line);
+        }
+        anyhow::bail!("Post-install hook {} failed ({})", rel, output.status);
+    }
+    for line in lines {
+        say!("    {}", line);
+    }
+    say!("  {} Post-install hook finished", "✓".green());
+
+    Ok(())
 }
 
 /// Add a skill directly from a GitHub URL
 ///
 /// URL format: https://github.com/owner/repo/tree/commit/path/to/skill
-pub fn add_skill_from_url(url: &str) -> Result<()> {
+///
+/// `branch` (`add --branch`) overrides the branch in the URL. When the tap is already
+/// cloned on another branch, the skill is copied from a one-off clone and `update`
+/// keeps following that branch.
+pub fn add_skill_from_url(url: &str, branch: Option<&str>) -> Result<()> {
     // Check if this is a gist URL — handle separately
     if is_gist_url(url) {
+        if branch.is_some() {
+            anyhow::bail!("--branch is not supported for gist skills");
+        }
         return add_skill_from_gist(url);
     }
 
-    let github_url = parse_github_url(url)?;
+    let mut github_url = parse_github_url(url)?;
+    if let Some(branch) = branch {
+        github_url.branch = Some(branch.to_string());
+    }
 
     // Must have a path to the skill folder
     let skill_path = github_url
@@ -181,6 +832,7 @@ pub fn add_skill_from_url(url: &str) -> Result<()> {
     let skill_name = github_url
         .skill_name()
         .with_context(|| "Could not determine skill name from URL path")?;
+    validate_skill_name(&skill_name)?;
 
     // Use repo name as tap name
     let tap_name = github_url.tap_name().to_string();
@@ -192,63 +844,89 @@ pub fn add_skill_from_url(url: &str) -> Result<()> {
     // Check if already installed
     if db::is_skill_installed(&db, &full_name) {
         let installed = db::get_installed_skill(&db, &full_name).unwrap();
-        println!(
+        say!(
             "{} Skill '{}' is already installed (commit: {})",
             "Info:".cyan(),
             full_name,
-            installed.commit.as_deref().unwrap_or("unknown")
+            display_commit(installed.commit.as_deref().unwrap_or("unknown"), false)
         );
-        println!(
+        say!(
             "Use '{}' to update it.",
             format!("skillshub update {}", full_name).bold()
         );
         return Ok(());
     }
 
-    // Reject pinned commit SHAs for non-gist taps — git clone -b cannot checkout a SHA
-    if github_url.is_commit_sha() {
+    // Reject pinned commit SHAs for non-gist taps — git clone -b cannot checkout a SHA.
+    // Branches and tags can look like SHAs too, so only refs the remote lacks are refused.
+    let is_pinned_sha = match github_url.branch.as_deref() {
+        Some(git_ref) if github_url.is_commit_sha() => !git_remote_has_ref(&github_url.base_url(), git_ref)?,
+        _ => false,
+    };
+    if is_pinned_sha {
         anyhow::bail!(
             "Pinned commits (@SHA) are not supported for git-based taps. \
              Use --branch with a branch or tag name instead."
         );
     }
 
-    println!("{} Adding '{}' from {}", "=>".green().bold(), full_name, url);
+    say!("{} Adding '{}' from {}", "=>".green().bold(), full_name, url);
 
-    // Ensure tap clone exists
     let base_url = github_url.base_url();
     let clone_dir = get_tap_clone_dir(&tap_name)?;
-    ensure_clone(&clone_dir, &base_url, github_url.branch.as_deref())?;
-
-    let dest = install_dir.join(&tap_name).join(&skill_name);
-    std::fs::create_dir_all(&dest)?;
+    validate_skill_path(skill_path)?;
+    ensure_flat_name_free(&db, &tap_name, &skill_name)?;
+    let dest = db.skill_dir(&install_dir, &tap_name, &skill_name);
+
+    // A branch (from --branch or the URL) the existing tap clone does not track is fetched
+    // separately and recorded, so `update` keeps following it
+    let own_branch = github_url
+        .branch
+        .as_deref()
+        .filter(|b| db::get_tap(&db, &tap_name).is_some_and(|tap| !tap_tracks_branch(tap, &clone_dir, b)));
+    let commit_sha = if let Some(branch) = own_branch {
+        std::fs::create_dir_all(&dest)?;
+        match install_from_branch(&base_url, branch, skill_path, &dest) {
+            Ok(commit) => commit,
+            Err(e) => {
+                let _ = std::fs::remove_dir_all(&dest);
+                return Err(e);
+            }
+        }
+    } else {
+        // Ensure tap clone exists
+        ensure_clone(&clone_dir, &base_url, github_url.branch.as_deref())?;
+        std::fs::create_dir_all(&dest)?;
 
-    // Copy from clone with path containment check
-    let source = clone_dir.join(skill_path);
-    let canonical_source = source
-        .canonicalize()
-        .with_context(|| format!("Skill path '{}' not found in repository", skill_path))?;
-    let canonical_clone = clone_dir.canonicalize()?;
-    if !canonical_source.starts_with(&canonical_clone) {
-        anyhow::bail!("Skill path escapes clone directory");
-    }
-    if !canonical_source.join("SKILL.md").exists() {
-        anyhow::bail!("No SKILL.md found at '{}'", skill_path);
-    }
-    copy_dir_contents(&source, &dest)?;
+        // Copy from clone with path containment check
+        let source = clone_dir.join(skill_path);
+        let canonical_source = source
+            .canonicalize()
+            .with_context(|| format!("Skill path '{}' not found in repository", skill_path))?;
+        let canonical_clone = clone_dir.canonicalize()?;
+        if !canonical_source.starts_with(&canonical_clone) {
+            anyhow::bail!("Skill path escapes clone directory");
+        }
+        if !canonical_source.join("SKILL.md").exists() {
+            anyhow::bail!("No SKILL.md found at '{}'", skill_path);
+        }
+        copy_dir_contents(&source, &dest)?;
 
-    let commit_sha = super::git::git_head_sha(&clone_dir)?;
+        super::git::git_head_sha(&clone_dir)?
+    };
 
     // Populate cached_registry so `update` works without manual `tap update`
     if db::get_tap(&db, &tap_name).is_none() {
         let registry = super::tap::discover_skills_from_local(&clone_dir, &tap_name).ok(); // Non-fatal: registry cache is a convenience
         let tap_info = super::models::TapInfo {
             url: base_url,
-            skills_path: "skills".to_string(),
+            custom_skills_path: None,
             updated_at: Some(Utc::now()),
             is_default: false,
             cached_registry: registry,
             branch: github_url.branch.clone(),
+            token_env: None,
+            registry_file: None,
         };
         db::add_tap(&mut db, &tap_name, tap_info);
     }
@@ -262,21 +940,25 @@ pub fn add_skill_from_url(url: &str) -> Result<()> {
         source_url: Some(url.to_string()),
         source_path: Some(skill_path.clone()),
         gist_updated_at: None,
+        content_sha256: hash_dir_contents(&dest).ok(),
+        size_bytes: Some(dir_size(&dest)),
+        alias: None,
+        branch: own_branch.map(str::to_string),
     };
 
     db::add_installed_skill(&mut db, &full_name, installed);
     db::save_db(&db)?;
 
-    println!(
+    say!(
         "{} Added '{}' (commit: {}) to {}",
         "✓".green(),
         full_name,
-        commit_sha,
+        display_commit(&commit_sha, false),
         dest.display()
     );
 
     // Auto-link to all agents
-    link_to_agents()?;
+    link_to_agents(&RunContext::default())?;
 
     Ok(())
 }
@@ -287,7 +969,7 @@ pub fn add_skill_from_url(url: &str) -> Result<()> {
 pub fn add_skill_from_gist(url: &str) -> Result<()> {
     let (owner, gist_id) = parse_gist_url(url).with_context(|| format!("Invalid gist URL: {}", url))?;
 
-    println!("{} Fetching gist from {}", "=>".green().bold(), url);
+    say!("{} Fetching gist from {}", "=>".green().bold(), url);
 
     let gist = fetch_gist(&gist_id)?;
 
@@ -308,11 +990,13 @@ pub fn add_skill_from_gist(url: &str) -> Result<()> {
     if db::get_tap(&db, &tap_name).is_none() {
         let tap_info = super::models::TapInfo {
             url: format!("https://gist.github.com/{}", owner),
-            skills_path: String::new(),
+            custom_skills_path: None,
             updated_at: Some(Utc::now()),
             is_default: false,
             cached_registry: None,
             branch: None,
+            token_env: None,
+            registry_file: None,
         };
         db::add_tap(&mut db, &tap_name, tap_info);
     }
@@ -324,7 +1008,7 @@ pub fn add_skill_from_gist(url: &str) -> Result<()> {
 
         // Check if already installed
         if db::is_skill_installed(&db, &full_name) {
-            println!(
+            say!(
                 "{} Skill '{}' is already installed. Use '{}' to update.",
                 "Info:".cyan(),
                 full_name,
@@ -333,7 +1017,8 @@ pub fn add_skill_from_gist(url: &str) -> Result<()> {
             continue;
         }
 
-        let dest = install_dir.join(&tap_name).join(skill_name);
+        ensure_flat_name_free(&db, &tap_name, skill_name)?;
+        let dest = db.skill_dir(&install_dir, &tap_name, skill_name);
         std::fs::create_dir_all(&dest)?;
         std::fs::write(dest.join("SKILL.md"), content)?;
 
@@ -345,30 +1030,142 @@ pub fn add_skill_from_gist(url: &str) -> Result<()> {
             source_url: Some(url.to_string()),
             source_path: Some(gist_id.clone()),
             gist_updated_at: Some(gist.updated_at.clone()),
+            content_sha256: None,
+            size_bytes: Some(dir_size(&dest)),
+            alias: None,
+            branch: None,
         };
 
         db::add_installed_skill(&mut db, &full_name, installed);
         installed_count += 1;
 
-        println!("{} Added '{}' from gist to {}", "✓".green(), full_name, dest.display());
+        say!("{} Added '{}' from gist to {}", "✓".green(), full_name, dest.display());
     }
 
     db::save_db(&db)?;
 
     if installed_count > 0 {
-        link_to_agents()?;
+        link_to_agents(&RunContext::default())?;
     }
 
     Ok(())
 }
 
-/// Install from local bundled skills directory (for the default tap).
-/// Copies the skill directory from the bundled skills path to the destination.
-fn install_from_local(skill_name: &str, dest: &std::path::Path) -> Result<()> {
-    let skills_dir = get_embedded_skills_dir()?;
-    let source = skills_dir.join(skill_name);
+/// Add a skill from a `.tar.gz`, `.tgz`, or `.tar` file on disk (`skillshub add --archive`).
+///
+/// The archive may wrap the skill in any number of folders (GitHub tarballs add a
+/// `repo-<sha>/` prefix); the shallowest `SKILL.md` marks the skill. It is installed
+/// under the `local` tap, like adopted skills, so `update` leaves it alone.
+pub fn add_skill_from_archive(archive: &Path) -> Result<()> {
+    say!(
+        "{} Adding skill from {}",
+        "=>".green().bold(),
+        display_path_with_tilde(archive)
+    );
 
-    if !source.exists() {
+    let unpacked = tempfile::TempDir::new()?;
+    unpack_archive(archive, unpacked.path())?;
+    let source = find_archived_skill_root(unpacked.path())?;
+    let metadata = parse_skill_metadata(&source.join("SKILL.md"))?;
+    validate_skill_name(&metadata.name)?;
+
+    let mut db = db::init_db()?;
+    let full_name = format!("{}/{}", LOCAL_TAP_NAME, metadata.name);
+    if db::is_skill_installed(&db, &full_name) {
+        anyhow::bail!(
+            "Skill '{}' is already installed. Uninstall it first to replace it.",
+            full_name
+        );
+    }
+    ensure_flat_name_free(&db, LOCAL_TAP_NAME, &metadata.name)?;
+
+    let dest = db.skill_dir(&get_skills_install_dir()?, LOCAL_TAP_NAME, &metadata.name);
+    if dest.exists() {
+        anyhow::bail!("{} already exists", dest.display());
+    }
+    std::fs::create_dir_all(&dest)?;
+    log_action(format!("copy {} -> {}", source.display(), dest.display()));
+    if let Err(e) = copy_dir_contents(&source, &dest) {
+        let _ = std::fs::remove_dir_all(&dest);
+        return Err(e);
+    }
+
+    db::add_installed_skill(
+        &mut db,
+        &full_name,
+        InstalledSkill {
+            tap: LOCAL_TAP_NAME.to_string(),
+            skill: metadata.name.clone(),
+            commit: None,
+            installed_at: Utc::now(),
+            source_url: None,
+            source_path: None,
+            gist_updated_at: None,
+            content_sha256: hash_dir_contents(&dest).ok(),
+            size_bytes: Some(dir_size(&dest)),
+            alias: None,
+            branch: None,
+        },
+    );
+    db::save_db(&db)?;
+
+    say!("{} Added '{}' to {}", "✓".green(), full_name, dest.display());
+
+    link_to_agents(&RunContext::default())
+}
+
+/// Extract a tar archive, gzip-compressed or not, into `dest`.
+/// Entries that would land outside `dest` are rejected by `tar` itself.
+fn unpack_archive(archive: &Path, dest: &Path) -> Result<()> {
+    use std::io::{BufRead, BufReader};
+
+    let file = std::fs::File::open(archive).with_context(|| format!("Failed to open {}", archive.display()))?;
+    let mut reader = BufReader::new(file);
+    let gzipped = reader.fill_buf()?.starts_with(&[0x1f, 0x8b]);
+
+    log_action(format!("unpack {} -> {}", archive.display(), dest.display()));
+    let result = if gzipped {
+        tar::Archive::new(flate2::read::GzDecoder::new(reader)).unpack(dest)
+    } else {
+        tar::Archive::new(reader).unpack(dest)
+    };
+    result.with_context(|| {
+        format!(
+            "Failed to extract {} (expected a .tar.gz, .tgz, or .tar file)",
+            archive.display()
+        )
+    })
+}
+
+/// The directory holding the shallowest `SKILL.md` under `dir`. Several at that depth
+/// means the archive holds more than one skill, which is an error.
+fn find_archived_skill_root(dir: &Path) -> Result<PathBuf> {
+    let mut found: Vec<(usize, PathBuf)> = walkdir::WalkDir::new(dir)
+        .into_iter()
+        .flatten()
+        .filter(|entry| entry.file_type().is_file() && entry.file_name() == "SKILL.md")
+        .filter_map(|entry| Some((entry.depth(), entry.path().parent()?.to_path_buf())))
+        .collect();
+    found.sort();
+
+    match found.as_slice() {
+        [] => anyhow::bail!("No SKILL.md found in the archive"),
+        [(depth, root), rest @ ..] => {
+            if rest.first().is_some_and(|(next, _)| next == depth) {
+                anyhow::bail!("The archive contains several skills; add them one at a time");
+            }
+            Ok(root.clone())
+        }
+    }
+}
+
+/// Install from local bundled skills directory (for the default tap).
+/// Copies the skill directory from the bundled skills path to the destination.
+fn install_from_local(skill_name: &str, dest: &std::path::Path) -> Result<()> {
+    let skills_dir = get_embedded_skills_dir()?;
+    let source = skills_dir.join(skill_name);
+
+    if !source.exists() {
         anyhow::bail!(
             "skill '{}' not found in bundled skills at {}",
             skill_name,
@@ -402,6 +1199,107 @@ fn install_from_clone(
     let clone_dir = crate::paths::get_tap_clone_dir(tap_name)?;
     super::git::ensure_clone(&clone_dir, tap_url, branch)?;
 
+    copy_skill_from_checkout(&clone_dir, skill_path, dest)?;
+
+    let commit = super::git::git_head_sha(&clone_dir).ok();
+    Ok(commit)
+}
+
+/// Install a skill from a tag, using a temporary shallow clone so the tap clone
+/// stays on its tracked branch
+fn install_from_tag(tap_url: &str, tag: &str, skill_path: &str, dest: &std::path::Path) -> Result<()> {
+    validate_skill_path(skill_path)?;
+    if !git_remote_has_tag(tap_url, tag)? {
+        anyhow::bail!("Tag '{}' not found in {}", tag, tap_url);
+    }
+    install_from_ref(tap_url, Some(tag), skill_path, dest).map(|_| ())
+}
+
+/// Install a skill from a branch other than the one the tap clone tracks, using a
+/// temporary shallow clone. Returns the branch's commit SHA.
+fn install_from_branch(tap_url: &str, branch: &str, skill_path: &str, dest: &std::path::Path) -> Result<String> {
+    validate_skill_path(skill_path)?;
+    install_from_ref(tap_url, Some(branch), skill_path, dest)
+}
+
+/// Install a skill at a specific commit through a temporary checkout, so the tap clone
+/// stays on its tracked branch. Returns the full commit SHA.
+fn install_from_commit(tap_url: &str, commit: &str, skill_path: &str, dest: &std::path::Path) -> Result<String> {
+    validate_skill_path(skill_path)?;
+    let checkout = tempfile::TempDir::new()?;
+    git_checkout_commit(tap_url, checkout.path(), commit)?;
+    copy_skill_from_checkout(checkout.path(), skill_path, dest)?;
+    git_head_sha(checkout.path())
+}
+
+/// Copy `skill_path` at `git_ref` (a tag or branch, or the remote's default branch when
+/// `None`) into `dest` through a temporary shallow clone, returning the checked-out
+/// commit SHA. Only the skill's folder is checked out when possible; a full shallow
+/// clone is the fallback.
+fn install_from_ref(tap_url: &str, git_ref: Option<&str>, skill_path: &str, dest: &std::path::Path) -> Result<String> {
+    // Root-level skills need the whole tree anyway
+    let sparse_path = skill_path.trim_matches('/');
+    if !sparse_path.is_empty() && sparse_path != "." {
+        let checkout = tempfile::TempDir::new()?;
+        match git_sparse_clone(tap_url, checkout.path(), git_ref, sparse_path)
+            .and_then(|()| copy_skill_from_checkout(checkout.path(), skill_path, dest))
+        {
+            Ok(()) => return git_head_sha(checkout.path()),
+            Err(e) => eprintln!(
+                "  {} Sparse checkout failed, cloning all of {}: {}",
+                "!".yellow(),
+                git_ref.unwrap_or("the default branch"),
+                e
+            ),
+        }
+    }
+
+    let checkout = tempfile::TempDir::new()?;
+    git_clone(tap_url, checkout.path(), git_ref)?;
+    copy_skill_from_checkout(checkout.path(), skill_path, dest)?;
+    git_head_sha(checkout.path())
+}
+
+/// Reject skill paths that are absolute or step out of the repository with `..`.
+///
+/// Registry paths come from the tap, so they are checked before anything is
+/// joined onto a clone directory; the canonical containment checks below stay as
+/// a second line of defense against symlinks.
+fn validate_skill_path(skill_path: &str) -> Result<()> {
+    use std::path::Component;
+
+    let path = std::path::Path::new(skill_path);
+    let unsafe_component = path
+        .components()
+        .any(|c| matches!(c, Component::ParentDir | Component::RootDir | Component::Prefix(_)));
+    if unsafe_component || skill_path.split(['/', '\\']).any(|part| part == "..") {
+        anyhow::bail!(
+            "Unsafe skill path '{}': must stay inside the tap repository",
+            skill_path
+        );
+    }
+    Ok(())
+}
+
+/// In the flat layout all skills share one directory, so a name can only come from one tap
+pub(crate) fn ensure_flat_name_free(db: &Database, tap: &str, skill: &str) -> Result<()> {
+    if db.layout != InstallLayout::Flat {
+        return Ok(());
+    }
+    if let Some(other) = db.installed.values().find(|i| i.skill == skill && i.tap != tap) {
+        anyhow::bail!(
+            "Skill '{}' is already installed from '{}'; the flat layout needs unique skill names \
+             (switch back with 'skillshub migrate --layout nested')",
+            skill,
+            other.tap
+        );
+    }
+    Ok(())
+}
+
+/// Copy `skill_path` from a git checkout into `dest`, replacing any previous contents
+fn copy_skill_from_checkout(clone_dir: &std::path::Path, skill_path: &str, dest: &std::path::Path) -> Result<()> {
+    validate_skill_path(skill_path)?;
     let source = clone_dir.join(skill_path);
 
     // Path containment check
@@ -418,55 +1316,371 @@ fn install_from_clone(
 
     // Clean destination and copy with cleanup on failure
     if dest.exists() {
+        log_action(format!("remove {}", dest.display()));
         std::fs::remove_dir_all(dest)?;
     }
     std::fs::create_dir_all(dest)?;
+    log_action(format!("copy {} -> {}", source.display(), dest.display()));
     if let Err(e) = copy_dir_contents(&source, dest) {
         // Clean up partial copy before propagating error
         let _ = std::fs::remove_dir_all(dest);
         return Err(e.context("Failed to copy skill from clone"));
     }
 
-    let commit = super::git::git_head_sha(&clone_dir).ok();
-    Ok(commit)
+    Ok(())
+}
+
+/// Options for `skillshub uninstall`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UninstallOptions {
+    /// Leave the tap's directory under the install dir in place once it is empty
+    /// (`--keep-empty-taps`)
+    pub keep_empty_taps: bool,
+    /// Also forget a tracked external skill of the same name (`--purge`)
+    pub purge: bool,
 }
 
-/// Uninstall a skill by full name
-pub fn uninstall_skill(full_name: &str) -> Result<()> {
+/// Uninstall a skill by full name. The tap's directory under the install dir is removed
+/// once it is empty, unless `keep_empty_taps` is set.
+///
+/// With `purge` a tracked external skill of the same name is forgotten too, along with
+/// the links that synced it into other agents. Purging never fails because a trace is
+/// already gone, so it also cleans up after a skill that is no longer in db.json.
+pub fn uninstall_skill(ctx: &RunContext, full_name: &str, options: &UninstallOptions) -> Result<()> {
+    let UninstallOptions { keep_empty_taps, purge } = *options;
     let skill_id = SkillId::parse(full_name)
         .with_context(|| format!("Invalid skill name '{}'. Use format: tap/skill", full_name))?;
 
-    let mut db = db::init_db()?;
+    let mut db = db::init_db_with(ctx)?;
     let install_dir = get_skills_install_dir()?;
 
     // Check if installed
-    if !db::is_skill_installed(&db, &skill_id.full_name()) {
+    let is_installed = db::is_skill_installed(&db, &skill_id.full_name());
+    if !is_installed && !purge {
         anyhow::bail!("Skill '{}' is not installed", skill_id.full_name());
     }
 
-    let skill_path = install_dir.join(&skill_id.tap).join(&skill_id.skill);
+    let skill_path = db.skill_dir(&install_dir, &skill_id.tap, &skill_id.skill);
+    // Find agent links while the skill still exists so their targets resolve
+    let agent_links = find_agent_links_to(&skill_path.canonicalize().unwrap_or_else(|_| skill_path.clone()));
+    let external = if purge {
+        db.external.get(&skill_id.skill).cloned()
+    } else {
+        None
+    };
+    let synced_links = external
+        .as_ref()
+        .map(|ext| find_agent_links_to(&ext.source_path))
+        .unwrap_or_default();
+
+    if ctx.dry_run {
+        if skill_path.exists() {
+            ctx.would(format!("remove {}", skill_path.display()));
+        }
+        for link in agent_links.iter().chain(&synced_links) {
+            ctx.would(format!("remove agent link {}", link.display()));
+        }
+        let tap_dir = install_dir.join(&skill_id.tap);
+        let only_entry = !keep_empty_taps
+            && tap_dir
+                .read_dir()
+                .map(|entries| entries.flatten().all(|e| e.path() == skill_path))
+                .unwrap_or(false);
+        if only_entry {
+            ctx.would(format!("remove empty directory {}", tap_dir.display()));
+        }
+        if is_installed {
+            ctx.would(format!("remove db.json entry installed[\"{}\"]", skill_id.full_name()));
+        }
+        if external.is_some() {
+            ctx.would(format!("remove db.json entry external[\"{}\"]", skill_id.skill));
+        }
+        say!("{}{} Uninstalled '{}'", ctx.prefix(), "✓".green(), skill_id.full_name());
+        return Ok(());
+    }
 
-    if skill_path.exists() {
+    let removed_dir = skill_path.exists();
+    if removed_dir {
+        log_action(format!("remove {}", skill_path.display()));
         std::fs::remove_dir_all(&skill_path)?;
     }
 
-    // Clean up empty tap directory
-    let tap_dir = install_dir.join(&skill_id.tap);
-    if tap_dir.exists() && tap_dir.read_dir()?.next().is_none() {
-        std::fs::remove_dir(&tap_dir)?;
+    if !keep_empty_taps {
+        remove_empty_tap_dir(&install_dir, &skill_id.tap)?;
     }
 
     db::remove_installed_skill(&mut db, &skill_id.full_name());
+    db.link_names.remove(&skill_id.full_name());
+    if external.is_some() {
+        db::remove_external_skill(&mut db, &skill_id.skill);
+    }
+    db::save_db(&db)?;
+
+    let unlinked = remove_agent_links(&agent_links);
+    let unsynced = remove_agent_links(&synced_links);
+
+    if !purge {
+        say!("{} Uninstalled '{}'", "✓".green(), skill_id.full_name());
+        if unlinked > 0 {
+            say!("  {} Removed {} agent link(s)", "✓".green(), unlinked);
+        }
+        return Ok(());
+    }
+
+    let mut purged = Vec::new();
+    if is_installed {
+        purged.push("db.json entry".to_string());
+    }
+    if removed_dir {
+        purged.push(format!("directory {}", display_path_with_tilde(&skill_path)));
+    }
+    if unlinked > 0 {
+        purged.push(format!("{} agent link(s)", unlinked));
+    }
+    if let Some(ext) = &external {
+        purged.push(format!("external entry (from {})", ext.source_agent));
+    }
+    if unsynced > 0 {
+        purged.push(format!("{} synced external link(s)", unsynced));
+    }
+
+    if purged.is_empty() {
+        say!("{} Nothing left to purge for '{}'", "○".yellow(), skill_id.full_name());
+    } else {
+        say!("{} Purged '{}'", "✓".green(), skill_id.full_name());
+        for item in purged {
+            say!("  {} {}", "✓".green(), item);
+        }
+    }
+
+    Ok(())
+}
+
+/// Reinstall an installed skill in place, replacing its directory with a fresh copy.
+///
+/// The source is the one recorded at install time: the bundled copy for the default tap,
+/// the pinned tag for `@<tag>` installs, the gist for gist skills, and the tap clone
+/// otherwise. The db.json entry (alias and pin included) is kept; `installed_at`, the
+/// content hash, and the size are refreshed. Agent links are left as they are, since the
+/// skill's directory does not move.
+pub fn reinstall_skill(full_name: &str) -> Result<()> {
+    let skill_id = SkillId::parse(full_name)
+        .with_context(|| format!("Invalid skill name '{}'. Use format: tap/skill", full_name))?;
+    let full_name = skill_id.full_name();
+
+    let mut db = db::init_db()?;
+    let installed = db::get_installed_skill(&db, &full_name)
+        .with_context(|| format!("Skill '{}' is not installed", full_name))?
+        .clone();
+    if installed.tap == LOCAL_TAP_NAME {
+        anyhow::bail!(
+            "Skill '{}' is a local skill (adopted or added from an archive) with no source to reinstall from",
+            full_name
+        );
+    }
+
+    let dest = db.skill_dir(&get_skills_install_dir()?, &installed.tap, &installed.skill);
+    say!("{} Reinstalling '{}'", "=>".green().bold(), full_name);
+
+    let mut gist_updated_at = installed.gist_updated_at.clone();
+    let commit = if installed.gist_updated_at.is_some() {
+        let gist_id = installed
+            .source_path
+            .as_deref()
+            .with_context(|| format!("No gist recorded for '{}'", full_name))?;
+        let gist = fetch_gist(gist_id)?;
+        let skills = discover_skills_from_gist(&gist);
+        let (_, content) = skills
+            .iter()
+            .find(|(name, _)| *name == installed.skill)
+            .with_context(|| format!("Skill '{}' is no longer in gist {}", installed.skill, gist_id))?;
+        if dest.exists() {
+            log_action(format!("remove {}", dest.display()));
+            std::fs::remove_dir_all(&dest)?;
+        }
+        std::fs::create_dir_all(&dest)?;
+        std::fs::write(dest.join("SKILL.md"), content)?;
+        say!("  {} Installed from gist", "✓".green());
+        gist_updated_at = Some(gist.updated_at);
+        installed.commit.clone()
+    } else {
+        let tap = db::get_tap(&db, &installed.tap)
+            .with_context(|| format!("Tap '{}' not found", installed.tap))?
+            .clone();
+        let is_default_tap = tap.is_default || installed.tap == DEFAULT_TAP_NAME;
+
+        if is_default_tap && installed.commit.is_none() {
+            install_from_local(&installed.skill, &dest)?;
+            say!("  {} Installed from bundled skills (no network required)", "✓".green());
+            None
+        } else {
+            // Skills added by URL record their path; tap installs look it up in the registry
+            let skill_path = match installed.source_path.clone() {
+                Some(path) => path,
+                None => get_tap_registry(&mut db, &installed.tap)?
+                    .and_then(|registry| registry.skills.get(&installed.skill).map(|e| e.path.clone()))
+                    .with_context(|| {
+                        format!(
+                            "Skill '{}' not found in the cached registry for tap '{}'",
+                            installed.skill, installed.tap
+                        )
+                    })?,
+            };
+
+            match installed.commit.as_deref() {
+                Some(tag) if !is_commit_sha(tag) => {
+                    install_from_tag(&tap.url, tag, &skill_path, &dest)?;
+                    say!("  {} Installed from tag {}", "✓".green(), tag);
+                    installed.commit.clone()
+                }
+                // Reinstalling keeps the recorded commit; moving to a newer one is `update`'s job
+                Some(recorded) => {
+                    let commit = install_from_commit(&tap.url, recorded, &skill_path, &dest)
+                        .with_context(|| format!("Could not reinstall '{}' at its recorded commit", full_name))?;
+                    say!(
+                        "  {} Installed at commit {}",
+                        "✓".green(),
+                        display_commit(&commit, false)
+                    );
+                    Some(commit)
+                }
+                None if installed.branch.is_some() => {
+                    let branch = installed.branch.as_deref().unwrap_or_default();
+                    let commit = install_from_branch(&tap.url, branch, &skill_path, &dest)?;
+                    say!("  {} Installed from branch {}", "✓".green(), branch);
+                    Some(commit)
+                }
+                None => {
+                    let commit =
+                        install_from_clone(&installed.tap, &tap.url, &skill_path, &dest, tap.branch.as_deref())?;
+                    say!("  {} Installed from local tap clone", "✓".green());
+                    commit
+                }
+            }
+        }
+    };
+
+    if let Some(note) = skipped_hook_note(&dest) {
+        say!("{}", note);
+    }
+
+    let content_sha256 = if commit.is_some() && gist_updated_at.is_none() {
+        hash_dir_contents(&dest).ok()
+    } else {
+        None
+    };
+    if let Some(skill) = db.installed.get_mut(&full_name) {
+        skill.commit = commit;
+        skill.installed_at = Utc::now();
+        skill.gist_updated_at = gist_updated_at;
+        skill.content_sha256 = content_sha256;
+        skill.size_bytes = Some(dir_size(&dest));
+    }
     db::save_db(&db)?;
 
-    println!("{} Uninstalled '{}'", "✓".green(), skill_id.full_name());
+    say!("{} Reinstalled '{}' to {}", "✓".green(), full_name, dest.display());
+
+    Ok(())
+}
 
+/// Remove the install directory of `tap` (and its owner directory) if nothing is left in it.
+///
+/// Another skillshub process may install into the same tap at any moment, so a directory
+/// that is already gone or no longer empty is left alone instead of failing the uninstall.
+pub(crate) fn remove_empty_tap_dir(install_dir: &Path, tap: &str) -> Result<()> {
+    let tap_dir = install_dir.join(tap);
+    for dir in tap_dir.ancestors().take_while(|dir| *dir != install_dir) {
+        match std::fs::remove_dir(dir) {
+            Ok(()) => log_action(format!("remove empty directory {}", dir.display())),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(_) if dir.read_dir().is_ok_and(|mut entries| entries.next().is_some()) => return Ok(()),
+            Err(e) => {
+                return Err(e).with_context(|| format!("Failed to remove empty directory {}", dir.display()));
+            }
+        }
+    }
     Ok(())
 }
 
-/// Update a skill (or all skills) to latest version
-pub fn update_skill(full_name: Option<&str>) -> Result<()> {
+/// Set (or with `None`, clear) the name an installed skill is linked under in agent directories.
+///
+/// Existing agent links to the skill are replaced by links under the new name.
+pub fn alias_skill(full_name: &str, alias: Option<&str>) -> Result<()> {
+    let skill_id = SkillId::parse(full_name)
+        .with_context(|| format!("Invalid skill name '{}'. Use format: tap/skill", full_name))?;
+    let full_name = skill_id.full_name();
+
     let mut db = db::init_db()?;
+    if !db::is_skill_installed(&db, &full_name) {
+        anyhow::bail!("Skill '{}' is not installed", full_name);
+    }
+
+    // Aliasing a skill to its own name is the same as having no alias
+    let alias = alias.map(str::trim).filter(|a| *a != skill_id.skill);
+    if let Some(alias) = alias {
+        validate_skill_name(alias)?;
+        if let Some((other, _)) = db
+            .installed
+            .iter()
+            .find(|(name, s)| **name != full_name && s.alias.as_deref() == Some(alias))
+        {
+            anyhow::bail!("Alias '{}' is already used by '{}'", alias, other);
+        }
+    }
+
+    let install_dir = get_skills_install_dir()?;
+    let skill_path = db.skill_dir(&install_dir, &skill_id.tap, &skill_id.skill);
+    let agent_links = find_agent_links_to(&skill_path.canonicalize().unwrap_or_else(|_| skill_path.clone()));
+
+    if let Some(installed) = db.installed.get_mut(&full_name) {
+        installed.alias = alias.map(str::to_string);
+    }
+    // The alias replaces any disambiguated name chosen by `link`
+    db.link_names.remove(&full_name);
+    db::save_db(&db)?;
+
+    match alias {
+        Some(alias) => say!("{} '{}' will be linked as '{}'", "✓".green(), full_name, alias),
+        None => say!("{} '{}' will be linked under its own name", "✓".green(), full_name),
+    }
+
+    // Re-link agents that already had the skill so they pick up the new name
+    if remove_agent_links(&agent_links) > 0 {
+        link_to_agents(&RunContext::default())?;
+    }
+
+    Ok(())
+}
+
+/// Whether `update` re-links agents after refreshing skill files
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UpdateLinkMode {
+    /// Re-link only when at least one skill was updated
+    Auto,
+    /// Never re-link (`--skill-only`)
+    SkillOnly,
+    /// Always re-link (`--link`)
+    Always,
+}
+
+/// Update a skill (or all skills) to latest version. Returns how many skills were updated.
+///
+/// With `check` (`update --check`), only compares installed commits against the tap
+/// registries and reports the available updates, returning how many there are.
+pub fn update_skill(
+    ctx: &RunContext,
+    full_name: Option<&str>,
+    link_mode: UpdateLinkMode,
+    check: bool,
+) -> Result<usize> {
+    // Checking shares the dry-run code path but without the "would ..." lines
+    let preview = ctx.dry_run || check;
+    let plan = |action: String| {
+        if ctx.dry_run {
+            ctx.would(action)
+        }
+    };
+    let mut db = db::init_db_with(&RunContext::new(preview))?;
 
     let skills_to_update: Vec<String> = match full_name {
         Some(name) => {
@@ -483,17 +1697,20 @@ pub fn update_skill(full_name: Option<&str>) -> Result<()> {
     };
 
     if skills_to_update.is_empty() {
-        println!("No skills installed to update.");
-        return Ok(());
+        say!("No skills installed to update.");
+        return Ok(0);
     }
 
-    println!(
-        "{} Checking {} skill(s) for updates...",
+    say!(
+        "{}{} Checking {} skill(s) for updates...",
+        ctx.prefix(),
         "=>".green().bold(),
         skills_to_update.len()
     );
 
     let mut updated_count = 0;
+    // Tap name -> upstream HEAD, so each tap is pulled (or ls-remoted) at most once per run
+    let mut refreshed_heads: std::collections::HashMap<String, String> = std::collections::HashMap::new();
 
     for skill_name in skills_to_update {
         let installed = db.installed.get(&skill_name).unwrap().clone();
@@ -504,7 +1721,7 @@ pub fn update_skill(full_name: Option<&str>) -> Result<()> {
                 match fetch_gist(gist_id) {
                     Ok(gist) => {
                         if Some(&gist.updated_at) == installed.gist_updated_at.as_ref() {
-                            println!("  {} {} (up to date)", "✓".green(), skill_name);
+                            say!("  {}{} {} (up to date)", ctx.prefix(), "✓".green(), skill_name);
                             continue;
                         }
 
@@ -515,77 +1732,139 @@ pub fn update_skill(full_name: Option<&str>) -> Result<()> {
                         match skill_content {
                             Some((_, content)) => {
                                 let install_dir = get_skills_install_dir()?;
-                                let dest = install_dir.join(&installed.tap).join(&installed.skill);
+                                let dest = db.skill_dir(&install_dir, &installed.tap, &installed.skill);
+
+                                if preview {
+                                    plan(format!("write {}", dest.join("SKILL.md").display()));
+                                    plan(format!(
+                                        "update db.json entry installed[\"{}\"] (gist_updated_at: {})",
+                                        skill_name, gist.updated_at
+                                    ));
+                                    let note = if check { "gist update available" } else { "gist updated" };
+                                    say!("  {}{} {} ({})", ctx.prefix(), "✓".green(), skill_name, note);
+                                    updated_count += 1;
+                                    continue;
+                                }
+
                                 std::fs::create_dir_all(&dest)?;
                                 std::fs::write(dest.join("SKILL.md"), content)?;
 
                                 if let Some(skill) = db.installed.get_mut(&skill_name) {
                                     skill.gist_updated_at = Some(gist.updated_at.clone());
                                     skill.installed_at = Utc::now();
+                                    skill.size_bytes = Some(dir_size(&dest));
                                 }
 
-                                println!("  {} {} (gist updated)", "✓".green(), skill_name,);
+                                say!("  {} {} (gist updated)", "✓".green(), skill_name,);
                                 updated_count += 1;
                             }
                             None => {
-                                println!("  {} {} (skill no longer found in gist)", "✗".red(), skill_name);
+                                say!("  {} {} (skill no longer found in gist)", "✗".red(), skill_name);
                             }
                         }
                     }
                     Err(e) => {
-                        println!("  {} {} ({})", "✗".red(), skill_name, e);
+                        say!("  {} {} ({})", "✗".red(), skill_name, e);
                     }
                 }
                 continue;
             }
         }
 
+        // Installs pinned with @<tag> record the tag instead of a SHA
+        if let Some(tag) = installed.commit.as_deref().filter(|c| !is_commit_sha(c)) {
+            say!(
+                "  {}{} {} (pinned to {}, skipped)",
+                ctx.prefix(),
+                "○".yellow(),
+                skill_name,
+                tag
+            );
+            continue;
+        }
+
+        // Local skills (adopted, or added from an archive) have no upstream to update from
+        if installed.tap == LOCAL_TAP_NAME {
+            say!("  {}{} {} (local, skipped)", ctx.prefix(), "○".yellow(), skill_name);
+            continue;
+        }
+
         let tap = match db::get_tap(&db, &installed.tap) {
             Some(t) => t.clone(),
             None => {
-                println!("  {} {} (tap not found)", "✗".red(), skill_name);
+                say!("  {} {} (tap not found)", "✗".red(), skill_name);
                 continue;
             }
         };
 
-        let registry = match get_tap_registry(&db, &installed.tap) {
-            Ok(Some(r)) => r,
-            Ok(None) => {
-                println!(
-                    "  {} {} (no cached registry, run 'skillshub tap update')",
-                    "✗".red(),
-                    skill_name
-                );
-                continue;
-            }
+        let registry = match get_tap_registry(&mut db, &installed.tap) {
+            Ok(r) => r,
             Err(e) => {
-                println!("  {} {} ({})", "✗".red(), skill_name, e);
+                say!("  {} {} ({})", "✗".red(), skill_name, e);
                 continue;
             }
         };
 
-        let skill_entry = match registry.skills.get(&installed.skill) {
-            Some(e) => e,
-            None => {
-                println!("  {} {} (not in registry)", "✗".red(), skill_name);
-                continue;
-            }
+        // Skills added by URL record their path, so they update from the tap clone even
+        // when the tap's registry does not list them (or there is no registry at all)
+        let (skill_path, registry_commit) = match registry.as_ref().and_then(|r| r.skills.get(&installed.skill)) {
+            Some(entry) => (entry.path.clone(), entry.commit.clone()),
+            None => match installed.source_path.clone() {
+                Some(path) => (path, None),
+                None if registry.is_none() => {
+                    say!(
+                        "  {} {} (no cached registry, run 'skillshub tap update')",
+                        "✗".red(),
+                        skill_name
+                    );
+                    continue;
+                }
+                None => {
+                    say!("  {} {} (not in registry)", "✗".red(), skill_name);
+                    continue;
+                }
+            },
         };
 
         let install_dir = get_skills_install_dir()?;
-        let dest = install_dir.join(&installed.tap).join(&installed.skill);
+        let dest = db.skill_dir(&install_dir, &installed.tap, &installed.skill);
         let is_default_tap = tap.is_default || installed.tap == DEFAULT_TAP_NAME;
 
         // For default tap skills installed locally (commit=None), refresh from local bundled dir.
-        // These are never compared by commit SHA, so always attempt a local-first refresh.
+        // These have no commit SHA, so the bundled copy's contents are compared instead.
         if is_default_tap && installed.commit.is_none() {
+            let source = get_embedded_skills_dir()?.join(&installed.skill);
+            // A hash that cannot be taken (e.g. a deleted install) counts as changed
+            let unchanged = matches!(
+                (hash_dir_contents(&source), hash_dir_contents(&dest)),
+                (Ok(source_hash), Ok(dest_hash)) if source_hash == dest_hash
+            );
+            if unchanged {
+                say!("  {}{} {} (up to date)", ctx.prefix(), "✓".green(), skill_name);
+                continue;
+            }
+            if check {
+                say!("  {} {} (bundled, update available)", "✓".green(), skill_name);
+                updated_count += 1;
+                continue;
+            }
+            if ctx.dry_run {
+                plan(format!("copy {} -> {}", source.display(), dest.display()));
+                say!("  {}{} {} (bundled, refreshed)", ctx.prefix(), "✓".green(), skill_name);
+                updated_count += 1;
+                continue;
+            }
             match install_from_local(&installed.skill, &dest) {
                 Ok(()) => {
-                    println!("  {} {} (bundled, refreshed)", "✓".green(), skill_name);
+                    if let Some(skill) = db.installed.get_mut(&skill_name) {
+                        skill.installed_at = Utc::now();
+                        skill.size_bytes = Some(dir_size(&dest));
+                    }
+                    say!("  {} {} (bundled, refreshed)", "✓".green(), skill_name);
                     updated_count += 1;
                 }
                 Err(e) => {
-                    println!("  {} {} ({})", "✗".red(), skill_name, e);
+                    say!("  {} {} ({})", "✗".red(), skill_name, e);
                 }
             }
             continue;
@@ -594,7 +1873,69 @@ pub fn update_skill(full_name: Option<&str>) -> Result<()> {
         // Update from local clone for non-gist, non-default taps
         if is_gist_url(&tap.url) {
             // Gist taps without gist_updated_at shouldn't reach here, but guard anyway
-            println!("  {} {} (unexpected state for gist skill)", "✗".red(), skill_name);
+            say!("  {} {} (unexpected state for gist skill)", "✗".red(), skill_name);
+            continue;
+        }
+
+        // Skills installed with --branch follow that branch through a one-off clone
+        if let Some(branch) = installed.branch.clone() {
+            let new_commit = match git_remote_head_sha(&tap.url, Some(&branch)) {
+                Ok(sha) => sha,
+                Err(e) => {
+                    say!("  {}{} {} ({})", ctx.prefix(), "✗".red(), skill_name, e);
+                    continue;
+                }
+            };
+            let old_commit = display_commit(installed.commit.as_deref().unwrap_or("unknown"), false);
+            if same_commit(old_commit, &new_commit) {
+                say!(
+                    "  {}{} {} (up to date on {})",
+                    ctx.prefix(),
+                    "✓".green(),
+                    skill_name,
+                    branch
+                );
+                continue;
+            }
+            if preview {
+                plan(format!(
+                    "clone {} at branch {} into a temporary directory",
+                    tap.url, branch
+                ));
+                plan(format!("copy {} -> {}", skill_path, dest.display()));
+                say!(
+                    "  {}{} {} ({}{} -> {} on {})",
+                    ctx.prefix(),
+                    "✓".green(),
+                    skill_name,
+                    if check { "update available: " } else { "" },
+                    old_commit,
+                    display_commit(&new_commit, false),
+                    branch
+                );
+                updated_count += 1;
+                continue;
+            }
+            match install_from_branch(&tap.url, &branch, &skill_path, &dest) {
+                Ok(commit) => {
+                    say!(
+                        "  {} {} ({} -> {} on {})",
+                        "✓".green(),
+                        skill_name,
+                        old_commit,
+                        display_commit(&commit, false),
+                        branch
+                    );
+                    if let Some(skill) = db.installed.get_mut(&skill_name) {
+                        skill.commit = Some(commit);
+                        skill.installed_at = Utc::now();
+                        skill.content_sha256 = hash_dir_contents(&dest).ok();
+                        skill.size_bytes = Some(dir_size(&dest));
+                    }
+                    updated_count += 1;
+                }
+                Err(e) => say!("  {} {} ({})", "✗".red(), skill_name, e),
+            }
             continue;
         }
 
@@ -602,7 +1943,7 @@ pub fn update_skill(full_name: Option<&str>) -> Result<()> {
         let clone_dir = tap_clone_path(&taps_dir, &installed.tap);
 
         if !clone_dir.exists() {
-            println!(
+            say!(
                 "  {} {} (No local clone for tap '{}'. Run 'skillshub tap update' to create one.)",
                 "✗".red(),
                 skill_name,
@@ -611,60 +1952,300 @@ pub fn update_skill(full_name: Option<&str>) -> Result<()> {
             continue;
         }
 
-        // Pull latest using resilient pull_or_reclone
-        if let Err(e) = super::git::pull_or_reclone(&clone_dir, &tap.url, tap.branch.as_deref()) {
-            println!("  {} {} (pull failed: {})", "✗".red(), skill_name, e);
+        // A fresh registry recorded the clone's HEAD when it was refreshed, so while the clone
+        // is still there the pull (or ls-remote) can be skipped
+        let cached_head = registry_commit
+            .filter(|_| registry_is_fresh(&db, &installed.tap))
+            .and_then(|sha| git_head_sha(&clone_dir).ok().filter(|head| same_commit(&sha, head)));
+
+        let new_commit = if let Some(sha) = refreshed_heads.get(&installed.tap) {
+            // An earlier skill from the same tap already refreshed it during this run
+            sha.clone()
+        } else if let Some(head) = cached_head.clone() {
+            head
+        } else if preview {
+            // Resolve the remote head without pulling into the clone
+            match git_remote_head_sha(&tap.url, tap.branch.as_deref()) {
+                Ok(sha) => sha,
+                Err(e) => {
+                    say!(
+                        "  {}{} {} (ls-remote failed: {})",
+                        ctx.prefix(),
+                        "✗".red(),
+                        skill_name,
+                        e
+                    );
+                    continue;
+                }
+            }
+        } else {
+            // Pull latest using resilient pull_or_reclone
+            if let Err(e) = super::git::pull_or_reclone(&clone_dir, &tap.url, tap.branch.as_deref()) {
+                say!("  {} {} (pull failed: {})", "✗".red(), skill_name, e);
+                continue;
+            }
+            git_head_sha(&clone_dir).unwrap_or_default()
+        };
+        refreshed_heads.insert(installed.tap.clone(), new_commit.clone());
+
+        if installed.commit.as_deref().is_some_and(|c| same_commit(c, &new_commit)) {
+            warn_if_content_changed(
+                &skill_name,
+                installed.content_sha256.as_deref(),
+                &clone_dir.join(&skill_path),
+            );
+            say!("  {}{} {} (up to date)", ctx.prefix(), "✓".green(), skill_name);
             continue;
         }
 
-        let new_commit = git_head_sha(&clone_dir).unwrap_or_default();
-
-        if installed.commit.as_deref() == Some(&new_commit) {
-            println!("  {} {} (up to date)", "✓".green(), skill_name);
+        let old_commit = display_commit(installed.commit.as_deref().unwrap_or("unknown"), false);
+        if preview {
+            if cached_head.is_none() {
+                plan(format!("pull {}", clone_dir.display()));
+            }
+            plan(format!(
+                "copy {} -> {}",
+                clone_dir.join(&skill_path).display(),
+                dest.display()
+            ));
+            plan(format!(
+                "update db.json entry installed[\"{}\"] (commit: {})",
+                skill_name, new_commit
+            ));
+            say!(
+                "  {}{} {} ({}{} -> {})",
+                ctx.prefix(),
+                "✓".green(),
+                skill_name,
+                if check { "update available: " } else { "" },
+                old_commit,
+                display_commit(&new_commit, false)
+            );
+            updated_count += 1;
             continue;
         }
 
         // Copy updated files from clone
-        match install_from_clone(
-            &installed.tap,
-            &tap.url,
-            &skill_entry.path,
-            &dest,
-            tap.branch.as_deref(),
-        ) {
+        match install_from_clone(&installed.tap, &tap.url, &skill_path, &dest, tap.branch.as_deref()) {
             Ok(commit) => {
-                let old_commit = installed.commit.as_deref().unwrap_or("unknown");
                 if let Some(skill) = db.installed.get_mut(&skill_name) {
                     skill.commit = commit;
                     skill.installed_at = Utc::now();
+                    skill.content_sha256 = hash_dir_contents(&dest).ok();
+                    skill.size_bytes = Some(dir_size(&dest));
                 }
-                println!("  {} {} ({} -> {})", "✓".green(), skill_name, old_commit, new_commit);
+                say!(
+                    "  {} {} ({} -> {})",
+                    "✓".green(),
+                    skill_name,
+                    old_commit,
+                    display_commit(&new_commit, false)
+                );
                 updated_count += 1;
             }
             Err(e) => {
-                println!("  {} {} ({})", "✗".red(), skill_name, e);
+                say!("  {} {} ({})", "✗".red(), skill_name, e);
             }
         }
     }
 
-    db::save_db(&db)?;
+    if check {
+        save_refreshed_registries(&db)?;
+        say!("\n{} {} update(s) available", "Done!".green().bold(), updated_count);
+        return Ok(updated_count);
+    }
 
-    println!("\n{} {} skill(s) updated", "Done!".green().bold(), updated_count);
+    if !ctx.dry_run {
+        db::save_db(&db)?;
+    }
 
-    Ok(())
-}
+    say!(
+        "\n{}{} {} skill(s) updated",
+        ctx.prefix(),
+        "Done!".green().bold(),
+        updated_count
+    );
 
-/// List all available and installed skills
-pub fn list_skills() -> Result<()> {
-    let db = db::init_db()?;
+    let relink = match link_mode {
+        UpdateLinkMode::Auto => updated_count > 0,
+        UpdateLinkMode::SkillOnly => false,
+        UpdateLinkMode::Always => true,
+    };
+    if relink {
+        say!();
+        link_to_agents(ctx)?;
+    }
+
+    Ok(updated_count)
+}
+
+/// Warn when a skill's source files no longer match the hash recorded at install time.
+///
+/// Called when the commit SHA is unchanged: identical commits should have identical
+/// content, so a mismatch points at a force-push, a rewritten clone, or a supply-chain
+/// change. Returns `true` when a mismatch was reported.
+fn warn_if_content_changed(skill_name: &str, recorded: Option<&str>, source: &std::path::Path) -> bool {
+    let Some(recorded) = recorded else {
+        return false;
+    };
+
+    match hash_dir_contents(source) {
+        Ok(current) if current != recorded => {
+            eprintln!(
+                "  {} {} (content changed but commit did not; possible force-push or supply-chain change)",
+                "!".yellow().bold(),
+                skill_name
+            );
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Parse the SKILL.md of an installed skill directory, if present
+fn read_installed_metadata(skill_dir: &std::path::Path) -> Option<SkillMetadata> {
+    let skill_md = skill_dir.join("SKILL.md");
+    if !skill_md.exists() {
+        return None;
+    }
+    parse_skill_metadata(&skill_md).ok()
+}
+
+/// Read SKILL.md of a skill that is not installed, from the bundled skills for the default
+/// tap or from the tap clone otherwise, so `info` can show what it asks for before install
+fn available_metadata(db: &Database, skill_id: &SkillId, entry: &SkillEntry) -> Option<SkillMetadata> {
+    let tap = db::get_tap(db, &skill_id.tap)?;
+    let source = if tap.is_default || skill_id.tap == DEFAULT_TAP_NAME {
+        get_embedded_skills_dir().ok()?.join(&skill_id.skill)
+    } else {
+        validate_skill_path(&entry.path).ok()?;
+        get_tap_clone_dir(&skill_id.tap).ok()?.join(&entry.path)
+    };
+    read_installed_metadata(&source)
+}
+
+/// List all available and installed skills
+///
+/// `limit` caps the number of rows shown; the summary still counts every skill. With `wrap`,
+/// descriptions are shown in full, wrapped to the terminal width.
+pub fn list_skills(tag: Option<&str>, source: Option<SkillSource>, limit: Option<usize>, wrap: bool) -> Result<()> {
+    let mut db = db::init_db()?;
+    let (mut rows, uncached_taps) = collect_list_rows(&mut db, tag, source)?;
+    save_refreshed_registries(&db)?;
+
+    if rows.is_empty() {
+        if let Some(t) = tag {
+            println!("No skills tagged '{}'", t);
+            return Ok(());
+        }
+        if let Some(s) = source {
+            println!("No {} skills", s);
+            return Ok(());
+        }
+        println!("No skills available.");
+        println!("  - Add a skill from URL: skillshub add <github-url>");
+        println!("  - Install from default tap: skillshub install skillshub/<skill>");
+        return Ok(());
+    }
+
+    // Sort by tap, then name
+    rows.sort_by(|a, b| (&a.tap, &a.name).cmp(&(&b.tap, &b.name)));
+
+    let installed_count = rows.iter().filter(|r| r.status == "✓").count();
+    let total_count = rows.len();
+    let install_dir = get_skills_install_dir()?;
+    let installed_size: u64 = rows
+        .iter()
+        .filter_map(|r| db.installed.get(&format!("{}/{}", r.tap, r.name)))
+        .map(|i| {
+            // Older installs have no recorded size, so measure the directory instead
+            i.size_bytes
+                .unwrap_or_else(|| dir_size(&db.skill_dir(&install_dir, &i.tap, &i.skill)))
+        })
+        .sum();
+
+    for row in &mut rows {
+        if let Some(alias) = db
+            .installed
+            .get(&format!("{}/{}", row.tap, row.name))
+            .and_then(|i| i.alias.as_deref())
+        {
+            row.name = format!("{} (as {})", row.name, alias);
+        }
+    }
+
+    let (rows, hidden) = take_limited(rows, limit);
+    let table = render_skill_table(rows, wrap, DESCRIPTION_MAX_LEN);
+
+    println!("{}", table);
+    if let Some(more) = more_line(hidden) {
+        println!("{}", more);
+    }
+    println!();
+    println!(
+        "{} installed ({}), {} total",
+        installed_count.to_string().green(),
+        format_size(installed_size),
+        total_count
+    );
+
+    if !uncached_taps.is_empty() {
+        println!(
+            "\n{} {} tap(s) have no cached registry: {}.\n  Run 'skillshub tap update' to fetch the full registry.",
+            "Note:".yellow().bold(),
+            uncached_taps.len(),
+            uncached_taps.join(", ")
+        );
+    }
+    print_stale_registry_note()?;
+
+    Ok(())
+}
+
+/// Render `list` and `search` rows as a table.
+///
+/// Descriptions are cut to `max_len` characters by default. With `wrap` they are kept whole and the
+/// table is wrapped to the terminal width, narrowing the widest column first; when stdout is
+/// not a terminal the full descriptions are printed unwrapped.
+fn render_skill_table(mut rows: Vec<SkillListRow>, wrap: bool, max_len: usize) -> String {
+    if !wrap {
+        for row in &mut rows {
+            row.description = truncate_string(&row.description, max_len);
+        }
+    }
+
+    let mut table = Table::new(rows);
+    table.with(Style::rounded()).with(Padding::new(1, 1, 0, 1));
+    if let Some(width) = terminal_width().filter(|_| wrap) {
+        table.with(Width::wrap(width).keep_words(true).priority(PriorityMax::right()));
+    }
+    table.to_string()
+}
+
+/// Source of a skill listed in a tap registry.
+/// Skills not yet installed come from their tap: bundled for the default tap.
+fn registry_skill_source(tap_name: &str, tap: &TapInfo, installed: Option<&InstalledSkill>) -> SkillSource {
+    match installed {
+        Some(i) => SkillSource::of_installed(i, Some(&tap.url)),
+        None if tap.is_default || tap_name == DEFAULT_TAP_NAME => SkillSource::Local,
+        None => SkillSource::Remote,
+    }
+}
 
+/// Build the `list` rows matching `tag` and `source`, plus the taps that have no cached registry
+fn collect_list_rows(
+    db: &mut Database,
+    tag: Option<&str>,
+    source: Option<SkillSource>,
+) -> Result<(Vec<SkillListRow>, Vec<String>)> {
     let mut rows: Vec<SkillListRow> = Vec::new();
     let mut seen_skills: std::collections::HashSet<String> = std::collections::HashSet::new();
 
     // Collect skills from all taps (available skills)
     let mut uncached_taps: Vec<String> = Vec::new();
-    for tap_name in db.taps.keys() {
-        let registry = match get_tap_registry(&db, tap_name) {
+    let tap_names: Vec<String> = db.taps.keys().cloned().collect();
+    for tap_name in &tap_names {
+        let registry = match get_tap_registry(db, tap_name) {
             Ok(Some(r)) => r,
             Ok(None) => {
                 uncached_taps.push(tap_name.clone());
@@ -672,20 +2253,32 @@ pub fn list_skills() -> Result<()> {
             }
             Err(_) => continue,
         };
+        let tap = &db.taps[tap_name];
 
         for (skill_name, entry) in &registry.skills {
             let full_name = format!("{}/{}", tap_name, skill_name);
             seen_skills.insert(full_name.clone());
+            if tag.is_some_and(|t| !has_tag(&entry.tags, t)) {
+                continue;
+            }
             let installed = db.installed.get(&full_name);
 
+            let skill_source = registry_skill_source(tap_name, tap, installed);
+            if source.is_some_and(|s| s != skill_source) {
+                continue;
+            }
+
             let status = if installed.is_some() { "✓" } else { "○" };
-            let commit = installed.and_then(|i| i.commit.clone()).unwrap_or_else(|| {
-                if installed.is_some() {
-                    "local".to_string()
-                } else {
-                    "-".to_string()
-                }
-            });
+            let commit = installed.and_then(|i| i.commit.as_deref()).map_or_else(
+                || {
+                    if installed.is_some() {
+                        "local".to_string()
+                    } else {
+                        "-".to_string()
+                    }
+                },
+                |c| display_commit(c, false).to_string(),
+            );
 
             // Check has_scripts/has_references for installed skills
             let extras = if installed.is_some() {
@@ -703,10 +2296,11 @@ pub fn list_skills() -> Result<()> {
                 status,
                 name: skill_name.clone(),
                 tap: tap_name.clone(),
-                description: truncate_string(
-                    entry.description.as_deref().unwrap_or("No description"),
-                    DESCRIPTION_MAX_LEN,
-                ),
+                source: skill_source,
+                description: entry
+                    .description
+                    .clone()
+                    .unwrap_or_else(|| "No description".to_string()),
                 extras,
                 commit,
             });
@@ -719,95 +2313,133 @@ pub fn list_skills() -> Result<()> {
             continue;
         }
 
+        let tap_url = db.taps.get(&installed.tap).map(|t| t.url.as_str());
+        let skill_source = SkillSource::of_installed(installed, tap_url);
+        if source.is_some_and(|s| s != skill_source) {
+            continue;
+        }
+
         // Get description from installed skill's SKILL.md if available
         let install_dir = get_skills_install_dir()?;
-        let skill_md_path = install_dir.join(&installed.tap).join(&installed.skill).join("SKILL.md");
+        let skill_md_path = db
+            .skill_dir(&install_dir, &installed.tap, &installed.skill)
+            .join("SKILL.md");
 
-        let description = if skill_md_path.exists() {
-            crate::skill::parse_skill_metadata(&skill_md_path)
-                .ok()
-                .and_then(|m| m.description)
-                .unwrap_or_else(|| "Added from URL".to_string())
+        let metadata = if skill_md_path.exists() {
+            crate::skill::parse_skill_metadata(&skill_md_path).ok()
         } else {
-            "Added from URL".to_string()
+            None
         };
 
-        let skill_dir = install_dir.join(&installed.tap).join(&installed.skill);
+        if let Some(t) = tag {
+            let tags = metadata.as_ref().map(|m| m.tags.as_slice()).unwrap_or_default();
+            if !has_tag(tags, t) {
+                continue;
+            }
+        }
+
+        let description = metadata
+            .and_then(|m| m.description)
+            .unwrap_or_else(|| "Added from URL".to_string());
+
+        let skill_dir = db.skill_dir(&install_dir, &installed.tap, &installed.skill);
 
         rows.push(SkillListRow {
             status: "✓",
             name: installed.skill.clone(),
             tap: installed.tap.clone(),
-            description: truncate_string(&description, DESCRIPTION_MAX_LEN),
+            source: skill_source,
+            description,
             extras: format_extras(has_scripts_dir(&skill_dir), has_references_dir(&skill_dir)),
             commit: installed.commit.clone().unwrap_or_else(|| "-".to_string()),
         });
     }
 
-    if rows.is_empty() {
-        println!("No skills available.");
-        println!("  - Add a skill from URL: skillshub add <github-url>");
-        println!("  - Install from default tap: skillshub install skillshub/<skill>");
+    Ok((rows, uncached_taps))
+}
+
+/// Search for skills across all taps
+///
+/// `installed` restricts results to installed (`Some(true)`) or not-installed (`Some(false)`) skills,
+/// `limit` caps the number of rows shown, and `wrap` shows descriptions in full as `list` does.
+pub fn search_skills(
+    query: &str,
+    fuzzy: bool,
+    tag: Option<&str>,
+    installed: Option<bool>,
+    limit: Option<usize>,
+    wrap: bool,
+) -> Result<()> {
+    let mut db = db::init_db()?;
+
+    if db.taps.is_empty() {
+        println!("No taps configured. Run 'skillshub tap add <url>' to add one.");
         return Ok(());
     }
 
-    // Sort by tap, then name
-    rows.sort_by(|a, b| (&a.tap, &a.name).cmp(&(&b.tap, &b.name)));
+    let results = collect_search_results(&mut db, query, fuzzy, tag, installed);
+    save_refreshed_registries(&db)?;
 
-    let installed_count = rows.iter().filter(|r| r.status == "✓").count();
-    let total_count = rows.len();
+    if results.is_empty() {
+        let kind = match installed {
+            Some(true) => "installed skills",
+            Some(false) => "uninstalled skills",
+            None => "skills",
+        };
+        match tag {
+            Some(t) => println!("No {} tagged '{}' found matching '{}'", kind, t, query),
+            None => println!("No {} found matching '{}'", kind, query),
+        }
+        return Ok(());
+    }
 
-    let table = Table::new(rows)
-        .with(Style::rounded())
-        .with(Padding::new(1, 1, 0, 1))
-        .to_string();
+    let total = results.len();
+    let (results, hidden) = take_limited(results, limit);
+    let table = render_skill_table(results, wrap, 50);
 
     println!("{}", table);
-    println!();
-    println!(
-        "{} installed, {} total",
-        installed_count.to_string().green(),
-        total_count
-    );
-
-    if !uncached_taps.is_empty() {
-        println!(
-            "\n{} {} tap(s) have no cached registry: {}.\n  Run 'skillshub tap update' to fetch the full registry.",
-            "Note:".yellow().bold(),
-            uncached_taps.len(),
-            uncached_taps.join(", ")
-        );
+    if let Some(more) = more_line(hidden) {
+        println!("{}", more);
     }
+    println!();
+    println!("{} result(s) for '{}'", total, query);
+    print_stale_registry_note()?;
 
     Ok(())
 }
 
-/// Search for skills across all taps
-pub fn search_skills(query: &str) -> Result<()> {
-    let db = db::init_db()?;
-
-    if db.taps.is_empty() {
-        println!("No taps configured. Run 'skillshub tap add <url>' to add one.");
-        return Ok(());
-    }
-
+/// Build the `search` rows for `query`, best matches first
+fn collect_search_results(
+    db: &mut Database,
+    query: &str,
+    fuzzy: bool,
+    tag: Option<&str>,
+    installed_filter: Option<bool>,
+) -> Vec<SkillListRow> {
     let query_lower = query.to_lowercase();
-    let mut results: Vec<SkillListRow> = Vec::new();
+    let matcher = fuzzy.then(SkimMatcherV2::default);
+    let mut results: Vec<(i64, SkillListRow)> = Vec::new();
 
-    for tap_name in db.taps.keys() {
-        let registry = match get_tap_registry(&db, tap_name) {
+    let tap_names: Vec<String> = db.taps.keys().cloned().collect();
+    for tap_name in &tap_names {
+        let registry = match get_tap_registry(db, tap_name) {
             Ok(Some(r)) => r,
             Ok(None) | Err(_) => continue,
         };
+        let tap = &db.taps[tap_name];
 
         for (skill_name, entry) in &registry.skills {
-            let name_lower = skill_name.to_lowercase();
-            let desc_lower = entry.description.as_deref().unwrap_or("").to_lowercase();
-
-            if name_lower.contains(&query_lower) || desc_lower.contains(&query_lower) {
-                let full_name = format!("{}/{}", tap_name, skill_name);
-                let installed = db.installed.get(&full_name);
+            if tag.is_some_and(|t| !has_tag(&entry.tags, t)) {
+                continue;
+            }
+            let full_name = format!("{}/{}", tap_name, skill_name);
+            let installed = db.installed.get(&full_name);
+            if installed_filter.is_some_and(|want| want != installed.is_some()) {
+                continue;
+            }
+            let description = entry.description.as_deref().unwrap_or("");
 
+            if let Some(score) = score_skill_match(matcher.as_ref(), &query_lower, skill_name, description) {
                 let extras = if installed.is_some() {
                     if let Ok(idir) = get_skills_install_dir() {
                         let skill_dir = idir.join(tap_name).join(skill_name);
@@ -819,53 +2451,205 @@ pub fn search_skills(query: &str) -> Result<()> {
                     "-".to_string()
                 };
 
-                results.push(SkillListRow {
+                let row = SkillListRow {
                     status: if installed.is_some() { "✓" } else { "○" },
                     name: skill_name.clone(),
                     tap: tap_name.clone(),
-                    description: truncate_string(entry.description.as_deref().unwrap_or("No description"), 50),
+                    source: registry_skill_source(tap_name, tap, installed),
+                    description: entry
+                        .description
+                        .clone()
+                        .unwrap_or_else(|| "No description".to_string()),
                     extras,
                     commit: installed
-                        .and_then(|i| i.commit.clone())
-                        .unwrap_or_else(|| "-".to_string()),
-                });
+                        .and_then(|i| i.commit.as_deref())
+                        .map_or_else(|| "-".to_string(), |c| display_commit(c, false).to_string()),
+                };
+                results.push((score, row));
             }
         }
     }
 
-    if results.is_empty() {
-        println!("No skills found matching '{}'", query);
-        return Ok(());
+    // Best matches first; ties broken by name for stable output
+    results.sort_by(|(a_score, a), (b_score, b)| b_score.cmp(a_score).then_with(|| a.name.cmp(&b.name)));
+    results.into_iter().map(|(_, row)| row).collect()
+}
+
+/// Whether `tags` contains `tag`, ignoring case.
+fn has_tag(tags: &[String], tag: &str) -> bool {
+    tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+}
+
+/// Score a skill against a lowercase search query, or `None` if it does not match.
+///
+/// Substring matches on the name rank highest, then substring matches on the description.
+/// When a fuzzy `matcher` is given, typo-tolerant matches follow, with name matches weighted
+/// above description matches.
+fn score_skill_match(matcher: Option<&SkimMatcherV2>, query: &str, name: &str, description: &str) -> Option<i64> {
+    let name_lower = name.to_lowercase();
+    let desc_lower = description.to_lowercase();
+
+    if name_lower.contains(query) {
+        return Some(SUBSTRING_MATCH_BONUS * 2);
+    }
+    if desc_lower.contains(query) {
+        return Some(SUBSTRING_MATCH_BONUS);
     }
 
-    let table = Table::new(&results)
-        .with(Style::rounded())
-        .with(Padding::new(1, 1, 0, 1))
-        .to_string();
+    let matcher = matcher?;
+    let name_score = matcher.fuzzy_match(&name_lower, query).map(|s| s * 2);
+    let desc_score = matcher.fuzzy_match(&desc_lower, query);
+    let score = name_score.max(desc_score)?;
 
-    println!("{}", table);
-    println!();
-    println!("{} result(s) for '{}'", results.len(), query);
+    let min_score = MIN_FUZZY_SCORE_PER_CHAR * query.chars().count() as i64;
+    (score >= min_score).then_some(score)
+}
 
-    Ok(())
+/// Decide whether `info` should resolve `target` against GitHub instead of the local database
+///
+/// Returns the repository and skill name for GitHub URLs, and for `owner/repo/skill`
+/// ids whose tap has not been added and which are not installed.
+fn remote_info_target(db: &Database, target: &str) -> Result<Option<(GitHubUrl, String)>> {
+    let is_url = ["https://", "http://", "github.com/"]
+        .iter()
+        .any(|prefix| target.starts_with(prefix));
+
+    if is_url {
+        let github_url = parse_github_url(target)?;
+        let skill = github_url
+            .path
+            .as_deref()
+            .and_then(|path| path.rsplit('/').next())
+            .map(str::to_string)
+            .with_context(|| {
+                format!(
+                    "URL '{}' does not point to a skill directory (expected .../tree/<branch>/<path-to-skill>)",
+                    target
+                )
+            })?;
+        return Ok(Some((github_url, skill)));
+    }
+
+    let Some(skill_id) = SkillId::parse(target) else {
+        return Ok(None);
+    };
+    if !skill_id.tap.contains('/')
+        || db::get_tap(db, &skill_id.tap).is_some()
+        || db::is_skill_installed(db, &skill_id.full_name())
+    {
+        return Ok(None);
+    }
+
+    Ok(Some((parse_github_url(&skill_id.tap)?, skill_id.skill)))
+}
+
+/// Everything `info` knows about a skill, printed as text or serialized by `info --json`
+#[derive(Debug, Serialize)]
+struct SkillInfo {
+    name: String,
+    tap: String,
+    /// False when the skill was resolved from GitHub for a tap that has not been added
+    tap_added: bool,
+    /// Name the installed skill is linked under in agent directories, set by `alias`
+    alias: Option<String>,
+    description: Option<String>,
+    /// Path of the skill within its repository
+    path: Option<String>,
+    homepage: Option<String>,
+    license: Option<String>,
+    author: Option<String>,
+    version: Option<String>,
+    tags: Vec<String>,
+    /// Tools the skill's `allowed-tools` frontmatter permits, from the copy on disk
+    allowed_tools: Vec<String>,
+    installed: bool,
+    commit: Option<String>,
+    /// GitHub URL of the installed commit, see [`installed_commit_url`]
+    commit_url: Option<String>,
+    installed_at: Option<DateTime<Utc>>,
+    source_url: Option<String>,
+    size_bytes: Option<u64>,
+    /// The skill directory, when it exists on disk
+    local_path: Option<PathBuf>,
+    scripts: Vec<String>,
+    references: Vec<String>,
+}
+
+/// GitHub URL of the exact commit an installed skill was copied from
+///
+/// Built from the recorded source (or tap) URL, commit, and source path. `None` for
+/// bundled skills, gists, and repositories not hosted on GitHub.
+fn installed_commit_url(installed: &InstalledSkill, tap_url: Option<&str>) -> Option<String> {
+    let commit = installed.commit.as_deref()?;
+    let repo_url = installed.source_url.as_deref().or(tap_url)?;
+    if is_gist_url(repo_url) {
+        return None;
+    }
+    let github_url = parse_github_url(repo_url).ok()?;
+    Some(github_url.tree_url(commit, installed.source_path.as_deref().unwrap_or("")))
+}
+
+/// Resolve info for a skill from a tap that has not been added, without persisting anything
+fn fetch_remote_skill_info(github_url: &GitHubUrl, skill: &str) -> Result<SkillInfo> {
+    ensure_online("resolving a skill from an un-added tap")?;
+
+    let tap_name = format!("{}/{}", github_url.owner, github_url.repo);
+    let registry = discover_skills_from_repo(github_url, &tap_name, tap_token(None)?.as_deref())?;
+
+    // A URL names the skill directory, so prefer an exact path match over the name
+    let found = github_url
+        .path
+        .as_deref()
+        .and_then(|path| registry.skills.iter().find(|(_, entry)| entry.path == path))
+        .or_else(|| resolve_skill_entry(&registry, skill));
+
+    let Some((name, entry)) = found else {
+        anyhow::bail!("Skill '{}' not found in {}", skill, tap_name);
+    };
+
+    Ok(SkillInfo {
+        name: format!("{}/{}", tap_name, name),
+        tap: tap_name,
+        tap_added: false,
+        alias: None,
+        description: entry.description.clone(),
+        path: Some(entry.path.clone()),
+        homepage: entry.homepage.clone(),
+        license: None,
+        author: None,
+        version: None,
+        tags: entry.tags.clone(),
+        allowed_tools: Vec::new(),
+        installed: false,
+        commit: None,
+        commit_url: None,
+        installed_at: None,
+        source_url: None,
+        size_bytes: None,
+        local_path: None,
+        scripts: Vec::new(),
+        references: Vec::new(),
+    })
 }
 
-/// Show detailed info about a skill
-pub fn show_skill_info(full_name: &str) -> Result<()> {
+/// Gather info for a skill from the database, its tap registry, and the installed copy
+fn collect_skill_info(db: &mut Database, full_name: &str) -> Result<SkillInfo> {
     let skill_id = SkillId::parse(full_name)
         .with_context(|| format!("Invalid skill name '{}'. Use format: tap/skill", full_name))?;
 
-    let db = db::init_db()?;
     let install_dir = get_skills_install_dir()?;
 
-    // Check if installed
-    let installed = db::get_installed_skill(&db, &skill_id.full_name());
-
     // Try to get info from tap registry first
-    let tap_entry = db::get_tap(&db, &skill_id.tap)
-        .and_then(|_| get_tap_registry(&db, &skill_id.tap).ok())
-        .and_then(|opt| opt)
-        .and_then(|r| r.skills.get(&skill_id.skill).cloned());
+    let tap_entry = match db::get_tap(db, &skill_id.tap) {
+        Some(_) => get_tap_registry(db, &skill_id.tap)
+            .ok()
+            .flatten()
+            .and_then(|r| r.skills.get(&skill_id.skill).cloned()),
+        None => None,
+    };
+
+    // Check if installed
+    let installed = db::get_installed_skill(db, &skill_id.full_name());
 
     // If not in tap registry, check if it's installed (directly added skill)
     if tap_entry.is_none() && installed.is_none() {
@@ -875,216 +2659,873 @@ pub fn show_skill_info(full_name: &str) -> Result<()> {
         );
     }
 
-    println!("{}", skill_id.full_name().bold());
-    println!();
+    let skill_dir = db.skill_dir(&install_dir, &skill_id.tap, &skill_id.skill);
 
-    // Get description from tap entry or from installed skill's SKILL.md
-    let description = if let Some(entry) = &tap_entry {
-        entry.description.clone()
-    } else if installed.is_some() {
-        // Try to read from installed skill's SKILL.md
-        let skill_path = install_dir.join(&skill_id.tap).join(&skill_id.skill);
-        discover_skills(&install_dir.join(&skill_id.tap))
-            .ok()
-            .and_then(|skills| {
-                skills
-                    .into_iter()
-                    .find(|s| s.name == skill_id.skill || s.path == skill_path)
-                    .map(|s| s.description)
-            })
-    } else {
-        None
-    };
+    // Read metadata straight from the installed copy; `None` when not on disk.
+    // Note: license, author, and version are only shown for locally installed
+    // skills; they are not available for tap-available skills that have not been installed.
+    let installed_meta = read_installed_metadata(&skill_dir);
+    let version_meta = installed_meta.as_ref().and_then(|meta| meta.metadata.as_ref());
+
+    // Prefer the installed SKILL.md, falling back to the tap registry
+    let installed_skill_meta = installed_meta.as_ref().filter(|_| installed.is_some());
+    let description = installed_skill_meta
+        .and_then(|meta| meta.description.clone())
+        .or_else(|| tap_entry.as_ref().and_then(|entry| entry.description.clone()));
+    let tags = installed_skill_meta
+        .map(|meta| meta.tags.clone())
+        .filter(|tags| !tags.is_empty())
+        .or_else(|| tap_entry.as_ref().map(|entry| entry.tags.clone()))
+        .unwrap_or_default();
+
+    let on_disk = skill_dir.exists();
+
+    Ok(SkillInfo {
+        name: skill_id.full_name(),
+        tap: skill_id.tap.clone(),
+        tap_added: db::get_tap(db, &skill_id.tap).is_some(),
+        alias: installed.and_then(|inst| inst.alias.clone()),
+        description,
+        path: tap_entry.as_ref().map(|entry| entry.path.clone()),
+        homepage: tap_entry.as_ref().and_then(|entry| entry.homepage.clone()),
+        license: installed_meta.as_ref().and_then(|meta| meta.license.clone()),
+        author: version_meta.and_then(|vm| vm.author.clone()),
+        version: version_meta.and_then(|vm| vm.version.clone()),
+        tags,
+        allowed_tools: installed_meta
+            .or_else(|| available_metadata(db, &skill_id, tap_entry.as_ref()?))
+            .map(|meta| meta.allowed_tools.0)
+            .unwrap_or_default(),
+        installed: installed.is_some(),
+        commit: installed.and_then(|inst| inst.commit.clone()),
+        commit_url: installed.and_then(|inst| {
+            let tap_url = db::get_tap(db, &inst.tap).map(|tap| tap.url.as_str());
+            installed_commit_url(inst, tap_url)
+        }),
+        installed_at: installed.map(|inst| inst.installed_at),
+        source_url: installed.and_then(|inst| inst.source_url.clone()),
+        // Older installs have no recorded size, so measure the directory instead
+        size_bytes: installed.map(|inst| inst.size_bytes.unwrap_or_else(|| dir_size(&skill_dir))),
+        scripts: if on_disk {
+            list_script_files(&skill_dir)
+        } else {
+            Vec::new()
+        },
+        references: if on_disk {
+            list_reference_files(&skill_dir)
+        } else {
+            Vec::new()
+        },
+        local_path: on_disk.then_some(skill_dir),
+    })
+}
 
-    if let Some(desc) = description {
-        println!("  {}: {}", "Description".cyan(), desc);
+/// Print a "Yes"/"No" line for a skill's support folder, followed by the files it contains
+fn print_support_files(label: &str, present: bool, files: &[String]) {
+    println!(
+        "  {}: {}",
+        label.cyan(),
+        if present {
+            "Yes".green().to_string()
+        } else {
+            "No".to_string()
+        }
+    );
+    for file in files {
+        println!("      {}", file);
     }
+}
 
-    println!("  {}: {}", "Tap".cyan(), skill_id.tap);
+/// Print skill info in the human-readable `info` layout
+///
+/// With `show_commit_url`, installed skills also get a link to their exact commit on GitHub.
+fn print_skill_info(info: &SkillInfo, show_commit_url: bool, full_sha: bool, rfc3339: bool) {
+    println!("{}", info.name.bold());
+    println!();
 
-    if let Some(entry) = &tap_entry {
-        println!("  {}: {}", "Path".cyan(), entry.path);
-        if let Some(homepage) = &entry.homepage {
-            println!("  {}: {}", "Homepage".cyan(), homepage);
-        }
+    if let Some(desc) = &info.description {
+        println!("  {}: {}", "Description".cyan(), desc);
     }
 
-    // Read versioning metadata from installed SKILL.md when available.
-    // Note: these fields (license, author, version) are only shown for locally installed
-    // skills; they are not available for tap-available skills that have not been installed.
-    let skill_md_path = install_dir.join(&skill_id.tap).join(&skill_id.skill).join("SKILL.md");
-    let version_meta = if skill_md_path.exists() {
-        parse_skill_metadata(&skill_md_path).ok()
+    // Skills added directly from a URL are installed without their tap
+    let from_unadded_tap = !info.tap_added && !info.installed;
+    if from_unadded_tap {
+        println!("  {}: {} {}", "Tap".cyan(), info.tap, "(not added)".dimmed());
     } else {
-        None
-    };
+        println!("  {}: {}", "Tap".cyan(), info.tap);
+    }
 
-    if let Some(ref meta) = version_meta {
-        if let Some(ref license) = meta.license {
-            println!("  {}: {}", "License".cyan(), license);
-        }
-        if let Some(ref vm) = meta.metadata {
-            if let Some(ref author) = vm.author {
-                println!("  {}: {}", "Author".cyan(), author);
-            }
-            if let Some(ref version) = vm.version {
-                println!("  {}: {}", "Version".cyan(), version);
-            }
-        }
+    if let Some(alias) = &info.alias {
+        println!("  {}: {}", "Alias".cyan(), alias);
+    }
+    if let Some(path) = &info.path {
+        println!("  {}: {}", "Path".cyan(), path);
+    }
+    if let Some(homepage) = &info.homepage {
+        println!("  {}: {}", "Homepage".cyan(), homepage);
+    }
+    if let Some(license) = &info.license {
+        println!("  {}: {}", "License".cyan(), license);
+    }
+    if let Some(author) = &info.author {
+        println!("  {}: {}", "Author".cyan(), author);
+    }
+    if let Some(version) = &info.version {
+        println!("  {}: {}", "Version".cyan(), version);
+    }
+    if !info.tags.is_empty() {
+        println!("  {}: {}", "Tags".cyan(), info.tags.join(", "));
+    }
+    if !info.allowed_tools.is_empty() {
+        println!("  {}: {}", "Allowed tools".cyan(), info.allowed_tools.join(", "));
     }
 
-    // Show has_scripts and has_references for installed skills
-    let skill_dir = install_dir.join(&skill_id.tap).join(&skill_id.skill);
-    if skill_dir.exists() {
-        // Use discover_skills to build a Skill with populated has_scripts/has_references
-        let tap_skills_dir = install_dir.join(&skill_id.tap);
-        let discovered = discover_skills(&tap_skills_dir).unwrap_or_default();
-        let skill_info = discovered
-            .into_iter()
-            .find(|s| s.name == skill_id.skill || s.path == skill_dir);
-        match skill_info {
-            Some(s) => {
-                println!(
-                    "  {}: {}",
-                    "Scripts".cyan(),
-                    if s.has_scripts {
-                        "Yes".green().to_string()
-                    } else {
-                        "No".to_string()
-                    }
-                );
-                println!(
-                    "  {}: {}",
-                    "References".cyan(),
-                    if s.has_references {
-                        "Yes".green().to_string()
-                    } else {
-                        "No".to_string()
-                    }
-                );
-            }
-            None => {
-                // Fallback to direct filesystem check
-                println!(
-                    "  {}: {}",
-                    "Scripts".cyan(),
-                    if has_scripts_dir(&skill_dir) {
-                        "Yes".green().to_string()
-                    } else {
-                        "No".to_string()
-                    }
-                );
-                println!(
-                    "  {}: {}",
-                    "References".cyan(),
-                    if has_references_dir(&skill_dir) {
-                        "Yes".green().to_string()
-                    } else {
-                        "No".to_string()
-                    }
-                );
-            }
-        }
+    // Show scripts and references for skills on disk
+    if let Some(skill_dir) = &info.local_path {
+        print_support_files("Scripts", has_scripts_dir(skill_dir), &info.scripts);
+        print_support_files("References", has_references_dir(skill_dir), &info.references);
     }
 
     println!(
         "  {}: {}",
         "Status".cyan(),
-        if installed.is_some() {
+        if info.installed {
             "Installed".green().to_string()
         } else {
             "Not installed".yellow().to_string()
         }
     );
 
-    if let Some(inst) = installed {
-        if let Some(commit) = &inst.commit {
-            println!("  {}: {}", "Commit".cyan(), commit);
+    if info.installed {
+        if let Some(commit) = &info.commit {
+            println!("  {}: {}", "Commit".cyan(), display_commit(commit, full_sha));
+        }
+        if show_commit_url {
+            match &info.commit_url {
+                Some(url) => println!("  {}: {}", "Commit URL".cyan(), url),
+                None => println!("  {}: {}", "Commit URL".cyan(), "unavailable".dimmed()),
+            }
+        }
+        if let Some(installed_at) = info.installed_at {
+            println!("  {}: {}", "Installed".cyan(), display_timestamp(installed_at, rfc3339));
         }
-        println!(
-            "  {}: {}",
-            "Installed".cyan(),
-            inst.installed_at.format("%Y-%m-%d %H:%M")
-        );
 
         // Show source URL for directly added skills
-        if let Some(url) = &inst.source_url {
+        if let Some(url) = &info.source_url {
             println!("  {}: {}", "Source".cyan(), url);
         }
-
-        // Show local path
-        println!("  {}: {}", "Local path".cyan(), skill_dir.display());
+        if let Some(size) = info.size_bytes {
+            println!("  {}: {}", "Size".cyan(), format_size(size));
+        }
+        if let Some(skill_dir) = &info.local_path {
+            println!("  {}: {}", "Local path".cyan(), skill_dir.display());
+        }
     }
 
-    // Show installation command if not installed
-    if installed.is_none() {
+    if from_unadded_tap {
         println!();
         println!(
-            "Install with: {}",
-            format!("skillshub install {}", skill_id.full_name()).bold()
+            "{} Add the tap with 'skillshub tap add {}' to install this skill.",
+            "Info:".cyan(),
+            info.tap
         );
+    } else if !info.installed {
+        // Show installation command if not installed
+        println!();
+        println!("Install with: {}", format!("skillshub install {}", info.name).bold());
     }
-
-    Ok(())
 }
 
-/// Install all skills from all added taps
-pub fn install_all() -> Result<()> {
-    let db = db::init_db()?;
+/// Show detailed info about a skill, as text or (with `json`) as a JSON object
+///
+/// `show_commit_url` adds the GitHub link to the installed commit to the text output;
+/// the JSON object always carries it as `commit_url`. The text output shortens commit
+/// SHAs unless `full_sha` is set and shows `rfc3339` timestamps on request; the JSON
+/// object always has the full SHA and RFC 3339 timestamps.
+///
+/// `full_name` may also be a GitHub URL to a skill directory, or an `owner/repo/skill`
+/// id for a tap that has not been added; those are resolved from GitHub without
+/// saving the tap.
+pub fn show_skill_info(
+    full_name: &str,
+    json: bool,
+    show_commit_url: bool,
+    full_sha: bool,
+    rfc3339: bool,
+) -> Result<()> {
+    let mut db = db::init_db()?;
 
-    let mut all_taps: Vec<String> = db.taps.keys().cloned().collect();
-    all_taps.sort();
+    let info = match remote_info_target(&db, full_name)? {
+        Some((github_url, skill)) => fetch_remote_skill_info(&github_url, &skill)?,
+        None => collect_skill_info(&mut db, full_name)?,
+    };
+    save_refreshed_registries(&db)?;
 
-    if all_taps.is_empty() {
-        println!("No taps configured. Add one with 'skillshub tap add <url>'.");
+    if json {
+        println!("{}", serde_json::to_string_pretty(&info)?);
+    } else {
+        print_skill_info(&info, show_commit_url, full_sha, rfc3339);
+    }
+
+    Ok(())
+}
+
+/// Whether `url` is an `http://` or `https://` URL, the only kinds `open` hands to the browser
+fn is_web_url(url: &str) -> bool {
+    let lower = url.to_ascii_lowercase();
+    lower.starts_with("https://") || lower.starts_with("http://")
+}
+
+/// The web page for a skill: its registry `homepage`, else its directory in the tap's
+/// GitHub repository, else the URL it was added from. A `homepage` that is not an
+/// http(s) URL is ignored, so a tap cannot point `open` at local files or other handlers.
+fn skill_page_url(db: &mut Database, full_name: &str) -> Result<String> {
+    let skill_id = SkillId::parse(full_name)
+        .with_context(|| format!("Invalid skill name '{}'. Use format: tap/skill", full_name))?;
+    let registry = get_tap_registry(db, &skill_id.tap).ok().flatten();
+    let tap = db::get_tap(db, &skill_id.tap);
+    if let Some((_, entry)) = registry.as_ref().and_then(|r| resolve_skill_entry(r, &skill_id.skill)) {
+        match &entry.homepage {
+            Some(homepage) if is_web_url(homepage) => return Ok(homepage.clone()),
+            Some(homepage) => eprintln!(
+                "  {} Ignoring homepage '{}' for '{}': only http(s) URLs are opened",
+                "!".yellow(),
+                homepage.escape_debug(),
+                skill_id.full_name()
+            ),
+            None => {}
+        }
+        let repo_url = tap
+            .map(|t| t.url.as_str())
+            .filter(|url| !is_gist_url(url))
+            .and_then(|url| parse_github_url(url).ok());
+        if let Some(repo_url) = repo_url {
+            let git_ref = tap.and_then(|t| t.branch.as_deref()).unwrap_or("HEAD");
+            return Ok(repo_url.tree_url(git_ref, &entry.path));
+        }
+    }
+
+    if let Some(source_url) = db::get_installed_skill(db, &skill_id.full_name()).and_then(|i| i.source_url.as_ref()) {
+        if is_web_url(source_url) {
+            return Ok(source_url.clone());
+        }
+    }
+
+    if tap.is_none() {
+        anyhow::bail!(
+            "Tap '{}' not found. Add it with 'skillshub tap add <url>'",
+            skill_id.tap
+        );
+    }
+    anyhow::bail!("No homepage or repository URL known for '{}'", skill_id.full_name())
+}
+
+/// Open a skill's homepage (or source repository) in the default browser, or with
+/// `print_only` (`open --print`) just print the URL
+pub fn open_skill_page(full_name: &str, print_only: bool) -> Result<()> {
+    let mut db = db::init_db()?;
+    let url = skill_page_url(&mut db, full_name)?;
+    save_refreshed_registries(&db)?;
+
+    if print_only {
+        println!("{}", url);
         return Ok(());
     }
 
-    let mut installed_count = 0;
+    say!("{} Opening {}", "=>".green().bold(), url);
+    open::that(&url).with_context(|| format!("Failed to open a browser; visit {} instead", url))?;
 
-    for tap_name in all_taps {
-        installed_count += install_all_from_tap_internal(&db, &tap_name)?;
+    Ok(())
+}
+
+/// Where a skill lives on disk: its install directory when installed, else the copy
+/// bundled with skillshub for default tap skills. A bare name is matched against the
+/// installed skills and must name exactly one.
+fn locate_skill(db: &Database, install_dir: &Path, name: &str) -> Result<PathBuf> {
+    let skill_id = if name.contains('/') {
+        Some(SkillId::parse(name).with_context(|| format!("Invalid skill name '{}'. Use format: tap/skill", name))?)
+    } else {
+        let mut matches: Vec<&String> = db
+            .installed
+            .iter()
+            .filter(|(_, installed)| installed.skill == name)
+            .map(|(full_name, _)| full_name)
+            .collect();
+        matches.sort();
+        if matches.len() > 1 {
+            let names: Vec<&str> = matches.iter().map(|s| s.as_str()).collect();
+            anyhow::bail!(
+                "'{}' is installed from several taps ({}); use the full name",
+                name,
+                names.join(", ")
+            );
+        }
+        matches.first().and_then(|full_name| SkillId::parse(full_name))
+    };
+
+    if let Some(installed) = skill_id
+        .as_ref()
+        .and_then(|id| db::get_installed_skill(db, &id.full_name()))
+    {
+        return Ok(db.skill_dir(install_dir, &installed.tap, &installed.skill));
+    }
+
+    let from_default_tap = match &skill_id {
+        Some(id) => id.tap == DEFAULT_TAP_NAME || db.taps.get(&id.tap).is_some_and(|t| t.is_default),
+        None => true,
+    };
+    let skill = skill_id.as_ref().map_or(name, |id| id.skill.as_str());
+    if from_default_tap {
+        let bundled = get_embedded_skills_dir()?.join(skill);
+        if bundled.join("SKILL.md").is_file() {
+            return Ok(bundled);
+        }
     }
 
-    println!("\n{} Installed {} skills", "Done!".green().bold(), installed_count);
+    anyhow::bail!("Skill '{}' is not installed", name)
+}
 
-    // Auto-link to all agents (once after all installations)
-    if installed_count > 0 {
-        link_to_agents()?;
+/// Print where a skill lives on disk and, with `show_links` (`which --link`), every
+/// agent link that points at it, one path per line
+pub fn which_skill(name: &str, show_links: bool) -> Result<()> {
+    let db = db::init_db()?;
+    let path = locate_skill(&db, &get_skills_install_dir()?, name)?;
+    println!("{}", path.display());
+
+    if show_links {
+        let target = path.canonicalize().unwrap_or_else(|_| path.clone());
+        for link in find_agent_links_to(&target) {
+            println!("{}", link.display());
+        }
+    }
+
+    Ok(())
+}
+
+/// How an entry in `tree` output is highlighted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TreeEntryKind {
+    SkillMd,
+    Scripts,
+    References,
+    Dir,
+    File,
+}
+
+/// One line of `tree` output: the branch drawing, the entry name, and its kind
+#[derive(Debug, PartialEq, Eq)]
+struct TreeLine {
+    prefix: String,
+    name: String,
+    kind: TreeEntryKind,
+}
+
+/// Lines of a `tree`-style drawing of `root`, sorted by name, descending at most
+/// `max_depth` levels (unlimited when `None`). Symlinks are listed but not followed.
+fn skill_tree(root: &Path, max_depth: Option<usize>) -> Vec<TreeLine> {
+    fn walk(dir: &Path, depth: usize, max_depth: Option<usize>, indent: &str, lines: &mut Vec<TreeLine>) {
+        let entries: Vec<walkdir::DirEntry> = walkdir::WalkDir::new(dir)
+            .min_depth(1)
+            .max_depth(1)
+            .sort_by_file_name()
+            .into_iter()
+            .flatten()
+            .collect();
+        for (i, entry) in entries.iter().enumerate() {
+            let last = i + 1 == entries.len();
+            let name = entry.file_name().to_string_lossy().to_string();
+            let is_dir = entry.file_type().is_dir();
+            let kind = match name.as_str() {
+                "SKILL.md" if depth == 1 => TreeEntryKind::SkillMd,
+                "scripts" if depth == 1 && is_dir => TreeEntryKind::Scripts,
+                "references" | "resources" if depth == 1 && is_dir => TreeEntryKind::References,
+                _ if is_dir => TreeEntryKind::Dir,
+                _ => TreeEntryKind::File,
+            };
+            lines.push(TreeLine {
+                prefix: format!("{}{}", indent, if last { "└── " } else { "├── " }),
+                name,
+                kind,
+            });
+            if is_dir && max_depth.map_or(true, |max| depth < max) {
+                let child_indent = format!("{}{}", indent, if last { "    " } else { "│   " });
+                walk(entry.path(), depth + 1, max_depth, &child_indent, lines);
+            }
+        }
+    }
+
+    let mut lines = Vec::new();
+    walk(root, 1, max_depth, "", &mut lines);
+    lines
+}
+
+/// Print the file structure of an installed (or bundled) skill like `tree`, highlighting
+/// SKILL.md and the scripts and references folders
+pub fn show_skill_tree(name: &str, depth: Option<usize>) -> Result<()> {
+    if depth == Some(0) {
+        anyhow::bail!("--depth must be at least 1");
+    }
+    let db = db::init_db()?;
+    let root = locate_skill(&db, &get_skills_install_dir()?, name)?;
+    if !root.is_dir() {
+        anyhow::bail!(
+            "Skill directory {} is missing. Run 'skillshub reinstall {}' to restore it.",
+            root.display(),
+            name
+        );
+    }
+
+    println!("{}", root.display().to_string().bold());
+    let lines = skill_tree(&root, depth);
+    for line in &lines {
+        let name = match line.kind {
+            TreeEntryKind::SkillMd => line.name.bold().to_string(),
+            TreeEntryKind::Scripts => format!("{}/", line.name).green().to_string(),
+            TreeEntryKind::References => format!("{}/", line.name).cyan().to_string(),
+            TreeEntryKind::Dir => format!("{}/", line.name).blue().to_string(),
+            TreeEntryKind::File => line.name.clone(),
+        };
+        println!("{}{}", line.prefix.dimmed(), name);
+    }
+
+    let dirs = lines
+        .iter()
+        .filter(|l| {
+            matches!(
+                l.kind,
+                TreeEntryKind::Scripts | TreeEntryKind::References | TreeEntryKind::Dir
+            )
+        })
+        .count();
+    let files = lines.len() - dirs;
+    println!();
+    println!(
+        "{} director{}, {} file{}",
+        dirs,
+        if dirs == 1 { "y" } else { "ies" },
+        files,
+        if files == 1 { "" } else { "s" }
+    );
+
+    Ok(())
+}
+
+/// How a file differs between the installed copy of a skill and its latest version
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FileChange {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// One changed file in `diff` output. Binary files carry no patch or line counts.
+#[derive(Debug, PartialEq, Eq)]
+struct FileDiff {
+    path: String,
+    change: FileChange,
+    insertions: usize,
+    deletions: usize,
+    /// Unified diff of a text file, `None` for binary files
+    patch: Option<String>,
+}
+
+/// Files under `root` keyed by their `/`-separated path relative to it, skipping `.git`
+fn skill_files(root: &Path) -> std::collections::BTreeMap<String, PathBuf> {
+    walkdir::WalkDir::new(root)
+        .into_iter()
+        .filter_entry(|e| e.file_name() != ".git")
+        .flatten()
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| {
+            let rel = e.path().strip_prefix(root).ok()?;
+            let key = rel
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            Some((key, e.path().to_path_buf()))
+        })
+        .collect()
+}
+
+/// Text contents of a file, or `None` when it looks binary (not UTF-8, or holds a NUL)
+fn text_contents(bytes: &[u8]) -> Option<&str> {
+    std::str::from_utf8(bytes).ok().filter(|text| !text.contains('\0'))
+}
+
+/// Compare the installed copy of a skill (`old`) with its latest version (`new`),
+/// returning the changed files sorted by path
+fn diff_skill_dirs(old: &Path, new: &Path) -> Result<Vec<FileDiff>> {
+    let old_files = skill_files(old);
+    let new_files = skill_files(new);
+    let paths: std::collections::BTreeSet<&String> = old_files.keys().chain(new_files.keys()).collect();
+
+    let mut diffs = Vec::new();
+    for path in paths {
+        let read = |files: &std::collections::BTreeMap<String, PathBuf>| -> Result<Option<Vec<u8>>> {
+            files
+                .get(path)
+                .map(|file| std::fs::read(file).with_context(|| format!("Failed to read {}", file.display())))
+                .transpose()
+        };
+        let old_bytes = read(&old_files)?;
+        let new_bytes = read(&new_files)?;
+        let change = match (&old_bytes, &new_bytes) {
+            (Some(a), Some(b)) if a == b => continue,
+            (Some(_), Some(_)) => FileChange::Modified,
+            (None, _) => FileChange::Added,
+            (_, None) => FileChange::Removed,
+        };
+
+        let old_text = old_bytes.as_deref().map_or(Some(""), text_contents);
+        let new_text = new_bytes.as_deref().map_or(Some(""), text_contents);
+        let (insertions, deletions, patch) = match (old_text, new_text) {
+            (Some(a), Some(b)) => {
+                let text_diff = similar::TextDiff::from_lines(a, b);
+                let count = |tag| text_diff.iter_all_changes().filter(|c| c.tag() == tag).count();
+                let patch = text_diff
+                    .unified_diff()
+                    .context_radius(3)
+                    .header(&format!("a/{}", path), &format!("b/{}", path))
+                    .to_string();
+                (
+                    count(similar::ChangeTag::Insert),
+                    count(similar::ChangeTag::Delete),
+                    Some(patch),
+                )
+            }
+            _ => (0, 0, None),
+        };
+
+        diffs.push(FileDiff {
+            path: path.clone(),
+            change,
+            insertions,
+            deletions,
+            patch,
+        });
+    }
+    Ok(diffs)
+}
+
+/// Fetch the latest version of an installed skill into `dest` the way `update` would,
+/// without touching the tap clone. Returns the upstream version (commit SHA or gist
+/// timestamp) when one is known.
+fn fetch_latest_skill(
+    db: &mut Database,
+    full_name: &str,
+    installed: &InstalledSkill,
+    dest: &Path,
+) -> Result<Option<String>> {
+    if let Some(gist_id) = installed.gist_updated_at.as_ref().and(installed.source_path.as_ref()) {
+        let gist = fetch_gist(gist_id)?;
+        let (_, content) = discover_skills_from_gist(&gist)
+            .into_iter()
+            .find(|(name, _)| *name == installed.skill)
+            .with_context(|| format!("Skill '{}' is no longer in its gist", full_name))?;
+        std::fs::write(dest.join("SKILL.md"), content)?;
+        return Ok(Some(gist.updated_at));
+    }
+    if let Some(tag) = installed.commit.as_deref().filter(|c| !is_commit_sha(c)) {
+        anyhow::bail!("'{}' is pinned to {}; update leaves it alone", full_name, tag);
+    }
+    if installed.tap == LOCAL_TAP_NAME {
+        anyhow::bail!("'{}' is a local skill with no upstream to compare against", full_name);
+    }
+
+    let tap = db::get_tap(db, &installed.tap)
+        .with_context(|| format!("Tap '{}' not found", installed.tap))?
+        .clone();
+    if (tap.is_default || installed.tap == DEFAULT_TAP_NAME) && installed.commit.is_none() {
+        copy_dir_contents(&get_embedded_skills_dir()?.join(&installed.skill), dest)?;
+        return Ok(None);
+    }
+
+    let skill_path = get_tap_registry(db, &installed.tap)?
+        .and_then(|registry| registry.skills.get(&installed.skill).map(|entry| entry.path.clone()))
+        .or_else(|| installed.source_path.clone())
+        .with_context(|| format!("'{}' is not in the tap registry; run 'skillshub tap update'", full_name))?;
+    validate_skill_path(&skill_path)?;
+    let branch = installed.branch.as_deref().or(tap.branch.as_deref());
+    install_from_ref(&tap.url, branch, &skill_path, dest).map(Some)
+}
+
+/// Show what `update` would change in an installed skill: a unified diff of every text
+/// file, or with `stat` (`diff --stat`) only per-file change counts
+pub fn diff_skill(name: &str, stat: bool) -> Result<()> {
+    let skill_id =
+        SkillId::parse(name).with_context(|| format!("Invalid skill name '{}'. Use format: tap/skill", name))?;
+    let full_name = skill_id.full_name();
+    let mut db = db::init_db()?;
+    let installed = db::get_installed_skill(&db, &full_name)
+        .with_context(|| format!("Skill '{}' is not installed", full_name))?
+        .clone();
+    let current = db.skill_dir(&get_skills_install_dir()?, &installed.tap, &installed.skill);
+    if !current.is_dir() {
+        anyhow::bail!(
+            "Skill directory {} is missing. Run 'skillshub reinstall {}' to restore it.",
+            current.display(),
+            full_name
+        );
+    }
+
+    let latest_dir = tempfile::TempDir::new()?;
+    let latest = latest_dir.path().join(&installed.skill);
+    std::fs::create_dir_all(&latest)?;
+    let version = fetch_latest_skill(&mut db, &full_name, &installed, &latest)?;
+    save_refreshed_registries(&db)?;
+    let diffs = diff_skill_dirs(&current, &latest)?;
+
+    let old_version = installed
+        .gist_updated_at
+        .clone()
+        .or_else(|| installed.commit.clone())
+        .unwrap_or_else(|| "bundled".to_string());
+    let new_version = version.unwrap_or_else(|| "bundled".to_string());
+    if diffs.is_empty() {
+        println!(
+            "{} {} matches the latest version ({})",
+            "✓".green(),
+            full_name,
+            new_version
+        );
+        return Ok(());
+    }
+    println!(
+        "{} {} ({} -> {})",
+        "=>".green().bold(),
+        full_name,
+        old_version,
+        new_version
+    );
+
+    if stat {
+        let width = diffs.iter().map(|d| d.path.len()).max().unwrap_or(0);
+        for diff in &diffs {
+            let counts = match diff.patch {
+                Some(_) => format!(
+                    "{} {}",
+                    format!("+{}", diff.insertions).green(),
+                    format!("-{}", diff.deletions).red()
+                ),
+                None => "changed (binary)".yellow().to_string(),
+            };
+            let marker = match diff.change {
+                FileChange::Added => " (new)",
+                FileChange::Removed => " (removed)",
+                FileChange::Modified => "",
+            };
+            println!(" {:<width$} | {}{}", diff.path, counts, marker, width = width);
+        }
+    } else {
+        for diff in &diffs {
+            match &diff.patch {
+                Some(patch) => {
+                    for line in patch.lines() {
+                        if line.starts_with("+++") || line.starts_with("---") {
+                            println!("{}", line.bold());
+                        } else if line.starts_with('+') {
+                            println!("{}", line.green());
+                        } else if line.starts_with('-') {
+                            println!("{}", line.red());
+                        } else if line.starts_with("@@") {
+                            println!("{}", line.cyan());
+                        } else {
+                            println!("{}", line);
+                        }
+                    }
+                }
+                None => {
+                    let what = match diff.change {
+                        FileChange::Added => "added (binary)",
+                        FileChange::Removed => "removed (binary)",
+                        FileChange::Modified => "changed (binary)",
+                    };
+                    println!("{} {}", diff.path.bold(), what.yellow());
+                }
+            }
+        }
     }
 
+    let insertions: usize = diffs.iter().map(|d| d.insertions).sum();
+    let deletions: usize = diffs.iter().map(|d| d.deletions).sum();
+    println!();
+    println!(
+        "{} file{} changed, {} insertion{}(+), {} deletion{}(-)",
+        diffs.len(),
+        if diffs.len() == 1 { "" } else { "s" },
+        insertions,
+        if insertions == 1 { "" } else { "s" },
+        deletions,
+        if deletions == 1 { "" } else { "s" }
+    );
+    println!(
+        "{} Run {} to apply it",
+        "Tip:".cyan(),
+        format!("skillshub update {}", full_name).bold()
+    );
+
     Ok(())
 }
 
-/// Install all skills from a specific tap
-pub fn install_all_from_tap(tap_name: &str) -> Result<()> {
+/// Install all skills from all added taps, returning how many were newly installed
+///
+/// With `only_new` (`install-all --only-new`), already-installed skills are skipped
+/// silently and a "N new installed, M already present" summary is printed instead.
+/// Having nothing to install from, or any skill failing, is an error.
+pub fn install_all(only_new: bool) -> Result<usize> {
     let db = db::init_db()?;
 
+    // The default tap goes first so its skills keep their plain link names on clashes
+    let default_tap = db::default_tap_name(&db);
+    let mut all_taps: Vec<String> = db.taps.keys().cloned().collect();
+    all_taps.sort_by_key(|name| (Some(name.as_str()) != default_tap, name.clone()));
+
+    if all_taps.is_empty() {
+        anyhow::bail!("No taps configured. Add one with 'skillshub tap add <url>'.");
+    }
+
+    let mut totals = InstallAllCounts::default();
+
+    for tap_name in all_taps {
+        // Each tap records its installs, so later taps plan against the updated db.json
+        let counts = install_all_from_tap_internal(&mut db::load_db()?, &tap_name, only_new)?;
+        totals.installed += counts.installed;
+        totals.already_present += counts.already_present;
+        totals.failed += counts.failed;
+    }
+
+    if only_new {
+        say!(
+            "\n{} {} new installed, {} already present.",
+            "Done!".green().bold(),
+            totals.installed,
+            totals.already_present
+        );
+    } else {
+        say!("\n{} Installed {} skills", "Done!".green().bold(), totals.installed);
+    }
+
+    finish_install_all(totals)
+}
+
+/// Install all skills from a specific tap, returning how many were newly installed
+pub fn install_all_from_tap(tap_name: &str) -> Result<usize> {
+    let mut db = db::init_db()?;
+
     // Verify tap exists
     if db::get_tap(&db, tap_name).is_none() {
         anyhow::bail!("Tap '{}' not found. Add it with 'skillshub tap add <url>'", tap_name);
     }
 
-    let installed_count = install_all_from_tap_internal(&db, tap_name)?;
+    let counts = install_all_from_tap_internal(&mut db, tap_name, false)?;
+
+    say!("\n{} Installed {} skills", "Done!".green().bold(), counts.installed);
 
-    println!("\n{} Installed {} skills", "Done!".green().bold(), installed_count);
+    finish_install_all(counts)
+}
 
+/// Link after `install-all` or `tap install-all`, then turn an empty registry or failed
+/// skills into an error so scripts see a non-zero exit
+fn finish_install_all(counts: InstallAllCounts) -> Result<usize> {
     // Auto-link to all agents (once after all installations)
-    if installed_count > 0 {
-        link_to_agents()?;
+    if counts.installed > 0 {
+        link_to_agents(&RunContext::default())?;
+    }
+
+    if counts.failed > 0 {
+        anyhow::bail!("{} skill(s) failed to install", counts.failed);
+    }
+    if counts.installed + counts.already_present == 0 {
+        anyhow::bail!("No skills available to install. Run 'skillshub tap update' to refresh the registries.");
+    }
+
+    Ok(counts.installed)
+}
+
+/// Uninstall every installed skill from a tap. The default tap is refused unless
+/// `include_default` is set. A skill that fails to uninstall is reported and the rest
+/// are still removed; the command fails at the end if any did.
+pub fn uninstall_all_from_tap(tap_name: &str, include_default: bool) -> Result<()> {
+    let db = db::init_db()?;
+
+    let is_default = db::get_tap(&db, tap_name).map_or(tap_name == DEFAULT_TAP_NAME, |tap| tap.is_default);
+    if is_default && !include_default {
+        anyhow::bail!(
+            "Refusing to uninstall all skills from the default tap '{}'. Pass --include-default to proceed.",
+            tap_name
+        );
+    }
+
+    let mut skill_names: Vec<String> = db::get_skills_from_tap(&db, tap_name)
+        .into_iter()
+        .map(|(name, _)| name.clone())
+        .collect();
+    skill_names.sort();
+
+    if skill_names.is_empty() {
+        say!("{} No skills installed from tap '{}'", "Info:".cyan(), tap_name);
+        return Ok(());
+    }
+
+    say!(
+        "{} Uninstalling {} skill(s) from tap '{}'",
+        "=>".green().bold(),
+        skill_names.len(),
+        tap_name
+    );
+
+    let mut failed: Vec<&str> = Vec::new();
+    for full_name in &skill_names {
+        if let Err(e) = uninstall_skill(&RunContext::default(), full_name, &UninstallOptions::default()) {
+            say!("{} {} ({})", "✗".red(), full_name, e);
+            failed.push(full_name);
+        }
+    }
+
+    say!(
+        "\n{} Uninstalled {} of {} skill(s) from '{}'",
+        "Done!".green().bold(),
+        skill_names.len() - failed.len(),
+        skill_names.len(),
+        tap_name
+    );
+
+    if !failed.is_empty() {
+        anyhow::bail!("{} skill(s) failed to uninstall: {}", failed.len(), failed.join(", "));
     }
 
     Ok(())
 }
 
+/// Outcome of installing every skill from one tap
+#[derive(Debug, Default, PartialEq, Eq)]
+struct InstallAllCounts {
+    /// Skills newly installed by this run
+    installed: usize,
+    /// Skills skipped because they were already installed
+    already_present: usize,
+    /// Skills that could not be planned or fetched
+    failed: usize,
+}
+
 /// Internal helper to install all skills from a tap (used by both install_all and install_all_from_tap)
-fn install_all_from_tap_internal(db: &super::models::Database, tap_name: &str) -> Result<usize> {
+///
+/// `quiet_skips` omits the per-skill "(already installed)" lines.
+fn install_all_from_tap_internal(
+    db: &mut super::models::Database,
+    tap_name: &str,
+    quiet_skips: bool,
+) -> Result<InstallAllCounts> {
     // Skip gist taps — their skills are installed at add-time and have no registry
     if let Some(tap) = db::get_tap(db, tap_name) {
         if tap.url.contains("gist.github.com") {
             let count = db::get_skills_from_tap(db, tap_name).len();
-            println!("  {} {} ({} skills, gist — skipped)", "○".yellow(), tap_name, count);
-            return Ok(0);
+            say!("  {} {} ({} skills, gist — skipped)", "○".yellow(), tap_name, count);
+            return Ok(InstallAllCounts {
+                already_present: count,
+                ..InstallAllCounts::default()
+            });
         }
     }
 
@@ -1096,44 +3537,135 @@ fn install_all_from_tap_internal(db: &super::models::Database, tap_name: &str) -
                 tap_name, tap_name
             )
         })?;
+    save_refreshed_registries(db)?;
 
     if registry.skills.is_empty() {
-        println!("No skills available in tap '{}'.", tap_name);
-        return Ok(0);
+        say!("No skills available in tap '{}'.", tap_name);
+        return Ok(InstallAllCounts::default());
     }
 
-    println!(
+    say!(
         "{} Installing {} skills from '{}'",
         "=>".green().bold(),
         registry.skills.len(),
         tap_name
     );
 
-    let mut installed_count = 0;
+    let mut counts = InstallAllCounts::default();
+    let tap = db::get_tap(db, tap_name)
+        .with_context(|| format!("Tap '{}' not found", tap_name))?
+        .clone();
 
-    for skill_name in registry.skills.keys() {
-        let full_name = format!("{}/{}", tap_name, skill_name);
+    let mut skill_names: Vec<&String> = registry.skills.keys().collect();
+    skill_names.sort();
 
+    let mut plans = Vec::new();
+    for skill_name in skill_names {
+        let full_name = format!("{}/{}", tap_name, skill_name);
         if db::is_skill_installed(db, &full_name) {
-            println!("  {} {} (already installed)", "○".yellow(), full_name);
+            if !quiet_skips {
+                say!("  {} {} (already installed)", "○".yellow(), full_name);
+            }
+            counts.already_present += 1;
             continue;
         }
 
-        match install_skill_internal(&full_name) {
-            Ok(true) => installed_count += 1,
-            Ok(false) => {}
+        let skill_id = SkillId {
+            tap: tap_name.to_string(),
+            skill: skill_name.clone(),
+        };
+        let entry = registry.skills[skill_name].clone();
+        match plan_from_entry(db, skill_id, tap.clone(), entry, None, None) {
+            Ok(plan) => plans.push(plan),
+            Err(e) => {
+                say!("  {} {} ({})", "✗".red(), full_name, e);
+                counts.failed += 1;
+            }
+        }
+    }
+    if plans.is_empty() {
+        return Ok(counts);
+    }
+
+    // Clone (or repair) the tap once up front so the workers only read from it
+    if !tap.is_default && tap_name != DEFAULT_TAP_NAME {
+        ensure_clone(&get_tap_clone_dir(tap_name)?, &tap.url, tap.branch.as_deref())?;
+    }
+
+    let bar = progress_bar(plans.len() as u64);
+    let results = fetch_in_parallel(&plans, &bar);
+    bar.finish_and_clear();
+
+    // Workers never touch db.json; record everything here in one write
+    for (plan, result) in plans.iter().zip(results) {
+        let full_name = plan.skill_id.full_name();
+        match result {
+            Ok(installed) => {
+                say!("  {} {}", "✓".green(), full_name);
+                if let Some(note) = skipped_hook_note(&plan.dest) {
+                    say!("{}", note);
+                }
+                if let Some(note) = unknown_tools_note(&plan.dest) {
+                    say!("{}", note);
+                }
+                db::add_installed_skill(db, &full_name, installed);
+                counts.installed += 1;
+            }
             Err(e) => {
-                println!("  {} {} ({})", "✗".red(), full_name, e);
+                say!("  {} {} ({})", "✗".red(), full_name, e);
+                counts.failed += 1;
             }
         }
     }
+    if counts.installed > 0 {
+        db::save_db(db)?;
+    }
+
+    Ok(counts)
+}
+
+/// Skills fetched at once by `install-all` and `tap install-all`, kept small so parallel
+/// git and file work stays gentle on the machine and on GitHub
+const INSTALL_WORKERS: usize = 4;
+
+/// Fetch `plans` on a pool of [`INSTALL_WORKERS`] threads, returning the results in plan order
+fn fetch_in_parallel(plans: &[InstallPlan], bar: &ProgressBar) -> Vec<Result<InstalledSkill>> {
+    let next = AtomicUsize::new(0);
+
+    let mut results: Vec<(usize, Result<InstalledSkill>)> = std::thread::scope(|scope| {
+        let workers: Vec<_> = (0..INSTALL_WORKERS.min(plans.len()))
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut done = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(plan) = plans.get(index) else {
+                            break;
+                        };
+                        bar.set_message(plan.skill_id.full_name());
+                        // Per-skill source lines would interleave across threads, so drop them
+                        let result = fetch_planned(plan, &mut Vec::new());
+                        done.push((index, result));
+                        bar.inc(1);
+                    }
+                    done
+                })
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|worker| worker.join().expect("install worker panicked"))
+            .collect()
+    });
 
-    Ok(installed_count)
+    results.sort_by_key(|(index, _)| *index);
+    results.into_iter().map(|(_, result)| result).collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashMap;
     use std::fs;
 
     #[test]
@@ -1177,27 +3709,29 @@ mod tests {
             "garrytan/gists".to_string(),
             TapInfo {
                 url: "https://gist.github.com/garrytan".to_string(),
-                skills_path: String::new(),
+                custom_skills_path: None,
                 updated_at: None,
                 is_default: false,
                 cached_registry: None,
                 branch: None,
+                token_env: None,
+                registry_file: None,
             },
         );
 
-        let db = Database {
+        let mut db = Database {
             taps,
             ..Default::default()
         };
 
-        // Should return Ok(0) instead of erroring about missing registry
-        let result = install_all_from_tap_internal(&db, "garrytan/gists");
+        // Should install nothing instead of erroring about missing registry
+        let result = install_all_from_tap_internal(&mut db, "garrytan/gists", false);
         assert!(
             result.is_ok(),
             "gist taps should be skipped, not error: {:?}",
             result.err()
         );
-        assert_eq!(result.unwrap(), 0);
+        assert_eq!(result.unwrap().installed, 0);
     }
 
     #[test]
@@ -1217,22 +3751,2027 @@ mod tests {
     }
 
     #[test]
-    fn test_format_extras_neither() {
-        assert_eq!(format_extras(false, false), "-");
+    fn test_warn_if_content_changed() {
+        use tempfile::TempDir;
+        let src = TempDir::new().unwrap();
+        fs::write(src.path().join("SKILL.md"), b"---\nname: a\n---\n").unwrap();
+        let recorded = hash_dir_contents(src.path()).unwrap();
+
+        assert!(!warn_if_content_changed("t/a", Some(&recorded), src.path()));
+        assert!(!warn_if_content_changed("t/a", None, src.path()));
+
+        fs::write(src.path().join("SKILL.md"), b"---\nname: a\n---\ntampered\n").unwrap();
+        assert!(warn_if_content_changed("t/a", Some(&recorded), src.path()));
     }
 
     #[test]
-    fn test_format_extras_scripts_only() {
-        assert_eq!(format_extras(true, false), "scripts");
+    fn test_read_installed_metadata_ignores_siblings() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let tap_dir = temp.path().join("tap");
+        // A sibling whose frontmatter name collides with the requested directory name
+        fs::create_dir_all(tap_dir.join("alpha")).unwrap();
+        fs::write(
+            tap_dir.join("alpha/SKILL.md"),
+            "---\nname: beta\ndescription: Alpha description\n---\n",
+        )
+        .unwrap();
+        fs::create_dir_all(tap_dir.join("beta")).unwrap();
+        fs::write(
+            tap_dir.join("beta/SKILL.md"),
+            "---\nname: beta-skill\ndescription: Beta description\n---\n",
+        )
+        .unwrap();
+
+        let meta = read_installed_metadata(&tap_dir.join("beta")).unwrap();
+        assert_eq!(meta.description.as_deref(), Some("Beta description"));
+        assert!(read_installed_metadata(&tap_dir.join("missing")).is_none());
     }
 
     #[test]
-    fn test_format_extras_refs_only() {
-        assert_eq!(format_extras(false, true), "refs");
+    fn test_score_skill_match_tolerates_typos() {
+        let matcher = SkimMatcherV2::default();
+        let reviewer = score_skill_match(
+            Some(&matcher),
+            "reviwer",
+            "code-reviewer",
+            "Review pull requests for bugs",
+        );
+        let unrelated = score_skill_match(
+            Some(&matcher),
+            "reviwer",
+            "pdf-tools",
+            "Extract text and tables from PDF files",
+        );
+
+        assert!(reviewer.is_some(), "typo should still match code-reviewer");
+        assert!(reviewer > unrelated, "code-reviewer should outrank unrelated skills");
     }
 
     #[test]
-    fn test_format_extras_both() {
-        assert_eq!(format_extras(true, true), "scripts, refs");
+    fn test_score_skill_match_substring_ranks_highest() {
+        let matcher = SkimMatcherV2::default();
+        let exact = score_skill_match(Some(&matcher), "review", "review-helper", "Helps");
+        let in_desc = score_skill_match(Some(&matcher), "review", "helper", "Review code");
+        let fuzzy = score_skill_match(Some(&matcher), "review", "re-view-tool", "Tool");
+
+        assert!(exact > in_desc);
+        assert!(in_desc > fuzzy);
+    }
+
+    #[test]
+    fn test_score_skill_match_exact_mode_skips_fuzzy() {
+        assert!(score_skill_match(None, "reviwer", "code-reviewer", "Reviews code").is_none());
+        assert!(score_skill_match(None, "review", "code-reviewer", "Reviews code").is_some());
+    }
+
+    #[test]
+    fn test_closest_skill_names_suggests_similar() {
+        let entry = |description: &str| SkillEntry {
+            path: String::new(),
+            description: Some(description.to_string()),
+            homepage: None,
+            commit: None,
+            tags: Vec::new(),
+            aliases: Vec::new(),
+        };
+        let registry = TapRegistry {
+            name: "owner/repo".to_string(),
+            description: None,
+            schema_version: None,
+            skills: HashMap::from([
+                ("code-reviewer".to_string(), entry("Reviews code")),
+                ("code-review-lite".to_string(), entry("Lighter reviews")),
+                ("pdf".to_string(), entry("Work with PDF files")),
+            ]),
+        };
+
+        let suggestions = closest_skill_names(&registry, "code-reviwer", 3);
+        assert_eq!(suggestions.first().map(String::as_str), Some("code-reviewer"));
+        assert!(!suggestions.contains(&"pdf".to_string()));
+
+        assert_eq!(closest_skill_names(&registry, "code", 1).len(), 1);
+        assert!(closest_skill_names(&registry, "zzzz", 3).is_empty());
+    }
+
+    #[test]
+    fn test_has_tag_ignores_case() {
+        let tags = vec!["Testing".to_string(), "rust".to_string()];
+        assert!(has_tag(&tags, "testing"));
+        assert!(has_tag(&tags, "RUST"));
+        assert!(!has_tag(&tags, "python"));
+        assert!(!has_tag(&[], "rust"));
+    }
+
+    #[test]
+    fn test_format_extras_neither() {
+        assert_eq!(format_extras(false, false), "-");
+    }
+
+    #[test]
+    fn test_format_extras_scripts_only() {
+        assert_eq!(format_extras(true, false), "scripts");
+    }
+
+    #[test]
+    fn test_format_extras_refs_only() {
+        assert_eq!(format_extras(false, true), "refs");
+    }
+
+    #[test]
+    fn test_format_extras_both() {
+        assert_eq!(format_extras(true, true), "scripts, refs");
+    }
+
+    /// RAII guard that restores `SKILLSHUB_TEST_HOME` on drop
+    struct TestHomeGuard(Option<String>);
+
+    impl TestHomeGuard {
+        fn set(home: &std::path::Path) -> Self {
+            let prev = std::env::var("SKILLSHUB_TEST_HOME").ok();
+            std::env::set_var("SKILLSHUB_TEST_HOME", home);
+            Self(prev)
+        }
+    }
+
+    impl Drop for TestHomeGuard {
+        fn drop(&mut self) {
+            match self.0.take() {
+                Some(v) => std::env::set_var("SKILLSHUB_TEST_HOME", v),
+                None => std::env::remove_var("SKILLSHUB_TEST_HOME"),
+            }
+        }
+    }
+
+    /// `uninstall --dry-run` must leave the skill directory and db.json untouched
+    #[test]
+    #[serial_test::serial]
+    fn test_uninstall_dry_run_changes_nothing() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let home = temp.path().join("home");
+        let skillshub_home = home.join(".skillshub");
+        let skill_dir = skillshub_home.join("skills/test-user/test-repo/skill-a");
+        fs::create_dir_all(&skill_dir).unwrap();
+        fs::write(skill_dir.join("SKILL.md"), "---\nname: skill-a\n---\n").unwrap();
+
+        let db_json = serde_json::json!({
+            "taps": {},
+            "installed": {
+                "test-user/test-repo/skill-a": {
+                    "tap": "test-user/test-repo",
+                    "skill": "skill-a",
+                    "commit": null,
+                    "installed_at": "2026-01-01T00:00:00Z",
+                    "source_url": null,
+                    "source_path": null,
+                    "gist_updated_at": null
+                }
+            },
+            "linked_agents": [],
+            "external": {}
+        })
+        .to_string();
+        fs::write(skillshub_home.join("db.json"), &db_json).unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        uninstall_skill(
+            &RunContext::new(true),
+            "test-user/test-repo/skill-a",
+            &UninstallOptions::default(),
+        )
+        .unwrap();
+
+        assert!(skill_dir.join("SKILL.md").exists(), "dry-run must not remove files");
+        assert_eq!(
+            fs::read_to_string(skillshub_home.join("db.json")).unwrap(),
+            db_json,
+            "dry-run must not rewrite db.json"
+        );
+    }
+
+    /// Uninstalling removes the skill's agent links, including categorized ones,
+    /// and leaves links to other skills alone
+    #[test]
+    #[serial_test::serial]
+    #[cfg(unix)]
+    fn test_uninstall_removes_agent_links() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let home = temp.path().join("home");
+        let skillshub_home = home.join(".skillshub");
+        let skills_dir = skillshub_home.join("skills");
+
+        let mut db = Database::default();
+        for skill in ["lint", "lint-extra"] {
+            fs::create_dir_all(skills_dir.join("acme/tools").join(skill)).unwrap();
+            db::add_installed_skill(
+                &mut db,
+                &format!("acme/tools/{}", skill),
+                installed_skill("acme/tools", skill, None, None),
+            );
+        }
+        fs::write(skillshub_home.join("db.json"), serde_json::to_string(&db).unwrap()).unwrap();
+
+        let claude_skills = home.join(".claude/skills");
+        fs::create_dir_all(claude_skills.join("review")).unwrap();
+        let link = claude_skills.join("lint");
+        let categorized = claude_skills.join("review/lint");
+        let other = claude_skills.join("lint-extra");
+        std::os::unix::fs::symlink(skills_dir.join("acme/tools/lint"), &link).unwrap();
+        std::os::unix::fs::symlink(skills_dir.join("acme/tools/lint"), &categorized).unwrap();
+        std::os::unix::fs::symlink(skills_dir.join("acme/tools/lint-extra"), &other).unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        uninstall_skill(&RunContext::default(), "acme/tools/lint", &UninstallOptions::default()).unwrap();
+
+        assert!(!link.is_symlink(), "agent link should be removed");
+        assert!(!categorized.is_symlink(), "categorized agent link should be removed");
+        assert!(other.is_symlink(), "links to other skills must stay");
+    }
+
+    /// An alias replaces the skill's agent link name, survives re-linking, and can be cleared
+    #[test]
+    #[serial_test::serial]
+    #[cfg(unix)]
+    fn test_alias_skill_renames_agent_links() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let home = temp.path().join("home");
+        let skillshub_home = home.join(".skillshub");
+        let skills_dir = skillshub_home.join("skills");
+
+        let mut db = Database::default();
+        for tap in ["alice/tools", "bob/kit"] {
+            let dir = skills_dir.join(tap).join("testing");
+            fs::create_dir_all(&dir).unwrap();
+            fs::write(dir.join("SKILL.md"), "---\nname: testing\ndescription: Tests\n---\n").unwrap();
+            db::add_installed_skill(
+                &mut db,
+                &format!("{}/testing", tap),
+                installed_skill(tap, "testing", None, None),
+            );
+        }
+        fs::write(skillshub_home.join("db.json"), serde_json::to_string(&db).unwrap()).unwrap();
+
+        let claude_skills = home.join(".claude/skills");
+        fs::create_dir_all(&claude_skills).unwrap();
+        let bob_dir = skills_dir.join("bob/kit/testing");
+        std::os::unix::fs::symlink(&bob_dir, claude_skills.join("testing@bob")).unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        alias_skill("bob/kit/testing", Some("testing-py")).unwrap();
+
+        assert_eq!(fs::read_link(claude_skills.join("testing-py")).unwrap(), bob_dir);
+        assert!(
+            !claude_skills.join("testing@bob").is_symlink(),
+            "old link name should be removed"
+        );
+        let db = db::load_db().unwrap();
+        assert_eq!(db.installed["bob/kit/testing"].alias.as_deref(), Some("testing-py"));
+        assert!(!db.link_names.contains_key("bob/kit/testing"));
+
+        assert!(
+            alias_skill("alice/tools/testing", Some("testing-py")).is_err(),
+            "alias already taken"
+        );
+        assert!(alias_skill("alice/tools/testing", Some("../escape")).is_err());
+
+        alias_skill("bob/kit/testing", None).unwrap();
+        assert!(!claude_skills.join("testing-py").is_symlink());
+        assert_eq!(fs::read_link(claude_skills.join("testing@bob")).unwrap(), bob_dir);
+        assert_eq!(db::load_db().unwrap().installed["bob/kit/testing"].alias, None);
+    }
+
+    /// `tap uninstall-all` removes only that tap's skills and its now-empty directory
+    #[test]
+    #[serial_test::serial]
+    fn test_uninstall_all_from_tap() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let home = temp.path().join("home");
+        let skillshub_home = home.join(".skillshub");
+        let skills_dir = skillshub_home.join("skills");
+
+        let mut db = Database::default();
+        for (tap, skill) in [("acme/tools", "lint"), ("acme/tools", "fmt"), ("other/repo", "keep")] {
+            fs::create_dir_all(skills_dir.join(tap).join(skill)).unwrap();
+            db::add_installed_skill(
+                &mut db,
+                &format!("{}/{}", tap, skill),
+                installed_skill(tap, skill, None, None),
+            );
+        }
+        fs::write(skillshub_home.join("db.json"), serde_json::to_string(&db).unwrap()).unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        uninstall_all_from_tap("acme/tools", false).unwrap();
+
+        let db = db::load_db().unwrap();
+        assert_eq!(db.installed.len(), 1);
+        assert!(db.installed.contains_key("other/repo/keep"));
+        assert!(!skills_dir.join("acme/tools").exists());
+        assert!(skills_dir.join("other/repo/keep").exists());
+
+        // The default tap needs --include-default
+        let err = uninstall_all_from_tap(DEFAULT_TAP_NAME, false).unwrap_err();
+        assert!(err.to_string().contains("--include-default"));
+        assert!(uninstall_all_from_tap(DEFAULT_TAP_NAME, true).is_ok());
+    }
+
+    /// One skill failing to uninstall does not stop the rest of `tap uninstall-all`
+    #[test]
+    #[serial_test::serial]
+    fn test_uninstall_all_from_tap_continues_past_failures() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let home = temp.path().join("home");
+        let skillshub_home = home.join(".skillshub");
+        let skills_dir = skillshub_home.join("skills");
+
+        let mut db = Database::default();
+        for skill in ["broken", "fmt", "lint"] {
+            db::add_installed_skill(
+                &mut db,
+                &format!("acme/tools/{}", skill),
+                installed_skill("acme/tools", skill, None, None),
+            );
+        }
+        fs::create_dir_all(skills_dir.join("acme/tools/fmt")).unwrap();
+        fs::create_dir_all(skills_dir.join("acme/tools/lint")).unwrap();
+        // A plain file where the skill directory should be cannot be removed as a directory
+        fs::write(skills_dir.join("acme/tools/broken"), "").unwrap();
+        fs::write(skillshub_home.join("db.json"), serde_json::to_string(&db).unwrap()).unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        let err = uninstall_all_from_tap("acme/tools", false).unwrap_err();
+        assert!(err.to_string().contains("acme/tools/broken"), "{}", err);
+
+        let db = db::load_db().unwrap();
+        let remaining: Vec<&String> = db.installed.keys().collect();
+        assert_eq!(remaining, ["acme/tools/broken"]);
+        assert!(!skills_dir.join("acme/tools/fmt").exists());
+        assert!(!skills_dir.join("acme/tools/lint").exists());
+    }
+
+    fn git(dir: &std::path::Path, args: &[&str]) -> String {
+        let output = std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap();
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    /// Set up an origin repo with one skill, a tap clone of it, and a db.json recording
+    /// the skill as installed at the clone's HEAD. A second commit is then pushed to the
+    /// origin so a pull would move the clone. Returns (home, clone_dir, installed_commit).
+    fn setup_update_fixture(
+        temp: &std::path::Path,
+        cached_commit: bool,
+    ) -> (std::path::PathBuf, std::path::PathBuf, String) {
+        let origin = temp.join("origin");
+        fs::create_dir_all(origin.join("skills/skill-a")).unwrap();
+        git(&origin, &["init"]);
+        git(&origin, &["config", "user.email", "test@test.com"]);
+        git(&origin, &["config", "user.name", "Test"]);
+        fs::write(
+            origin.join("skills/skill-a/SKILL.md"),
+            "---\nname: skill-a\ndescription: v1\n---\n",
+        )
+        .unwrap();
+        git(&origin, &["add", "."]);
+        git(&origin, &["commit", "-m", "v1"]);
+
+        let home = temp.join("home");
+        let clone_dir = home.join(".skillshub/taps/test-user/test-repo");
+        fs::create_dir_all(clone_dir.parent().unwrap()).unwrap();
+        let url = format!("file://{}", origin.display());
+        super::super::git::git_clone(&url, &clone_dir, None).unwrap();
+        let installed_commit = git_head_sha(&clone_dir).unwrap();
+
+        fs::write(
+            origin.join("skills/skill-a/SKILL.md"),
+            "---\nname: skill-a\ndescription: v2\n---\n",
+        )
+        .unwrap();
+        git(&origin, &["commit", "-am", "v2"]);
+
+        let db_json = serde_json::json!({
+            "taps": {
+                "test-user/test-repo": {
+                    "url": url,
+                    "skills_path": "skills",
+                    "updated_at": null,
+                    "is_default": false,
+                    "cached_registry": {
+                        "name": "test-user/test-repo",
+                        "description": null,
+                        "skills": {
+                            "skill-a": {
+                                "path": "skills/skill-a",
+                                "description": "v1",
+                                "homepage": null,
+                                "commit": if cached_commit { Some(&installed_commit) } else { None }
+                            }
+                        }
+                    }
+                }
+            },
+            "installed": {
+                "test-user/test-repo/skill-a": {
+                    "tap": "test-user/test-repo",
+                    "skill": "skill-a",
+                    "commit": installed_commit,
+                    "installed_at": "2026-01-01T00:00:00Z",
+                    "source_url": null,
+                    "source_path": "skills/skill-a",
+                    "gist_updated_at": null
+                }
+            },
+            "linked_agents": [],
+            "external": {}
+        });
+        fs::create_dir_all(home.join(".skillshub")).unwrap();
+        fs::write(home.join(".skillshub/db.json"), db_json.to_string()).unwrap();
+
+        (home, clone_dir, installed_commit)
+    }
+
+    /// `install-all --only-new` installs skills added upstream and skips those already present
+    #[test]
+    #[serial_test::serial]
+    fn test_install_all_only_new_skips_installed() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let (home, clone_dir, _) = setup_update_fixture(temp.path(), false);
+        let origin = temp.path().join("origin");
+        fs::create_dir_all(origin.join("skills/skill-b")).unwrap();
+        fs::write(
+            origin.join("skills/skill-b/SKILL.md"),
+            "---\nname: skill-b\ndescription: new\n---\n",
+        )
+        .unwrap();
+        git(&origin, &["add", "."]);
+        git(&origin, &["commit", "-m", "add skill-b"]);
+        git(&clone_dir, &["pull", "--ff-only"]);
+
+        let _guard = TestHomeGuard::set(&home);
+        let mut db = db::load_db().unwrap();
+        let tap = db.taps.get_mut("test-user/test-repo").unwrap();
+        tap.cached_registry.as_mut().unwrap().skills.insert(
+            "skill-b".to_string(),
+            SkillEntry {
+                path: "skills/skill-b".to_string(),
+                description: Some("new".to_string()),
+                homepage: None,
+                commit: None,
+                tags: Vec::new(),
+                aliases: Vec::new(),
+            },
+        );
+        db::save_db(&db).unwrap();
+
+        let counts = install_all_from_tap_internal(&mut db, "test-user/test-repo", true).unwrap();
+        assert_eq!(
+            counts,
+            InstallAllCounts {
+                installed: 1,
+                already_present: 1,
+                failed: 0
+            }
+        );
+
+        let db = db::load_db().unwrap();
+        assert!(db::is_skill_installed(&db, "test-user/test-repo/skill-b"));
+        assert!(home
+            .join(".skillshub/skills/test-user/test-repo/skill-b/SKILL.md")
+            .exists());
+        assert!(
+            !home.join(".skillshub/skills/test-user/test-repo/skill-a").exists(),
+            "already-installed skill must not be reinstalled"
+        );
+    }
+
+    /// Set up an origin repo with one skill per name in `names`, a tap clone of it, and a
+    /// db.json with the tap's registry and nothing installed. Returns (home, clone HEAD).
+    fn setup_tap_fixture(temp: &std::path::Path, names: &[String]) -> (std::path::PathBuf, String) {
+        let origin = temp.join("origin");
+        for name in names {
+            fs::create_dir_all(origin.join("skills").join(name)).unwrap();
+            fs::write(
+                origin.join("skills").join(name).join("SKILL.md"),
+                format!("---\nname: {}\n---\n", name),
+            )
+            .unwrap();
+        }
+        git(&origin, &["init"]);
+        git(&origin, &["config", "user.email", "test@test.com"]);
+        git(&origin, &["config", "user.name", "Test"]);
+        git(&origin, &["add", "."]);
+        git(&origin, &["commit", "-m", "add skills"]);
+
+        let home = temp.join("home");
+        let clone_dir = home.join(".skillshub/taps/test-user/test-repo");
+        fs::create_dir_all(clone_dir.parent().unwrap()).unwrap();
+        let url = format!("file://{}", origin.display());
+        super::super::git::git_clone(&url, &clone_dir, None).unwrap();
+        let commit = git_head_sha(&clone_dir).unwrap();
+
+        let registry = super::super::tap::discover_skills_from_local(&clone_dir, "test-user/test-repo").unwrap();
+        let db_json = serde_json::json!({
+            "taps": {
+                "test-user/test-repo": {
+                    "url": url,
+                    "skills_path": "skills",
+                    "updated_at": null,
+                    "is_default": false,
+                    "cached_registry": registry
+                }
+            },
+            "installed": {},
+            "linked_agents": [],
+            "external": {}
+        });
+        fs::write(home.join(".skillshub/db.json"), db_json.to_string()).unwrap();
+
+        (home, commit)
+    }
+
+    /// `install --branch` copies the skill from that branch, leaves the tap clone on its own
+    /// branch, and `update` keeps following the recorded branch
+    #[test]
+    #[serial_test::serial]
+    fn test_install_from_branch_records_and_updates_branch() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let (home, main_commit) = setup_tap_fixture(temp.path(), &["alpha".to_string()]);
+        let origin = temp.path().join("origin");
+        let default_branch = super::super::git::git_current_branch(&origin).unwrap();
+        git(&origin, &["checkout", "-b", "beta"]);
+        fs::write(origin.join("skills/alpha/SKILL.md"), "---\nname: alpha\n---\nbeta\n").unwrap();
+        git(&origin, &["commit", "-am", "beta change"]);
+        git(&origin, &["checkout", &default_branch]);
+
+        let _guard = TestHomeGuard::set(&home);
+        assert!(install_skill_internal(&RunContext::default(), "test-user/test-repo/alpha", Some("beta")).unwrap());
+
+        let install_dir = get_skills_install_dir().unwrap();
+        let dest = install_dir.join("test-user/test-repo/alpha");
+        assert!(fs::read_to_string(dest.join("SKILL.md")).unwrap().contains("beta"));
+        let db = db::load_db().unwrap();
+        let installed = &db.installed["test-user/test-repo/alpha"];
+        assert_eq!(installed.branch.as_deref(), Some("beta"));
+        assert_ne!(installed.commit.as_deref(), Some(main_commit.as_str()));
+        let clone_dir = home.join(".skillshub/taps/test-user/test-repo");
+        assert_eq!(git_head_sha(&clone_dir).unwrap(), main_commit, "tap clone stays put");
+
+        git(&origin, &["checkout", "beta"]);
+        fs::write(origin.join("skills/alpha/SKILL.md"), "---\nname: alpha\n---\nbeta 2\n").unwrap();
+        git(&origin, &["commit", "-am", "beta change 2"]);
+        let updated = update_skill(
+            &RunContext::default(),
+            Some("test-user/test-repo/alpha"),
+            UpdateLinkMode::SkillOnly,
+            false,
+        )
+        .unwrap();
+        assert_eq!(updated, 1);
+        assert!(fs::read_to_string(dest.join("SKILL.md")).unwrap().contains("beta 2"));
+        let db = db::load_db().unwrap();
+        assert_eq!(
+            db.installed["test-user/test-repo/alpha"].commit,
+            Some(git_head_sha(&origin).unwrap())
+        );
+    }
+
+    /// A tap added without a branch tracks the branch its clone checked out, so naming
+    /// that branch explicitly installs from the clone and records no branch
+    #[test]
+    #[serial_test::serial]
+    fn test_default_branch_install_uses_tap_clone() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let (home, commit) = setup_tap_fixture(temp.path(), &["alpha".to_string()]);
+        let clone_dir = home.join(".skillshub/taps/test-user/test-repo");
+        let default_branch = super::super::git::git_current_branch(&clone_dir).unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        let db = db::load_db().unwrap();
+        let tap = db.taps["test-user/test-repo"].clone();
+        assert!(tap_tracks_branch(&tap, &clone_dir, &default_branch));
+        assert!(!tap_tracks_branch(&tap, &clone_dir, "beta"));
+        let pinned = TapInfo {
+            branch: Some("beta".to_string()),
+            ..tap
+        };
+        assert!(tap_tracks_branch(&pinned, &clone_dir, "beta"));
+        assert!(!tap_tracks_branch(&pinned, &clone_dir, &default_branch));
+
+        assert!(install_skill_internal(
+            &RunContext::default(),
+            "test-user/test-repo/alpha",
+            Some(&default_branch)
+        )
+        .unwrap());
+        let installed = &db::load_db().unwrap().installed["test-user/test-repo/alpha"];
+        assert_eq!(installed.branch, None);
+        assert_eq!(installed.commit.as_deref(), Some(commit.as_str()));
+    }
+
+    /// `diff` fetches the latest commit without moving the tap clone and reports text
+    /// edits with line counts, new files, and binary changes
+    #[test]
+    #[serial_test::serial]
+    fn test_diff_skill_dirs_against_latest_version() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let (home, commit) = setup_tap_fixture(temp.path(), &["alpha".to_string()]);
+        let _guard = TestHomeGuard::set(&home);
+        assert!(install_skill_internal(&RunContext::default(), "test-user/test-repo/alpha", None).unwrap());
+
+        let origin = temp.path().join("origin");
+        let skill_md = origin.join("skills/alpha/SKILL.md");
+        let mut content = fs::read_to_string(&skill_md).unwrap();
+        content.push_str("A new line\n");
+        fs::write(&skill_md, content).unwrap();
+        fs::write(origin.join("skills/alpha/notes.md"), "one\ntwo\n").unwrap();
+        fs::write(origin.join("skills/alpha/logo.png"), [0u8, 1, 2, 3]).unwrap();
+        git(&origin, &["add", "."]);
+        git(&origin, &["commit", "-m", "change alpha"]);
+
+        let mut db = db::load_db().unwrap();
+        let installed = db.installed["test-user/test-repo/alpha"].clone();
+        let latest = temp.path().join("latest");
+        fs::create_dir_all(&latest).unwrap();
+        let version = fetch_latest_skill(&mut db, "test-user/test-repo/alpha", &installed, &latest).unwrap();
+        assert_eq!(version, Some(git_head_sha(&origin).unwrap()));
+        let clone_dir = home.join(".skillshub/taps/test-user/test-repo");
+        assert_eq!(git_head_sha(&clone_dir).unwrap(), commit, "tap clone stays put");
+
+        let current = get_skills_install_dir().unwrap().join("test-user/test-repo/alpha");
+        let diffs = diff_skill_dirs(&current, &latest).unwrap();
+        let summary: Vec<(&str, FileChange, usize, usize, bool)> = diffs
+            .iter()
+            .map(|d| (d.path.as_str(), d.change, d.insertions, d.deletions, d.patch.is_some()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("SKILL.md", FileChange::Modified, 1, 0, true),
+                ("logo.png", FileChange::Added, 0, 0, false),
+                ("notes.md", FileChange::Added, 2, 0, true),
+            ]
+        );
+        assert!(diffs[0].patch.as_deref().unwrap().contains("+A new line"));
+        assert!(diff_skill_dirs(&current, &current).unwrap().is_empty());
+    }
+
+    /// Installing a whole tap fetches on several workers but records every skill in db.json
+    #[test]
+    #[serial_test::serial]
+    fn test_install_all_from_tap_installs_every_skill_in_parallel() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let names: Vec<String> = (1..=9).map(|i| format!("skill-{}", i)).collect();
+        let (home, commit) = setup_tap_fixture(temp.path(), &names);
+
+        let _guard = TestHomeGuard::set(&home);
+        let mut db = db::load_db().unwrap();
+        let counts = install_all_from_tap_internal(&mut db, "test-user/test-repo", false).unwrap();
+        assert_eq!(
+            counts,
+            InstallAllCounts {
+                installed: names.len(),
+                already_present: 0,
+                failed: 0
+            }
+        );
+
+        let db = db::load_db().unwrap();
+        for name in &names {
+            let installed = &db.installed[&format!("test-user/test-repo/{}", name)];
+            assert_eq!(installed.commit.as_deref(), Some(commit.as_str()));
+            assert!(home
+                .join(".skillshub/skills/test-user/test-repo")
+                .join(name)
+                .join("SKILL.md")
+                .exists());
+        }
+    }
+
+    /// `tap install-all` reports how many skills it installed, returns 0 once everything
+    /// is present, and fails for a tap with no skills
+    #[test]
+    #[serial_test::serial]
+    fn test_install_all_from_tap_result_distinguishes_no_op() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let (home, _) = setup_tap_fixture(temp.path(), &["alpha".to_string(), "beta".to_string()]);
+
+        let _guard = TestHomeGuard::set(&home);
+        assert_eq!(install_all_from_tap("test-user/test-repo").unwrap(), 2);
+        assert_eq!(install_all_from_tap("test-user/test-repo").unwrap(), 0);
+
+        let mut db = db::load_db().unwrap();
+        db.taps
+            .get_mut("test-user/test-repo")
+            .unwrap()
+            .cached_registry
+            .as_mut()
+            .unwrap()
+            .skills
+            .clear();
+        db.installed.clear();
+        db::save_db(&db).unwrap();
+        let err = install_all_from_tap("test-user/test-repo").unwrap_err();
+        assert!(err.to_string().contains("No skills available"), "{}", err);
+    }
+
+    /// `install a b c` installs every valid name even when one of them fails, then reports
+    /// the failure
+    #[test]
+    #[serial_test::serial]
+    fn test_install_skills_continues_past_failures() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let (home, _) = setup_tap_fixture(temp.path(), &["alpha".to_string(), "beta".to_string()]);
+
+        let _guard = TestHomeGuard::set(&home);
+        let names = [
+            "test-user/test-repo/alpha".to_string(),
+            "test-user/test-repo/missing".to_string(),
+            "test-user/test-repo/beta".to_string(),
+        ];
+        let err = install_skills(&RunContext::default(), &names, None).unwrap_err();
+        assert!(err.to_string().contains("test-user/test-repo/missing"), "{}", err);
+
+        let db = db::load_db().unwrap();
+        assert!(db.installed.contains_key("test-user/test-repo/alpha"));
+        assert!(db.installed.contains_key("test-user/test-repo/beta"));
+        assert_eq!(db.installed.len(), 2);
+    }
+
+    /// `install --from-file` skips blank lines and comments and reports failures by line
+    #[test]
+    #[serial_test::serial]
+    fn test_install_skills_from_file_reports_line_numbers() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let (home, _) = setup_tap_fixture(temp.path(), &["alpha".to_string(), "beta".to_string()]);
+        let list = temp.path().join("skills.txt");
+        fs::write(
+            &list,
+            "# team skills\ntest-user/test-repo/alpha\n\n  test-user/test-repo/missing\ntest-user/test-repo/beta\n",
+        )
+        .unwrap();
+        assert_eq!(
+            parse_skill_list(&fs::read_to_string(&list).unwrap()),
+            vec![
+                (2, "test-user/test-repo/alpha".to_string()),
+                (4, "test-user/test-repo/missing".to_string()),
+                (5, "test-user/test-repo/beta".to_string()),
+            ]
+        );
+
+        let _guard = TestHomeGuard::set(&home);
+        let err = install_skills_from_file(&RunContext::default(), &list, None).unwrap_err();
+        assert!(
+            err.to_string().contains("line 4: test-user/test-repo/missing"),
+            "{}",
+            err
+        );
+
+        let db = db::load_db().unwrap();
+        assert!(db.installed.contains_key("test-user/test-repo/alpha"));
+        assert!(db.installed.contains_key("test-user/test-repo/beta"));
+        assert_eq!(db.installed.len(), 2);
+    }
+
+    /// Glob lines in a `--from-file` list expand like glob arguments, and one matching
+    /// nothing is reported by its line number
+    #[test]
+    #[serial_test::serial]
+    fn test_install_skills_from_file_expands_glob_patterns() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let names: Vec<String> = ["py-lint", "py-test", "js-lint"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let (home, _) = setup_tap_fixture(temp.path(), &names);
+        let list = temp.path().join("skills.txt");
+        fs::write(&list, "test-user/test-repo/py-*\ntest-user/test-repo/go-?\n").unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        let err = install_skills_from_file(&RunContext::default(), &list, None).unwrap_err();
+        assert!(err.to_string().contains("line 2: test-user/test-repo/go-?"), "{}", err);
+
+        let db = db::load_db().unwrap();
+        let mut installed: Vec<&String> = db.installed.keys().collect();
+        installed.sort();
+        assert_eq!(
+            installed,
+            ["test-user/test-repo/py-lint", "test-user/test-repo/py-test"]
+        );
+    }
+
+    /// `uninstall --purge` also forgets an external skill of the same name with its synced
+    /// links, and running it again once everything is gone still succeeds
+    #[test]
+    #[serial_test::serial]
+    #[cfg(unix)]
+    fn test_uninstall_purge_removes_external_traces() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let (home, _) = setup_tap_fixture(temp.path(), &["alpha".to_string()]);
+        let _guard = TestHomeGuard::set(&home);
+        let ctx = RunContext::default();
+        install_skill_internal(&ctx, "test-user/test-repo/alpha", None).unwrap();
+        let skill_path = home.join(".skillshub/skills/test-user/test-repo/alpha");
+
+        let claude_link = home.join(".claude/skills/alpha");
+        fs::create_dir_all(claude_link.parent().unwrap()).unwrap();
+        std::os::unix::fs::symlink(&skill_path, &claude_link).unwrap();
+        let external_source = home.join(".codex/skills/alpha");
+        fs::create_dir_all(&external_source).unwrap();
+        fs::write(external_source.join("SKILL.md"), "---\nname: alpha\n---\n").unwrap();
+        let synced_link = home.join(".cursor/skills/alpha");
+        fs::create_dir_all(synced_link.parent().unwrap()).unwrap();
+        std::os::unix::fs::symlink(&external_source, &synced_link).unwrap();
+        let mut db = db::load_db().unwrap();
+        db.external.insert(
+            "alpha".to_string(),
+            crate::registry::models::ExternalSkill {
+                name: "alpha".to_string(),
+                source_agent: ".codex".to_string(),
+                source_path: external_source.clone(),
+                discovered_at: Utc::now(),
+            },
+        );
+        db::save_db(&db).unwrap();
+
+        uninstall_skill(
+            &ctx,
+            "test-user/test-repo/alpha",
+            &UninstallOptions {
+                purge: true,
+                ..UninstallOptions::default()
+            },
+        )
+        .unwrap();
+
+        assert!(!skill_path.exists());
+        assert!(!claude_link.is_symlink());
+        assert!(!synced_link.is_symlink());
+        assert!(external_source.join("SKILL.md").exists(), "the agent's own copy stays");
+        let db = db::load_db().unwrap();
+        assert!(db.installed.is_empty());
+        assert!(db.external.is_empty());
+
+        uninstall_skill(
+            &ctx,
+            "test-user/test-repo/alpha",
+            &UninstallOptions {
+                purge: true,
+                ..UninstallOptions::default()
+            },
+        )
+        .unwrap();
+        assert!(uninstall_skill(&ctx, "test-user/test-repo/alpha", &UninstallOptions::default()).is_err());
+    }
+
+    /// Uninstalling a tap's last skill removes its directory, unless asked to keep it
+    #[test]
+    #[serial_test::serial]
+    fn test_uninstall_last_skill_removes_empty_tap_dir() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let (home, _) = setup_tap_fixture(temp.path(), &["alpha".to_string(), "beta".to_string()]);
+
+        let _guard = TestHomeGuard::set(&home);
+        let ctx = RunContext::default();
+        let skills_dir = home.join(".skillshub/skills");
+        install_skill_internal(&ctx, "test-user/test-repo/alpha", None).unwrap();
+        install_skill_internal(&ctx, "test-user/test-repo/beta", None).unwrap();
+
+        uninstall_skill(&ctx, "test-user/test-repo/alpha", &UninstallOptions::default()).unwrap();
+        assert!(skills_dir.join("test-user/test-repo").is_dir());
+        uninstall_skill(&ctx, "test-user/test-repo/beta", &UninstallOptions::default()).unwrap();
+        assert!(
+            !skills_dir.join("test-user").exists(),
+            "empty tap dir should be removed"
+        );
+
+        install_skill_internal(&ctx, "test-user/test-repo/alpha", None).unwrap();
+        uninstall_skill(
+            &ctx,
+            "test-user/test-repo/alpha",
+            &UninstallOptions {
+                keep_empty_taps: true,
+                ..UninstallOptions::default()
+            },
+        )
+        .unwrap();
+        assert!(skills_dir.join("test-user/test-repo").is_dir());
+
+        // A tap dir that is already gone or refilled is not an error
+        remove_empty_tap_dir(&skills_dir, "test-user/test-repo").unwrap();
+        assert!(!skills_dir.join("test-user").exists());
+        remove_empty_tap_dir(&skills_dir, "test-user/test-repo").unwrap();
+        fs::create_dir_all(skills_dir.join("other/repo/gamma")).unwrap();
+        remove_empty_tap_dir(&skills_dir, "other/repo").unwrap();
+        assert!(skills_dir.join("other/repo/gamma").is_dir());
+    }
+
+    /// A gzipped tarball with a GitHub-style `repo-<sha>/` prefix installs as a local skill
+    #[test]
+    #[serial_test::serial]
+    fn test_add_skill_from_archive_installs_under_local_tap() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let home = temp.path().join("home");
+        fs::create_dir_all(home.join(".skillshub")).unwrap();
+
+        let archive = temp.path().join("skill.tar.gz");
+        let encoder =
+            flate2::write::GzEncoder::new(fs::File::create(&archive).unwrap(), flate2::Compression::default());
+        let mut builder = tar::Builder::new(encoder);
+        for (path, content) in [
+            (
+                "kit-abc1234/skills/packed/SKILL.md",
+                "---\nname: packed\ndescription: From a tarball\n---\n",
+            ),
+            ("kit-abc1234/skills/packed/scripts/run.sh", "echo hi\n"),
+            ("kit-abc1234/README.md", "readme\n"),
+        ] {
+            let mut header = tar::Header::new_gnu();
+            header.set_size(content.len() as u64);
+            header.set_mode(0o644);
+            header.set_cksum();
+            builder.append_data(&mut header, path, content.as_bytes()).unwrap();
+        }
+        builder.into_inner().unwrap().finish().unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        add_skill_from_archive(&archive).unwrap();
+
+        let skill_dir = home.join(".skillshub/skills/local/packed");
+        assert!(skill_dir.join("SKILL.md").exists());
+        assert!(skill_dir.join("scripts/run.sh").exists());
+        assert!(!skill_dir.join("README.md").exists());
+        let db = db::load_db().unwrap();
+        let installed = &db.installed["local/packed"];
+        assert_eq!(installed.tap, LOCAL_TAP_NAME);
+        assert_eq!(installed.commit, None);
+
+        let err = add_skill_from_archive(&archive).unwrap_err();
+        assert!(err.to_string().contains("already installed"), "{}", err);
+
+        let not_archive = temp.path().join("notes.txt");
+        fs::write(&not_archive, "just text").unwrap();
+        assert!(add_skill_from_archive(&not_archive).is_err());
+    }
+
+    /// `reinstall` restores a damaged skill directory from the tap clone and keeps the
+    /// db.json entry's alias
+    #[test]
+    #[serial_test::serial]
+    fn test_reinstall_skill_repairs_directory_and_keeps_alias() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let (home, commit) = setup_tap_fixture(temp.path(), &["alpha".to_string()]);
+
+        let _guard = TestHomeGuard::set(&home);
+        install_skill_internal(&RunContext::default(), "test-user/test-repo/alpha", None).unwrap();
+        let mut db = db::load_db().unwrap();
+        let before = db.installed["test-user/test-repo/alpha"].installed_at;
+        db.installed.get_mut("test-user/test-repo/alpha").unwrap().alias = Some("a".to_string());
+        db::save_db(&db).unwrap();
+
+        let skill_dir = home.join(".skillshub/skills/test-user/test-repo/alpha");
+        fs::remove_file(skill_dir.join("SKILL.md")).unwrap();
+        fs::write(skill_dir.join("stray.txt"), "leftover").unwrap();
+
+        reinstall_skill("test-user/test-repo/alpha").unwrap();
+
+        assert!(skill_dir.join("SKILL.md").exists(), "SKILL.md should be restored");
+        assert!(!skill_dir.join("stray.txt").exists(), "stray files should be removed");
+        let db = db::load_db().unwrap();
+        let installed = &db.installed["test-user/test-repo/alpha"];
+        assert_eq!(installed.alias.as_deref(), Some("a"));
+        assert_eq!(installed.commit.as_deref(), Some(commit.as_str()));
+        assert!(installed.installed_at >= before);
+
+        let err = reinstall_skill("test-user/test-repo/missing").unwrap_err();
+        assert!(err.to_string().contains("is not installed"), "{}", err);
+    }
+
+    /// `reinstall` restores the recorded commit even after the tap clone has moved on
+    #[test]
+    #[serial_test::serial]
+    fn test_reinstall_skill_keeps_recorded_commit() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let (home, commit) = setup_tap_fixture(temp.path(), &["alpha".to_string()]);
+
+        let _guard = TestHomeGuard::set(&home);
+        install_skill_internal(&RunContext::default(), "test-user/test-repo/alpha", None).unwrap();
+
+        let origin = temp.path().join("origin");
+        fs::write(origin.join("skills/alpha/SKILL.md"), "---\nname: alpha\n---\nv2\n").unwrap();
+        git(&origin, &["commit", "-am", "v2"]);
+        let clone_dir = home.join(".skillshub/taps/test-user/test-repo");
+        super::super::git::git_pull(&clone_dir).unwrap();
+        let moved = git_head_sha(&clone_dir).unwrap();
+        assert_ne!(moved, commit);
+
+        reinstall_skill("test-user/test-repo/alpha").unwrap();
+
+        let skill_md = fs::read_to_string(home.join(".skillshub/skills/test-user/test-repo/alpha/SKILL.md")).unwrap();
+        assert!(!skill_md.contains("v2"), "{}", skill_md);
+        let db = db::load_db().unwrap();
+        assert_eq!(
+            db.installed["test-user/test-repo/alpha"].commit.as_deref(),
+            Some(commit.as_str())
+        );
+        assert_eq!(git_head_sha(&clone_dir).unwrap(), moved, "tap clone must be left alone");
+    }
+
+    /// `install tap/py-*` installs every matching skill, and a pattern matching nothing
+    /// is reported as a failed entry
+    #[test]
+    #[serial_test::serial]
+    fn test_install_skills_expands_glob_patterns() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let names: Vec<String> = ["py-lint", "py-test", "js-lint"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let (home, _) = setup_tap_fixture(temp.path(), &names);
+
+        let _guard = TestHomeGuard::set(&home);
+        install_skills(&RunContext::default(), &["test-user/test-repo/py-*".to_string()], None).unwrap();
+
+        let db = db::load_db().unwrap();
+        let mut installed: Vec<&String> = db.installed.keys().collect();
+        installed.sort();
+        assert_eq!(
+            installed,
+            ["test-user/test-repo/py-lint", "test-user/test-repo/py-test"]
+        );
+
+        // A pattern matching nothing fails on its own; the other names still install
+        let err = install_skills(
+            &RunContext::default(),
+            &[
+                "test-user/test-repo/go-?".to_string(),
+                "test-user/test-repo/js-lint".to_string(),
+            ],
+            None,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("go-?"), "{}", err);
+        assert!(db::load_db()
+            .unwrap()
+            .installed
+            .contains_key("test-user/test-repo/js-lint"));
+        assert!(!is_skill_pattern("test-user/test-repo/py-lint"));
+    }
+
+    fn installed_skill(tap: &str, skill: &str, commit: Option<&str>, source_url: Option<&str>) -> InstalledSkill {
+        InstalledSkill {
+            tap: tap.to_string(),
+            skill: skill.to_string(),
+            commit: commit.map(str::to_string),
+            installed_at: Utc::now(),
+            source_url: source_url.map(str::to_string),
+            source_path: None,
+            gist_updated_at: None,
+            content_sha256: None,
+            size_bytes: None,
+            alias: None,
+            branch: None,
+        }
+    }
+
+    /// `open` prefers the registry homepage, then the skill's directory in the tap repo,
+    /// then the URL a skill was added from
+    #[test]
+    fn test_skill_page_url_fallbacks() {
+        let entry = |path: &str, homepage: Option<&str>| SkillEntry {
+            path: path.to_string(),
+            description: None,
+            homepage: homepage.map(str::to_string),
+            commit: None,
+            tags: Vec::new(),
+            aliases: Vec::new(),
+        };
+        let mut registry = TapRegistry {
+            name: "acme/tools".to_string(),
+            description: None,
+            schema_version: None,
+            skills: Default::default(),
+        };
+        registry.skills.insert(
+            "lint".to_string(),
+            entry("skills/lint", Some("https://lint.example.com")),
+        );
+        registry.skills.insert("fmt".to_string(), entry("skills/fmt", None));
+        registry
+            .skills
+            .insert("sneaky".to_string(), entry("skills/sneaky", Some("file:///etc/passwd")));
+
+        let mut db = Database::default();
+        db.taps.insert(
+            "acme/tools".to_string(),
+            TapInfo {
+                url: "https://github.com/acme/tools".to_string(),
+                custom_skills_path: None,
+                updated_at: Some(Utc::now()),
+                is_default: false,
+                cached_registry: Some(registry),
+                branch: Some("dev".to_string()),
+                token_env: None,
+                registry_file: None,
+            },
+        );
+        db.installed.insert(
+            "acme/tools/added".to_string(),
+            installed_skill(
+                "acme/tools",
+                "added",
+                Some("abc1234"),
+                Some("https://github.com/acme/tools/tree/main/extra/added"),
+            ),
+        );
+
+        assert_eq!(
+            skill_page_url(&mut db, "acme/tools/lint").unwrap(),
+            "https://lint.example.com"
+        );
+        assert_eq!(
+            skill_page_url(&mut db, "acme/tools/fmt").unwrap(),
+            "https://github.com/acme/tools/tree/dev/skills/fmt"
+        );
+        // Non-http(s) homepages fall back to the repository
+        assert_eq!(
+            skill_page_url(&mut db, "acme/tools/sneaky").unwrap(),
+            "https://github.com/acme/tools/tree/dev/skills/sneaky"
+        );
+        assert_eq!(
+            skill_page_url(&mut db, "acme/tools/added").unwrap(),
+            "https://github.com/acme/tools/tree/main/extra/added"
+        );
+        assert!(skill_page_url(&mut db, "acme/tools/missing").is_err());
+        assert!(skill_page_url(&mut db, "other/repo/lint")
+            .unwrap_err()
+            .to_string()
+            .contains("not found"));
+    }
+
+    /// `info` lists `allowed-tools` whether the frontmatter gives a string or an array
+    #[test]
+    #[serial_test::serial]
+    fn test_collect_skill_info_lists_allowed_tools() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let _guard = TestHomeGuard::set(temp.path());
+        let mut db = db::init_db().unwrap();
+        let install_dir = get_skills_install_dir().unwrap();
+        for (skill, tools) in [
+            ("string-form", "allowed-tools: Read, Bash(git:*)"),
+            ("array-form", "allowed-tools:\n  - Read\n  - Bash(git:*)"),
+        ] {
+            let dir = db.skill_dir(&install_dir, "acme/tools", skill);
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(
+                dir.join("SKILL.md"),
+                format!("---\nname: {}\ndescription: Tools\n{}\n---\n", skill, tools),
+            )
+            .unwrap();
+            db.installed.insert(
+                format!("acme/tools/{}", skill),
+                installed_skill("acme/tools", skill, Some("abc1234"), None),
+            );
+        }
+
+        for skill in ["string-form", "array-form"] {
+            let info = collect_skill_info(&mut db, &format!("acme/tools/{}", skill)).unwrap();
+            assert_eq!(info.allowed_tools, vec!["Read", "Bash(git:*)"], "{}", skill);
+        }
+    }
+
+    /// A skill that is not installed yet shows the tools its SKILL.md in the tap clone asks for
+    #[test]
+    #[serial_test::serial]
+    fn test_collect_skill_info_allowed_tools_before_install() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let _guard = TestHomeGuard::set(temp.path());
+        let mut db = db::init_db().unwrap();
+
+        let clone_skill = get_tap_clone_dir("acme/tools").unwrap().join("skills/lint");
+        std::fs::create_dir_all(&clone_skill).unwrap();
+        std::fs::write(
+            clone_skill.join("SKILL.md"),
+            "---\nname: lint\nallowed-tools: Read, Bash(git:*)\n---\n",
+        )
+        .unwrap();
+        let mut registry = TapRegistry {
+            name: "acme/tools".to_string(),
+            description: None,
+            schema_version: None,
+            skills: Default::default(),
+        };
+        registry.skills.insert(
+            "lint".to_string(),
+            SkillEntry {
+                path: "skills/lint".to_string(),
+                description: None,
+                homepage: None,
+                commit: None,
+                tags: Vec::new(),
+                aliases: Vec::new(),
+            },
+        );
+        db.taps.insert(
+            "acme/tools".to_string(),
+            TapInfo {
+                url: "https://github.com/acme/tools".to_string(),
+                custom_skills_path: None,
+                updated_at: Some(Utc::now()),
+                is_default: false,
+                cached_registry: Some(registry),
+                branch: None,
+                token_env: None,
+                registry_file: None,
+            },
+        );
+
+        let info = collect_skill_info(&mut db, "acme/tools/lint").unwrap();
+        assert!(!info.installed);
+        assert_eq!(info.allowed_tools, vec!["Read", "Bash(git:*)"]);
+    }
+
+    /// `tree` draws nested entries sorted by name, marks the skill's special files, and
+    /// stops at the depth limit
+    #[test]
+    fn test_skill_tree_draws_and_limits_depth() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        std::fs::create_dir_all(root.join("scripts/lib")).unwrap();
+        std::fs::create_dir_all(root.join("references")).unwrap();
+        std::fs::write(root.join("SKILL.md"), "---\nname: t\n---\n").unwrap();
+        std::fs::write(root.join("scripts/run.sh"), "").unwrap();
+        std::fs::write(root.join("scripts/lib/util.sh"), "").unwrap();
+        std::fs::write(root.join("references/api.md"), "").unwrap();
+
+        let drawn: Vec<String> = skill_tree(root, None)
+            .iter()
+            .map(|l| format!("{}{}", l.prefix, l.name))
+            .collect();
+        assert_eq!(
+            drawn,
+            vec![
+                "├── SKILL.md",
+                "├── references",
+                "│   └── api.md",
+                "└── scripts",
+                "    ├── lib",
+                "    │   └── util.sh",
+                "    └── run.sh",
+            ]
+        );
+
+        let kinds: Vec<TreeEntryKind> = skill_tree(root, Some(1)).iter().map(|l| l.kind).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                TreeEntryKind::SkillMd,
+                TreeEntryKind::References,
+                TreeEntryKind::Scripts
+            ]
+        );
+        assert_eq!(skill_tree(root, Some(2)).len(), 6);
+    }
+
+    /// `which` resolves full and unique bare names to the install directory and falls
+    /// back to the bundled copy for default tap skills that are not installed
+    #[test]
+    fn test_locate_skill_resolves_names() {
+        let install_dir = Path::new("/home/me/.skillshub/skills");
+        let mut db = Database::default();
+        for (tap, skill) in [("acme/tools", "lint"), ("acme/tools", "fmt"), ("other/repo", "fmt")] {
+            db.installed.insert(
+                format!("{}/{}", tap, skill),
+                installed_skill(tap, skill, Some("abc1234"), None),
+            );
+        }
+        let lint_dir = db.skill_dir(install_dir, "acme/tools", "lint");
+
+        assert_eq!(locate_skill(&db, install_dir, "acme/tools/lint").unwrap(), lint_dir);
+        assert_eq!(locate_skill(&db, install_dir, "lint").unwrap(), lint_dir);
+        assert_eq!(
+            locate_skill(&db, install_dir, "other/repo/fmt").unwrap(),
+            db.skill_dir(install_dir, "other/repo", "fmt")
+        );
+        let err = locate_skill(&db, install_dir, "fmt").unwrap_err().to_string();
+        assert!(err.contains("acme/tools/fmt, other/repo/fmt"), "{}", err);
+
+        let bundled = get_embedded_skills_dir().unwrap().join("using-skillshub");
+        assert_eq!(locate_skill(&db, install_dir, "using-skillshub").unwrap(), bundled);
+        assert_eq!(
+            locate_skill(&db, install_dir, &format!("{}/using-skillshub", DEFAULT_TAP_NAME)).unwrap(),
+            bundled
+        );
+        assert!(locate_skill(&db, install_dir, "acme/tools/using-skillshub").is_err());
+        assert!(locate_skill(&db, install_dir, "missing")
+            .unwrap_err()
+            .to_string()
+            .contains("not installed"));
+    }
+
+    #[test]
+    fn test_installed_commit_url() {
+        let tap_url = Some("https://github.com/owner/repo");
+
+        let mut remote = installed_skill("owner/repo", "lint", Some("abc1234"), tap_url);
+        remote.source_path = Some("skills/lint".to_string());
+        assert_eq!(
+            installed_commit_url(&remote, tap_url).as_deref(),
+            Some("https://github.com/owner/repo/tree/abc1234/skills/lint")
+        );
+
+        // Falls back to the tap URL, and a root-level skill links the repo root
+        let mut root = installed_skill("owner/repo", "root", Some("abc1234"), None);
+        root.source_path = Some(".".to_string());
+        assert_eq!(
+            installed_commit_url(&root, tap_url).as_deref(),
+            Some("https://github.com/owner/repo/tree/abc1234")
+        );
+
+        let bundled = installed_skill("owner/repo", "local", None, tap_url);
+        assert_eq!(installed_commit_url(&bundled, tap_url), None);
+
+        let gist = installed_skill(
+            "owner/repo",
+            "g",
+            Some("abc1234"),
+            Some("https://gist.github.com/owner/abc"),
+        );
+        assert_eq!(installed_commit_url(&gist, tap_url), None);
+    }
+
+    #[test]
+    fn test_skill_source_of_installed() {
+        let tap_url = Some("https://github.com/owner/repo");
+
+        let from_tap = installed_skill("owner/repo", "a", Some("abc1234"), tap_url);
+        assert_eq!(SkillSource::of_installed(&from_tap, tap_url), SkillSource::Remote);
+
+        let added = installed_skill(
+            "owner/repo",
+            "b",
+            Some("abc1234"),
+            Some("https://github.com/owner/repo/tree/main/skills/b"),
+        );
+        assert_eq!(SkillSource::of_installed(&added, tap_url), SkillSource::Url);
+
+        let bundled = installed_skill(DEFAULT_TAP_NAME, "c", None, None);
+        assert_eq!(SkillSource::of_installed(&bundled, None), SkillSource::Local);
+
+        let mut gist = installed_skill("gists/user", "d", None, Some("https://gist.github.com/user/1"));
+        gist.gist_updated_at = Some("2026-01-01T00:00:00Z".to_string());
+        assert_eq!(SkillSource::of_installed(&gist, None), SkillSource::Url);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_list_source_url_shows_only_url_added_skills() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let _guard = TestHomeGuard::set(temp.path());
+
+        let tap_url = "https://github.com/owner/repo";
+        let mut skills = HashMap::new();
+        for name in ["from-tap", "available"] {
+            skills.insert(
+                name.to_string(),
+                SkillEntry {
+                    path: format!("skills/{}", name),
+                    description: None,
+                    homepage: None,
+                    commit: None,
+                    tags: Vec::new(),
+                    aliases: Vec::new(),
+                },
+            );
+        }
+
+        let mut db = Database::default();
+        db::add_tap(
+            &mut db,
+            "owner/repo",
+            TapInfo {
+                url: tap_url.to_string(),
+                custom_skills_path: None,
+                updated_at: Some(Utc::now()),
+                is_default: false,
+                cached_registry: Some(TapRegistry {
+                    name: "owner/repo".to_string(),
+                    description: None,
+                    schema_version: None,
+                    skills,
+                }),
+                branch: None,
+                token_env: None,
+                registry_file: None,
+            },
+        );
+        db.installed.insert(
+            "owner/repo/from-tap".to_string(),
+            installed_skill("owner/repo", "from-tap", Some("abc1234"), Some(tap_url)),
+        );
+        db.installed.insert(
+            "owner/repo/added".to_string(),
+            installed_skill(
+                "owner/repo",
+                "added",
+                Some("abc1234"),
+                Some("https://github.com/owner/repo/tree/main/extra/added"),
+            ),
+        );
+        db.installed.insert(
+            "skillshub/bundled".to_string(),
+            installed_skill(DEFAULT_TAP_NAME, "bundled", None, None),
+        );
+
+        let (rows, _) = collect_list_rows(&mut db, None, Some(SkillSource::Url)).unwrap();
+        let names: Vec<&str> = rows.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["added"]);
+
+        let (rows, _) = collect_list_rows(&mut db, None, Some(SkillSource::Remote)).unwrap();
+        let mut names: Vec<&str> = rows.iter().map(|r| r.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["available", "from-tap"]);
+
+        let (rows, _) = collect_list_rows(&mut db, None, None).unwrap();
+        assert_eq!(rows.len(), 4);
+    }
+
+    /// A registry path with `..` or an absolute root is rejected before anything is copied
+    #[test]
+    fn test_copy_skill_from_checkout_rejects_escaping_paths() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let clone_dir = temp.path().join("clone");
+        fs::create_dir_all(clone_dir.join("skills/ok")).unwrap();
+        fs::write(clone_dir.join("skills/ok/SKILL.md"), "---\nname: ok\n---\n").unwrap();
+        // A real skill outside the clone that a crafted path would reach
+        let outside = temp.path().join("evil");
+        fs::create_dir_all(&outside).unwrap();
+        fs::write(outside.join("SKILL.md"), "---\nname: evil\n---\n").unwrap();
+
+        let dest = temp.path().join("dest");
+        for path in ["../evil", "skills/../../evil", "skills\\..\\..\\evil"] {
+            let err = copy_skill_from_checkout(&clone_dir, path, &dest).unwrap_err();
+            assert!(err.to_string().contains("Unsafe skill path"), "{}: {}", path, err);
+        }
+        let absolute = outside.to_string_lossy().to_string();
+        assert!(copy_skill_from_checkout(&clone_dir, &absolute, &dest).is_err());
+        assert!(!dest.exists(), "nothing should be written for a rejected path");
+
+        copy_skill_from_checkout(&clone_dir, "skills/ok", &dest).unwrap();
+        assert!(dest.join("SKILL.md").exists());
+    }
+
+    /// `@<tag>` installs the tagged content, records the tag, and is skipped by update
+    #[test]
+    #[serial_test::serial]
+    fn test_install_at_tag_records_tag_and_is_pinned() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let (home, _clone_dir, _) = setup_update_fixture(temp.path(), true);
+        let origin = temp.path().join("origin");
+        git(&origin, &["tag", "v1.0.0", "HEAD~1"]);
+
+        let db_path = home.join(".skillshub/db.json");
+        let mut db_json: serde_json::Value = serde_json::from_str(&fs::read_to_string(&db_path).unwrap()).unwrap();
+        db_json["installed"] = serde_json::json!({});
+        fs::write(&db_path, db_json.to_string()).unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        let installed =
+            install_skill_internal(&RunContext::default(), "test-user/test-repo/skill-a@v1.0.0", None).unwrap();
+        assert!(installed);
+
+        let skill_md = home.join(".skillshub/skills/test-user/test-repo/skill-a/SKILL.md");
+        assert!(fs::read_to_string(&skill_md).unwrap().contains("description: v1"));
+        let db = db::load_db().unwrap();
+        let entry = db.installed.get("test-user/test-repo/skill-a").unwrap();
+        assert_eq!(entry.commit.as_deref(), Some("v1.0.0"));
+
+        update_skill(
+            &RunContext::default(),
+            Some("test-user/test-repo/skill-a"),
+            UpdateLinkMode::SkillOnly,
+            false,
+        )
+        .unwrap();
+        assert!(
+            fs::read_to_string(&skill_md).unwrap().contains("description: v1"),
+            "tag-pinned install should not be updated"
+        );
+    }
+
+    /// A tag that looks like a commit SHA installs as a tag; a SHA-like ref the remote
+    /// does not have is refused as a pinned commit
+    #[test]
+    #[serial_test::serial]
+    fn test_install_at_hex_tag_is_not_taken_for_a_sha() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let (home, _clone_dir, _) = setup_update_fixture(temp.path(), true);
+        let origin = temp.path().join("origin");
+        git(&origin, &["tag", "1234567", "HEAD~1"]);
+
+        let db_path = home.join(".skillshub/db.json");
+        let mut db_json: serde_json::Value = serde_json::from_str(&fs::read_to_string(&db_path).unwrap()).unwrap();
+        db_json["installed"] = serde_json::json!({});
+        fs::write(&db_path, db_json.to_string()).unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        let err =
+            install_skill_internal(&RunContext::default(), "test-user/test-repo/skill-a@abcdef0", None).unwrap_err();
+        assert!(err.to_string().contains("Pinned commits"), "{}", err);
+
+        assert!(install_skill_internal(&RunContext::default(), "test-user/test-repo/skill-a@1234567", None).unwrap());
+        let skill_md = home.join(".skillshub/skills/test-user/test-repo/skill-a/SKILL.md");
+        assert!(fs::read_to_string(&skill_md).unwrap().contains("description: v1"));
+    }
+
+    /// Installing by an alias from the frontmatter installs the canonical skill
+    #[test]
+    #[serial_test::serial]
+    fn test_install_via_alias_installs_canonical_skill() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let origin = temp.path().join("origin");
+        fs::create_dir_all(origin.join("skills/python-code-reviewer")).unwrap();
+        git(&origin, &["init"]);
+        git(&origin, &["config", "user.email", "test@test.com"]);
+        git(&origin, &["config", "user.name", "Test"]);
+        fs::write(
+            origin.join("skills/python-code-reviewer/SKILL.md"),
+            "---\nname: python-code-reviewer\naliases: [reviewer]\n---\n",
+        )
+        .unwrap();
+        git(&origin, &["add", "."]);
+        git(&origin, &["commit", "-m", "add reviewer"]);
+
+        let home = temp.path().join("home");
+        let clone_dir = home.join(".skillshub/taps/test-user/test-repo");
+        fs::create_dir_all(clone_dir.parent().unwrap()).unwrap();
+        let url = format!("file://{}", origin.display());
+        super::super::git::git_clone(&url, &clone_dir, None).unwrap();
+
+        let registry = super::super::tap::discover_skills_from_local(&clone_dir, "test-user/test-repo").unwrap();
+        let db_json = serde_json::json!({
+            "taps": {
+                "test-user/test-repo": {
+                    "url": url,
+                    "skills_path": "skills",
+                    "updated_at": null,
+                    "is_default": false,
+                    "cached_registry": registry
+                }
+            },
+            "installed": {},
+            "linked_agents": [],
+            "external": {}
+        });
+        fs::write(home.join(".skillshub/db.json"), db_json.to_string()).unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        let installed = install_skill_internal(&RunContext::default(), "test-user/test-repo/reviewer", None).unwrap();
+        assert!(installed);
+
+        let skills_dir = home.join(".skillshub/skills/test-user/test-repo");
+        assert!(skills_dir.join("python-code-reviewer/SKILL.md").exists());
+        assert!(
+            !skills_dir.join("reviewer").exists(),
+            "alias must not become a directory"
+        );
+
+        let db = db::load_db().unwrap();
+        assert!(db::is_skill_installed(&db, "test-user/test-repo/python-code-reviewer"));
+        assert!(!db::is_skill_installed(&db, "test-user/test-repo/reviewer"));
+
+        // A second install by alias sees the canonical skill as already installed
+        assert!(!install_skill_internal(&RunContext::default(), "test-user/test-repo/reviewer", None).unwrap());
+    }
+
+    /// Hooks only run with `--run-hooks`, and a failing hook leaves nothing installed
+    #[test]
+    #[serial_test::serial]
+    #[cfg(unix)]
+    fn test_install_runs_postinstall_hook_when_requested() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let origin = temp.path().join("origin");
+        for (skill, hook) in [
+            ("good", "echo configured > generated.txt"),
+            ("bad", "echo broken; exit 3"),
+        ] {
+            fs::create_dir_all(origin.join("skills").join(skill).join("scripts")).unwrap();
+            fs::write(
+                origin.join("skills").join(skill).join("SKILL.md"),
+                format!("---\nname: {}\n---\n", skill),
+            )
+            .unwrap();
+            fs::write(origin.join("skills").join(skill).join("scripts/postinstall.sh"), hook).unwrap();
+        }
+        git(&origin, &["init"]);
+        git(&origin, &["config", "user.email", "test@test.com"]);
+        git(&origin, &["config", "user.name", "Test"]);
+        git(&origin, &["add", "."]);
+        git(&origin, &["commit", "-m", "add hooked skills"]);
+
+        let home = temp.path().join("home");
+        let clone_dir = home.join(".skillshub/taps/test-user/test-repo");
+        fs::create_dir_all(clone_dir.parent().unwrap()).unwrap();
+        let url = format!("file://{}", origin.display());
+        super::super::git::git_clone(&url, &clone_dir, None).unwrap();
+
+        let registry = super::super::tap::discover_skills_from_local(&clone_dir, "test-user/test-repo").unwrap();
+        let db_json = serde_json::json!({
+            "taps": {
+                "test-user/test-repo": {
+                    "url": url,
+                    "skills_path": "skills",
+                    "updated_at": null,
+                    "is_default": false,
+                    "cached_registry": registry
+                }
+            },
+            "installed": {},
+            "linked_agents": [],
+            "external": {}
+        });
+        fs::write(home.join(".skillshub/db.json"), db_json.to_string()).unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        let skills_dir = home.join(".skillshub/skills/test-user/test-repo");
+        let hooks = RunContext::default().with_hooks(true);
+
+        // Without --run-hooks the script is copied but never run
+        assert!(install_skill_internal(&RunContext::default(), "test-user/test-repo/good", None).unwrap());
+        assert!(!skills_dir.join("good/generated.txt").exists());
+        uninstall_skill(
+            &RunContext::default(),
+            "test-user/test-repo/good",
+            &UninstallOptions::default(),
+        )
+        .unwrap();
+
+        assert!(install_skill_internal(&hooks, "test-user/test-repo/good", None).unwrap());
+        assert_eq!(
+            fs::read_to_string(skills_dir.join("good/generated.txt")).unwrap(),
+            "configured\n",
+            "hook runs in the skill directory"
+        );
+
+        let err = install_skill_internal(&hooks, "test-user/test-repo/bad", None).unwrap_err();
+        assert!(err.to_string().contains("failed"), "{}", err);
+        assert!(!skills_dir.join("bad").exists(), "failed install is removed");
+        assert!(!db::is_skill_installed(
+            &db::load_db().unwrap(),
+            "test-user/test-repo/bad"
+        ));
+    }
+
+    /// A stale registry commit is not trusted: `update` still pulls when upstream moved
+    #[test]
+    #[serial_test::serial]
+    fn test_update_ignores_stale_registry_commit() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let (home, clone_dir, installed_commit) = setup_update_fixture(temp.path(), true);
+
+        let _guard = TestHomeGuard::set(&home);
+        let updated = update_skill(
+            &RunContext::default(),
+            Some("test-user/test-repo/skill-a"),
+            UpdateLinkMode::SkillOnly,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(updated, 1);
+        let head = git_head_sha(&clone_dir).unwrap();
+        assert_ne!(head, installed_commit, "clone should have been pulled");
+        let db = db::load_db().unwrap();
+        assert_eq!(
+            db.installed["test-user/test-repo/skill-a"].commit.as_deref(),
+            Some(head.as_str())
+        );
+        let content = fs::read_to_string(home.join(".skillshub/skills/test-user/test-repo/skill-a/SKILL.md")).unwrap();
+        assert!(content.contains("v2"));
+    }
+
+    /// A registry refreshed within the cache TTL is trusted: when its recorded commit matches
+    /// the installed one, `update` reports the skill up to date without pulling
+    #[test]
+    #[serial_test::serial]
+    fn test_update_uses_fresh_registry_commit() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let (home, clone_dir, installed_commit) = setup_update_fixture(temp.path(), true);
+        let db_path = home.join(".skillshub/db.json");
+        let mut db_json: serde_json::Value = serde_json::from_str(&fs::read_to_string(&db_path).unwrap()).unwrap();
+        db_json["taps"]["test-user/test-repo"]["updated_at"] = serde_json::json!(Utc::now());
+        fs::write(&db_path, db_json.to_string()).unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        let prev_ttl = std::env::var(super::super::tap::CACHE_TTL_ENV).ok();
+        std::env::set_var(super::super::tap::CACHE_TTL_ENV, "3600");
+        let updated = update_skill(
+            &RunContext::default(),
+            Some("test-user/test-repo/skill-a"),
+            UpdateLinkMode::SkillOnly,
+            false,
+        );
+        match prev_ttl {
+            Some(v) => std::env::set_var(super::super::tap::CACHE_TTL_ENV, v),
+            None => std::env::remove_var(super::super::tap::CACHE_TTL_ENV),
+        }
+
+        assert_eq!(updated.unwrap(), 0);
+        assert_eq!(
+            git_head_sha(&clone_dir).unwrap(),
+            installed_commit,
+            "clone should not be pulled when the fresh cached commit matches"
+        );
+        let db = db::load_db().unwrap();
+        assert_eq!(
+            db.installed["test-user/test-repo/skill-a"].commit.as_deref(),
+            Some(installed_commit.as_str())
+        );
+    }
+
+    /// Without a cached commit, `update` pulls the clone
+    #[test]
+    #[serial_test::serial]
+    fn test_update_pulls_without_cached_commit() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let (home, clone_dir, installed_commit) = setup_update_fixture(temp.path(), false);
+
+        let _guard = TestHomeGuard::set(&home);
+        update_skill(
+            &RunContext::default(),
+            Some("test-user/test-repo/skill-a"),
+            UpdateLinkMode::SkillOnly,
+            false,
+        )
+        .unwrap();
+
+        let head = git_head_sha(&clone_dir).unwrap();
+        assert_ne!(head, installed_commit, "clone should have been pulled");
+        let db = db::load_db().unwrap();
+        assert_eq!(
+            db.installed["test-user/test-repo/skill-a"].commit.as_deref(),
+            Some(head.as_str())
+        );
+        let content = fs::read_to_string(home.join(".skillshub/skills/test-user/test-repo/skill-a/SKILL.md")).unwrap();
+        assert!(content.contains("v2"));
+    }
+
+    /// A skill added by URL updates from its recorded path even though its tap has no
+    /// cached registry
+    #[test]
+    #[serial_test::serial]
+    fn test_update_url_added_skill_without_registry() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let (home, clone_dir, installed_commit) = setup_update_fixture(temp.path(), false);
+
+        let _guard = TestHomeGuard::set(&home);
+        let mut db = db::load_db().unwrap();
+        db.taps.get_mut("test-user/test-repo").unwrap().cached_registry = None;
+        db.installed.get_mut("test-user/test-repo/skill-a").unwrap().source_url =
+            Some("https://github.com/test-user/test-repo/tree/main/skills/skill-a".to_string());
+        db::save_db(&db).unwrap();
+
+        let updated = update_skill(
+            &RunContext::default(),
+            Some("test-user/test-repo/skill-a"),
+            UpdateLinkMode::SkillOnly,
+            false,
+        )
+        .unwrap();
+
+        assert_eq!(updated, 1);
+        let head = git_head_sha(&clone_dir).unwrap();
+        assert_ne!(head, installed_commit);
+        let db = db::load_db().unwrap();
+        assert_eq!(
+            db.installed["test-user/test-repo/skill-a"].commit.as_deref(),
+            Some(head.as_str())
+        );
+        let content = fs::read_to_string(home.join(".skillshub/skills/test-user/test-repo/skill-a/SKILL.md")).unwrap();
+        assert!(content.contains("v2"));
+    }
+
+    /// Bundled skills compare their contents with the bundled copy: an untouched install is
+    /// up to date, while an edited or deleted one is refreshed
+    #[test]
+    #[serial_test::serial]
+    fn test_update_bundled_skill_compares_contents() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let _guard = TestHomeGuard::set(temp.path());
+        let ctx = RunContext::default();
+        let full_name = format!("{}/using-skillshub", DEFAULT_TAP_NAME);
+        assert!(install_skill_internal(&ctx, &full_name, None).unwrap());
+        let db = db::load_db().unwrap();
+        let dest = db.skill_dir(&get_skills_install_dir().unwrap(), DEFAULT_TAP_NAME, "using-skillshub");
+        let bundled = fs::read_to_string(dest.join("SKILL.md")).unwrap();
+
+        assert_eq!(
+            update_skill(&ctx, Some(&full_name), UpdateLinkMode::SkillOnly, false).unwrap(),
+            0
+        );
+
+        fs::write(dest.join("SKILL.md"), "edited").unwrap();
+        assert_eq!(
+            update_skill(&ctx, Some(&full_name), UpdateLinkMode::SkillOnly, false).unwrap(),
+            1
+        );
+        assert_eq!(fs::read_to_string(dest.join("SKILL.md")).unwrap(), bundled);
+
+        fs::remove_dir_all(&dest).unwrap();
+        assert_eq!(
+            update_skill(&ctx, Some(&full_name), UpdateLinkMode::SkillOnly, false).unwrap(),
+            1
+        );
+        assert_eq!(fs::read_to_string(dest.join("SKILL.md")).unwrap(), bundled);
+    }
+
+    /// `--check` reports the available update without pulling or changing anything
+    #[test]
+    #[serial_test::serial]
+    fn test_update_check_reports_without_applying() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let (home, clone_dir, installed_commit) = setup_update_fixture(temp.path(), false);
+        let skill_dir = home.join(".skillshub/skills/test-user/test-repo/skill-a");
+
+        let _guard = TestHomeGuard::set(&home);
+        let available = update_skill(
+            &RunContext::default(),
+            Some("test-user/test-repo/skill-a"),
+            UpdateLinkMode::Auto,
+            true,
+        )
+        .unwrap();
+
+        assert_eq!(available, 1);
+        assert_eq!(
+            git_head_sha(&clone_dir).unwrap(),
+            installed_commit,
+            "clone must not be pulled"
+        );
+        assert!(!skill_dir.exists(), "nothing should be copied");
+        let db = db::load_db().unwrap();
+        assert_eq!(
+            db.installed["test-user/test-repo/skill-a"].commit.as_deref(),
+            Some(installed_commit.as_str())
+        );
+    }
+
+    /// `--skill-only` refreshes the installed files but does not touch agent links
+    #[test]
+    #[serial_test::serial]
+    fn test_update_skill_only_leaves_agent_links_untouched() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let (home, _clone_dir, _) = setup_update_fixture(temp.path(), false);
+        fs::create_dir_all(home.join(".claude")).unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        update_skill(
+            &RunContext::default(),
+            Some("test-user/test-repo/skill-a"),
+            UpdateLinkMode::SkillOnly,
+            false,
+        )
+        .unwrap();
+
+        let content = fs::read_to_string(home.join(".skillshub/skills/test-user/test-repo/skill-a/SKILL.md")).unwrap();
+        assert!(content.contains("v2"), "skill files should be refreshed");
+        assert!(
+            !home.join(".claude/skills").exists(),
+            "agent directory should not be touched"
+        );
+    }
+
+    /// By default an update that changed something re-links agents
+    #[test]
+    #[serial_test::serial]
+    fn test_update_relinks_agents_after_changes() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let (home, _clone_dir, _) = setup_update_fixture(temp.path(), false);
+        fs::create_dir_all(home.join(".claude")).unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        update_skill(
+            &RunContext::default(),
+            Some("test-user/test-repo/skill-a"),
+            UpdateLinkMode::Auto,
+            false,
+        )
+        .unwrap();
+
+        assert!(home.join(".claude/skills/skill-a").is_symlink());
+    }
+
+    /// Descriptions are truncated by default and kept whole with `--wrap`
+    #[test]
+    fn test_render_skill_table_truncates_unless_wrapping() {
+        let description = "Reviews pull requests. Usage: mention the PR number and the files to focus on";
+        let row = || SkillListRow {
+            status: "○",
+            name: "code-review".to_string(),
+            tap: "owner/repo".to_string(),
+            source: SkillSource::Remote,
+            description: description.to_string(),
+            extras: "-".to_string(),
+            commit: "-".to_string(),
+        };
+
+        let truncated = render_skill_table(vec![row()], false, DESCRIPTION_MAX_LEN);
+        assert!(!truncated.contains(description));
+        assert!(truncated.contains(&truncate_string(description, DESCRIPTION_MAX_LEN)));
+
+        // Tests do not run on a terminal, so the full text is not wrapped either
+        assert!(render_skill_table(vec![row()], true, DESCRIPTION_MAX_LEN).contains(description));
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_search_not_installed_excludes_installed_matches() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let _guard = TestHomeGuard::set(temp.path());
+
+        let mut skills = HashMap::new();
+        for name in ["code-review", "review-notes", "deploy"] {
+            skills.insert(
+                name.to_string(),
+                SkillEntry {
+                    path: format!("skills/{}", name),
+                    description: None,
+                    homepage: None,
+                    commit: None,
+                    tags: Vec::new(),
+                    aliases: Vec::new(),
+                },
+            );
+        }
+
+        let mut db = Database::default();
+        db::add_tap(
+            &mut db,
+            "owner/repo",
+            TapInfo {
+                url: "https://github.com/owner/repo".to_string(),
+                custom_skills_path: None,
+                updated_at: Some(Utc::now()),
+                is_default: false,
+                cached_registry: Some(TapRegistry {
+                    name: "owner/repo".to_string(),
+                    description: None,
+                    schema_version: None,
+                    skills,
+                }),
+                branch: None,
+                token_env: None,
+                registry_file: None,
+            },
+        );
+        db.installed.insert(
+            "owner/repo/code-review".to_string(),
+            installed_skill("owner/repo", "code-review", Some("abc1234"), None),
+        );
+
+        let mut names = |installed| -> Vec<String> {
+            collect_search_results(&mut db, "review", false, None, installed)
+                .into_iter()
+                .map(|row| row.name)
+                .collect()
+        };
+        assert_eq!(names(None), vec!["code-review", "review-notes"]);
+        assert_eq!(names(Some(false)), vec!["review-notes"]);
+        assert_eq!(names(Some(true)), vec!["code-review"]);
+    }
+
+    #[test]
+    fn test_remote_info_target() {
+        let mut db = Database::default();
+        db::add_tap(
+            &mut db,
+            "added/tap",
+            super::super::models::TapInfo {
+                url: "https://github.com/added/tap".to_string(),
+                custom_skills_path: None,
+                updated_at: None,
+                is_default: false,
+                cached_registry: None,
+                branch: None,
+                token_env: None,
+                registry_file: None,
+            },
+        );
+
+        // Skills from added taps resolve locally
+        assert!(remote_info_target(&db, "added/tap/skill").unwrap().is_none());
+
+        let (url, skill) = remote_info_target(&db, "other/repo/skill").unwrap().unwrap();
+        assert_eq!((url.owner.as_str(), url.repo.as_str()), ("other", "repo"));
+        assert_eq!(skill, "skill");
+
+        let (url, skill) = remote_info_target(&db, "https://github.com/other/repo/tree/main/skills/my-skill")
+            .unwrap()
+            .unwrap();
+        assert_eq!(url.path.as_deref(), Some("skills/my-skill"));
+        assert_eq!(skill, "my-skill");
+
+        assert!(remote_info_target(&db, "https://github.com/other/repo").is_err());
     }
 }
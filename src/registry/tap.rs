@@ -1,5 +1,5 @@
 use anyhow::{Context, Result};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use colored::Colorize;
 use std::collections::HashMap;
 use std::path::Path;
@@ -10,17 +10,28 @@ use tabled::{
 use walkdir::WalkDir;
 
 use super::db::{self, DEFAULT_TAP_NAME};
-use super::git::{git_clone, pull_or_reclone, tap_clone_path};
+use super::git::{git_clone, git_current_branch, git_head_sha, pull_or_reclone, tap_clone_path};
 use super::github::{
-    discover_skills_from_repo, fetch_star_list_repos, is_gist_url, is_safe_skill_name, parse_github_url,
-    parse_skill_md_content, parse_star_list_url,
+    discover_skills_from_repo, ensure_online, fetch_star_list_repos, is_gist_url, is_offline, is_safe_skill_name,
+    parse_github_url, parse_skill_md_frontmatter, parse_star_list_url, tap_token,
 };
-use super::models::{Database, SkillEntry, TapInfo, TapRegistry};
-use crate::paths::get_taps_clone_dir;
-use crate::util::truncate_string;
+use super::models::{
+    Database, InstallLayout, SkillEntry, TapInfo, TapRegistry, DEFAULT_SKILLS_PATH, REGISTRY_SCHEMA_VERSION,
+};
+use super::skill::remove_empty_tap_dir;
+use crate::action_log::log_action;
+use crate::commands::{find_agent_links_to, link_to_agents, remove_agent_links};
+use crate::context::RunContext;
+use crate::output::is_quiet;
+use crate::paths::{get_skills_install_dir, get_taps_clone_dir};
+use crate::skill::{parse_skill_metadata, SkillMetadata};
+use crate::util::{more_line, take_limited, truncate_string};
 
 const TAP_URL_MAX_LEN: usize = 50;
 
+/// Environment variable holding the registry cache TTL in seconds (also set by `--registry-cache-ttl`)
+pub const CACHE_TTL_ENV: &str = "SKILLSHUB_CACHE_TTL_SECS";
+
 /// Table row for displaying taps
 #[derive(Tabled)]
 pub struct TapRow {
@@ -34,12 +45,65 @@ pub struct TapRow {
     pub is_default: &'static str,
 }
 
-/// Add a new tap from a GitHub URL
-pub fn add_tap(url: &str, branch: Option<&str>, install: bool) -> Result<()> {
+/// Number of skills `tap add` lists after adding a tap, unless `--limit` says otherwise
+pub const TAP_PREVIEW_LIMIT: usize = 10;
+
+/// Options for `skillshub tap add`
+#[derive(Debug, Clone, Copy)]
+pub struct TapAddOptions<'a> {
+    /// Branch to track instead of the one in the URL or the repository default (`--branch`)
+    pub branch: Option<&'a str>,
+    /// Environment variable holding this tap's GitHub token (`--token-env`)
+    pub token_env: Option<&'a str>,
+    /// Only discover skills under this folder of the repo (`--skills-path`)
+    pub skills_path: Option<&'a str>,
+    /// Read the skill list from this file in the repo instead (`--registry-file`)
+    pub registry_file: Option<&'a str>,
+    /// Install every skill once the tap is added (`--install`)
+    pub install: bool,
+    /// How many of the tap's skills are listed afterwards (`--limit`)
+    pub preview_limit: usize,
+}
+
+impl Default for TapAddOptions<'_> {
+    fn default() -> Self {
+        Self {
+            branch: None,
+            token_env: None,
+            skills_path: None,
+            registry_file: None,
+            install: false,
+            preview_limit: TAP_PREVIEW_LIMIT,
+        }
+    }
+}
+
+/// Add a new tap from a GitHub URL. With `--dry-run` the repository is cloned into a
+/// scratch directory and its skills are listed, but nothing is saved.
+pub fn add_tap(ctx: &RunContext, url: &str, options: &TapAddOptions) -> Result<()> {
+    let TapAddOptions {
+        branch,
+        token_env,
+        skills_path,
+        registry_file,
+        install,
+        preview_limit,
+    } = *options;
+    ensure_online("tap add")?;
+
+    let skills_path = skills_path.map(validate_skills_path).transpose()?;
+    if skills_path.is_some() && is_gist_url(url) {
+        anyhow::bail!("--skills-path is not supported for gist taps");
+    }
+    let registry_file = registry_file.map(validate_registry_file).transpose()?;
+    if registry_file.is_some() && is_gist_url(url) {
+        anyhow::bail!("--registry-file is not supported for gist taps");
+    }
+
     let github_url = parse_github_url(url)?;
     let tap_name = github_url.tap_name();
 
-    let mut db = db::init_db()?;
+    let mut db = db::init_db_with(ctx)?;
 
     // Check if tap already exists
     if db.taps.contains_key(&tap_name) {
@@ -51,20 +115,36 @@ pub fn add_tap(url: &str, branch: Option<&str>, install: bool) -> Result<()> {
     }
 
     let base_url = github_url.base_url();
-    println!("{} Adding tap '{}' from {}", "=>".green().bold(), tap_name, base_url);
+    say!(
+        "{}{} Adding tap '{}' from {}",
+        ctx.prefix(),
+        "=>".green().bold(),
+        tap_name,
+        base_url
+    );
 
     // CLI --branch overrides URL-parsed branch; either is persisted in TapInfo
     let effective_branch = branch.or(github_url.branch.as_deref());
 
+    // A dry run clones into a scratch directory that is dropped afterwards
+    let scratch = if ctx.dry_run {
+        Some(tempfile::TempDir::new()?)
+    } else {
+        None
+    };
+    let tap_clone_dir = tap_clone_path(&get_taps_clone_dir()?, &tap_name);
+
     // For gist URLs, use the API-based discovery (no local clone)
     let registry = if is_gist_url(url) {
-        println!("  {} Discovering skills...", "○".yellow());
-        discover_skills_from_repo(&github_url, &tap_name)
+        say!("  {} Discovering skills...", "○".yellow());
+        discover_skills_from_repo(&github_url, &tap_name, tap_token(token_env)?.as_deref())
             .with_context(|| format!("Failed to discover skills from {}", base_url))?
     } else {
         // Clone the repo locally and discover skills from the filesystem
-        let taps_dir = get_taps_clone_dir()?;
-        let clone_dir = tap_clone_path(&taps_dir, &tap_name);
+        let clone_dir = match &scratch {
+            Some(scratch) => scratch.path().join("tap"),
+            None => tap_clone_dir.clone(),
+        };
 
         if clone_dir.exists() {
             std::fs::remove_dir_all(&clone_dir)?;
@@ -73,48 +153,72 @@ pub fn add_tap(url: &str, branch: Option<&str>, install: bool) -> Result<()> {
             std::fs::create_dir_all(parent)?;
         }
 
-        println!("  {} Cloning repository...", "○".yellow());
+        say!("  {} Cloning repository...", "○".yellow());
         git_clone(&base_url, &clone_dir, effective_branch).with_context(|| format!("Failed to clone {}", base_url))?;
 
-        println!("  {} Discovering skills...", "○".yellow());
-        discover_skills_from_local(&clone_dir, &tap_name)
+        if let Some(path) = &skills_path {
+            if !clone_dir.join(path).is_dir() {
+                let _ = std::fs::remove_dir_all(&clone_dir);
+                anyhow::bail!("Skills path '{}' not found in {}", path, base_url);
+            }
+        }
+
+        say!("  {} Discovering skills...", "○".yellow());
+        load_tap_registry(&clone_dir, skills_path.as_deref(), registry_file.as_deref(), &tap_name)
             .with_context(|| format!("Failed to discover skills from {}", base_url))?
     };
 
     let tap_info = TapInfo {
         url: base_url.clone(),
-        skills_path: "skills".to_string(),
+        custom_skills_path: skills_path,
         updated_at: Some(Utc::now()),
         is_default: false,
         cached_registry: Some(registry.clone()),
         branch: effective_branch.map(|s| s.to_string()),
+        token_env: token_env.map(str::to_string),
+        registry_file,
     };
 
-    db::add_tap(&mut db, &tap_name, tap_info);
-    db::save_db(&db)?;
+    if ctx.dry_run {
+        if !is_gist_url(url) {
+            ctx.would(format!("clone {} into {}", base_url, tap_clone_dir.display()));
+        }
+        ctx.would(format!(
+            "add tap '{}' with {} skills to db.json",
+            tap_name,
+            registry.skills.len()
+        ));
+        if install && !registry.skills.is_empty() {
+            ctx.would(format!("install {} skills from '{}'", registry.skills.len(), tap_name));
+        }
+    } else {
+        db::add_tap(&mut db, &tap_name, tap_info);
+        db::save_db(&db)?;
 
-    println!(
-        "  {} Added tap '{}' with {} skills",
-        "✓".green(),
-        tap_name,
-        registry.skills.len()
-    );
+        say!(
+            "  {} Added tap '{}' with {} skills",
+            "✓".green(),
+            tap_name,
+            registry.skills.len()
+        );
+    }
 
     // Show available skills (only if not installing)
-    if !install && !registry.skills.is_empty() {
-        println!("\n  Available skills:");
-        for (name, entry) in registry.skills.iter().take(10) {
+    if (!install || ctx.dry_run) && !registry.skills.is_empty() {
+        say!("\n  Available skills:");
+        let (shown, hidden) = take_limited(&registry.skills, Some(preview_limit));
+        for (name, entry) in shown {
             let desc = entry.description.as_deref().unwrap_or("No description");
-            println!("    {} {}/{} - {}", "•".cyan(), tap_name, name, desc);
+            say!("    {} {}/{} - {}", "•".cyan(), tap_name, name, desc);
         }
-        if registry.skills.len() > 10 {
-            println!("    {} ... and {} more", "•".cyan(), registry.skills.len() - 10);
+        if let Some(more) = more_line(hidden) {
+            say!("    {} {}", "•".cyan(), more);
         }
     }
 
     // Install all skills if requested
-    if install && !registry.skills.is_empty() {
-        println!();
+    if install && !ctx.dry_run && !registry.skills.is_empty() {
+        say!();
         super::skill::install_all_from_tap(&tap_name)?;
     }
 
@@ -139,16 +243,16 @@ pub fn remove_tap(name: &str, keep_skills: bool) -> Result<()> {
         let skill_names: Vec<String> = installed_from_tap.iter().map(|(n, _)| (*n).clone()).collect();
 
         if keep_skills {
-            println!(
+            say!(
                 "  {} {} skill(s) kept but can no longer be updated (tap removed):",
                 "!".yellow().bold(),
                 skill_names.len()
             );
             for full_name in &skill_names {
-                println!("      {}", full_name);
+                say!("      {}", full_name);
             }
         } else {
-            println!(
+            say!(
                 "{} Uninstalling {} skill(s) from tap '{}'",
                 "=>".green().bold(),
                 skill_names.len(),
@@ -156,7 +260,11 @@ pub fn remove_tap(name: &str, keep_skills: bool) -> Result<()> {
             );
 
             for full_name in &skill_names {
-                super::skill::uninstall_skill(full_name)?;
+                super::skill::uninstall_skill(
+                    &RunContext::default(),
+                    full_name,
+                    &super::skill::UninstallOptions::default(),
+                )?;
             }
 
             // Re-init db since uninstall_skill saves after each removal
@@ -187,14 +295,198 @@ pub fn remove_tap(name: &str, keep_skills: bool) -> Result<()> {
         }
     }
 
-    println!("{} Removed tap '{}'", "✓".green(), name);
+    say!("{} Removed tap '{}'", "✓".green(), name);
+
+    Ok(())
+}
+
+/// Well-known taps that `tap add-default <shortname>` adds by name (shortname -> URL)
+pub const WELL_KNOWN_TAPS: &[(&str, &str)] = &[
+    ("anthropics", "https://github.com/anthropics/skills"),
+    ("superpowers", "https://github.com/obra/superpowers"),
+];
+
+/// URL of the well-known tap called `shortname`
+fn well_known_tap_url(shortname: &str) -> Result<&'static str> {
+    WELL_KNOWN_TAPS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(shortname))
+        .map(|(_, url)| *url)
+        .with_context(|| {
+            let known: Vec<&str> = WELL_KNOWN_TAPS.iter().map(|(name, _)| *name).collect();
+            format!("Unknown tap shortname '{}'. Known: {}", shortname, known.join(", "))
+        })
+}
+
+/// Add a tap from [`WELL_KNOWN_TAPS`] by its shortname (`tap add-default <shortname>`)
+pub fn add_well_known_tap(ctx: &RunContext, shortname: &str) -> Result<()> {
+    let url = well_known_tap_url(shortname)?;
+    add_tap(ctx, url, &TapAddOptions::default())
+}
+
+/// Re-add the bundled default tap if it is missing or was edited in `db.json`
+pub fn add_default_tap() -> Result<()> {
+    // Load without init_db, which would silently re-add the tap before we can report it
+    let mut db = db::load_db()?;
+    let restored = db::restore_default_taps(&mut db);
+
+    if restored.is_empty() {
+        say!(
+            "{} Default tap '{}' is already configured",
+            "Info:".cyan(),
+            DEFAULT_TAP_NAME
+        );
+        return Ok(());
+    }
+
+    db::save_db(&db)?;
+    for name in restored {
+        say!("{} Restored default tap '{}'", "✓".green(), name);
+    }
 
     Ok(())
 }
 
+/// Make `name` the default tap (`skillshub tap set-default`).
+///
+/// `install-all` installs the default tap's skills first, so they keep their plain link
+/// names when another tap ships a skill with the same name. Choosing the bundled tap
+/// again clears the setting. The bundled tap keeps its local-copy installs either way.
+pub fn set_default_tap(name: &str) -> Result<()> {
+    let mut db = db::init_db()?;
+    let tap = db::get_tap(&db, name).with_context(|| format!("Tap '{}' not found", name))?;
+
+    if db::default_tap_name(&db) == Some(name) {
+        say!("{} '{}' is already the default tap", "Info:".cyan(), name);
+        return Ok(());
+    }
+
+    db.default_tap = if tap.is_default { None } else { Some(name.to_string()) };
+    db::save_db(&db)?;
+    say!("{} Default tap set to '{}'", "✓".green(), name);
+
+    Ok(())
+}
+
+/// Rename a tap, e.g. after its GitHub repository was renamed.
+///
+/// Moves the tap's db.json entry, the keys and `tap` field of its installed skills, its
+/// directory under the install dir, and its clone. Agent links into the old directory are
+/// replaced by links to the new one. The tap URL is left unchanged.
+pub fn rename_tap(old: &str, new: &str) -> Result<()> {
+    validate_tap_name(new)?;
+    let mut db = db::init_db()?;
+    let tap = db::get_tap(&db, old).with_context(|| format!("Tap '{}' not found", old))?;
+    if tap.is_default || old == DEFAULT_TAP_NAME {
+        anyhow::bail!("The bundled tap '{}' cannot be renamed", old);
+    }
+    if db.taps.contains_key(new) {
+        anyhow::bail!("Tap '{}' already exists", new);
+    }
+
+    let install_dir = get_skills_install_dir()?;
+    let old_dir = install_dir.join(old);
+    let new_dir = install_dir.join(new);
+    let moves_dir = db.layout == InstallLayout::Nested && old_dir.exists();
+    if moves_dir && new_dir.exists() {
+        anyhow::bail!("Cannot rename tap: {} already exists", new_dir.display());
+    }
+
+    // Agent links only resolve to the skills while the old directory is still there
+    let moved: Vec<String> = db
+        .installed
+        .iter()
+        .filter(|(_, installed)| installed.tap == old)
+        .map(|(full_name, _)| full_name.clone())
+        .collect();
+    let mut agent_links = Vec::new();
+    if moves_dir {
+        for full_name in &moved {
+            let skill_dir = db.skill_dir(&install_dir, old, &db.installed[full_name].skill);
+            agent_links.extend(find_agent_links_to(
+                &skill_dir.canonicalize().unwrap_or_else(|_| skill_dir.clone()),
+            ));
+        }
+
+        if let Some(parent) = new_dir.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        log_action(format!("rename {} -> {}", old_dir.display(), new_dir.display()));
+        std::fs::rename(&old_dir, &new_dir)
+            .with_context(|| format!("Failed to move {} to {}", old_dir.display(), new_dir.display()))?;
+        remove_empty_tap_dir(&install_dir, old)?;
+    }
+
+    // A clone that can't be moved is simply re-cloned by the next `tap update`
+    if let Ok(taps_dir) = get_taps_clone_dir() {
+        let (old_clone, new_clone) = (tap_clone_path(&taps_dir, old), tap_clone_path(&taps_dir, new));
+        if old_clone.exists() && !new_clone.exists() {
+            let moved_clone = new_clone
+                .parent()
+                .map_or(Ok(()), std::fs::create_dir_all)
+                .and_then(|()| std::fs::rename(&old_clone, &new_clone));
+            if let Err(e) = moved_clone {
+                eprintln!("  {} Failed to move clone directory: {}", "!".yellow(), e);
+            }
+        }
+    }
+
+    let mut tap = db.taps.remove(old).expect("tap checked above");
+    if let Some(registry) = tap.cached_registry.as_mut() {
+        registry.name = new.to_string();
+    }
+    db.taps.insert(new.to_string(), tap);
+    for full_name in &moved {
+        let mut installed = db.installed.remove(full_name).expect("key collected above");
+        installed.tap = new.to_string();
+        let new_name = format!("{}/{}", new, installed.skill);
+        if let Some(link_name) = db.link_names.remove(full_name) {
+            db.link_names.insert(new_name.clone(), link_name);
+        }
+        db.installed.insert(new_name, installed);
+    }
+    if db.default_tap.as_deref() == Some(old) {
+        db.default_tap = Some(new.to_string());
+    }
+    db::save_db(&db)?;
+
+    say!(
+        "{} Renamed tap '{}' to '{}' ({} installed skill(s) moved)",
+        "✓".green(),
+        old,
+        new,
+        moved.len()
+    );
+
+    if !agent_links.is_empty() {
+        remove_agent_links(&agent_links);
+        link_to_agents(&RunContext::default())?;
+    }
+
+    Ok(())
+}
+
+/// Check a tap name given on the command line: `owner/repo` (or a single legacy name)
+/// made of letters, digits, `-`, `_`, and `.`
+fn validate_tap_name(name: &str) -> Result<()> {
+    let parts: Vec<&str> = name.split('/').collect();
+    let valid_part = |part: &&str| {
+        !part.is_empty()
+            && *part != "."
+            && *part != ".."
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    };
+    if parts.len() > 2 || !parts.iter().all(valid_part) {
+        anyhow::bail!("Invalid tap name '{}'. Use format: owner/repo", name);
+    }
+    Ok(())
+}
+
 /// List all configured taps
 pub fn list_taps() -> Result<()> {
-    let db = db::init_db()?;
+    let mut db = db::init_db()?;
 
     if db.taps.is_empty() {
         println!("No taps configured.");
@@ -202,13 +494,16 @@ pub fn list_taps() -> Result<()> {
     }
 
     let mut rows: Vec<TapRow> = Vec::new();
-
-    for (name, tap) in &db.taps {
+    let default_tap = db::default_tap_name(&db).map(str::to_string);
+    let mut tap_names: Vec<String> = db.taps.keys().cloned().collect();
+    tap_names.sort();
+    for name in &tap_names {
         let installed_count = count_installed_skills(&db, name);
-        let available_count = get_tap_registry(&db, name)
+        let available_count = get_tap_registry(&mut db, name)
             .ok()
             .and_then(|opt| opt)
             .map(|registry| registry.skills.len());
+        let tap = &db.taps[name];
         let skills_count = format_skills_count(installed_count, available_count);
 
         let display_url = match &tap.branch {
@@ -223,7 +518,11 @@ pub fn list_taps() -> Result<()> {
             name: name.clone(),
             url: display_url,
             skills_count,
-            is_default: if tap.is_default { "✓" } else { "" },
+            is_default: if default_tap.as_deref() == Some(name.as_str()) {
+                "✓"
+            } else {
+                ""
+            },
         });
     }
 
@@ -244,9 +543,264 @@ pub fn list_taps() -> Result<()> {
     println!();
     println!("{} taps configured", db.taps.len());
 
+    save_refreshed_registries(&db)
+}
+
+/// Result of checking one registry entry in `tap verify`
+#[derive(Debug, PartialEq, Eq)]
+enum VerifyStatus {
+    Ok,
+    /// The path is absolute or steps outside the repository
+    UnsafePath,
+    MissingSkillMd,
+    /// SKILL.md exists but its frontmatter does not parse
+    InvalidSkillMd(String),
+}
+
+/// Table row for `tap verify`
+#[derive(Tabled)]
+struct VerifyRow {
+    #[tabled(rename = "Skill")]
+    skill: String,
+    #[tabled(rename = "Path")]
+    path: String,
+    #[tabled(rename = "Result")]
+    result: String,
+}
+
+/// Check every entry of `registry` against the repository checked out at `root`,
+/// sorted by skill name
+fn verify_registry(root: &Path, registry: &TapRegistry) -> Vec<(String, String, VerifyStatus)> {
+    let mut results: Vec<(String, String, VerifyStatus)> = registry
+        .skills
+        .iter()
+        .map(|(name, entry)| {
+            let entry_path = entry.path.trim_end_matches('/');
+            let safe = !entry_path.starts_with('/')
+                && entry_path
+                    .split('/')
+                    .all(|part| part.is_empty() || part == "." || is_safe_skill_name(part));
+            let skill_md = root.join(entry_path).join("SKILL.md");
+            let status = if !safe {
+                VerifyStatus::UnsafePath
+            } else if !skill_md.is_file() {
+                VerifyStatus::MissingSkillMd
+            } else {
+                match parse_skill_metadata(&skill_md) {
+                    Ok(_) => VerifyStatus::Ok,
+                    Err(e) => VerifyStatus::InvalidSkillMd(format!("{:#}", e)),
+                }
+            };
+            (name.clone(), entry.path.clone(), status)
+        })
+        .collect();
+    results.sort_by(|a, b| a.0.cmp(&b.0));
+    results
+}
+
+/// Check that every skill in a tap's registry has a readable SKILL.md at its listed path
+/// in the local clone (`tap verify`), printing a pass/fail table. Fails if any entry does.
+pub fn verify_tap(name: &str) -> Result<()> {
+    let mut db = db::init_db()?;
+    let tap = db::get_tap(&db, name).with_context(|| format!("Tap '{}' not found", name))?;
+    if is_gist_url(&tap.url) {
+        anyhow::bail!("tap verify is not supported for gist taps");
+    }
+    let registry = get_tap_registry(&mut db, name)?.with_context(|| {
+        format!(
+            "No cached registry for tap '{}'. Run 'skillshub tap update {}' first.",
+            name, name
+        )
+    })?;
+    save_refreshed_registries(&db)?;
+    let tap = &db.taps[name];
+
+    let clone_dir = tap_clone_path(&get_taps_clone_dir()?, name);
+    let root = if clone_dir.join(".git").exists() {
+        clone_dir
+    } else if tap.is_default && tap.cached_registry.is_none() {
+        // Bundled registry paths are relative to the folder holding the bundled skills dir
+        let bundled = crate::paths::get_embedded_skills_dir()?;
+        bundled.parent().map(Path::to_path_buf).unwrap_or(bundled)
+    } else {
+        anyhow::bail!(
+            "No local clone for tap '{}'. Run 'skillshub tap update {}' to create one.",
+            name,
+            name
+        );
+    };
+
+    let results = verify_registry(&root, &registry);
+    let failed = results
+        .iter()
+        .filter(|(_, _, status)| *status != VerifyStatus::Ok)
+        .count();
+    let rows: Vec<VerifyRow> = results
+        .into_iter()
+        .map(|(skill, path, status)| VerifyRow {
+            skill,
+            path,
+            result: match status {
+                VerifyStatus::Ok => format!("{} ok", "✓".green()),
+                VerifyStatus::UnsafePath => format!("{} unsafe path", "✗".red()),
+                VerifyStatus::MissingSkillMd => format!("{} no SKILL.md", "✗".red()),
+                VerifyStatus::InvalidSkillMd(e) => format!("{} invalid SKILL.md: {}", "✗".red(), e),
+            },
+        })
+        .collect();
+    let total = rows.len();
+
+    println!(
+        "{}",
+        Table::new(rows).with(Style::rounded()).with(Padding::new(1, 1, 0, 0))
+    );
+    println!();
+
+    if failed > 0 {
+        anyhow::bail!("{} of {} skills in '{}' failed verification", failed, total, name);
+    }
+    println!("{} All {} skills in '{}' verified", "✓".green(), total, name);
+    Ok(())
+}
+
+/// Show full detail for one tap: source, flags, registry cache state, branch, and the
+/// skills it offers with their install status
+pub fn show_tap_info(name: &str) -> Result<()> {
+    let mut db = db::init_db()?;
+    if db::get_tap(&db, name).is_none() {
+        anyhow::bail!("Tap '{}' not found", name);
+    }
+    let registry = get_tap_registry(&mut db, name)?;
+    save_refreshed_registries(&db)?;
+    let tap = &db.taps[name];
+    let clone_dir = tap_clone_path(&get_taps_clone_dir()?, name);
+    let cloned = clone_dir.join(".git").exists();
+
+    println!("{}", name.bold());
+    println!();
+    println!("  {}: {}", "URL".cyan(), tap.url);
+    println!(
+        "  {}: {}",
+        "Skills path".cyan(),
+        tap.custom_skills_path.as_deref().unwrap_or("(whole repository)")
+    );
+    let is_default = db::default_tap_name(&db) == Some(name);
+    println!("  {}: {}", "Default".cyan(), if is_default { "yes" } else { "no" });
+    // The default tap falls back to the skills bundled with skillshub until it has a cache
+    let bundled = tap.is_default && tap.cached_registry.is_none();
+    println!("  {}: {}", "Bundled".cyan(), if bundled { "yes" } else { "no" });
+    if cloned {
+        println!("  {}: {}", "Local clone".cyan(), clone_dir.display());
+    } else {
+        println!("  {}: {}", "Local clone".cyan(), "none".dimmed());
+    }
+
+    let branch = match &tap.branch {
+        Some(branch) => branch.clone(),
+        None if cloned => git_current_branch(&clone_dir).unwrap_or_else(|_| "default".to_string()),
+        None => "default".to_string(),
+    };
+    println!("  {}: {}", "Branch".cyan(), branch);
+    if let Some(var) = &tap.token_env {
+        println!("  {}: {}", "Token env".cyan(), var);
+    }
+    if let Some(file) = &tap.registry_file {
+        println!("  {}: {}", "Registry file".cyan(), file);
+    }
+
+    match tap.updated_at {
+        Some(updated_at) => println!(
+            "  {}: {} ({})",
+            "Updated".cyan(),
+            updated_at.format("%Y-%m-%d %H:%M"),
+            format_age(updated_at, Utc::now())
+        ),
+        None => println!("  {}: {}", "Updated".cyan(), "never".dimmed()),
+    }
+    match &tap.cached_registry {
+        Some(cached) => println!("  {}: yes ({} skills)", "Cached registry".cyan(), cached.skills.len()),
+        None => println!("  {}: {}", "Cached registry".cyan(), "no".dimmed()),
+    }
+
+    println!();
+    let Some(registry) = registry else {
+        println!(
+            "{} No skills cached. Run 'skillshub tap update {}' to fetch them.",
+            "Info:".cyan(),
+            name
+        );
+        return Ok(());
+    };
+
+    let installed: Vec<&str> = db::get_skills_from_tap(&db, name)
+        .into_iter()
+        .map(|(_, skill)| skill.skill.as_str())
+        .collect();
+
+    let mut skills: Vec<(&String, &SkillEntry)> = registry.skills.iter().collect();
+    skills.sort_by(|a, b| a.0.cmp(b.0));
+
+    println!(
+        "{} ({}):",
+        "Skills".bold(),
+        format_skills_count(installed.len(), Some(skills.len()))
+    );
+    for (skill_name, entry) in &skills {
+        let status = if installed.contains(&skill_name.as_str()) {
+            "✓".green()
+        } else {
+            "○".yellow()
+        };
+        match &entry.description {
+            Some(desc) => println!("  {} {} - {}", status, skill_name, desc),
+            None => println!("  {} {}", status, skill_name),
+        }
+    }
+
+    // Installed skills the cached registry no longer lists
+    let mut stale: Vec<&str> = installed
+        .into_iter()
+        .filter(|skill| !registry.skills.contains_key(*skill))
+        .collect();
+    stale.sort();
+    for skill in stale {
+        println!("  {} {} {}", "✓".green(), skill, "(not in registry)".dimmed());
+    }
+
     Ok(())
 }
 
+/// Normalize a `--skills-path` value to a `/`-separated path inside the repo
+fn validate_skills_path(path: &str) -> Result<String> {
+    let parts: Vec<&str> = path.split(['/', '\\']).filter(|part| !part.is_empty()).collect();
+    if parts.is_empty() || path.starts_with('/') || !parts.iter().all(|part| is_safe_skill_name(part)) {
+        anyhow::bail!(
+            "Invalid --skills-path '{}': use a folder relative to the repository root (e.g., 'skills')",
+            path
+        );
+    }
+    Ok(parts.join("/"))
+}
+
+/// Join a repo-relative folder and a skill name (`""` means the repo root)
+fn join_repo_path(dir: &str, name: &str) -> String {
+    match dir.trim_matches('/') {
+        "" => name.to_string(),
+        dir => format!("{}/{}", dir, name),
+    }
+}
+
+/// Human-readable age of a timestamp, e.g. "3h ago"
+fn format_age(then: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    let secs = now.signed_duration_since(then).num_seconds().max(0);
+    match secs {
+        0..=59 => "just now".to_string(),
+        60..=3599 => format!("{}m ago", secs / 60),
+        3600..=86_399 => format!("{}h ago", secs / 3600),
+        _ => format!("{}d ago", secs / 86_400),
+    }
+}
+
 /// Update tap registries (fetch latest from remote)
 pub fn update_tap(name: Option<&str>) -> Result<()> {
     let mut db = db::init_db()?;
@@ -261,49 +815,81 @@ pub fn update_tap(name: Option<&str>) -> Result<()> {
         None => db.taps.keys().cloned().collect(),
     };
 
+    if is_offline() {
+        say!(
+            "{} Offline mode: showing cached registries without fetching",
+            "Info:".cyan()
+        );
+        for tap_name in &taps_to_update {
+            match get_tap_registry(&mut db, tap_name) {
+                Ok(Some(registry)) => {
+                    say!(
+                        "  {} {} ({} skills, cached)",
+                        "✓".green(),
+                        tap_name,
+                        registry.skills.len()
+                    )
+                }
+                _ => say!("  {} {} (no cached registry)", "✗".red(), tap_name),
+            }
+        }
+        return Ok(());
+    }
+
     for tap_name in taps_to_update {
         let tap = db.taps.get(&tap_name).unwrap().clone();
 
         // Skip synthetic gist taps — they have no backing repository to update from
         if tap.url.contains("gist.github.com") {
             let count = count_installed_skills(&db, &tap_name);
-            println!("  {} {} ({} skills, gist)", "✓".green(), tap_name, count);
+            say!("  {} {} ({} skills, gist)", "✓".green(), tap_name, count);
             continue;
         }
 
-        print!("  {} Updating {}...", "○".yellow(), tap_name);
+        say_inline!("  {} Updating {}...", "○".yellow(), tap_name);
 
         match update_single_tap(&mut db, &tap_name, &tap) {
             Ok(result) => {
-                println!("\r  {} {} ({} skills)", "✓".green(), tap_name, result.total);
+                say!("\r  {} {} ({} skills)", "✓".green(), tap_name, result.total);
 
                 if !result.new_skills.is_empty() {
-                    println!("    {} new:", "+".green());
+                    say!("    {} new:", "+".green());
                     for skill in &result.new_skills {
-                        println!("      {} {}/{}", "+".green(), tap_name, skill);
+                        say!("      {} {}/{}", "+".green(), tap_name, skill);
                     }
                 }
 
                 if !result.removed_skills.is_empty() {
-                    println!("    {} removed:", "-".red());
+                    say!("    {} removed:", "-".red());
                     for skill in &result.removed_skills {
-                        println!("      {} {}/{}", "-".red(), tap_name, skill);
+                        say!("      {} {}/{}", "-".red(), tap_name, skill);
+                    }
+                }
+
+                if !result.changed_skills.is_empty() {
+                    say!("    {} changed description:", "~".yellow());
+                    for skill in &result.changed_skills {
+                        say!("      {} {}/{}", "~".yellow(), tap_name, skill);
                     }
                 }
 
                 if !result.removed_installed.is_empty() {
-                    println!(
+                    say!(
                         "\n    {} {} installed skill(s) no longer in tap:",
                         "!".yellow().bold(),
                         result.removed_installed.len()
                     );
                     for skill in &result.removed_installed {
-                        println!("      skillshub uninstall {}/{}", tap_name, skill);
+                        say!("      skillshub uninstall {}/{}", tap_name, skill);
                     }
                 }
             }
             Err(e) => {
-                println!("\r  {} {} ({})", "✗".red(), tap_name, e);
+                if is_quiet() {
+                    eprintln!("  {} {} ({})", "✗".red(), tap_name, e);
+                } else {
+                    println!("\r  {} {} ({})", "✗".red(), tap_name, e);
+                }
             }
         }
     }
@@ -321,6 +907,8 @@ struct TapUpdateResult {
     new_skills: Vec<String>,
     /// Skills removed from the tap since last update
     removed_skills: Vec<String>,
+    /// Skills whose description changed since last update
+    changed_skills: Vec<String>,
     /// Subset of removed_skills that are currently installed (need user action)
     removed_installed: Vec<String>,
 }
@@ -330,7 +918,7 @@ fn update_single_tap(db: &mut Database, name: &str, tap: &TapInfo) -> Result<Tap
     // For gist taps, use API-based discovery (no local clone)
     let new_registry = if is_gist_url(&tap.url) {
         let github_url = parse_github_url(&tap.url)?;
-        discover_skills_from_repo(&github_url, name)?
+        discover_skills_from_repo(&github_url, name, tap_token(tap.token_env.as_deref())?.as_deref())?
     } else {
         let taps_dir = get_taps_clone_dir()?;
         let clone_dir = tap_clone_path(&taps_dir, name);
@@ -347,7 +935,12 @@ fn update_single_tap(db: &mut Database, name: &str, tap: &TapInfo) -> Result<Tap
                 .with_context(|| format!("Failed to pull updates for {}", name))?;
         }
 
-        discover_skills_from_local(&clone_dir, name)?
+        load_tap_registry(
+            &clone_dir,
+            tap.custom_skills_path.as_deref(),
+            tap.registry_file.as_deref(),
+            name,
+        )?
     };
 
     // Compare old vs new registries to detect changes
@@ -375,6 +968,11 @@ fn update_single_tap(db: &mut Database, name: &str, tap: &TapInfo) -> Result<Tap
     // Sort for deterministic output
     added.sort();
     removed.sort();
+    let changed = tap
+        .cached_registry
+        .as_ref()
+        .map(|old| changed_descriptions(old, &new_registry))
+        .unwrap_or_default();
 
     // Check which removed skills are currently installed
     let mut removed_installed: Vec<String> = removed
@@ -399,10 +997,27 @@ fn update_single_tap(db: &mut Database, name: &str, tap: &TapInfo) -> Result<Tap
         total,
         new_skills: added,
         removed_skills: removed,
+        changed_skills: changed,
         removed_installed,
     })
 }
 
+/// Skills present in both registries whose description differs, sorted by name
+fn changed_descriptions(old: &TapRegistry, new: &TapRegistry) -> Vec<String> {
+    let mut changed: Vec<String> = new
+        .skills
+        .iter()
+        .filter(|(name, entry)| {
+            old.skills
+                .get(*name)
+                .is_some_and(|old_entry| old_entry.description != entry.description)
+        })
+        .map(|(name, _)| name.clone())
+        .collect();
+    changed.sort();
+    changed
+}
+
 /// Count installed skills for a given tap
 fn count_installed_skills(db: &Database, tap_name: &str) -> usize {
     db::get_skills_from_tap(db, tap_name).len()
@@ -425,29 +1040,140 @@ fn format_skills_count(installed: usize, available: Option<usize>) -> String {
     format!("{}/{}", installed, available_display)
 }
 
-/// Get the registry for a tap (uses cache only, never fetches from remote).
+/// Get the registry for a tap.
 ///
-/// If the cache is empty, falls back to local bundled skills for the default tap,
-/// or returns `None` for non-default taps. Use `tap update` to populate the cache.
-pub fn get_tap_registry(db: &Database, tap_name: &str) -> Result<Option<TapRegistry>> {
+/// Uses the cached registry, refreshing it in `db` first when `SKILLSHUB_CACHE_TTL_SECS` is
+/// set and the cache is older than that (never in offline mode). If the cache is empty, falls
+/// back to local bundled skills for the default tap, or returns `None` for non-default
+/// taps. Use `tap update` to populate the cache.
+pub fn get_tap_registry(db: &mut Database, tap_name: &str) -> Result<Option<TapRegistry>> {
+    get_tap_registry_with_ttl(db, tap_name, cache_ttl_secs())
+}
+
+fn get_tap_registry_with_ttl(db: &mut Database, tap_name: &str, ttl_secs: Option<u64>) -> Result<Option<TapRegistry>> {
     let tap = db::get_tap(db, tap_name).with_context(|| format!("Tap '{}' not found", tap_name))?;
 
     // Return cached registry if available
-    if let Some(ref registry) = tap.cached_registry {
-        return Ok(Some(registry.clone()));
+    if let Some(registry) = tap.cached_registry.clone() {
+        let expired = ttl_secs.is_some_and(|ttl| cache_expired(tap.updated_at, ttl, Utc::now()));
+        if expired && !is_offline() && !is_gist_url(&tap.url) {
+            match refresh_tap_cache(db, tap_name) {
+                Ok(fresh) => return Ok(Some(fresh)),
+                Err(e) => eprintln!(
+                    "  {} Could not refresh stale registry for '{}', using cache: {}",
+                    "!".yellow(),
+                    tap_name,
+                    e
+                ),
+            }
+        }
+        return Ok(Some(registry));
     }
 
     // No cache available — use local bundled skills for the default tap,
     // return None for non-default taps (user should run `tap update`)
     if tap.is_default {
-        return Ok(Some(generate_local_registry()?));
+        return Ok(Some(generate_local_registry(
+            tap.custom_skills_path.as_deref().unwrap_or(DEFAULT_SKILLS_PATH),
+        )?));
     }
 
     Ok(None)
 }
 
-/// Generate a registry from local/bundled skills
-pub fn generate_local_registry() -> Result<TapRegistry> {
+/// Cached registries older than this get a staleness note in `list` and `search`
+const STALE_REGISTRY_DAYS: i64 = 7;
+
+/// Note taps whose cached registry is older than [`STALE_REGISTRY_DAYS`].
+///
+/// Re-reads db.json so registries refreshed earlier in the command are not reported.
+pub fn print_stale_registry_note() -> Result<()> {
+    let db = db::load_db()?;
+    let stale = stale_registries(&db, Utc::now());
+    if !stale.is_empty() {
+        println!(
+            "\n{} {} cached registr{} may be out of date: {}.\n  Run 'skillshub tap update' or pass --refresh to fetch the latest.",
+            "Note:".yellow().bold(),
+            stale.len(),
+            if stale.len() == 1 { "y" } else { "ies" },
+            stale.join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// "tap (age)" for each non-gist tap whose cached registry is older than the staleness threshold
+fn stale_registries(db: &Database, now: DateTime<Utc>) -> Vec<String> {
+    let mut stale: Vec<String> = db
+        .taps
+        .iter()
+        .filter(|(_, tap)| tap.cached_registry.is_some() && !is_gist_url(&tap.url))
+        .filter_map(|(name, tap)| {
+            let updated = tap.updated_at?;
+            (now.signed_duration_since(updated).num_days() >= STALE_REGISTRY_DAYS)
+                .then(|| format!("{} ({})", name, format_age(updated, now)))
+        })
+        .collect();
+    stale.sort();
+    stale
+}
+
+/// Whether the commits recorded in `tap_name`'s cached registry can stand in for its
+/// upstream HEAD: the registry was refreshed during this run, or a cache TTL is set and
+/// the registry is younger than it. Without a TTL the cache is never assumed current.
+pub(crate) fn registry_is_fresh(db: &Database, tap_name: &str) -> bool {
+    let Some(ttl) = cache_ttl_secs() else {
+        return false;
+    };
+    db.registries_refreshed
+        || db::get_tap(db, tap_name).is_some_and(|tap| !cache_expired(tap.updated_at, ttl, Utc::now()))
+}
+
+/// Registry cache TTL from `SKILLSHUB_CACHE_TTL_SECS`, or `None` when unset or invalid
+fn cache_ttl_secs() -> Option<u64> {
+    parse_cache_ttl(std::env::var(CACHE_TTL_ENV).ok().as_deref())
+}
+
+fn parse_cache_ttl(value: Option<&str>) -> Option<u64> {
+    value?.trim().parse().ok()
+}
+
+/// Whether a cache last refreshed at `updated_at` is older than `ttl_secs`.
+/// A cache with no timestamp counts as expired.
+fn cache_expired(updated_at: Option<DateTime<Utc>>, ttl_secs: u64, now: DateTime<Utc>) -> bool {
+    match updated_at {
+        Some(t) => now.signed_duration_since(t).num_seconds() >= ttl_secs as i64,
+        None => true,
+    }
+}
+
+/// Re-discover a tap's skills and store the refreshed cache in `db`. The caller saves `db`;
+/// `registries_refreshed` tells read-only commands they need to.
+fn refresh_tap_cache(db: &mut Database, tap_name: &str) -> Result<TapRegistry> {
+    say!("{} Refreshing stale registry for '{}'", "Info:".cyan(), tap_name);
+
+    let tap = db::get_tap(db, tap_name)
+        .with_context(|| format!("Tap '{}' not found", tap_name))?
+        .clone();
+    update_single_tap(db, tap_name, &tap)?;
+    db.registries_refreshed = true;
+
+    db.taps
+        .get(tap_name)
+        .and_then(|t| t.cached_registry.clone())
+        .with_context(|| format!("Tap '{}' not found", tap_name))
+}
+
+/// Save registries that `get_tap_registry` refreshed while a read-only command ran
+pub fn save_refreshed_registries(db: &Database) -> Result<()> {
+    if db.registries_refreshed {
+        db::save_db(db)?;
+    }
+    Ok(())
+}
+
+/// Generate a registry from local/bundled skills, with entry paths under `skills_path`
+pub fn generate_local_registry(skills_path: &str) -> Result<TapRegistry> {
     use crate::paths::get_embedded_skills_dir;
     use crate::skill::discover_skills;
     use std::collections::HashMap;
@@ -462,9 +1188,12 @@ pub fn generate_local_registry() -> Result<TapRegistry> {
         skill_entries.insert(
             skill.name.clone(),
             SkillEntry {
-                path: format!("skills/{}", skill.name),
+                path: join_repo_path(skills_path, &skill.name),
                 description: Some(skill.description),
                 homepage: None,
+                commit: None,
+                tags: skill.tags,
+                aliases: skill.aliases,
             },
         );
     }
@@ -472,6 +1201,7 @@ pub fn generate_local_registry() -> Result<TapRegistry> {
     Ok(TapRegistry {
         name: DEFAULT_TAP_NAME.to_string(),
         description: Some("Default skillshub tap with bundled skills".to_string()),
+        schema_version: None,
         skills: skill_entries,
     })
 }
@@ -483,7 +1213,7 @@ pub fn generate_local_registry() -> Result<TapRegistry> {
 pub fn import_star_list(url: &str, install: bool) -> Result<()> {
     let (username, list_name) = parse_star_list_url(url)?;
 
-    println!(
+    say!(
         "{} Fetching star list '{}' from user '{}'...",
         "=>".green().bold(),
         list_name,
@@ -493,11 +1223,11 @@ pub fn import_star_list(url: &str, install: bool) -> Result<()> {
     let repos = fetch_star_list_repos(&username, &list_name)?;
 
     if repos.is_empty() {
-        println!("  {} No repositories found in star list '{}'", "!".yellow(), list_name);
+        say!("  {} No repositories found in star list '{}'", "!".yellow(), list_name);
         return Ok(());
     }
 
-    println!("  {} Found {} repositories", "✓".green(), repos.len());
+    say!("  {} Found {} repositories", "✓".green(), repos.len());
 
     let mut added = 0usize;
     let mut skipped = 0usize;
@@ -507,13 +1237,20 @@ pub fn import_star_list(url: &str, install: bool) -> Result<()> {
         // Reload DB each iteration since add_tap() modifies it internally
         let db = db::init_db()?;
         if db.taps.contains_key(repo) {
-            println!("  {} {} (already added)", "–".dimmed(), repo);
+            say!("  {} {} (already added)", "–".dimmed(), repo);
             skipped += 1;
             continue;
         }
 
-        println!();
-        match add_tap(repo, None, install) {
+        say!();
+        match add_tap(
+            &RunContext::default(),
+            repo,
+            &TapAddOptions {
+                install,
+                ..TapAddOptions::default()
+            },
+        ) {
             Ok(()) => {
                 added += 1;
             }
@@ -524,8 +1261,8 @@ pub fn import_star_list(url: &str, install: bool) -> Result<()> {
         }
     }
 
-    println!();
-    println!(
+    say!();
+    say!(
         "{} Star list import complete: {} added, {} skipped, {} failed",
         "=>".green().bold(),
         added,
@@ -537,8 +1274,23 @@ pub fn import_star_list(url: &str, install: bool) -> Result<()> {
 }
 
 /// Discover skills by walking a local clone directory for SKILL.md files.
+///
+/// Each entry records the clone's HEAD so `update` can skip pulling while the registry is
+/// still within its cache TTL.
 pub(crate) fn discover_skills_from_local(clone_dir: &Path, tap_name: &str) -> Result<TapRegistry> {
+    discover_skills_under(clone_dir, None, tap_name)
+}
+
+/// Like `discover_skills_from_local`, but only scans `skills_path` (relative to the clone)
+/// when given. Entry paths stay relative to the clone root.
+pub(crate) fn discover_skills_under(
+    clone_dir: &Path,
+    skills_path: Option<&str>,
+    tap_name: &str,
+) -> Result<TapRegistry> {
+    let scan_root = skills_path.map_or_else(|| clone_dir.to_path_buf(), |p| clone_dir.join(p));
     let mut skills = HashMap::new();
+    let head = git_head_sha(clone_dir).ok();
     let skip_dirs = [
         ".git",
         "node_modules",
@@ -551,7 +1303,7 @@ pub(crate) fn discover_skills_from_local(clone_dir: &Path, tap_name: &str) -> Re
         "benchmark",
     ];
 
-    for entry in WalkDir::new(clone_dir)
+    for entry in WalkDir::new(&scan_root)
         .into_iter()
         .filter_entry(|e| {
             // Never skip the root directory itself (depth 0)
@@ -565,8 +1317,14 @@ pub(crate) fn discover_skills_from_local(clone_dir: &Path, tap_name: &str) -> Re
     {
         if entry.file_name() == "SKILL.md" && entry.file_type().is_file() {
             if let Ok(content) = std::fs::read_to_string(entry.path()) {
-                match parse_skill_md_content(&content) {
-                    Some((name, description)) => {
+                match parse_skill_md_frontmatter(&content) {
+                    Some(SkillMetadata {
+                        name,
+                        description,
+                        tags,
+                        aliases,
+                        ..
+                    }) => {
                         // Reject names with path traversal sequences
                         if !is_safe_skill_name(&name) {
                             let rel_path = entry.path().strip_prefix(clone_dir).unwrap_or(entry.path());
@@ -601,6 +1359,9 @@ pub(crate) fn discover_skills_from_local(clone_dir: &Path, tap_name: &str) -> Re
                                     path: skill_path,
                                     description,
                                     homepage: None,
+                                    commit: head.clone(),
+                                    tags,
+                                    aliases,
                                 },
                             );
                         }
@@ -626,10 +1387,121 @@ pub(crate) fn discover_skills_from_local(clone_dir: &Path, tap_name: &str) -> Re
     Ok(TapRegistry {
         name: tap_name.to_string(),
         description: Some(format!("Skills from {}", tap_name)),
+        schema_version: None,
         skills,
     })
 }
 
+/// Build a tap's registry from its clone: read `registry_file` when the tap names one,
+/// otherwise discover SKILL.md files under `skills_path`
+pub(crate) fn load_tap_registry(
+    clone_dir: &Path,
+    skills_path: Option<&str>,
+    registry_file: Option<&str>,
+    tap_name: &str,
+) -> Result<TapRegistry> {
+    match registry_file {
+        Some(file) => read_registry_file(clone_dir, file, tap_name),
+        None => discover_skills_under(clone_dir, skills_path, tap_name),
+    }
+}
+
+/// Parse a registry file shipped in a tap clone.
+///
+/// Entries with unsafe names or paths missing from the clone are skipped with a warning,
+/// and entries without a commit get the clone's HEAD.
+fn read_registry_file(clone_dir: &Path, file: &str, tap_name: &str) -> Result<TapRegistry> {
+    let path = clone_dir.join(file);
+    let content = std::fs::read_to_string(&path)
+        .with_context(|| format!("Registry file '{}' not found in tap '{}'", file, tap_name))?;
+    let mut registry = parse_registry_file(&content, file, tap_name)?;
+    let head = git_head_sha(clone_dir).ok();
+
+    registry.skills.retain(|name, entry| {
+        if !is_safe_skill_name(name) {
+            eprintln!("  {} Skipping {}: unsafe skill name '{}'", "!".yellow(), file, name);
+            return false;
+        }
+        let entry_path = entry.path.trim_matches('/');
+        let safe_path = entry_path
+            .split('/')
+            .all(|part| part.is_empty() || is_safe_skill_name(part));
+        if !safe_path || !clone_dir.join(entry_path).join("SKILL.md").is_file() {
+            eprintln!(
+                "  {} Skipping '{}': no SKILL.md at '{}' listed in {}",
+                "!".yellow(),
+                name,
+                entry.path,
+                file
+            );
+            return false;
+        }
+        entry.path = entry_path.to_string();
+        if entry.commit.is_none() {
+            entry.commit = head.clone();
+        }
+        true
+    });
+
+    if registry.skills.is_empty() {
+        anyhow::bail!("No skills found in registry file '{}'", file);
+    }
+
+    registry.name = tap_name.to_string();
+    Ok(registry)
+}
+
+/// Parse registry file JSON, checking its `schema_version` first. A registry written for a
+/// newer format is read when it still parses, with a warning to upgrade skillshub; when it
+/// does not, the error says so instead of reporting the serde failure alone.
+fn parse_registry_file(content: &str, file: &str, tap_name: &str) -> Result<TapRegistry> {
+    let value: serde_json::Value =
+        serde_json::from_str(content).with_context(|| format!("Failed to parse registry file '{}'", file))?;
+    let version = value.get("schema_version").and_then(serde_json::Value::as_u64);
+    let newer = version.filter(|v| *v > u64::from(REGISTRY_SCHEMA_VERSION));
+
+    match serde_json::from_value::<TapRegistry>(value) {
+        Ok(registry) => {
+            if let Some(version) = newer {
+                eprintln!("  {} {}", "!".yellow(), newer_schema_warning(tap_name, version));
+            }
+            Ok(registry)
+        }
+        Err(e) => match newer {
+            Some(version) => anyhow::bail!(
+                "Tap '{}' uses registry schema version {}, but this skillshub only understands version {}. \
+                 Upgrade skillshub to use this tap. ({})",
+                tap_name,
+                version,
+                REGISTRY_SCHEMA_VERSION,
+                e
+            ),
+            None => Err(e).with_context(|| format!("Failed to parse registry file '{}'", file)),
+        },
+    }
+}
+
+/// Warning for a registry written for a newer schema than [`REGISTRY_SCHEMA_VERSION`]
+fn newer_schema_warning(tap_name: &str, version: u64) -> String {
+    format!(
+        "Tap '{}' uses registry schema version {}, newer than the {} this skillshub understands; \
+         some details may be missing. Upgrade skillshub to read it fully.",
+        tap_name, version, REGISTRY_SCHEMA_VERSION
+    )
+}
+
+/// Check a `--registry-file` value and normalize it to a repo-relative path
+fn validate_registry_file(path: &str) -> Result<String> {
+    let parts: Vec<&str> = path.split(['/', '\\']).filter(|part| !part.is_empty()).collect();
+    if parts.is_empty() || path.starts_with('/') || !parts.iter().all(|part| is_safe_skill_name(part)) {
+        anyhow::bail!(
+            "Invalid --registry-file '{}': use a file relative to the repository root (e.g., 'registry.json')",
+            path
+        );
+    }
+    Ok(parts.join("/"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -637,6 +1509,21 @@ mod tests {
     use chrono::Utc;
     use serial_test::serial;
 
+    #[test]
+    fn test_well_known_tap_url() {
+        assert_eq!(
+            well_known_tap_url("Anthropics").unwrap(),
+            "https://github.com/anthropics/skills"
+        );
+        for (_, url) in WELL_KNOWN_TAPS {
+            assert!(parse_github_url(url).is_ok(), "{} should be a valid tap URL", url);
+        }
+
+        let err = well_known_tap_url("nope").unwrap_err().to_string();
+        assert!(err.contains("Unknown tap shortname 'nope'"), "{}", err);
+        assert!(err.contains("anthropics, superpowers"), "{}", err);
+    }
+
     #[test]
     fn test_truncate_url_short() {
         assert_eq!(
@@ -695,6 +1582,10 @@ mod tests {
                 source_url: None,
                 source_path: None,
                 gist_updated_at: None,
+                content_sha256: None,
+                size_bytes: None,
+                alias: None,
+                branch: None,
             },
         );
         db.installed.insert(
@@ -707,6 +1598,10 @@ mod tests {
                 source_url: None,
                 source_path: None,
                 gist_updated_at: None,
+                content_sha256: None,
+                size_bytes: None,
+                alias: None,
+                branch: None,
             },
         );
         db.installed.insert(
@@ -719,6 +1614,10 @@ mod tests {
                 source_url: None,
                 source_path: None,
                 gist_updated_at: None,
+                content_sha256: None,
+                size_bytes: None,
+                alias: None,
+                branch: None,
             },
         );
 
@@ -738,12 +1637,16 @@ mod tests {
                     path: format!("skills/{}", s),
                     description: Some(format!("{} skill", s)),
                     homepage: None,
+                    commit: None,
+                    tags: Vec::new(),
+                    aliases: Vec::new(),
                 },
             );
         }
         TapRegistry {
             name: name.to_string(),
             description: None,
+            schema_version: None,
             skills,
         }
     }
@@ -763,6 +1666,20 @@ mod tests {
         assert!(removed.is_empty());
     }
 
+    #[test]
+    fn test_tap_update_detects_changed_descriptions() {
+        let old_registry = make_registry("test/tap", &["alpha", "beta", "gamma"]);
+        let mut new_registry = make_registry("test/tap", &["alpha", "beta", "delta"]);
+        new_registry.skills.get_mut("beta").unwrap().description = Some("Reworded".to_string());
+
+        // New and removed skills are not reported as changed
+        assert_eq!(
+            changed_descriptions(&old_registry, &new_registry),
+            vec!["beta".to_string()]
+        );
+        assert!(changed_descriptions(&old_registry, &old_registry).is_empty());
+    }
+
     #[test]
     fn test_tap_update_detects_removed_skills() {
         let old_registry = make_registry("test/tap", &["alpha", "beta", "gamma"]);
@@ -795,6 +1712,10 @@ mod tests {
                 source_url: None,
                 source_path: None,
                 gist_updated_at: None,
+                content_sha256: None,
+                size_bytes: None,
+                alias: None,
+                branch: None,
             },
         );
 
@@ -866,6 +1787,150 @@ mod tests {
         }
     }
 
+    /// `tap add-default` restores a default tap removed from db.json by hand
+    #[test]
+    #[serial]
+    fn test_add_default_tap_restores_removed_default() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let home = temp.path().join("home");
+        std::fs::create_dir_all(home.join(".skillshub")).unwrap();
+        std::fs::write(
+            home.join(".skillshub/db.json"),
+            r#"{"taps":{},"installed":{},"linked_agents":[],"external":{}}"#,
+        )
+        .unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        assert!(db::load_db().unwrap().taps.is_empty());
+
+        add_default_tap().unwrap();
+
+        let db = db::load_db().unwrap();
+        let tap = db.taps.get(DEFAULT_TAP_NAME).expect("default tap restored");
+        assert!(tap.is_default);
+    }
+
+    /// `tap set-default` moves the default to another tap without touching the bundled
+    /// tap's flag, and choosing the bundled tap again clears the setting
+    #[test]
+    #[serial]
+    fn test_set_default_tap_switches_and_restores() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let home = temp.path().join("home");
+        std::fs::create_dir_all(home.join(".skillshub")).unwrap();
+        let _guard = TestHomeGuard::set(&home);
+
+        let mut db = db::init_db().unwrap();
+        db::add_tap(
+            &mut db,
+            "acme/tools",
+            TapInfo {
+                url: "https://github.com/acme/tools".to_string(),
+                custom_skills_path: None,
+                updated_at: None,
+                is_default: false,
+                cached_registry: None,
+                branch: None,
+                token_env: None,
+                registry_file: None,
+            },
+        );
+        db::save_db(&db).unwrap();
+        assert_eq!(db::default_tap_name(&db), Some(DEFAULT_TAP_NAME));
+
+        set_default_tap("acme/tools").unwrap();
+        let db = db::load_db().unwrap();
+        assert_eq!(db::default_tap_name(&db), Some("acme/tools"));
+        assert!(db.taps[DEFAULT_TAP_NAME].is_default, "bundled tap stays bundled");
+        assert!(!db.taps["acme/tools"].is_default);
+
+        assert!(set_default_tap("missing/tap").is_err());
+
+        set_default_tap(DEFAULT_TAP_NAME).unwrap();
+        let db = db::load_db().unwrap();
+        assert_eq!(db.default_tap, None);
+        assert_eq!(db::default_tap_name(&db), Some(DEFAULT_TAP_NAME));
+    }
+
+    /// `tap rename` re-keys the tap and its installed skills, moves their directory,
+    /// and points agent links at the new location
+    #[test]
+    #[serial]
+    #[cfg(unix)]
+    fn test_rename_tap_rewrites_db_and_moves_skills() {
+        use std::fs;
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let home = temp.path().join("home");
+        let skills_dir = home.join(".skillshub/skills");
+        fs::create_dir_all(skills_dir.join("acme/old-kit/lint")).unwrap();
+        fs::write(
+            skills_dir.join("acme/old-kit/lint/SKILL.md"),
+            "---\nname: lint\ndescription: Lints\n---\n",
+        )
+        .unwrap();
+        fs::write(
+            home.join(".skillshub/db.json"),
+            serde_json::json!({
+                "taps": {
+                    "acme/old-kit": {
+                        "url": "https://github.com/acme/old-kit",
+                        "skills_path": "skills",
+                        "updated_at": null,
+                        "is_default": false,
+                        "cached_registry": null
+                    },
+                    "other/taken": {
+                        "url": "https://github.com/other/taken",
+                        "skills_path": "skills",
+                        "updated_at": null,
+                        "is_default": false,
+                        "cached_registry": null
+                    }
+                },
+                "installed": {
+                    "acme/old-kit/lint": {
+                        "tap": "acme/old-kit",
+                        "skill": "lint",
+                        "commit": null,
+                        "installed_at": "2026-01-01T00:00:00Z"
+                    }
+                },
+                "default_tap": "acme/old-kit",
+                "linked_agents": [".claude"],
+                "external": {}
+            })
+            .to_string(),
+        )
+        .unwrap();
+        let claude_skills = home.join(".claude/skills");
+        fs::create_dir_all(&claude_skills).unwrap();
+        std::os::unix::fs::symlink(skills_dir.join("acme/old-kit/lint"), claude_skills.join("lint")).unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        assert!(rename_tap("acme/old-kit", "other/taken")
+            .unwrap_err()
+            .to_string()
+            .contains("already exists"));
+        assert!(rename_tap("acme/old-kit", "../escape").is_err());
+
+        rename_tap("acme/old-kit", "acme/new-kit").unwrap();
+
+        let db = db::load_db().unwrap();
+        assert!(!db.taps.contains_key("acme/old-kit"));
+        assert!(db.taps.contains_key("acme/new-kit"));
+        assert!(!db.installed.contains_key("acme/old-kit/lint"));
+        assert_eq!(db.installed["acme/new-kit/lint"].tap, "acme/new-kit");
+        assert_eq!(db.default_tap.as_deref(), Some("acme/new-kit"));
+
+        assert!(!skills_dir.join("acme/old-kit").exists());
+        assert!(skills_dir.join("acme/new-kit/lint/SKILL.md").exists());
+        assert_eq!(
+            fs::read_link(claude_skills.join("lint")).unwrap(),
+            skills_dir.join("acme/new-kit/lint")
+        );
+    }
+
     /// Removing a non-default tap should also uninstall all its installed skills
     #[test]
     #[serial]
@@ -1438,4 +2503,423 @@ mod tests {
         );
         assert!(registry.skills.contains_key("legit"));
     }
+
+    #[test]
+    fn test_parse_cache_ttl() {
+        assert_eq!(parse_cache_ttl(Some("3600")), Some(3600));
+        assert_eq!(parse_cache_ttl(Some(" 60 ")), Some(60));
+        assert_eq!(parse_cache_ttl(Some("soon")), None);
+        assert_eq!(parse_cache_ttl(None), None);
+    }
+
+    #[test]
+    fn test_cache_expired() {
+        let now = Utc::now();
+        assert!(cache_expired(Some(now - chrono::Duration::seconds(120)), 60, now));
+        assert!(!cache_expired(Some(now - chrono::Duration::seconds(30)), 60, now));
+        assert!(cache_expired(None, 60, now));
+    }
+
+    #[test]
+    fn test_stale_registries() {
+        let now = Utc::now();
+        let tap = |url: &str, age_days: i64, cached: bool| TapInfo {
+            url: url.to_string(),
+            custom_skills_path: None,
+            updated_at: Some(now - chrono::Duration::days(age_days)),
+            is_default: false,
+            cached_registry: cached.then(|| make_registry("t", &["a"])),
+            branch: None,
+            token_env: None,
+            registry_file: None,
+        };
+        let mut db = Database::default();
+        db.taps
+            .insert("old/tap".into(), tap("https://github.com/old/tap", 30, true));
+        db.taps
+            .insert("fresh/tap".into(), tap("https://github.com/fresh/tap", 1, true));
+        db.taps
+            .insert("uncached/tap".into(), tap("https://github.com/uncached/tap", 30, false));
+        db.taps
+            .insert("me/gists".into(), tap("https://gist.github.com/me", 30, true));
+
+        assert_eq!(stale_registries(&db, now), vec!["old/tap (30d ago)".to_string()]);
+    }
+
+    #[test]
+    fn test_validate_skills_path() {
+        assert_eq!(validate_skills_path("plugins/skills/").unwrap(), "plugins/skills");
+        assert_eq!(validate_skills_path("agent-skills").unwrap(), "agent-skills");
+        assert!(validate_skills_path("").is_err());
+        assert!(validate_skills_path("/etc").is_err());
+        assert!(validate_skills_path("../outside").is_err());
+    }
+
+    #[test]
+    fn test_generate_local_registry_uses_skills_path() {
+        let registry = generate_local_registry("bundled").unwrap();
+        let entry = registry.skills.values().next().expect("bundled skills should exist");
+        assert!(entry.path.starts_with("bundled/"), "got {}", entry.path);
+
+        let root = generate_local_registry("").unwrap();
+        assert!(root.skills.values().all(|e| !e.path.contains('/')));
+    }
+
+    /// A custom skills path limits discovery to that folder, keeping clone-relative paths
+    #[test]
+    fn test_discover_skills_under_custom_path() {
+        let temp = tempfile::TempDir::new().unwrap();
+        for (dir, name) in [("agent-skills/lint", "lint"), ("docs/example", "example")] {
+            let skill_dir = temp.path().join(dir);
+            std::fs::create_dir_all(&skill_dir).unwrap();
+            std::fs::write(
+                skill_dir.join("SKILL.md"),
+                format!("---\nname: {}\ndescription: A skill\n---\n", name),
+            )
+            .unwrap();
+        }
+
+        let registry = discover_skills_under(temp.path(), Some("agent-skills"), "test/tap").unwrap();
+        assert_eq!(registry.skills.len(), 1);
+        assert_eq!(registry.skills["lint"].path, "agent-skills/lint");
+
+        let all = discover_skills_under(temp.path(), None, "test/tap").unwrap();
+        assert_eq!(all.skills.len(), 2);
+    }
+
+    #[test]
+    fn test_format_age() {
+        let now = Utc::now();
+        assert_eq!(format_age(now - chrono::Duration::seconds(5), now), "just now");
+        assert_eq!(format_age(now - chrono::Duration::minutes(12), now), "12m ago");
+        assert_eq!(format_age(now - chrono::Duration::hours(3), now), "3h ago");
+        assert_eq!(format_age(now - chrono::Duration::days(2), now), "2d ago");
+        // Clock skew never yields a negative age
+        assert_eq!(format_age(now + chrono::Duration::minutes(5), now), "just now");
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_show_tap_info() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let _guard = TestHomeGuard::set(temp.path());
+
+        // The default tap falls back to bundled skills without a cache
+        assert!(show_tap_info(DEFAULT_TAP_NAME).is_ok());
+
+        let err = show_tap_info("nobody/nothing").unwrap_err();
+        assert!(err.to_string().contains("Tap 'nobody/nothing' not found"));
+    }
+
+    fn git(dir: &std::path::Path, args: &[&str]) {
+        let status = std::process::Command::new("git")
+            .args(args)
+            .current_dir(dir)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    /// Create an origin repo with `skill-a`, clone it as tap `test-user/test-repo`, cache a
+    /// registry refreshed `age_secs` ago, then add `skill-b` upstream. Returns the home dir.
+    fn setup_ttl_fixture(temp: &std::path::Path, age_secs: i64) -> std::path::PathBuf {
+        use std::fs;
+
+        let origin = temp.join("origin");
+        fs::create_dir_all(origin.join("skills/skill-a")).unwrap();
+        git(&origin, &["init"]);
+        git(&origin, &["config", "user.email", "test@test.com"]);
+        git(&origin, &["config", "user.name", "Test"]);
+        fs::write(origin.join("skills/skill-a/SKILL.md"), "---\nname: skill-a\n---\n").unwrap();
+        git(&origin, &["add", "."]);
+        git(&origin, &["commit", "-m", "add skill-a"]);
+
+        let home = temp.join("home");
+        let clone_dir = home.join(".skillshub/taps/test-user/test-repo");
+        fs::create_dir_all(clone_dir.parent().unwrap()).unwrap();
+        let url = format!("file://{}", origin.display());
+        git_clone(&url, &clone_dir, None).unwrap();
+
+        fs::create_dir_all(origin.join("skills/skill-b")).unwrap();
+        fs::write(origin.join("skills/skill-b/SKILL.md"), "---\nname: skill-b\n---\n").unwrap();
+        git(&origin, &["add", "."]);
+        git(&origin, &["commit", "-m", "add skill-b"]);
+
+        let updated_at = Utc::now() - chrono::Duration::seconds(age_secs);
+        let db_json = serde_json::json!({
+            "taps": {
+                "test-user/test-repo": {
+                    "url": url,
+                    "skills_path": "skills",
+                    "updated_at": updated_at,
+                    "is_default": false,
+                    "cached_registry": {
+                        "name": "test-user/test-repo",
+                        "description": null,
+                        "skills": {
+                            "skill-a": { "path": "skills/skill-a", "description": null, "homepage": null }
+                        }
+                    }
+                }
+            },
+            "installed": {},
+            "linked_agents": [],
+            "external": {}
+        });
+        fs::write(home.join(".skillshub/db.json"), db_json.to_string()).unwrap();
+        home
+    }
+
+    /// `tap add --dry-run` clones into a scratch directory and leaves db.json and the taps
+    /// directory untouched. Git's `insteadOf` stands in for GitHub.
+    #[test]
+    #[serial]
+    fn test_add_tap_dry_run_leaves_db_unchanged() {
+        use std::fs;
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let origin = temp.path().join("origin");
+        fs::create_dir_all(origin.join("skills/skill-a")).unwrap();
+        fs::write(origin.join("skills/skill-a/SKILL.md"), "---\nname: skill-a\n---\n").unwrap();
+        git(&origin, &["init"]);
+        git(&origin, &["config", "user.email", "test@test.com"]);
+        git(&origin, &["config", "user.name", "Test"]);
+        git(&origin, &["add", "."]);
+        git(&origin, &["commit", "-m", "add skill-a"]);
+
+        let home = temp.path().join("home");
+        fs::create_dir_all(home.join(".skillshub")).unwrap();
+        let db_path = home.join(".skillshub/db.json");
+        fs::write(
+            &db_path,
+            r#"{"taps":{},"installed":{},"linked_agents":[],"external":{}}"#,
+        )
+        .unwrap();
+        let before = fs::read(&db_path).unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        std::env::set_var("GIT_CONFIG_COUNT", "1");
+        std::env::set_var("GIT_CONFIG_KEY_0", format!("url.file://{}.insteadOf", origin.display()));
+        std::env::set_var("GIT_CONFIG_VALUE_0", "https://github.com/acme/tools");
+        let result = add_tap(
+            &RunContext::new(true),
+            "acme/tools",
+            &TapAddOptions {
+                install: true,
+                ..TapAddOptions::default()
+            },
+        );
+        for var in ["GIT_CONFIG_COUNT", "GIT_CONFIG_KEY_0", "GIT_CONFIG_VALUE_0"] {
+            std::env::remove_var(var);
+        }
+
+        result.unwrap();
+        assert_eq!(fs::read(&db_path).unwrap(), before, "db.json must not change");
+        assert!(!home.join(".skillshub/taps/acme/tools").exists());
+        assert!(!home.join(".skillshub/skills/acme").exists());
+    }
+
+    /// `--skills-path skills` scopes discovery to `skills/` on add and on every `tap update`,
+    /// even though it matches the default folder name
+    #[test]
+    #[serial]
+    fn test_add_tap_skills_path_matching_default_is_kept_on_update() {
+        use std::fs;
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let origin = temp.path().join("origin");
+        for dir in ["skills/skill-a", "other/skill-c"] {
+            fs::create_dir_all(origin.join(dir)).unwrap();
+            let name = dir.rsplit('/').next().unwrap();
+            fs::write(origin.join(dir).join("SKILL.md"), format!("---\nname: {}\n---\n", name)).unwrap();
+        }
+        git(&origin, &["init"]);
+        git(&origin, &["config", "user.email", "test@test.com"]);
+        git(&origin, &["config", "user.name", "Test"]);
+        git(&origin, &["add", "."]);
+        git(&origin, &["commit", "-m", "add skills"]);
+
+        let home = temp.path().join("home");
+        fs::create_dir_all(home.join(".skillshub")).unwrap();
+        fs::write(
+            home.join(".skillshub/db.json"),
+            r#"{"taps":{},"installed":{},"linked_agents":[],"external":{}}"#,
+        )
+        .unwrap();
+
+        let _guard = TestHomeGuard::set(&home);
+        std::env::set_var("GIT_CONFIG_COUNT", "1");
+        std::env::set_var("GIT_CONFIG_KEY_0", format!("url.file://{}.insteadOf", origin.display()));
+        std::env::set_var("GIT_CONFIG_VALUE_0", "https://github.com/acme/tools");
+        let added = add_tap(
+            &RunContext::default(),
+            "acme/tools",
+            &TapAddOptions {
+                skills_path: Some("skills"),
+                ..TapAddOptions::default()
+            },
+        );
+        fs::create_dir_all(origin.join("skills/skill-b")).unwrap();
+        fs::write(origin.join("skills/skill-b/SKILL.md"), "---\nname: skill-b\n---\n").unwrap();
+        git(&origin, &["add", "."]);
+        git(&origin, &["commit", "-m", "add skill-b"]);
+        let updated = update_tap(Some("acme/tools"));
+        for var in ["GIT_CONFIG_COUNT", "GIT_CONFIG_KEY_0", "GIT_CONFIG_VALUE_0"] {
+            std::env::remove_var(var);
+        }
+
+        added.unwrap();
+        updated.unwrap();
+        let db = db::load_db().unwrap();
+        let tap = db::get_tap(&db, "acme/tools").unwrap();
+        assert_eq!(tap.custom_skills_path.as_deref(), Some("skills"));
+        let mut skills: Vec<&String> = tap.cached_registry.as_ref().unwrap().skills.keys().collect();
+        skills.sort();
+        assert_eq!(skills, ["skill-a", "skill-b"]);
+    }
+
+    #[test]
+    #[serial]
+    fn test_get_tap_registry_refreshes_expired_cache() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let home = setup_ttl_fixture(temp.path(), 7200);
+        let _guard = TestHomeGuard::set(&home);
+
+        let mut db = db::load_db().unwrap();
+        let registry = get_tap_registry_with_ttl(&mut db, "test-user/test-repo", Some(3600))
+            .unwrap()
+            .unwrap();
+        assert!(
+            registry.skills.contains_key("skill-b"),
+            "expired cache should be refreshed"
+        );
+
+        // The refresh lands in the caller's db, so the caller's own save keeps it
+        assert!(db.registries_refreshed);
+        db.default_tap = Some("test-user/test-repo".to_string());
+        db::save_db(&db).unwrap();
+        let saved = db::load_db().unwrap();
+        assert_eq!(saved.default_tap.as_deref(), Some("test-user/test-repo"));
+        let tap = db::get_tap(&saved, "test-user/test-repo").unwrap();
+        assert!(tap.cached_registry.as_ref().unwrap().skills.contains_key("skill-b"));
+        assert!(!cache_expired(tap.updated_at, 3600, Utc::now()));
+    }
+
+    /// A tap with a registry file is refreshed from that file instead of SKILL.md discovery,
+    /// and entries whose path has no SKILL.md are skipped
+    #[test]
+    #[serial]
+    fn test_get_tap_registry_reads_custom_registry_file() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let home = setup_ttl_fixture(temp.path(), 7200);
+        let origin = temp.path().join("origin");
+        std::fs::write(
+            origin.join("skills.json"),
+            r#"{"name": "upstream", "description": null, "skills": {
+                "skill-b": {"path": "skills/skill-b/", "description": "From the file", "homepage": null},
+                "ghost": {"path": "skills/ghost", "description": null, "homepage": null}
+            }}"#,
+        )
+        .unwrap();
+        git(&origin, &["add", "."]);
+        git(&origin, &["commit", "-m", "add skills.json"]);
+        let _guard = TestHomeGuard::set(&home);
+
+        let mut db = db::load_db().unwrap();
+        db.taps.get_mut("test-user/test-repo").unwrap().registry_file = Some("skills.json".to_string());
+        db::save_db(&db).unwrap();
+
+        let registry = get_tap_registry_with_ttl(&mut db, "test-user/test-repo", Some(3600))
+            .unwrap()
+            .unwrap();
+        assert_eq!(registry.name, "test-user/test-repo");
+        assert_eq!(registry.skills.len(), 1, "only listed skills with a SKILL.md are kept");
+        let entry = &registry.skills["skill-b"];
+        assert_eq!(entry.path, "skills/skill-b");
+        assert_eq!(entry.description.as_deref(), Some("From the file"));
+        assert!(entry.commit.is_some(), "missing commits default to the clone's HEAD");
+
+        assert!(validate_registry_file("../registry.json").is_err());
+        assert!(validate_registry_file("/etc/registry.json").is_err());
+        assert_eq!(validate_registry_file("meta/skills.json").unwrap(), "meta/skills.json");
+    }
+
+    /// A registry from a newer schema is read when it still parses (with a warning to
+    /// upgrade), and one that no longer parses fails with an upgrade hint
+    #[test]
+    fn test_parse_registry_file_checks_schema_version() {
+        let future = format!(
+            r#"{{"name": "t", "description": null, "schema_version": {}, "mirrors": ["x"],
+                "skills": {{"a": {{"path": "skills/a", "description": null, "homepage": null}}}}}}"#,
+            REGISTRY_SCHEMA_VERSION + 1
+        );
+        let registry = parse_registry_file(&future, "registry.json", "acme/tools").unwrap();
+        assert_eq!(registry.schema_version, Some(REGISTRY_SCHEMA_VERSION + 1));
+        assert!(registry.skills.contains_key("a"));
+        let warning = newer_schema_warning("acme/tools", u64::from(REGISTRY_SCHEMA_VERSION + 1));
+        assert!(
+            warning.contains("acme/tools") && warning.contains("Upgrade skillshub"),
+            "{}",
+            warning
+        );
+
+        let incompatible = format!(
+            r#"{{"name": "t", "description": null, "schema_version": {}, "skills": [{{"id": "a"}}]}}"#,
+            REGISTRY_SCHEMA_VERSION + 1
+        );
+        let err = parse_registry_file(&incompatible, "registry.json", "acme/tools").unwrap_err();
+        assert!(err.to_string().contains("Upgrade skillshub"), "{}", err);
+
+        let broken = r#"{"name": "t", "description": null, "skills": []}"#;
+        let err = parse_registry_file(broken, "registry.json", "acme/tools").unwrap_err();
+        assert!(err.to_string().contains("Failed to parse registry file"), "{}", err);
+    }
+
+    /// `tap verify` passes entries with a readable SKILL.md and flags missing, unsafe, and
+    /// unparsable ones
+    #[test]
+    fn test_verify_registry_flags_broken_entries() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let root = temp.path();
+        std::fs::create_dir_all(root.join("skills/good")).unwrap();
+        std::fs::write(root.join("skills/good/SKILL.md"), "---\nname: good\n---\n").unwrap();
+        std::fs::create_dir_all(root.join("skills/bad")).unwrap();
+        std::fs::write(root.join("skills/bad/SKILL.md"), "no frontmatter").unwrap();
+
+        let mut registry = make_registry("t", &["good", "bad", "gone", "escape"]);
+        registry.skills.get_mut("escape").unwrap().path = "../outside".to_string();
+
+        let results = verify_registry(root, &registry);
+        let statuses: Vec<(&str, &VerifyStatus)> = results.iter().map(|(n, _, s)| (n.as_str(), s)).collect();
+        assert_eq!(statuses[0].0, "bad");
+        assert!(matches!(statuses[0].1, VerifyStatus::InvalidSkillMd(_)));
+        assert_eq!(statuses[1], ("escape", &VerifyStatus::UnsafePath));
+        assert_eq!(statuses[2], ("gone", &VerifyStatus::MissingSkillMd));
+        assert_eq!(statuses[3], ("good", &VerifyStatus::Ok));
+    }
+
+    #[test]
+    #[serial]
+    fn test_get_tap_registry_keeps_fresh_cache() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let home = setup_ttl_fixture(temp.path(), 60);
+        let _guard = TestHomeGuard::set(&home);
+
+        let mut db = db::load_db().unwrap();
+        let registry = get_tap_registry_with_ttl(&mut db, "test-user/test-repo", Some(3600))
+            .unwrap()
+            .unwrap();
+        assert_eq!(registry.skills.len(), 1, "fresh cache should be used as-is");
+
+        // Nothing was pulled into the clone
+        let clone_dir = home.join(".skillshub/taps/test-user/test-repo");
+        assert!(!clone_dir.join("skills/skill-b").exists());
+
+        // Without a TTL the cache is never refreshed, however old
+        let registry = get_tap_registry_with_ttl(&mut db, "test-user/test-repo", None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(registry.skills.len(), 1);
+    }
 }
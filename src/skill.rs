@@ -17,55 +17,132 @@ pub struct SkillMetadata {
     pub description: Option<String>,
     #[serde(rename = "allowed-tools")]
     #[serde(default)]
-    #[allow(dead_code)]
     pub allowed_tools: AllowedTools,
     pub license: Option<String>,
     #[serde(default)]
     pub metadata: Option<SkillVersionMetadata>,
+    /// Optional category; the skill is linked under `<agent skills>/<category>/<skill>`
+    #[serde(default, deserialize_with = "lenient_string")]
+    pub category: Option<String>,
+    /// Optional tags used to filter `search` and `list` results
+    #[serde(default, deserialize_with = "string_or_list")]
+    pub tags: Vec<String>,
+    /// Optional alternative names, e.g. `install tap/reviewer` for `python-code-reviewer`
+    #[serde(default, deserialize_with = "string_or_list")]
+    pub aliases: Vec<String>,
+    /// Optional post-install script, relative to the skill directory
+    /// (defaults to `scripts/postinstall.sh` when that file exists)
+    #[serde(default)]
+    pub postinstall: Option<String>,
 }
 
+/// Environment variable listing the tools skills are expected to request, comma-separated.
+/// When set, installs warn about `allowed-tools` entries outside the list.
+pub const KNOWN_TOOLS_ENV: &str = "SKILLSHUB_KNOWN_TOOLS";
+
 /// Flexible deserializer for allowed-tools (can be string or array)
 #[derive(Debug, Default)]
-#[allow(dead_code)]
 pub struct AllowedTools(pub Vec<String>);
 
+impl AllowedTools {
+    /// Requested tools missing from `known`, compared by the tool name before any
+    /// `(...)` argument, so `Bash` covers `Bash(git status:*)`
+    pub fn unknown<'a>(&'a self, known: &[String]) -> Vec<&'a str> {
+        self.0
+            .iter()
+            .map(String::as_str)
+            .filter(|tool| {
+                let name = tool.split('(').next().unwrap_or(tool).trim();
+                !known.iter().any(|k| k == name)
+            })
+            .collect()
+    }
+}
+
+/// The tools listed in `SKILLSHUB_KNOWN_TOOLS`, or `None` when it is unset or empty
+pub fn known_tools() -> Option<Vec<String>> {
+    let value = std::env::var(KNOWN_TOOLS_ENV).ok()?;
+    let tools: Vec<String> = value
+        .split(',')
+        .map(str::trim)
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+        .collect();
+    (!tools.is_empty()).then_some(tools)
+}
+
 impl<'de> Deserialize<'de> for AllowedTools {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
-        use serde::de::{self, Visitor};
+        string_or_list(deserializer).map(AllowedTools)
+    }
+}
 
-        struct AllowedToolsVisitor;
+/// Deserialize a frontmatter field written either as a comma-separated string or as
+/// an array of strings
+fn string_or_list<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::{self, Visitor};
 
-        impl<'de> Visitor<'de> for AllowedToolsVisitor {
-            type Value = AllowedTools;
+    struct StringOrListVisitor;
 
-            fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
-                formatter.write_str("a string or array of strings")
-            }
+    impl<'de> Visitor<'de> for StringOrListVisitor {
+        type Value = Vec<String>;
 
-            fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
-            where
-                E: de::Error,
-            {
-                Ok(AllowedTools(value.split(',').map(|s| s.trim().to_string()).collect()))
-            }
+        fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            formatter.write_str("a string or array of strings")
+        }
 
-            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
-            where
-                A: de::SeqAccess<'de>,
-            {
-                let mut tools = Vec::new();
-                while let Some(value) = seq.next_element::<String>()? {
-                    tools.push(value);
-                }
-                Ok(AllowedTools(tools))
-            }
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(value
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+                .collect())
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(Vec::new())
         }
 
-        deserializer.deserialize_any(AllowedToolsVisitor)
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: de::SeqAccess<'de>,
+        {
+            let mut values = Vec::new();
+            while let Some(value) = seq.next_element::<String>()? {
+                values.push(value);
+            }
+            Ok(values)
+        }
     }
+
+    deserializer.deserialize_any(StringOrListVisitor)
+}
+
+/// Deserialize an optional string field, ignoring values of any other shape (lists,
+/// maps) instead of failing the whole frontmatter. Numbers and booleans are kept as text.
+fn lenient_string<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(match serde_yaml::Value::deserialize(deserializer)? {
+        serde_yaml::Value::String(s) => Some(s),
+        serde_yaml::Value::Number(n) => Some(n.to_string()),
+        serde_yaml::Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    })
 }
 
 /// Check whether a skill directory contains a `scripts/` subdirectory.
@@ -78,14 +155,98 @@ pub fn has_references_dir(skill_dir: &Path) -> bool {
     skill_dir.join("references").exists() || skill_dir.join("resources").exists()
 }
 
+/// List the files under a skill's `scripts/` directory, relative to the skill directory.
+pub fn list_script_files(skill_dir: &Path) -> Vec<String> {
+    list_files_under(skill_dir, &["scripts"])
+}
+
+/// List the files under a skill's `references/` or `resources/` directory, relative to the skill directory.
+pub fn list_reference_files(skill_dir: &Path) -> Vec<String> {
+    list_files_under(skill_dir, &["references", "resources"])
+}
+
+/// Sorted, `/`-separated paths of every file below the given subdirectories of `skill_dir`
+fn list_files_under(skill_dir: &Path, subdirs: &[&str]) -> Vec<String> {
+    let mut files: Vec<String> = subdirs
+        .iter()
+        .flat_map(|subdir| walkdir::WalkDir::new(skill_dir.join(subdir)).into_iter().flatten())
+        .filter(|entry| entry.file_type().is_file())
+        .filter_map(|entry| {
+            let rel = entry.path().strip_prefix(skill_dir).ok()?;
+            Some(
+                rel.components()
+                    .map(|c| c.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/"),
+            )
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+/// Default location of a skill's post-install hook
+pub const DEFAULT_POSTINSTALL: &str = "scripts/postinstall.sh";
+
+/// The post-install hook of the skill in `skill_dir`, if it has one.
+///
+/// A `postinstall` field in the frontmatter names the script; otherwise
+/// `scripts/postinstall.sh` is used when present. A named script must exist and
+/// stay inside the skill directory.
+pub fn postinstall_script(skill_dir: &Path) -> Result<Option<PathBuf>> {
+    let declared = parse_skill_metadata(&skill_dir.join("SKILL.md"))
+        .ok()
+        .and_then(|metadata| metadata.postinstall);
+
+    let Some(declared) = declared else {
+        let default = skill_dir.join(DEFAULT_POSTINSTALL);
+        return Ok(default.is_file().then_some(default));
+    };
+
+    let escapes = Path::new(&declared).components().any(|c| {
+        matches!(
+            c,
+            std::path::Component::ParentDir | std::path::Component::RootDir | std::path::Component::Prefix(_)
+        )
+    });
+    if escapes {
+        anyhow::bail!(
+            "Post-install script '{}' must stay inside the skill directory",
+            declared
+        );
+    }
+    let script = skill_dir.join(&declared);
+    if !script.is_file() {
+        anyhow::bail!(
+            "Post-install script '{}' not found in {}",
+            declared,
+            skill_dir.display()
+        );
+    }
+    // Symlinks could still point outside the skill
+    let canonical_dir = skill_dir.canonicalize()?;
+    if !script.canonicalize()?.starts_with(&canonical_dir) {
+        anyhow::bail!(
+            "Post-install script '{}' must stay inside the skill directory",
+            declared
+        );
+    }
+    Ok(Some(script))
+}
+
 /// Represents a discovered skill
 #[derive(Debug, Clone)]
 pub struct Skill {
     pub name: String,
     pub description: String,
     pub path: PathBuf,
+    #[allow(dead_code)] // populated during discovery, read in tests
     pub has_scripts: bool,
+    #[allow(dead_code)] // populated during discovery, read in tests
     pub has_references: bool,
+    pub category: Option<String>,
+    pub tags: Vec<String>,
+    pub aliases: Vec<String>,
 }
 
 /// Parse skill metadata from SKILL.md file
@@ -110,6 +271,10 @@ pub fn parse_skill_metadata(skill_md_path: &Path) -> Result<SkillMetadata> {
 }
 
 /// Discover all skills in a directory
+///
+/// Each subdirectory containing a SKILL.md is a skill. If `skills_dir` itself has a
+/// SKILL.md at its root, the directory is a single skill and is returned on its own,
+/// matching how root-level skills are handled for remote repositories.
 pub fn discover_skills(skills_dir: &Path) -> Result<Vec<Skill>> {
     let mut skills = Vec::new();
 
@@ -117,47 +282,85 @@ pub fn discover_skills(skills_dir: &Path) -> Result<Vec<Skill>> {
         return Ok(skills);
     }
 
+    if skills_dir.join("SKILL.md").is_file() {
+        skills.extend(load_skill(skills_dir.to_path_buf()));
+        return Ok(skills);
+    }
+
     for entry in fs::read_dir(skills_dir)? {
         let entry = entry?;
         let path = entry.path();
 
-        if !path.is_dir() {
+        if !path.is_dir() || !path.join("SKILL.md").exists() {
             continue;
         }
 
-        let skill_md = path.join("SKILL.md");
-        if !skill_md.exists() {
-            continue;
-        }
-
-        match parse_skill_metadata(&skill_md) {
-            Ok(metadata) => {
-                let has_scripts = has_scripts_dir(&path);
-                let has_references = has_references_dir(&path);
-
-                skills.push(Skill {
-                    name: metadata.name,
-                    description: metadata.description.unwrap_or_else(|| "No description".to_string()),
-                    path,
-                    has_scripts,
-                    has_references,
-                });
-            }
-            Err(e) => {
-                eprintln!(
-                    "{} Failed to parse skill at {}: {}",
-                    colored::Colorize::yellow("Warning:"),
-                    path.display(),
-                    e
-                );
-            }
-        }
+        skills.extend(load_skill(path));
     }
 
     skills.sort_by(|a, b| a.name.cmp(&b.name));
     Ok(skills)
 }
 
+/// Check that a skill name can safely be used as a directory name.
+///
+/// Rejects empty names, path separators, `..`, a leading `.`, and control
+/// characters, any of which could escape the skills directory or corrupt the
+/// install layout.
+pub fn validate_skill_name(name: &str) -> Result<()> {
+    let problem = if name.is_empty() {
+        "it is empty"
+    } else if name.contains(['/', '\\']) {
+        "it contains a path separator"
+    } else if name.contains("..") {
+        "it contains '..'"
+    } else if name.starts_with('.') {
+        "it starts with '.'"
+    } else if name.chars().any(char::is_control) {
+        "it contains control characters"
+    } else {
+        return Ok(());
+    };
+    anyhow::bail!("Invalid skill name '{}': {}", name.escape_debug(), problem)
+}
+
+/// Load the skill at `path` (a directory containing SKILL.md), warning if it cannot be parsed
+/// or its name is not a valid skill name
+fn load_skill(path: PathBuf) -> Option<Skill> {
+    let metadata = parse_skill_metadata(&path.join("SKILL.md")).and_then(|mut metadata| {
+        // Surrounding whitespace in the frontmatter is not part of the name
+        metadata.name = metadata.name.trim().to_string();
+        validate_skill_name(&metadata.name)?;
+        Ok(metadata)
+    });
+    match metadata {
+        Ok(metadata) => {
+            let has_scripts = has_scripts_dir(&path);
+            let has_references = has_references_dir(&path);
+
+            Some(Skill {
+                name: metadata.name,
+                description: metadata.description.unwrap_or_else(|| "No description".to_string()),
+                path,
+                has_scripts,
+                has_references,
+                category: metadata.category,
+                tags: metadata.tags,
+                aliases: metadata.aliases,
+            })
+        }
+        Err(e) => {
+            eprintln!(
+                "{} Failed to parse skill at {}: {}",
+                colored::Colorize::yellow("Warning:"),
+                path.display(),
+                e
+            );
+            None
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -226,6 +429,78 @@ allowed-tools:
         assert_eq!(metadata.allowed_tools.0, vec!["Tool1", "Tool2"]);
     }
 
+    #[test]
+    fn test_parse_skill_metadata_with_tags_string() {
+        let dir = TempDir::new().unwrap();
+        let skill_md = dir.path().join("SKILL.md");
+        fs::write(
+            &skill_md,
+            r#"---
+name: test-skill
+tags: testing
+---
+# Test
+"#,
+        )
+        .unwrap();
+
+        let metadata = parse_skill_metadata(&skill_md).unwrap();
+        assert_eq!(metadata.tags, vec!["testing"]);
+    }
+
+    #[test]
+    fn test_parse_skill_metadata_with_aliases_string() {
+        let dir = TempDir::new().unwrap();
+        let skill_md = dir.path().join("SKILL.md");
+        fs::write(
+            &skill_md,
+            r#"---
+name: python-code-reviewer
+aliases: reviewer
+---
+# Test
+"#,
+        )
+        .unwrap();
+
+        let metadata = parse_skill_metadata(&skill_md).unwrap();
+        assert_eq!(metadata.aliases, vec!["reviewer"]);
+    }
+
+    #[test]
+    fn test_parse_skill_metadata_ignores_non_string_category() {
+        let dir = TempDir::new().unwrap();
+        let skill_md = dir.path().join("SKILL.md");
+        fs::write(
+            &skill_md,
+            r#"---
+name: test-skill
+category:
+  - testing
+  - python
+---
+# Test
+"#,
+        )
+        .unwrap();
+
+        let metadata = parse_skill_metadata(&skill_md).unwrap();
+        assert_eq!(metadata.name, "test-skill");
+        assert_eq!(metadata.category, None);
+    }
+
+    #[test]
+    fn test_allowed_tools_unknown_ignores_arguments() {
+        let tools = AllowedTools(vec![
+            "Read".to_string(),
+            "Bash(git status:*)".to_string(),
+            "WebFetch".to_string(),
+        ]);
+        let known = vec!["Read".to_string(), "Bash".to_string()];
+        assert_eq!(tools.unknown(&known), vec!["WebFetch"]);
+        assert!(AllowedTools::default().unknown(&known).is_empty());
+    }
+
     #[test]
     fn test_parse_skill_metadata_with_license_and_version_metadata() {
         let dir = TempDir::new().unwrap();
@@ -357,10 +632,118 @@ name: skill3
         assert!(skills[2].has_references);
     }
 
+    #[test]
+    fn test_validate_skill_name() {
+        assert!(validate_skill_name("code-reviewer").is_ok());
+        assert!(validate_skill_name("skill_v2.1").is_ok());
+        for name in ["../escape", "foo/bar", "foo\\bar", "..", "", ".hidden", "bad\nname"] {
+            let err = validate_skill_name(name).unwrap_err().to_string();
+            assert!(err.starts_with("Invalid skill name"), "{:?}: {}", name, err);
+        }
+        assert!(validate_skill_name("foo/bar")
+            .unwrap_err()
+            .to_string()
+            .contains("path separator"));
+    }
+
+    #[test]
+    fn test_discover_skills_skips_invalid_names_and_trims() {
+        let dir = TempDir::new().unwrap();
+        for (folder, name) in [
+            ("escape", "../escape"),
+            ("nested", "foo/bar"),
+            ("ok", "  code-reviewer "),
+        ] {
+            fs::create_dir(dir.path().join(folder)).unwrap();
+            fs::write(
+                dir.path().join(folder).join("SKILL.md"),
+                format!("---\nname: \"{}\"\ndescription: Test\n---\n", name),
+            )
+            .unwrap();
+        }
+
+        let skills = discover_skills(dir.path()).unwrap();
+        let names: Vec<&str> = skills.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["code-reviewer"]);
+    }
+
+    #[test]
+    fn test_discover_skills_root_level_skill_md() {
+        let dir = TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("SKILL.md"),
+            "---\nname: root-skill\ndescription: Lives at the root\n---\n",
+        )
+        .unwrap();
+        // Support folders inside a root-level skill are not skills of their own
+        fs::create_dir(dir.path().join("scripts")).unwrap();
+
+        let skills = discover_skills(dir.path()).unwrap();
+        assert_eq!(skills.len(), 1);
+        assert_eq!(skills[0].name, "root-skill");
+        assert_eq!(skills[0].path, dir.path());
+        assert!(skills[0].has_scripts);
+    }
+
     #[test]
     fn test_discover_skills_nonexistent_dir() {
         let path = PathBuf::from("/nonexistent/path");
         let skills = discover_skills(&path).unwrap();
         assert!(skills.is_empty());
     }
+
+    #[test]
+    fn test_list_script_and_reference_files() {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join("scripts/lib")).unwrap();
+        fs::write(dir.path().join("scripts/run.sh"), "").unwrap();
+        fs::write(dir.path().join("scripts/lib/util.py"), "").unwrap();
+        fs::create_dir(dir.path().join("resources")).unwrap();
+        fs::write(dir.path().join("resources/guide.md"), "").unwrap();
+        fs::write(dir.path().join("SKILL.md"), "").unwrap();
+
+        assert_eq!(
+            list_script_files(dir.path()),
+            vec!["scripts/lib/util.py".to_string(), "scripts/run.sh".to_string()]
+        );
+        assert_eq!(list_reference_files(dir.path()), vec!["resources/guide.md".to_string()]);
+        assert!(list_script_files(&dir.path().join("missing")).is_empty());
+    }
+
+    #[test]
+    fn test_postinstall_script_resolution() {
+        let dir = TempDir::new().unwrap();
+        let skill = dir.path();
+        fs::write(skill.join("SKILL.md"), "---\nname: hooked\n---\n").unwrap();
+        assert_eq!(postinstall_script(skill).unwrap(), None);
+
+        fs::create_dir_all(skill.join("scripts")).unwrap();
+        fs::write(skill.join(DEFAULT_POSTINSTALL), "true").unwrap();
+        assert_eq!(
+            postinstall_script(skill).unwrap(),
+            Some(skill.join(DEFAULT_POSTINSTALL))
+        );
+
+        // A frontmatter `postinstall` replaces the default
+        fs::write(skill.join("setup.py"), "").unwrap();
+        fs::write(
+            skill.join("SKILL.md"),
+            "---\nname: hooked\npostinstall: setup.py\n---\n",
+        )
+        .unwrap();
+        assert_eq!(postinstall_script(skill).unwrap(), Some(skill.join("setup.py")));
+
+        fs::write(
+            skill.join("SKILL.md"),
+            "---\nname: hooked\npostinstall: missing.sh\n---\n",
+        )
+        .unwrap();
+        assert!(postinstall_script(skill).is_err());
+        fs::write(
+            skill.join("SKILL.md"),
+            "---\nname: hooked\npostinstall: ../outside.sh\n---\n",
+        )
+        .unwrap();
+        assert!(postinstall_script(skill).is_err());
+    }
 }
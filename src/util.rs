@@ -1,7 +1,15 @@
 use anyhow::Result;
+use chrono::{DateTime, SecondsFormat, Utc};
+use indicatif::{ProgressBar, ProgressStyle};
+use sha2::{Digest, Sha256};
 use std::fs;
+use std::io::IsTerminal;
 use std::path::Path;
+use walkdir::WalkDir;
 
+/// Shorten `value` to at most `max_len` bytes, ending in `...` when anything was cut.
+///
+/// The cut point is moved back to a character boundary, so multi-byte text never panics.
 pub fn truncate_string(value: &str, max_len: usize) -> String {
     if value.len() <= max_len {
         value.to_string()
@@ -19,32 +27,272 @@ pub fn truncate_string(value: &str, max_len: usize) -> String {
     }
 }
 
+/// Length commit SHAs are shortened to for display
+pub const SHORT_SHA_LEN: usize = 7;
+
+/// Shorten a full commit SHA for display unless `full` is set.
+///
+/// Other recorded refs (tags, short SHAs) are returned unchanged.
+pub fn display_commit(commit: &str, full: bool) -> &str {
+    if full || commit.len() <= SHORT_SHA_LEN || !commit.chars().all(|c| c.is_ascii_hexdigit()) {
+        commit
+    } else {
+        &commit[..SHORT_SHA_LEN]
+    }
+}
+
+/// Format a timestamp for display: `%Y-%m-%d %H:%M` by default, RFC 3339 in UTC with `rfc3339`
+pub fn display_timestamp(at: DateTime<Utc>, rfc3339: bool) -> String {
+    if rfc3339 {
+        at.to_rfc3339_opts(SecondsFormat::Secs, true)
+    } else {
+        at.format("%Y-%m-%d %H:%M").to_string()
+    }
+}
+
+/// Keep the first `limit` items (all of them when `None`) and count the ones left out,
+/// for lists that end with an "... and N more" line.
+pub fn take_limited<T>(items: impl IntoIterator<Item = T>, limit: Option<usize>) -> (Vec<T>, usize) {
+    let mut items: Vec<T> = items.into_iter().collect();
+    let hidden = limit.map_or(0, |limit| items.len().saturating_sub(limit));
+    items.truncate(items.len() - hidden);
+    (items, hidden)
+}
+
+/// The "... and N more" line for items left out by [`take_limited`], or `None` if none were
+pub fn more_line(hidden: usize) -> Option<String> {
+    (hidden > 0).then(|| format!("... and {} more", hidden))
+}
+
+/// Width of the terminal attached to stdout, or `None` when stdout is not a terminal
+pub fn terminal_width() -> Option<usize> {
+    console::Term::stdout()
+        .size_checked()
+        .map(|(_, columns)| usize::from(columns))
+}
+
+/// Create a progress bar showing `pos/len` and the current item name.
+///
+/// The bar is hidden when stdout is not a TTY so piped and CI output stays clean.
+pub fn progress_bar(len: u64) -> ProgressBar {
+    if !std::io::stdout().is_terminal() {
+        return ProgressBar::hidden();
+    }
+
+    let bar = ProgressBar::new(len);
+    if let Ok(style) = ProgressStyle::with_template("  [{bar:30.cyan/blue}] {pos}/{len} {msg}") {
+        bar.set_style(style.progress_chars("=> "));
+    }
+    bar
+}
+
+/// File at a skill's root listing glob patterns to leave out of installs
+pub const IGNORE_FILE: &str = ".skillshubignore";
+
+/// Exclude rules for a skill directory: `.git` always, plus the patterns in its
+/// `.skillshubignore`.
+///
+/// Patterns follow a small `.gitignore` subset: blank lines and `#` comments are
+/// skipped, a trailing `/` matches directories only, and a pattern containing `/`
+/// is matched against the path from the skill root (`**` spans directories)
+/// while one without is matched against the entry name at any depth.
+pub struct IgnoreRules {
+    patterns: Vec<IgnorePattern>,
+}
+
+struct IgnorePattern {
+    glob: glob::Pattern,
+    dir_only: bool,
+    anchored: bool,
+}
+
+impl IgnoreRules {
+    /// Read `root/.skillshubignore`; a missing file or invalid pattern is skipped
+    pub fn load(root: &Path) -> Self {
+        let content = fs::read_to_string(root.join(IGNORE_FILE)).unwrap_or_default();
+        Self::parse(&content)
+    }
+
+    fn parse(content: &str) -> Self {
+        let patterns = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let dir_only = line.ends_with('/');
+                let line = line.trim_end_matches('/');
+                let anchored = line.contains('/');
+                let glob = glob::Pattern::new(line.trim_start_matches('/')).ok()?;
+                Some(IgnorePattern {
+                    glob,
+                    dir_only,
+                    anchored,
+                })
+            })
+            .collect();
+        Self { patterns }
+    }
+
+    /// Whether the entry at `rel_path` (relative to the skill root) is excluded
+    pub fn is_ignored(&self, rel_path: &Path, is_dir: bool) -> bool {
+        let Some(name) = rel_path.file_name().map(|n| n.to_string_lossy()) else {
+            return false;
+        };
+        if name == ".git" {
+            return true;
+        }
+
+        let rel = rel_path.to_string_lossy().replace('\\', "/");
+        let options = glob::MatchOptions {
+            require_literal_separator: true,
+            ..Default::default()
+        };
+        self.patterns.iter().any(|p| {
+            (is_dir || !p.dir_only)
+                && if p.anchored {
+                    p.glob.matches_with(&rel, options)
+                } else {
+                    p.glob.matches_with(&name, options)
+                }
+        })
+    }
+}
+
 /// Recursively copy directory contents
 ///
-/// Symlinks are skipped as a defense-in-depth measure to prevent a malicious
-/// cloned repo from including symlinks that point outside the clone directory.
+/// Symlinks that resolve inside `src` are recreated as symlinks (Unix only). Links
+/// that escape the source tree, are absolute, or dangle are skipped as a
+/// defense-in-depth measure against malicious cloned repos. Unix mode bits are
+/// carried over so bundled scripts stay executable. Entries excluded by
+/// [`IgnoreRules`] are not copied.
 pub fn copy_dir_contents(src: &Path, dst: &Path) -> Result<()> {
+    let root = src.canonicalize()?;
+    let rules = IgnoreRules::load(src);
+    copy_tree(src, dst, Path::new(""), &root, &rules)
+}
+
+/// Copy `src` (at `rel` below the copy root) into `dst`
+fn copy_tree(src: &Path, dst: &Path, rel: &Path, root: &Path, rules: &IgnoreRules) -> Result<()> {
     for entry in fs::read_dir(src)? {
         let entry = entry?;
+        let src_path = entry.path();
+        let dst_path = dst.join(entry.file_name());
 
-        // Skip symlinks to avoid following links that escape the source tree
-        if entry.file_type()?.is_symlink() {
+        let is_dir = src_path.is_dir();
+        let rel_path = rel.join(entry.file_name());
+        if rules.is_ignored(&rel_path, is_dir) {
             continue;
         }
 
-        let src_path = entry.path();
-        let dst_path = dst.join(entry.file_name());
-
-        if src_path.is_dir() {
+        if entry.file_type()?.is_symlink() {
+            copy_internal_symlink(&src_path, &dst_path, root)?;
+        } else if is_dir {
             fs::create_dir_all(&dst_path)?;
-            copy_dir_contents(&src_path, &dst_path)?;
+            copy_tree(&src_path, &dst_path, &rel_path, root, rules)?;
         } else {
             fs::copy(&src_path, &dst_path)?;
+            copy_mode(&src_path, &dst_path)?;
         }
     }
     Ok(())
 }
 
+/// Recreate `link` at `dst` if its target stays inside `root`; otherwise skip it
+fn copy_internal_symlink(link: &Path, dst: &Path, root: &Path) -> Result<()> {
+    let target = fs::read_link(link)?;
+    if target.is_absolute() {
+        return Ok(());
+    }
+
+    let resolved = match link.parent().map(|parent| parent.join(&target).canonicalize()) {
+        Some(Ok(resolved)) => resolved,
+        _ => return Ok(()),
+    };
+    if !resolved.starts_with(root) {
+        return Ok(());
+    }
+
+    #[cfg(unix)]
+    std::os::unix::fs::symlink(&target, dst)?;
+    #[cfg(not(unix))]
+    let _ = dst;
+
+    Ok(())
+}
+
+/// Copy Unix permission bits from `src` to `dst` (a no-op elsewhere)
+fn copy_mode(src: &Path, dst: &Path) -> Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(src)?.permissions().mode();
+        fs::set_permissions(dst, fs::Permissions::from_mode(mode))?;
+    }
+    #[cfg(not(unix))]
+    let _ = (src, dst);
+
+    Ok(())
+}
+
+/// Compute a SHA-256 over all regular files in a directory tree.
+///
+/// Files are visited in sorted order and each contributes its relative path
+/// and contents, so the digest is stable across machines. Symlinks and entries
+/// excluded by [`IgnoreRules`] are skipped to mirror `copy_dir_contents`, so a
+/// source tree and its installed copy hash the same.
+pub fn hash_dir_contents(dir: &Path) -> Result<String> {
+    let mut hasher = Sha256::new();
+    let rules = IgnoreRules::load(dir);
+
+    let walker = WalkDir::new(dir).sort_by_file_name().into_iter().filter_entry(|e| {
+        let rel_path = e.path().strip_prefix(dir).unwrap_or(e.path());
+        e.depth() == 0 || !rules.is_ignored(rel_path, e.file_type().is_dir())
+    });
+    for entry in walker {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+
+        let rel_path = entry.path().strip_prefix(dir).unwrap_or(entry.path());
+        hasher.update(rel_path.to_string_lossy().replace('\\', "/").as_bytes());
+        hasher.update([0]);
+        hasher.update(fs::read(entry.path())?);
+        hasher.update([0]);
+    }
+
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Total size in bytes of all regular files under `path` (symlinks are not followed).
+/// Unreadable entries are skipped, and a missing directory has size 0.
+pub fn dir_size(path: &Path) -> u64 {
+    WalkDir::new(path)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.metadata().ok())
+        .map(|m| m.len())
+        .sum()
+}
+
+/// Format a byte count for display, e.g. `512 B`, `1.5 KB`, `2.0 MB`
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -56,6 +304,33 @@ mod tests {
         assert_eq!(truncate_string("hello world", 8), "hello...");
     }
 
+    #[test]
+    fn test_display_commit_and_timestamp() {
+        let sha = "0123456789abcdef0123456789abcdef01234567";
+        assert_eq!(display_commit(sha, false), "0123456");
+        assert_eq!(display_commit(sha, true), sha);
+        assert_eq!(display_commit("v1.2.0", false), "v1.2.0");
+        assert_eq!(display_commit("abc1234", false), "abc1234");
+
+        let at = DateTime::parse_from_rfc3339("2024-03-05T14:07:09Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(display_timestamp(at, false), "2024-03-05 14:07");
+        assert_eq!(display_timestamp(at, true), "2024-03-05T14:07:09Z");
+    }
+
+    #[test]
+    fn test_take_limited_counts_the_rest() {
+        assert_eq!(take_limited(1..=12, Some(10)), ((1..=10).collect::<Vec<_>>(), 2));
+        assert_eq!(take_limited(1..=3, Some(10)), (vec![1, 2, 3], 0));
+        assert_eq!(take_limited(1..=3, Some(3)), (vec![1, 2, 3], 0));
+        assert_eq!(take_limited(1..=3, Some(0)), (Vec::new(), 3));
+        assert_eq!(take_limited(1..=3, None), (vec![1, 2, 3], 0));
+
+        assert_eq!(more_line(2).as_deref(), Some("... and 2 more"));
+        assert_eq!(more_line(0), None);
+    }
+
     #[test]
     fn test_truncate_string_multibyte() {
         // Should not panic when truncation falls inside a multi-byte char
@@ -65,6 +340,17 @@ mod tests {
         assert!(result.len() <= 20); // up to 17 bytes of chars + "..."
     }
 
+    #[test]
+    fn test_truncate_string_emoji_at_boundary() {
+        // The 4-byte emoji spans bytes 6..10, across the cut at byte 7
+        let description = "Deploy🚀 to production with rollback";
+        assert_eq!(truncate_string(description, 10), "Deploy...");
+        // A cut exactly at the emoji's end keeps it whole
+        assert_eq!(truncate_string(description, 13), "Deploy🚀...");
+        assert_eq!(truncate_string("🚀🚀🚀", 12), "🚀🚀🚀");
+        assert_eq!(truncate_string("🚀🚀🚀", 11), "🚀🚀...");
+    }
+
     #[test]
     fn test_copy_dir_contents_copies_tree() {
         use tempfile::TempDir;
@@ -98,6 +384,48 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_copy_dir_contents_honors_skillshubignore() {
+        use tempfile::TempDir;
+        let src = TempDir::new().unwrap();
+        let dst = TempDir::new().unwrap();
+
+        fs::write(
+            src.path().join(IGNORE_FILE),
+            "# editor cruft\nscratch/\n*.log\n/docs/*.tmp\n",
+        )
+        .unwrap();
+        fs::write(src.path().join("SKILL.md"), b"---\nname: a\n---\n").unwrap();
+        fs::create_dir_all(src.path().join("scratch/deep")).unwrap();
+        fs::write(src.path().join("scratch/deep/notes.md"), b"wip").unwrap();
+        fs::create_dir_all(src.path().join("scripts")).unwrap();
+        fs::write(src.path().join("scripts/run.sh"), b"echo").unwrap();
+        fs::write(src.path().join("scripts/debug.log"), b"noise").unwrap();
+        fs::create_dir_all(src.path().join("docs/nested")).unwrap();
+        fs::write(src.path().join("docs/draft.tmp"), b"x").unwrap();
+        fs::write(src.path().join("docs/nested/keep.tmp"), b"x").unwrap();
+        // `scratch` as a file is not matched by the directory-only pattern
+        fs::write(src.path().join("docs/scratch"), b"kept").unwrap();
+        fs::create_dir_all(src.path().join(".git")).unwrap();
+        fs::write(src.path().join(".git/HEAD"), b"ref").unwrap();
+
+        copy_dir_contents(src.path(), dst.path()).unwrap();
+
+        assert!(dst.path().join("SKILL.md").exists());
+        assert!(dst.path().join("scripts/run.sh").exists());
+        assert!(dst.path().join("docs/nested/keep.tmp").exists());
+        assert!(dst.path().join("docs/scratch").exists());
+        assert!(!dst.path().join("scratch").exists(), "scratch/ should be excluded");
+        assert!(!dst.path().join("scripts/debug.log").exists());
+        assert!(!dst.path().join("docs/draft.tmp").exists());
+        assert!(!dst.path().join(".git").exists(), ".git is always excluded");
+        assert_eq!(
+            hash_dir_contents(src.path()).unwrap(),
+            hash_dir_contents(dst.path()).unwrap(),
+            "source and installed copy should hash the same"
+        );
+    }
+
     #[test]
     #[cfg(unix)]
     fn test_copy_dir_contents_skips_symlinks() {
@@ -147,6 +475,86 @@ mod tests {
         );
     }
 
+    #[test]
+    #[cfg(unix)]
+    fn test_copy_dir_contents_keeps_executable_scripts_and_internal_symlinks() {
+        use std::os::unix::fs::{symlink, PermissionsExt};
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let src = temp.path().join("src");
+        let dst = temp.path().join("dst");
+        fs::create_dir_all(src.join("scripts")).unwrap();
+        fs::create_dir_all(&dst).unwrap();
+
+        fs::write(src.join("SKILL.md"), "---\nname: s\n---\n").unwrap();
+        let script = src.join("scripts/run.sh");
+        fs::write(&script, "#!/bin/sh\necho hi\n").unwrap();
+        fs::set_permissions(&script, fs::Permissions::from_mode(0o755)).unwrap();
+        symlink("scripts/run.sh", src.join("run")).unwrap();
+        symlink("../SKILL.md", src.join("scripts/SKILL.md")).unwrap();
+
+        copy_dir_contents(&src, &dst).unwrap();
+
+        let mode = fs::metadata(dst.join("scripts/run.sh")).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o755, "script should stay executable");
+
+        assert!(dst.join("run").is_symlink(), "internal symlink should be preserved");
+        assert_eq!(fs::read_link(dst.join("run")).unwrap(), Path::new("scripts/run.sh"));
+        assert_eq!(fs::read_to_string(dst.join("run")).unwrap(), "#!/bin/sh\necho hi\n");
+        assert!(dst.join("scripts/SKILL.md").is_symlink());
+        assert!(
+            dst.join("scripts/SKILL.md").exists(),
+            "link should resolve inside the copy"
+        );
+    }
+
+    #[test]
+    fn test_hash_dir_contents_known_bytes() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::write(dir.path().join("SKILL.md"), b"abc").unwrap();
+
+        // sha256(b"SKILL.md\0abc\0")
+        assert_eq!(
+            hash_dir_contents(dir.path()).unwrap(),
+            "e879d6da5911b568392565808735738b822789bd31dfe3d52e23c0fbdf67970e"
+        );
+    }
+
+    #[test]
+    fn test_hash_dir_contents_detects_changes() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join("scripts")).unwrap();
+        fs::write(dir.path().join("SKILL.md"), b"---\nname: a\n---\n").unwrap();
+        fs::write(dir.path().join("scripts/run.sh"), b"echo hi").unwrap();
+
+        let first = hash_dir_contents(dir.path()).unwrap();
+        assert_eq!(first, hash_dir_contents(dir.path()).unwrap(), "hash should be stable");
+        assert_eq!(first.len(), 64);
+
+        fs::write(dir.path().join("scripts/run.sh"), b"echo bye").unwrap();
+        assert_ne!(first, hash_dir_contents(dir.path()).unwrap());
+    }
+
+    #[test]
+    fn test_dir_size_sums_nested_files() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join("scripts/nested")).unwrap();
+        fs::write(dir.path().join("SKILL.md"), vec![b'a'; 100]).unwrap();
+        fs::write(dir.path().join("scripts/run.sh"), vec![b'b'; 20]).unwrap();
+        fs::write(dir.path().join("scripts/nested/data.txt"), vec![b'c'; 3]).unwrap();
+
+        assert_eq!(dir_size(dir.path()), 123);
+        assert_eq!(dir_size(&dir.path().join("missing")), 0);
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(0), "0 B");
+        assert_eq!(format_size(1023), "1023 B");
+        assert_eq!(format_size(1536), "1.5 KB");
+        assert_eq!(format_size(2 * 1024 * 1024), "2.0 MB");
+    }
+
     /// Verify that the `colored` crate suppresses ANSI escape codes when
     /// the `NO_COLOR` environment variable is set (per <https://no-color.org>).
     ///
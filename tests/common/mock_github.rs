@@ -81,6 +81,48 @@ impl MockGitHub {
             .await;
     }
 
+    /// Mock a repository with the given SKILL.md files, keyed by their directory path
+    ///
+    /// Serves the repo info (default branch `main`), the recursive tree, and the raw
+    /// SKILL.md contents, so `discover_skills_from_repo` can run against it.
+    pub async fn mock_repo_with_skills(&self, owner: &str, repo: &str, skills: &[(&str, &str)]) {
+        Mock::given(method("GET"))
+            .and(path_regex(format!("^/repos/{}/{}$", owner, repo)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "default_branch": "main" })))
+            .mount(&self.server)
+            .await;
+
+        let tree: Vec<serde_json::Value> = skills
+            .iter()
+            .map(|(dir, _)| json!({ "path": format!("{}/SKILL.md", dir), "type": "blob" }))
+            .collect();
+        Mock::given(method("GET"))
+            .and(path_regex(format!("^/repos/{}/{}/git/trees/main$", owner, repo)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "tree": tree })))
+            .mount(&self.server)
+            .await;
+
+        for (dir, content) in skills {
+            Mock::given(method("GET"))
+                .and(path_regex(format!("^/{}/{}/main/{}/SKILL.md$", owner, repo, dir)))
+                .respond_with(ResponseTemplate::new(200).set_body_string(*content))
+                .mount(&self.server)
+                .await;
+        }
+    }
+
+    /// Mock the latest release of a repository with the given tag
+    pub async fn mock_latest_release(&self, owner: &str, repo: &str, tag: &str) {
+        Mock::given(method("GET"))
+            .and(path_regex(format!("^/repos/{}/{}/releases/latest$", owner, repo)))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "tag_name": tag,
+                "html_url": format!("https://github.com/{}/{}/releases/tag/{}", owner, repo, tag)
+            })))
+            .mount(&self.server)
+            .await;
+    }
+
     /// Mock a 500 server error response
     pub async fn mock_server_error(&self, path_pattern: &str) {
         Mock::given(method("GET"))
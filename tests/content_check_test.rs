@@ -0,0 +1,88 @@
+//! Tests for the content check in `skillshub update`
+//!
+//! Installs a skill from a local `file://` tap, then corrupts the content hash
+//! recorded in db.json so `update` sees unchanged commits with changed content,
+//! and checks that the warning goes to stderr, leaving stdout for status lines.
+
+mod common;
+
+use std::process::Command;
+
+fn cargo_bin(home: &std::path::Path) -> Command {
+    let mut cmd = Command::new(env!("CARGO"));
+    cmd.args(["run", "--quiet", "--"]);
+    cmd.env("SKILLSHUB_TEST_HOME", home);
+    cmd.env_remove("SKILLSHUB_HOME");
+    cmd
+}
+
+#[test]
+fn test_update_warns_about_changed_content_on_stderr() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let home = temp.path();
+    let origin = home.join("origin");
+    std::fs::create_dir_all(&origin).unwrap();
+    common::init_test_repo_with_skill(&origin, "checked-skill", "A skill for content checks");
+
+    let db_path = home.join(".skillshub/db.json");
+    std::fs::create_dir_all(db_path.parent().unwrap()).unwrap();
+    let db_json = serde_json::json!({
+        "taps": {
+            "example/skills": {
+                "url": format!("file://{}", origin.display()),
+                "skills_path": "skills",
+                "updated_at": null,
+                "is_default": false,
+                "cached_registry": {
+                    "name": "example/skills",
+                    "description": null,
+                    "skills": {
+                        "checked-skill": { "path": "skills/checked-skill", "description": null, "homepage": null }
+                    }
+                }
+            }
+        },
+        "installed": {},
+        "linked_agents": [],
+        "external": {}
+    });
+    std::fs::write(&db_path, db_json.to_string()).unwrap();
+
+    let output = cargo_bin(home)
+        .args(["install", "example/skills/checked-skill"])
+        .output()
+        .expect("failed to run skillshub install");
+    assert!(
+        output.status.success(),
+        "install failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let mut db: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&db_path).unwrap()).unwrap();
+    db["installed"]["example/skills/checked-skill"]["content_sha256"] = serde_json::json!("0".repeat(64));
+    std::fs::write(&db_path, db.to_string()).unwrap();
+
+    let output = cargo_bin(home)
+        .args(["update", "example/skills/checked-skill", "--skill-only"])
+        .output()
+        .expect("failed to run skillshub update");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(output.status.success(), "update failed: {}", stderr);
+    assert!(
+        stderr.contains("content changed but commit did not"),
+        "warning should be on stderr:\n{}",
+        stderr
+    );
+    assert!(
+        !stdout.contains("content changed"),
+        "warning should not be on stdout:\n{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("up to date"),
+        "status lines stay on stdout:\n{}",
+        stdout
+    );
+}
@@ -0,0 +1,115 @@
+//! Tests for `skillshub info`
+//!
+//! Runs the binary against a mock GitHub API so registries of taps that have
+//! not been added are fetched transiently, then checks that nothing was
+//! persisted. Also checks the `--json` output for installed skills.
+
+mod common;
+
+use common::{skill_md, MockGitHub};
+use std::process::Command;
+
+fn cargo_bin(home: &std::path::Path, mock_url: &str) -> Command {
+    let mut cmd = Command::new(env!("CARGO"));
+    cmd.args(["run", "--quiet", "--"]);
+    cmd.env("SKILLSHUB_TEST_HOME", home);
+    cmd.env_remove("SKILLSHUB_HOME");
+    cmd.env("SKILLSHUB_GITHUB_API_BASE", mock_url);
+    cmd.env("SKILLSHUB_GITHUB_RAW_BASE", mock_url);
+    cmd.env_remove("SKILLSHUB_OFFLINE");
+    cmd
+}
+
+fn start_mock() -> (tokio::runtime::Runtime, MockGitHub) {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    let mock = rt.block_on(async {
+        let mock = MockGitHub::start().await;
+        mock.mock_repo_with_skills(
+            "acme",
+            "skills",
+            &[
+                ("skills/deploy", &skill_md("deploy", "Ship it to production")),
+                ("skills/review", &skill_md("review", "Review pull requests")),
+            ],
+        )
+        .await;
+        mock
+    });
+    (rt, mock)
+}
+
+#[test]
+fn test_info_from_unadded_tap_url() {
+    let (_rt, mock) = start_mock();
+    let temp = tempfile::TempDir::new().unwrap();
+
+    let output = cargo_bin(temp.path(), &mock.url())
+        .args(["info", "https://github.com/acme/skills/tree/main/skills/deploy"])
+        .output()
+        .expect("failed to run skillshub info");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "info should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.contains("acme/skills/deploy"), "unexpected stdout: {}", stdout);
+    assert!(
+        stdout.contains("Ship it to production"),
+        "unexpected stdout: {}",
+        stdout
+    );
+    assert!(stdout.contains("not added"), "unexpected stdout: {}", stdout);
+
+    let db = std::fs::read_to_string(temp.path().join(".skillshub/db.json")).unwrap_or_default();
+    assert!(!db.contains("acme/skills"), "tap should not be persisted: {}", db);
+}
+
+#[test]
+fn test_info_from_unadded_tap_id() {
+    let (_rt, mock) = start_mock();
+    let temp = tempfile::TempDir::new().unwrap();
+
+    let output = cargo_bin(temp.path(), &mock.url())
+        .args(["info", "acme/skills/review"])
+        .output()
+        .expect("failed to run skillshub info");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "info should succeed");
+    assert!(stdout.contains("Review pull requests"), "unexpected stdout: {}", stdout);
+}
+
+#[test]
+fn test_info_json_lists_installed_files() {
+    let env = common::TestEnv::new();
+    let skill_dir = env.create_skill("local-tap", "json-skill", &skill_md("json-skill", "Structured info"));
+    std::fs::create_dir_all(skill_dir.join("scripts")).unwrap();
+    std::fs::write(skill_dir.join("scripts/run.sh"), "#!/bin/sh\n").unwrap();
+    std::fs::create_dir_all(skill_dir.join("references")).unwrap();
+    std::fs::write(skill_dir.join("references/api.md"), "# API\n").unwrap();
+    env.write_db(&common::db_with_installed_skill("local-tap", "json-skill"));
+
+    let output = cargo_bin(&env.home_dir, "http://127.0.0.1:9")
+        .args(["info", "local-tap/json-skill", "--json"])
+        .output()
+        .expect("failed to run skillshub info --json");
+
+    assert!(
+        output.status.success(),
+        "info --json should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let info: serde_json::Value = serde_json::from_slice(&output.stdout).expect("stdout should be JSON");
+    assert_eq!(info["name"], "local-tap/json-skill");
+    assert_eq!(info["tap"], "local-tap");
+    assert_eq!(info["description"], "Structured info");
+    assert_eq!(info["installed"], true);
+    assert_eq!(info["local_path"], skill_dir.display().to_string());
+    assert_eq!(info["scripts"], serde_json::json!(["scripts/run.sh"]));
+    assert_eq!(info["references"], serde_json::json!(["references/api.md"]));
+}
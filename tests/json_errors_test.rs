@@ -0,0 +1,40 @@
+//! Tests for JSON error output (`--json-errors`, or a command's own `--json`)
+
+use std::process::Command;
+
+fn cargo_bin(home: &std::path::Path) -> Command {
+    let mut cmd = Command::new(env!("CARGO"));
+    cmd.args(["run", "--quiet", "--"]);
+    cmd.env("SKILLSHUB_TEST_HOME", home);
+    cmd.env_remove("SKILLSHUB_HOME");
+    cmd
+}
+
+#[test]
+fn test_json_errors_prints_error_object_on_stderr() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let output = cargo_bin(temp.path())
+        .args(["--json-errors", "tap", "remove", "nobody/nothing"])
+        .output()
+        .expect("failed to run skillshub tap remove");
+
+    assert!(!output.status.success(), "removing a missing tap should fail");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let error: serde_json::Value = serde_json::from_str(stderr.trim()).expect("stderr should be only the JSON error");
+    assert_eq!(error["error"]["kind"], "Other");
+    assert_eq!(error["error"]["message"], "Tap 'nobody/nothing' not found");
+}
+
+#[test]
+fn test_command_json_flag_implies_json_errors() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let output = cargo_bin(temp.path())
+        .args(["link", "--json", "--agent", ".nonexistent"])
+        .output()
+        .expect("failed to run skillshub link");
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let error: serde_json::Value = serde_json::from_str(stderr.trim()).expect("stderr should be only the JSON error");
+    assert!(error["error"]["message"].as_str().unwrap().contains(".nonexistent"));
+}
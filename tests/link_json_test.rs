@@ -0,0 +1,62 @@
+//! Tests for `skillshub link --json`
+//!
+//! Links an installed skill into two agents, one of which already holds an
+//! unmanaged file with the same name, and checks the per-agent report.
+
+mod common;
+
+use common::skill_md;
+use std::process::Command;
+
+fn cargo_bin(home: &std::path::Path) -> Command {
+    let mut cmd = Command::new(env!("CARGO"));
+    cmd.args(["run", "--quiet", "--"]);
+    cmd.env("SKILLSHUB_TEST_HOME", home);
+    cmd.env_remove("SKILLSHUB_HOME");
+    cmd
+}
+
+#[test]
+fn test_link_json_reports_each_agent() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let home = temp.path();
+
+    let skill_dir = home.join(".skillshub/skills/acme/tools/linter");
+    std::fs::create_dir_all(&skill_dir).unwrap();
+    std::fs::write(skill_dir.join("SKILL.md"), skill_md("linter", "Lints code")).unwrap();
+
+    std::fs::create_dir_all(home.join(".claude/skills")).unwrap();
+    // An unmanaged file with the same name blocks the link in .codex
+    std::fs::create_dir_all(home.join(".codex/skills")).unwrap();
+    std::fs::write(home.join(".codex/skills/linter"), "not a skill").unwrap();
+
+    let output = cargo_bin(home)
+        .args(["link", "--json"])
+        .output()
+        .expect("failed to run skillshub link");
+
+    assert!(
+        output.status.success(),
+        "link should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let reports: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should be only the JSON report");
+    let reports = reports.as_array().unwrap();
+
+    let report_for = |agent: &str| {
+        reports
+            .iter()
+            .find(|r| r["agent"] == agent)
+            .unwrap_or_else(|| panic!("missing report for {}", agent))
+            .clone()
+    };
+    let claude = report_for(".claude");
+    assert_eq!(claude["linked"], serde_json::json!(["linter"]));
+    assert_eq!(claude["skipped"], serde_json::json!([]));
+    assert_eq!(claude["synced_external"], serde_json::json!([]));
+
+    let codex = report_for(".codex");
+    assert_eq!(codex["linked"], serde_json::json!([]));
+    assert_eq!(codex["skipped"], serde_json::json!(["linter"]));
+}
@@ -0,0 +1,76 @@
+//! Tests for `--log-file`
+//!
+//! Installs a skill from a local `file://` tap and checks that the fetched
+//! URLs and file operations are written to the log while console output stays
+//! the same.
+
+mod common;
+
+use std::process::Command;
+
+fn cargo_bin(home: &std::path::Path) -> Command {
+    let mut cmd = Command::new(env!("CARGO"));
+    cmd.args(["run", "--quiet", "--"]);
+    cmd.env("SKILLSHUB_TEST_HOME", home);
+    // SKILLSHUB_HOME would take precedence over the temporary home
+    cmd.env_remove("SKILLSHUB_HOME");
+    cmd.env_remove("SKILLSHUB_LOG_FILE");
+    cmd
+}
+
+#[test]
+fn test_log_file_records_install_fetch_url() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let origin = temp.path().join("origin");
+    std::fs::create_dir_all(&origin).unwrap();
+    common::init_test_repo_with_skill(&origin, "logged-skill", "A skill for log tests");
+    let origin_url = format!("file://{}", origin.display());
+
+    let skillshub_home = temp.path().join(".skillshub");
+    std::fs::create_dir_all(&skillshub_home).unwrap();
+    let db_json = serde_json::json!({
+        "taps": {
+            "example/skills": {
+                "url": origin_url,
+                "skills_path": "skills",
+                "updated_at": null,
+                "is_default": false,
+                "cached_registry": {
+                    "name": "example/skills",
+                    "description": null,
+                    "skills": {
+                        "logged-skill": { "path": "skills/logged-skill", "description": null, "homepage": null }
+                    }
+                }
+            }
+        },
+        "installed": {},
+        "linked_agents": [],
+        "external": {}
+    });
+    std::fs::write(skillshub_home.join("db.json"), db_json.to_string()).unwrap();
+
+    let log_path = temp.path().join("skillshub.log");
+    let output = cargo_bin(temp.path())
+        .args(["--log-file"])
+        .arg(&log_path)
+        .args(["install", "example/skills/logged-skill"])
+        .output()
+        .expect("failed to run skillshub install");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "install failed: {}\n{}", stdout, stderr);
+    assert!(
+        !stdout.contains(&origin_url) && !stderr.contains(&origin_url),
+        "console output should not include the action log"
+    );
+
+    let log = std::fs::read_to_string(&log_path).expect("log file should be written");
+    assert!(
+        log.contains(&format!("git clone {}", origin_url)),
+        "log should contain the fetched URL:\n{}",
+        log
+    );
+    assert!(log.contains("copy "), "log should record file copies:\n{}", log);
+}
@@ -0,0 +1,94 @@
+//! Tests for offline mode
+//!
+//! Runs the binary with `--offline` / `SKILLSHUB_OFFLINE=1` against a temporary
+//! home directory and checks that network-only commands fail fast.
+
+use std::process::Command;
+
+fn cargo_bin(home: &std::path::Path) -> Command {
+    let mut cmd = Command::new(env!("CARGO"));
+    cmd.args(["run", "--quiet", "--"]);
+    cmd.env("SKILLSHUB_TEST_HOME", home);
+    // SKILLSHUB_HOME would take precedence over the temporary home
+    cmd.env_remove("SKILLSHUB_HOME");
+    cmd
+}
+
+#[test]
+fn test_offline_flag_rejects_tap_add() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let output = cargo_bin(temp.path())
+        .args(["--offline", "tap", "add", "https://github.com/example/skills"])
+        .output()
+        .expect("failed to run skillshub tap add");
+
+    assert!(!output.status.success(), "tap add should fail offline");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("offline mode: network disabled"),
+        "unexpected stderr: {}",
+        stderr
+    );
+    assert!(
+        !temp.path().join(".skillshub/taps/example").exists(),
+        "nothing should be cloned offline"
+    );
+}
+
+#[test]
+fn test_offline_env_tap_update_uses_cache() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let output = cargo_bin(temp.path())
+        .env("SKILLSHUB_OFFLINE", "1")
+        .args(["tap", "update"])
+        .output()
+        .expect("failed to run skillshub tap update");
+
+    assert!(output.status.success(), "tap update should succeed offline");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("Offline mode"), "unexpected stdout: {}", stdout);
+}
+
+#[test]
+fn test_offline_ignores_expired_registry_cache() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let skillshub_home = temp.path().join(".skillshub");
+    std::fs::create_dir_all(&skillshub_home).unwrap();
+
+    // A cache far older than the TTL, for a tap whose remote does not exist
+    let db_json = serde_json::json!({
+        "taps": {
+            "example/skills": {
+                "url": "https://github.com/example/skills",
+                "skills_path": "skills",
+                "updated_at": "2020-01-01T00:00:00Z",
+                "is_default": false,
+                "cached_registry": {
+                    "name": "example/skills",
+                    "description": null,
+                    "skills": {
+                        "cached-skill": { "path": "skills/cached-skill", "description": null, "homepage": null }
+                    }
+                }
+            }
+        },
+        "installed": {},
+        "linked_agents": [],
+        "external": {}
+    });
+    std::fs::write(skillshub_home.join("db.json"), db_json.to_string()).unwrap();
+
+    let output = cargo_bin(temp.path())
+        .args(["--offline", "--registry-cache-ttl", "1", "list"])
+        .output()
+        .expect("failed to run skillshub list");
+
+    assert!(output.status.success(), "list should succeed offline");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("cached-skill"), "unexpected stdout: {}", stdout);
+    assert!(
+        !stdout.contains("Refreshing"),
+        "offline mode must not refresh: {}",
+        stdout
+    );
+}
@@ -0,0 +1,115 @@
+//! Tests for `--quiet` and `--verbose`
+//!
+//! Installs a skill from a local `file://` tap and checks that `--quiet` keeps
+//! stdout empty while errors still reach stderr, and that `--verbose` echoes the
+//! fetched URL to stderr.
+
+mod common;
+
+use std::process::Command;
+
+fn cargo_bin(home: &std::path::Path) -> Command {
+    let mut cmd = Command::new(env!("CARGO"));
+    cmd.args(["run", "--quiet", "--"]);
+    cmd.env("SKILLSHUB_TEST_HOME", home);
+    // SKILLSHUB_HOME would take precedence over the temporary home
+    cmd.env_remove("SKILLSHUB_HOME");
+    cmd.env_remove("SKILLSHUB_QUIET");
+    cmd.env_remove("SKILLSHUB_VERBOSE");
+    cmd
+}
+
+/// A home with one tap backed by a local git repo holding `quiet-skill`; returns the tap URL
+fn setup_tap(home: &std::path::Path) -> String {
+    let origin = home.join("origin");
+    std::fs::create_dir_all(&origin).unwrap();
+    common::init_test_repo_with_skill(&origin, "quiet-skill", "A skill for output tests");
+    let origin_url = format!("file://{}", origin.display());
+
+    let skillshub_home = home.join(".skillshub");
+    std::fs::create_dir_all(&skillshub_home).unwrap();
+    let db_json = serde_json::json!({
+        "taps": {
+            "example/skills": {
+                "url": origin_url,
+                "skills_path": "skills",
+                "updated_at": null,
+                "is_default": false,
+                "cached_registry": {
+                    "name": "example/skills",
+                    "description": null,
+                    "skills": {
+                        "quiet-skill": { "path": "skills/quiet-skill", "description": null, "homepage": null }
+                    }
+                }
+            }
+        },
+        "installed": {},
+        "linked_agents": [],
+        "external": {}
+    });
+    std::fs::write(skillshub_home.join("db.json"), db_json.to_string()).unwrap();
+    origin_url
+}
+
+#[test]
+fn test_quiet_install_prints_nothing_but_errors() {
+    let temp = tempfile::TempDir::new().unwrap();
+    setup_tap(temp.path());
+    std::fs::create_dir_all(temp.path().join(".claude")).unwrap();
+
+    let output = cargo_bin(temp.path())
+        .args(["--quiet", "install", "example/skills/quiet-skill"])
+        .output()
+        .expect("failed to run skillshub install");
+    assert!(
+        output.status.success(),
+        "install failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        output.stdout.is_empty(),
+        "quiet install should print nothing: {}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+    assert!(temp
+        .path()
+        .join(".skillshub/skills/example/skills/quiet-skill/SKILL.md")
+        .exists());
+    assert!(temp.path().join(".claude/skills/quiet-skill").exists());
+
+    let output = cargo_bin(temp.path())
+        .args(["-q", "install", "example/skills/missing"])
+        .output()
+        .expect("failed to run skillshub install");
+    assert!(!output.status.success());
+    assert!(output.stdout.is_empty());
+    assert!(
+        String::from_utf8_lossy(&output.stderr).contains("missing"),
+        "the error should still be reported on stderr"
+    );
+}
+
+#[test]
+fn test_verbose_install_echoes_fetch_url() {
+    let temp = tempfile::TempDir::new().unwrap();
+    let origin_url = setup_tap(temp.path());
+
+    let output = cargo_bin(temp.path())
+        .args(["--verbose", "install", "example/skills/quiet-skill"])
+        .output()
+        .expect("failed to run skillshub install");
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(output.status.success(), "install failed: {}", stderr);
+    assert!(
+        stderr.contains(&format!("git clone {}", origin_url)),
+        "verbose output should include the fetched URL:\n{}",
+        stderr
+    );
+    assert!(
+        stderr.contains("copy "),
+        "verbose output should include file copies:\n{}",
+        stderr
+    );
+}
@@ -0,0 +1,101 @@
+//! Tests for cached tap registries in `list` and `search`
+//!
+//! Points the GitHub API at a mock server and checks that listing an added tap
+//! is served from `cached_registry` without any HTTP requests.
+
+mod common;
+
+use common::{skill_md, MockGitHub};
+use std::process::Command;
+
+fn cargo_bin(home: &std::path::Path, mock_url: &str) -> Command {
+    let mut cmd = Command::new(env!("CARGO"));
+    cmd.args(["run", "--quiet", "--"]);
+    cmd.env("SKILLSHUB_TEST_HOME", home);
+    cmd.env_remove("SKILLSHUB_HOME");
+    cmd.env("SKILLSHUB_GITHUB_API_BASE", mock_url);
+    cmd.env("SKILLSHUB_GITHUB_RAW_BASE", mock_url);
+    cmd.env_remove("SKILLSHUB_OFFLINE");
+    cmd.env_remove("SKILLSHUB_CACHE_TTL_SECS");
+    cmd
+}
+
+/// db.json with one added tap whose registry was cached `updated_at`
+fn db_with_cached_tap(updated_at: &str) -> String {
+    serde_json::json!({
+        "taps": {
+            "acme/skills": {
+                "url": "https://github.com/acme/skills",
+                "skills_path": "skills",
+                "updated_at": updated_at,
+                "is_default": false,
+                "cached_registry": {
+                    "name": "acme/skills",
+                    "description": null,
+                    "skills": {
+                        "deploy": {
+                            "path": "skills/deploy",
+                            "description": "Ship it to production",
+                            "homepage": null
+                        }
+                    }
+                }
+            }
+        },
+        "installed": {},
+        "linked_agents": [],
+        "external": {}
+    })
+    .to_string()
+}
+
+#[test]
+fn test_list_and_search_use_cached_registry_without_http() {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    let mock = rt.block_on(async {
+        let mock = MockGitHub::start().await;
+        mock.mock_repo_with_skills(
+            "acme",
+            "skills",
+            &[("skills/deploy", &skill_md("deploy", "Fetched from the API"))],
+        )
+        .await;
+        mock
+    });
+    let env = common::TestEnv::new();
+    env.write_db(&db_with_cached_tap("2020-01-01T00:00:00Z"));
+
+    for args in [&["list"][..], &["list"], &["search", "deploy"]] {
+        let output = cargo_bin(&env.home_dir, &mock.url())
+            .args(args)
+            .output()
+            .expect("failed to run skillshub");
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        assert!(
+            output.status.success(),
+            "{:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        assert!(
+            stdout.contains("Ship it to production"),
+            "unexpected stdout: {}",
+            stdout
+        );
+        assert!(
+            stdout.contains("may be out of date: acme/skills"),
+            "old cache should get a staleness note: {}",
+            stdout
+        );
+    }
+
+    let requests = rt.block_on(mock.server.received_requests()).unwrap_or_default();
+    assert!(
+        requests.is_empty(),
+        "cached registries must not hit the network: {:?}",
+        requests
+    );
+}
@@ -0,0 +1,61 @@
+//! Tests for `skillshub version`
+//!
+//! Points the GitHub API at a mock server serving the latest release and checks
+//! the update notice against the compiled version.
+
+mod common;
+
+use common::MockGitHub;
+use std::process::Command;
+
+fn cargo_bin(home: &std::path::Path, mock_url: &str) -> Command {
+    let mut cmd = Command::new(env!("CARGO"));
+    cmd.args(["run", "--quiet", "--"]);
+    cmd.env("SKILLSHUB_TEST_HOME", home);
+    cmd.env_remove("SKILLSHUB_HOME");
+    cmd.env("SKILLSHUB_GITHUB_API_BASE", mock_url);
+    cmd.env_remove("SKILLSHUB_OFFLINE");
+    cmd
+}
+
+/// Run `skillshub version` against a mock whose latest release is `tag`
+fn version_output(tag: &str) -> String {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    let mock = rt.block_on(async {
+        let mock = MockGitHub::start().await;
+        mock.mock_latest_release("EYH0602", "skillshub", tag).await;
+        mock
+    });
+    let env = common::TestEnv::new();
+
+    let output = cargo_bin(&env.home_dir, &mock.url())
+        .arg("version")
+        .output()
+        .expect("failed to run skillshub version");
+    assert!(
+        output.status.success(),
+        "version failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).to_string()
+}
+
+#[test]
+fn test_version_reports_available_update() {
+    let stdout = version_output("v99.0.0");
+
+    assert!(stdout.contains(&format!("skillshub {}", env!("CARGO_PKG_VERSION"))));
+    assert!(stdout.contains("Update available"), "stdout: {}", stdout);
+    assert!(stdout.contains("https://github.com/EYH0602/skillshub/releases/tag/v99.0.0"));
+}
+
+#[test]
+fn test_version_reports_up_to_date() {
+    let stdout = version_output(&format!("v{}", env!("CARGO_PKG_VERSION")));
+
+    assert!(stdout.contains("Up to date"), "stdout: {}", stdout);
+    assert!(!stdout.contains("Update available"));
+}